@@ -0,0 +1,132 @@
+//! Fast-path coverage and metrics benchmarks for atof.
+//!
+//! Parses the real dataset with metrics collection enabled, and reports
+//! how often each float type requires the moderate or slow path. Every
+//! value in the dataset has few significant digits and no exponent, so
+//! the expected fast-path coverage is 100% for both `f32` and `f64`.
+
+#[macro_use]
+extern crate lazy_static;
+extern crate criterion;
+extern crate lexical_core;
+
+use std::env;
+use std::fs::read_to_string;
+use std::path::PathBuf;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use lexical_core::parse_with_metrics as lexical_parse_with_metrics;
+
+// PATH
+
+/// Return the `target/debug` or `target/release` directory path.
+pub fn build_dir() -> PathBuf {
+    env::current_exe()
+        .expect("unittest executable path")
+        .parent()
+        .expect("unittest executable directory")
+        .parent()
+        .expect("debug/release directory")
+        .to_path_buf()
+}
+
+/// Return the `target` directory path.
+pub fn target_dir() -> PathBuf {
+    build_dir()
+        .parent()
+        .expect("target directory")
+        .to_path_buf()
+}
+
+/// Return the project directory path.
+pub fn project_dir() -> PathBuf {
+    target_dir()
+        .parent()
+        .expect("project directory")
+        .to_path_buf()
+}
+
+/// Return the `data` directory path.
+pub fn data_dir() -> PathBuf {
+    let mut dir = project_dir();
+    dir.push("data");
+    dir
+}
+
+// SAMPLE DATA
+
+fn read_data_impl() -> String {
+    let mut path = data_dir();
+    path.push("AURA_UVI_CLIM_M_2010-12-01_rgb_720x360.CSV");
+    read_to_string(&path).unwrap()
+}
+
+fn read_data() -> &'static String {
+    lazy_static! {
+        static ref DATA: String = read_data_impl();
+    }
+    &DATA
+}
+
+// COVERAGE
+
+// Print the fraction of values parsed with the fast path.
+macro_rules! coverage_generator {
+    ($name:ident, $t:ty) => (
+        fn $name() {
+            let data = read_data();
+            let mut total = 0usize;
+            let mut slow = 0usize;
+            for line in data.lines() {
+                for item in line.split(',') {
+                    let (_, metrics) = lexical_parse_with_metrics::<$t>(item.as_bytes()).unwrap();
+                    total += 1;
+                    slow += metrics.used_slow_path as usize;
+                }
+            }
+            let fast = total - slow;
+            println!("{}: {}/{} values used the fast path ({:.2}%).", stringify!($t), fast, total, 100.0 * fast as f64 / total as f64);
+        }
+    );
+}
+
+// BENCHMARK GENERATORS
+
+// Lexical atof with metrics generator.
+macro_rules! metrics_generator {
+    ($name:ident, $coverage:ident, $t:ty) => (
+        fn $name(criterion: &mut Criterion) {
+            $coverage();
+            let data = read_data();
+            criterion.bench_function(stringify!($name), |b| b.iter(|| {
+                for line in data.lines() {
+                    for item in line.split(',') {
+                        black_box(lexical_parse_with_metrics::<$t>(item.as_bytes()).unwrap());
+                    }
+                }
+            }));
+        }
+    );
+}
+
+// F32
+
+// Benchmark to real data, downloaded from NASA Earth Observation.
+// http://neo.sci.gsfc.nasa.gov/servlet/RenderData?si=1582435&cs=rgb&format=CSV&width=720&height=360
+
+coverage_generator!(atof_metrics_f32_coverage, f32);
+metrics_generator!(atof_metrics_f32_lexical, atof_metrics_f32_coverage, f32);
+
+// F64
+
+// Benchmark to real data, downloaded from NASA Earth Observation.
+// http://neo.sci.gsfc.nasa.gov/servlet/RenderData?si=1582435&cs=rgb&format=CSV&width=720&height=360
+
+coverage_generator!(atof_metrics_f64_coverage, f64);
+metrics_generator!(atof_metrics_f64_lexical, atof_metrics_f64_coverage, f64);
+
+// MAIN
+
+criterion_group!(f32_benches, atof_metrics_f32_lexical);
+criterion_group!(f64_benches, atof_metrics_f64_lexical);
+criterion_main!(f32_benches, f64_benches);
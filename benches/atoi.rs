@@ -19,6 +19,34 @@ macro_rules! lexical_generator {
     );
 }
 
+// Lexical atoi generator with a custom radix.
+#[cfg(feature = "radix")]
+macro_rules! lexical_radix_generator {
+    ($name:ident, $data:ident, $t:ty, $radix:expr) => (
+        fn $name(criterion: &mut Criterion) {
+            criterion.bench_function(stringify!($name), |b| b.iter(|| {
+                $data.iter().for_each(|x| {
+                    black_box(lexical_core::parse_radix::<$t>(x.as_bytes(), $radix).unwrap());
+                })
+            }));
+        }
+    );
+}
+
+// Parse atoi generator with a custom radix.
+#[cfg(feature = "radix")]
+macro_rules! parse_radix_generator {
+    ($name:ident, $data:ident, $t:tt, $radix:expr) => (
+        fn $name(criterion: &mut Criterion) {
+            criterion.bench_function(stringify!($name), |b| b.iter(|| {
+                $data.iter().for_each(|x| {
+                    black_box($t::from_str_radix(x, $radix).unwrap());
+                })
+            }));
+        }
+    );
+}
+
 // Parse atoi generator.
 macro_rules! parse_generator {
     ($name:ident, $data:ident, $t:tt) => (
@@ -142,6 +170,25 @@ const I128_DATA: [&'static str; 10000] = ["6173327567928819658003470088243620926
 lexical_generator!(atoi_i128_lexical, I128_DATA, i128);
 parse_generator!(atoi_i128_parse, I128_DATA, i128);
 
+// U64 16 DIGIT
+
+// Randomly generated, 16-digit decimal tokens.
+const U64_16_DIGIT_DATA: [&'static str; 100] = ["6462356893056039", "4957518291433800", "1800165574235091", "3576185741290862", "6787032527467287", "5366412619344719", "7453619377668678", "8324420411944238", "7192748317220131", "9103950531888203", "3688033010788784", "1655971750671948", "9772387939276491", "9801164896112820", "7667477248806568", "3178726784320995", "2430036399365609", "7027071570498833", "3489309507956706", "3049052027848768", "4053647225749400", "9217830580826037", "4691524628256891", "9598653617472922", "6698935289152600", "5175793985856051", "3250599836626252", "3578386893996078", "4855357189854008", "1006109602503336", "7326136216468578", "5858561380768955", "9077181099608133", "3147425947424629", "2641652613893606", "2939646229956397", "1899724000139535", "8475012364485056", "5630003065289284", "3220988232845093", "9019641211356849", "4278696445460575", "7788742437289576", "3335174058541270", "7506650664477263", "9657968063516818", "5285920863799791", "2484251480541346", "6918986624662953", "9672861671276503", "9315607049050294", "6261232575394660", "3949637162720762", "6965613170422790", "7854798118872216", "5520677807149934", "4935621793144954", "2344600797436282", "3840425800772736", "6636064111234685", "8096348712760032", "9190072955836543", "2069049843572971", "2775603216986887", "3602215691839405", "1754433217647263", "5973527930152794", "9113086759209869", "2138833198944073", "8060048490373945", "2338662372069315", "7193138651046683", "7365435027655345", "8628806365589335", "4460728217369340", "8592687028240349", "2196394135670815", "9162491152036618", "8327721306673254", "2593061237504463", "7687638633612969", "9787059537706778", "7952680351642869", "6501939412440865", "7798512143631034", "6652574067393719", "3315304220451373", "2831781173831265", "4598308174960714", "1831453139770665", "2248778980827900", "4413240758142121", "9069194929702082", "2745598489725449", "2965685749189081", "2420016636296513", "7628513126013100", "6723757480886712", "4462434222291499", "2459454642505643"];
+
+lexical_generator!(atoi_u64_16_digit_lexical, U64_16_DIGIT_DATA, u64);
+parse_generator!(atoi_u64_16_digit_parse, U64_16_DIGIT_DATA, u64);
+
+// U64 HEX
+
+// Randomly generated hexadecimal tokens.
+#[cfg(feature = "radix")]
+const U64_HEX_DATA: [&'static str; 100] = ["269ef2a74de4", "c5ca6a3a450", "d23f0824", "5d9dc9f81818e811", "e8e25d940ed90475", "99950d836f675cc", "6f03675a", "3d9c11e20b8f", "8d116ece", "d3ac0f21ddb6", "f28c105d1fb17c23", "a170b338", "f29d0da9953f48f1", "93bd04cf", "cb1e29c658cda14", "becd7b0", "2217beaddbc496cb", "24ed6b4cb242", "922766581e27a1c0", "d0ed8f6d0558", "1a61dbe22e44158b", "a38fd547923a7369", "5f557203", "8c38fb29", "907a70c31012f037", "9e7769b1", "7f150524", "6d76b07e881ed162", "95e77731af10", "4cbd5c90a958", "cb5c7427", "b2f14c94", "14f4733f", "867347214cdd2055", "57eee00902c7", "49b64a0872e6cc3a", "12bd4acefaecbd38", "830e07bc", "c1d32a3af4d4", "eeea26e87555", "a096bf46c69", "c3baea9e13deef86", "ca02135e92b1d3f2", "b1fe57124242", "7f2698289fcd", "74c9df6acc011cdd", "d70820fe", "f1d69ed6", "b271795e8229", "f88080b10a3d6b2", "4f426dcbb394fb36", "fe3b890b93f448b3", "72158370d269a9a5", "62c3b774eb52", "5c6af0758d5563d", "2b055affb229", "7e62aa0a1df9fd78", "37dc76fb", "bd05211c70cf", "65dc9f50", "df15eab477d2", "2a9614a0f9e7", "8ca866d22876", "230de2257159", "8cdbdd2e1609", "6a50b4d66a3a", "e25aaec6f024", "3b12f52ddf5d", "153e7c2a", "26bb7dbd", "a8948c89", "316909e", "96d0d4c28c2e", "43435cc5", "254b010c4759", "5e8788daf401", "519088f590fbbd11", "b0c4312d", "9e1a8ef4f341e07a", "bd628881ad1b72db", "74e69a5d", "8f2c6ec8cc4169a3", "662365e7e423", "7b451a81682c", "fef792866836886", "113db17d", "70ccec31", "1c2442f9", "d7599c94309", "f49c8", "895fd7b326b94c7f", "f2ee4e45", "6879d1de2a0", "dfd43f37", "9d33a01c", "a2682607679d", "58eef4998d7c", "7961fd925d39d0a8", "1d87cec3", "fa52fe3bfada"];
+
+#[cfg(feature = "radix")]
+lexical_radix_generator!(atoi_u64_hex_lexical, U64_HEX_DATA, u64, 16);
+#[cfg(feature = "radix")]
+parse_radix_generator!(atoi_u64_hex_parse, U64_HEX_DATA, u64, 16);
+
 // MAIN
 
 // Random data
@@ -163,9 +210,31 @@ criterion_group!(u32_simple_benches, atoi_u32_simple_lexical, atoi_u32_simple_pa
 criterion_group!(u64_simple_benches, atoi_u64_simple_lexical, atoi_u64_simple_parse);
 criterion_group!(u128_simple_benches, atoi_u128_simple_lexical, atoi_u128_simple_parse);
 
+// 16-digit data
+criterion_group!(u64_16_digit_benches, atoi_u64_16_digit_lexical, atoi_u64_16_digit_parse);
+
+// Hexadecimal data
+#[cfg(feature = "radix")]
+criterion_group!(u64_hex_benches, atoi_u64_hex_lexical, atoi_u64_hex_parse);
+
+#[cfg(feature = "radix")]
+criterion_main!(
+    // Random data
+    u8_benches, u16_benches, u32_benches, u64_benches, u128_benches, i8_benches, i16_benches, i32_benches, i64_benches, i128_benches,
+    // Simple data
+    u8_simple_benches, u16_simple_benches, u32_simple_benches, u64_simple_benches, u128_simple_benches,
+    // 16-digit data
+    u64_16_digit_benches,
+    // Hexadecimal data
+    u64_hex_benches
+);
+
+#[cfg(not(feature = "radix"))]
 criterion_main!(
     // Random data
     u8_benches, u16_benches, u32_benches, u64_benches, u128_benches, i8_benches, i16_benches, i32_benches, i64_benches, i128_benches,
     // Simple data
-    u8_simple_benches, u16_simple_benches, u32_simple_benches, u64_simple_benches, u128_simple_benches
+    u8_simple_benches, u16_simple_benches, u32_simple_benches, u64_simple_benches, u128_simple_benches,
+    // 16-digit data
+    u64_16_digit_benches
 );
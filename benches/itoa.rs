@@ -289,6 +289,34 @@ lexical_generator!(itoa_i128_lexical, I128_DATA.iter());
 itoa_generator!(itoa_i128_itoa, I128_DATA.iter());
 fmt_generator!(itoa_i128_std, I128_DATA.iter());
 
+// U32 SLICE
+
+// Lexical bulk slice writer.
+#[cfg(feature = "simd")]
+fn itoa_u32_slice_lexical(criterion: &mut Criterion) {
+    let mut buffer = vec![b'0'; U32_DATA.len() * 11];
+    criterion.bench_function("itoa_u32_slice_lexical", |b| b.iter(|| {
+        black_box(lexical_core::format_u32_slice(&U32_DATA, b',', &mut buffer));
+    }));
+}
+
+// Lexical writer for each element of the slice.
+#[cfg(feature = "simd")]
+fn itoa_u32_slice_scalar(criterion: &mut Criterion) {
+    let mut buffer = vec![b'0'; U32_DATA.len() * 11];
+    criterion.bench_function("itoa_u32_slice_scalar", |b| b.iter(|| {
+        let mut index = 0;
+        for (i, &x) in U32_DATA.iter().enumerate() {
+            if i != 0 {
+                buffer[index] = b',';
+                index += 1;
+            }
+            index += lexical_write(x, &mut buffer[index..]).len();
+        }
+        black_box(index);
+    }));
+}
+
 // MAIN
 
 // Random data
@@ -317,6 +345,23 @@ criterion_group!(u32_heterogeneous_benches, itoa_u32_heterogeneous_lexical, itoa
 criterion_group!(u64_heterogeneous_benches, itoa_u64_heterogeneous_lexical, itoa_u64_heterogeneous_itoa, itoa_u64_heterogeneous_std);
 criterion_group!(u128_heterogeneous_benches, itoa_u128_heterogeneous_lexical, itoa_u128_heterogeneous_itoa, itoa_u128_heterogeneous_std);
 
+// Slice data
+#[cfg(feature = "simd")]
+criterion_group!(u32_slice_benches, itoa_u32_slice_lexical, itoa_u32_slice_scalar);
+
+#[cfg(feature = "simd")]
+criterion_main!(
+    // Random data
+    u8_benches, u16_benches, u32_benches, u64_benches, u128_benches, i8_benches, i16_benches, i32_benches, i64_benches, i128_benches,
+    // Simple data
+    u8_simple_benches, u16_simple_benches, u32_simple_benches, u64_simple_benches, u128_simple_benches,
+    // Heterogeneous data.
+    u8_heterogeneous_benches, u16_heterogeneous_benches, u32_heterogeneous_benches, u64_heterogeneous_benches, u128_heterogeneous_benches,
+    // Slice data
+    u32_slice_benches
+);
+
+#[cfg(not(feature = "simd"))]
 criterion_main!(
     // Random data
     u8_benches, u16_benches, u32_benches, u64_benches, u128_benches, i8_benches, i16_benches, i32_benches, i64_benches, i128_benches,
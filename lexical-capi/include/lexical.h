@@ -183,6 +183,14 @@ enum lexical_error_code {
     lexical_exponent_without_fraction = -14,
     // Integer had invalid leading zeros.
     lexical_invalid_leading_zeros = -15,
+    // Sign was found after the leading sign.
+    lexical_invalid_sign = -16,
+    // Parsed value was outside of the bounds.
+    lexical_out_of_range = -17,
+    // Parsed value was zero, but must be non-zero.
+    lexical_zero = -18,
+    // Parsed value had the wrong number of significant digits.
+    lexical_invalid_significant_digits = -19,
 };
 
 // Declare extern to lexical function definitions.
@@ -1568,6 +1576,8 @@ enum lexical_error_code {
 struct lexical_error {
     int32_t code;
     size_t index;
+    uint8_t byte;
+    bool has_byte;
 };
 
 // Determine if an error code matches the desired code.
@@ -1592,6 +1602,10 @@ lexical_is_error(invalid_positive_exponent_sign);
 lexical_is_error(missing_exponent_sign);
 lexical_is_error(exponent_without_fraction);
 lexical_is_error(invalid_leading_zeros);
+lexical_is_error(invalid_sign);
+lexical_is_error(out_of_range);
+lexical_is_error(zero);
+lexical_is_error(invalid_significant_digits);
 
 // RESULT TAG
 
@@ -825,6 +825,10 @@ enum class error_code: int32_t {
     missing_exponent_sign = ::lexical_missing_exponent_sign,
     exponent_without_fraction = ::lexical_exponent_without_fraction,
     invalid_leading_zeros = ::lexical_invalid_leading_zeros,
+    invalid_sign = ::lexical_invalid_sign,
+    out_of_range = ::lexical_out_of_range,
+    zero = ::lexical_zero,
+    invalid_significant_digits = ::lexical_invalid_significant_digits,
 };
 
 // Determine if an error code matches the desired code.
@@ -839,6 +843,8 @@ struct error
 {
     error_code code;
     size_t index;
+    uint8_t byte;
+    bool has_byte;
 
     lexical_is_error(overflow);
     lexical_is_error(underflow);
@@ -855,6 +861,10 @@ struct error
     lexical_is_error(missing_exponent_sign);
     lexical_is_error(exponent_without_fraction);
     lexical_is_error(invalid_leading_zeros);
+    lexical_is_error(invalid_sign);
+    lexical_is_error(out_of_range);
+    lexical_is_error(zero);
+    lexical_is_error(invalid_significant_digits);
 
     inline friend bool operator==(const error& lhs, const error& rhs)
     {
@@ -1523,13 +1523,19 @@ class ErrorCode(enum.Enum):
     MissingExponentSign = -13
     ExponentWithoutFraction = -14
     InvalidLeadingZeros = -15
+    InvalidSign = -16
+    OutOfRange = -17
+    Zero = -18
+    InvalidSignificantDigits = -19
 
 class Error(Structure):
     '''C-compatible error for FFI.'''
 
     _fields_ = [
         ("_code", c_int),
-        ("index", c_size_t)
+        ("index", c_size_t),
+        ("byte", c_ubyte),
+        ("has_byte", c_bool)
     ]
 
     @property
@@ -1587,6 +1593,18 @@ class Error(Structure):
     def is_invalid_leading_zeros(self):
         return self.code == ErrorCode.InvalidLeadingZeros
 
+    def is_invalid_sign(self):
+        return self.code == ErrorCode.InvalidSign
+
+    def is_out_of_range(self):
+        return self.code == ErrorCode.OutOfRange
+
+    def is_zero(self):
+        return self.code == ErrorCode.Zero
+
+    def is_invalid_significant_digits(self):
+        return self.code == ErrorCode.InvalidSignificantDigits
+
 class LexicalError(Exception):
     '''Python-native exception raised during errors in lexical parsing.'''
 
@@ -1599,6 +1617,8 @@ class LexicalError(Exception):
             return 'Numeric overflow occurred at index {}'.format(self.error.index)
         elif code == ErrorCode.Underflow:
             return 'Numeric underflow occurred at index {}'.format(self.error.index)
+        elif code == ErrorCode.InvalidDigit and self.error.has_byte:
+            return 'Invalid digit {!r} found at index {}'.format(chr(self.error.byte), self.error.index)
         elif code == ErrorCode.InvalidDigit:
             return 'Invalid digit found at index {}'.format(self.error.index)
         elif code == ErrorCode.Empty:
@@ -49,17 +49,15 @@ pub unsafe extern fn lexical_get_exponent_default_char() -> u8
 /// Default character for scientific notation, used when the radix < 15.
 ///
 /// To change the expected, default character for an exponent,
-/// change this value before using lexical.
+/// change this value before using lexical. The exponent characters
+/// and special strings are frozen once any of them is read.
 ///
 /// * `ch`      - Character for exponent symbol.
 ///
-/// # Safety
-///
-/// Do not call this function in threaded-code, as it is not thread-safe.
-///
 /// # Panics
 ///
-/// Panics if the character is in the character set `[A-Da-d.+\-]`.
+/// Panics if the character is in the character set `[A-Da-d.+\-]`,
+/// or if the character was already read and `ch` is different.
 #[doc(hidden)]
 #[no_mangle]
 pub unsafe extern fn lexical_set_exponent_default_char(ch: u8)
@@ -85,17 +83,15 @@ pub unsafe extern fn lexical_get_exponent_backup_char() -> u8
 /// and therefore may no longer be used as a marker for the exponent.
 ///
 /// To change the expected, backup character for an exponent,
-/// change this value before using lexical.
+/// change this value before using lexical. The exponent characters
+/// and special strings are frozen once any of them is read.
 ///
 /// * `ch`      - Character for exponent symbol.
 ///
-/// # Safety
-///
-/// Do not call this function in threaded-code, as it is not thread-safe.
-///
 /// # Panics
 ///
-/// Panics if the character is in the character set `[A-Za-z.+\-]`.
+/// Panics if the character is in the character set `[A-Za-z.+\-]`,
+/// or if the character was already read and `ch` is different.
 #[cfg(feature ="radix")]
 #[doc(hidden)]
 #[no_mangle]
@@ -158,9 +154,13 @@ pub unsafe extern fn lexical_get_nan_string(ptr: *mut *const u8, size: *mut usiz
 /// * `ptr`     - Pointer to the first character in the contiguous string.
 /// * `size`    - Size of the string, without the null-terminator.
 ///
+/// The exponent characters and special strings are frozen once any
+/// of them is read, so set the string before parsing or writing floats.
+///
 /// # Panics
 ///
-/// Panics if `bytes.len() >= MAX_F32_SIZE`.
+/// Panics if `bytes.len() >= MAX_F32_SIZE`, or if the string was
+/// already read and the new string is different.
 #[doc(hidden)]
 #[no_mangle]
 pub unsafe extern fn lexical_set_nan_string(ptr: *const u8, size: usize)
@@ -191,9 +191,13 @@ pub unsafe extern fn lexical_get_inf_string(ptr: *mut *const u8, size: *mut usiz
 /// * `ptr`     - Pointer to the first character in the contiguous string.
 /// * `size`    - Size of the string, without the null-terminator.
 ///
+/// The exponent characters and special strings are frozen once any
+/// of them is read, so set the string before parsing or writing floats.
+///
 /// # Panics
 ///
-/// Panics if `bytes.len() >= MAX_F32_SIZE`.
+/// Panics if `bytes.len() >= MAX_F32_SIZE`, or if the string was
+/// already read and the new string is different.
 #[doc(hidden)]
 #[no_mangle]
 pub unsafe extern fn lexical_set_inf_string(ptr: *const u8, size: usize)
@@ -224,9 +228,13 @@ pub unsafe extern fn lexical_get_infinity_string(ptr: *mut *const u8, size: *mut
 /// * `ptr`     - Pointer to the first character in the contiguous string.
 /// * `size`    - Size of the string, without the null-terminator.
 ///
+/// The exponent characters and special strings are frozen once any
+/// of them is read, so set the string before parsing or writing floats.
+///
 /// # Panics
 ///
-/// Panics if `bytes.len() >= MAX_F32_SIZE`.
+/// Panics if `bytes.len() >= MAX_F32_SIZE`, or if the string was
+/// already read and the new string is different.
 #[doc(hidden)]
 #[no_mangle]
 pub unsafe extern fn lexical_set_infinity_string(ptr: *const u8, size: usize)
@@ -74,6 +74,8 @@ inline lexical_i8_result result_err(int32_t code, size_t index)
     lexical_error e;
     e.code = code;
     e.index = index;
+    e.byte = 0;
+    e.has_byte = false;
 
     // Initialize the union.
     lexical_i8_result_union u;
@@ -108,6 +110,10 @@ lexical_result_error(invalid_positive_exponent_sign);
 lexical_result_error(missing_exponent_sign);
 lexical_result_error(exponent_without_fraction);
 lexical_result_error(invalid_leading_zeros);
+lexical_result_error(invalid_sign);
+lexical_result_error(out_of_range);
+lexical_result_error(zero);
+lexical_result_error(invalid_significant_digits);
 
 inline lexical_i8_partial_result partial_result_ok(lexical_i8 value, size_t index)
 {
@@ -134,6 +140,8 @@ inline lexical_i8_partial_result partial_result_err(int32_t code, size_t index)
     lexical_error e;
     e.code = code;
     e.index = index;
+    e.byte = 0;
+    e.has_byte = false;
 
     // Initialize the union.
     lexical_i8_partial_result_union u;
@@ -168,6 +176,10 @@ lexical_partial_result_error(invalid_positive_exponent_sign);
 lexical_partial_result_error(missing_exponent_sign);
 lexical_partial_result_error(exponent_without_fraction);
 lexical_partial_result_error(invalid_leading_zeros);
+lexical_partial_result_error(invalid_sign);
+lexical_partial_result_error(out_of_range);
+lexical_partial_result_error(zero);
+lexical_partial_result_error(invalid_significant_digits);
 
 #define lexical_is_error(type)                                                  \
     inline bool is_##type(lexical_error error)                                  \
@@ -190,6 +202,10 @@ lexical_is_error(invalid_positive_exponent_sign);
 lexical_is_error(missing_exponent_sign);
 lexical_is_error(exponent_without_fraction);
 lexical_is_error(invalid_leading_zeros);
+lexical_is_error(invalid_sign);
+lexical_is_error(out_of_range);
+lexical_is_error(zero);
+lexical_is_error(invalid_significant_digits);
 
 // CONFIG TESTS
 // ------------
@@ -422,6 +438,38 @@ TEST(test_is_invalid_leading_zeros, error_tests)
     EXPECT_TRUE(lexical_error_is_invalid_leading_zeros(&invalid_leading_zeros));
 }
 
+TEST(test_is_invalid_sign, error_tests)
+{
+    lexical_error overflow = { lexical_overflow, 0 };
+    lexical_error invalid_sign = { lexical_invalid_sign, 0 };
+    EXPECT_FALSE(lexical_error_is_invalid_sign(&overflow));
+    EXPECT_TRUE(lexical_error_is_invalid_sign(&invalid_sign));
+}
+
+TEST(test_is_out_of_range, error_tests)
+{
+    lexical_error overflow = { lexical_overflow, 0 };
+    lexical_error out_of_range = { lexical_out_of_range, 0 };
+    EXPECT_FALSE(lexical_error_is_out_of_range(&overflow));
+    EXPECT_TRUE(lexical_error_is_out_of_range(&out_of_range));
+}
+
+TEST(test_is_zero, error_tests)
+{
+    lexical_error overflow = { lexical_overflow, 0 };
+    lexical_error zero = { lexical_zero, 0 };
+    EXPECT_FALSE(lexical_error_is_zero(&overflow));
+    EXPECT_TRUE(lexical_error_is_zero(&zero));
+}
+
+TEST(test_is_invalid_significant_digits, error_tests)
+{
+    lexical_error overflow = { lexical_overflow, 0 };
+    lexical_error invalid_significant_digits = { lexical_invalid_significant_digits, 0 };
+    EXPECT_FALSE(lexical_error_is_invalid_significant_digits(&overflow));
+    EXPECT_TRUE(lexical_error_is_invalid_significant_digits(&invalid_significant_digits));
+}
+
 // RESULT TESTS
 
 TEST(result, result_tests)
@@ -442,6 +490,10 @@ TEST(result, result_tests)
     auto missing_exponent_sign = result_missing_exponent_sign(0);
     auto exponent_without_fraction = result_exponent_without_fraction(0);
     auto invalid_leading_zeros = result_invalid_leading_zeros(0);
+    auto invalid_sign = result_invalid_sign(0);
+    auto out_of_range = result_out_of_range(0);
+    auto zero = result_zero(0);
+    auto invalid_significant_digits = result_invalid_significant_digits(0);
 
     EXPECT_TRUE(lexical_i8_result_is_ok(&ok));
     EXPECT_FALSE(lexical_i8_result_is_err(&ok));
@@ -460,6 +512,10 @@ TEST(result, result_tests)
     EXPECT_TRUE(lexical_i8_result_is_err(&missing_exponent_sign));
     EXPECT_TRUE(lexical_i8_result_is_err(&exponent_without_fraction));
     EXPECT_TRUE(lexical_i8_result_is_err(&invalid_leading_zeros));
+    EXPECT_TRUE(lexical_i8_result_is_err(&invalid_sign));
+    EXPECT_TRUE(lexical_i8_result_is_err(&out_of_range));
+    EXPECT_TRUE(lexical_i8_result_is_err(&zero));
+    EXPECT_TRUE(lexical_i8_result_is_err(&invalid_significant_digits));
 
     EXPECT_EQ(lexical_i8_result_ok(ok), 0);
     EXPECT_TRUE(is_overflow(lexical_i8_result_err(overflow)));
@@ -477,6 +533,10 @@ TEST(result, result_tests)
     EXPECT_TRUE(is_missing_exponent_sign(lexical_i8_result_err(missing_exponent_sign)));
     EXPECT_TRUE(is_exponent_without_fraction(lexical_i8_result_err(exponent_without_fraction)));
     EXPECT_TRUE(is_invalid_leading_zeros(lexical_i8_result_err(invalid_leading_zeros)));
+    EXPECT_TRUE(is_invalid_sign(lexical_i8_result_err(invalid_sign)));
+    EXPECT_TRUE(is_out_of_range(lexical_i8_result_err(out_of_range)));
+    EXPECT_TRUE(is_zero(lexical_i8_result_err(zero)));
+    EXPECT_TRUE(is_invalid_significant_digits(lexical_i8_result_err(invalid_significant_digits)));
 }
 
 // PARTIAL RESULT TESTS
@@ -499,6 +559,10 @@ TEST(partial_result, partial_result_tests)
     auto missing_exponent_sign = partial_result_missing_exponent_sign(0);
     auto exponent_without_fraction = partial_result_exponent_without_fraction(0);
     auto invalid_leading_zeros = partial_result_invalid_leading_zeros(0);
+    auto invalid_sign = partial_result_invalid_sign(0);
+    auto out_of_range = partial_result_out_of_range(0);
+    auto zero = partial_result_zero(0);
+    auto invalid_significant_digits = partial_result_invalid_significant_digits(0);
 
     EXPECT_TRUE(lexical_i8_partial_result_is_ok(&ok));
     EXPECT_FALSE(lexical_i8_partial_result_is_err(&ok));
@@ -517,6 +581,10 @@ TEST(partial_result, partial_result_tests)
     EXPECT_TRUE(lexical_i8_partial_result_is_err(&missing_exponent_sign));
     EXPECT_TRUE(lexical_i8_partial_result_is_err(&exponent_without_fraction));
     EXPECT_TRUE(lexical_i8_partial_result_is_err(&invalid_leading_zeros));
+    EXPECT_TRUE(lexical_i8_partial_result_is_err(&invalid_sign));
+    EXPECT_TRUE(lexical_i8_partial_result_is_err(&out_of_range));
+    EXPECT_TRUE(lexical_i8_partial_result_is_err(&zero));
+    EXPECT_TRUE(lexical_i8_partial_result_is_err(&invalid_significant_digits));
 
     EXPECT_EQ(lexical_i8_partial_result_ok(ok).x, 0);
     EXPECT_TRUE(is_overflow(lexical_i8_partial_result_err(overflow)));
@@ -534,4 +602,8 @@ TEST(partial_result, partial_result_tests)
     EXPECT_TRUE(is_missing_exponent_sign(lexical_i8_partial_result_err(missing_exponent_sign)));
     EXPECT_TRUE(is_exponent_without_fraction(lexical_i8_partial_result_err(exponent_without_fraction)));
     EXPECT_TRUE(is_invalid_leading_zeros(lexical_i8_partial_result_err(invalid_leading_zeros)));
+    EXPECT_TRUE(is_invalid_sign(lexical_i8_partial_result_err(invalid_sign)));
+    EXPECT_TRUE(is_out_of_range(lexical_i8_partial_result_err(out_of_range)));
+    EXPECT_TRUE(is_zero(lexical_i8_partial_result_err(zero)));
+    EXPECT_TRUE(is_invalid_significant_digits(lexical_i8_partial_result_err(invalid_significant_digits)));
 }
@@ -60,6 +60,8 @@ inline result<T> result_err(error_code code, size_t index)
     error e;
     e.code = code;
     e.index = index;
+    e.byte = 0;
+    e.has_byte = false;
 
     // Initialize the union.
     result_union<T> u;
@@ -95,6 +97,10 @@ lexical_result_error(invalid_positive_exponent_sign);
 lexical_result_error(missing_exponent_sign);
 lexical_result_error(exponent_without_fraction);
 lexical_result_error(invalid_leading_zeros);
+lexical_result_error(invalid_sign);
+lexical_result_error(out_of_range);
+lexical_result_error(zero);
+lexical_result_error(invalid_significant_digits);
 
 template <typename T>
 inline partial_result<T> partial_result_ok(T value, size_t index)
@@ -123,6 +129,8 @@ inline partial_result<T> partial_result_err(error_code code, size_t index)
     error e;
     e.code = code;
     e.index = index;
+    e.byte = 0;
+    e.has_byte = false;
 
     // Initialize the union.
     partial_result_union<T> u;
@@ -158,6 +166,10 @@ lexical_partial_result_error(invalid_positive_exponent_sign);
 lexical_partial_result_error(missing_exponent_sign);
 lexical_partial_result_error(exponent_without_fraction);
 lexical_partial_result_error(invalid_leading_zeros);
+lexical_partial_result_error(invalid_sign);
+lexical_partial_result_error(out_of_range);
+lexical_partial_result_error(zero);
+lexical_partial_result_error(invalid_significant_digits);
 
 // CONFIG TESTS
 // ------------
@@ -466,6 +478,38 @@ TEST(test_is_invalid_leading_zeros, error_tests)
     EXPECT_TRUE(invalid_leading_zeros.is_invalid_leading_zeros());
 }
 
+TEST(test_is_invalid_sign, error_tests)
+{
+    error overflow = { error_code::overflow, 0 };
+    error invalid_sign = { error_code::invalid_sign, 0 };
+    EXPECT_FALSE(overflow.is_invalid_sign());
+    EXPECT_TRUE(invalid_sign.is_invalid_sign());
+}
+
+TEST(test_is_out_of_range, error_tests)
+{
+    error overflow = { error_code::overflow, 0 };
+    error out_of_range = { error_code::out_of_range, 0 };
+    EXPECT_FALSE(overflow.is_out_of_range());
+    EXPECT_TRUE(out_of_range.is_out_of_range());
+}
+
+TEST(test_is_zero, error_tests)
+{
+    error overflow = { error_code::overflow, 0 };
+    error zero = { error_code::zero, 0 };
+    EXPECT_FALSE(overflow.is_zero());
+    EXPECT_TRUE(zero.is_zero());
+}
+
+TEST(test_is_invalid_significant_digits, error_tests)
+{
+    error overflow = { error_code::overflow, 0 };
+    error invalid_significant_digits = { error_code::invalid_significant_digits, 0 };
+    EXPECT_FALSE(overflow.is_invalid_significant_digits());
+    EXPECT_TRUE(invalid_significant_digits.is_invalid_significant_digits());
+}
+
 // RESULT TESTS
 
 TEST(result, result_tests)
@@ -486,6 +530,10 @@ TEST(result, result_tests)
     auto missing_exponent_sign = result_missing_exponent_sign<u8>(0);
     auto exponent_without_fraction = result_exponent_without_fraction<u8>(0);
     auto invalid_leading_zeros = result_invalid_leading_zeros<u8>(0);
+    auto invalid_sign = result_invalid_sign<u8>(0);
+    auto out_of_range = result_out_of_range<u8>(0);
+    auto zero = result_zero<u8>(0);
+    auto invalid_significant_digits = result_invalid_significant_digits<u8>(0);
 
     EXPECT_TRUE(ok.is_ok());
     EXPECT_FALSE(ok.is_err());
@@ -504,6 +552,10 @@ TEST(result, result_tests)
     EXPECT_TRUE(missing_exponent_sign.is_err());
     EXPECT_TRUE(exponent_without_fraction.is_err());
     EXPECT_TRUE(invalid_leading_zeros.is_err());
+    EXPECT_TRUE(invalid_sign.is_err());
+    EXPECT_TRUE(out_of_range.is_err());
+    EXPECT_TRUE(zero.is_err());
+    EXPECT_TRUE(invalid_significant_digits.is_err());
 
     EXPECT_EQ(ok.ok(), 0);
     EXPECT_TRUE(overflow.err().is_overflow());
@@ -521,6 +573,10 @@ TEST(result, result_tests)
     EXPECT_TRUE(missing_exponent_sign.err().is_missing_exponent_sign());
     EXPECT_TRUE(exponent_without_fraction.err().is_exponent_without_fraction());
     EXPECT_TRUE(invalid_leading_zeros.err().is_invalid_leading_zeros());
+    EXPECT_TRUE(invalid_sign.err().is_invalid_sign());
+    EXPECT_TRUE(out_of_range.err().is_out_of_range());
+    EXPECT_TRUE(zero.err().is_zero());
+    EXPECT_TRUE(invalid_significant_digits.err().is_invalid_significant_digits());
 }
 
 // PARTIAL RESULT TESTS
@@ -543,6 +599,10 @@ TEST(partial_result, partial_result_tests)
     auto missing_exponent_sign = partial_result_missing_exponent_sign<u8>(0);
     auto exponent_without_fraction = partial_result_exponent_without_fraction<u8>(0);
     auto invalid_leading_zeros = partial_result_invalid_leading_zeros<u8>(0);
+    auto invalid_sign = partial_result_invalid_sign<u8>(0);
+    auto out_of_range = partial_result_out_of_range<u8>(0);
+    auto zero = partial_result_zero<u8>(0);
+    auto invalid_significant_digits = partial_result_invalid_significant_digits<u8>(0);
 
     EXPECT_TRUE(ok.is_ok());
     EXPECT_FALSE(ok.is_err());
@@ -561,6 +621,10 @@ TEST(partial_result, partial_result_tests)
     EXPECT_TRUE(missing_exponent_sign.is_err());
     EXPECT_TRUE(exponent_without_fraction.is_err());
     EXPECT_TRUE(invalid_leading_zeros.is_err());
+    EXPECT_TRUE(invalid_sign.is_err());
+    EXPECT_TRUE(out_of_range.is_err());
+    EXPECT_TRUE(zero.is_err());
+    EXPECT_TRUE(invalid_significant_digits.is_err());
 
     EXPECT_EQ(ok.ok(), std::make_tuple(0, 1));
     EXPECT_TRUE(overflow.err().is_overflow());
@@ -578,6 +642,10 @@ TEST(partial_result, partial_result_tests)
     EXPECT_TRUE(missing_exponent_sign.err().is_missing_exponent_sign());
     EXPECT_TRUE(exponent_without_fraction.err().is_exponent_without_fraction());
     EXPECT_TRUE(invalid_leading_zeros.err().is_invalid_leading_zeros());
+    EXPECT_TRUE(invalid_sign.err().is_invalid_sign());
+    EXPECT_TRUE(out_of_range.err().is_out_of_range());
+    EXPECT_TRUE(zero.err().is_zero());
+    EXPECT_TRUE(invalid_significant_digits.err().is_invalid_significant_digits());
 }
 
 // TO STRING TESTS
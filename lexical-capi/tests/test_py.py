@@ -179,6 +179,10 @@ class ErrorTests(unittest.TestCase):
         self.missing_exponent_sign = lexical.Error(lexical.ErrorCode.MissingExponentSign.value, 0)
         self.exponent_without_fraction = lexical.Error(lexical.ErrorCode.ExponentWithoutFraction.value, 0)
         self.invalid_leading_zeros = lexical.Error(lexical.ErrorCode.InvalidLeadingZeros.value, 0)
+        self.invalid_sign = lexical.Error(lexical.ErrorCode.InvalidSign.value, 0)
+        self.out_of_range = lexical.Error(lexical.ErrorCode.OutOfRange.value, 0)
+        self.zero = lexical.Error(lexical.ErrorCode.Zero.value, 0)
+        self.invalid_significant_digits = lexical.Error(lexical.ErrorCode.InvalidSignificantDigits.value, 0)
 
     def test_is_overflow(self):
         self.assertTrue(self.overflow.is_overflow())
@@ -240,6 +244,22 @@ class ErrorTests(unittest.TestCase):
         self.assertFalse(self.overflow.is_invalid_leading_zeros())
         self.assertTrue(self.invalid_leading_zeros.is_invalid_leading_zeros())
 
+    def test_is_invalid_sign(self):
+        self.assertFalse(self.overflow.is_invalid_sign())
+        self.assertTrue(self.invalid_sign.is_invalid_sign())
+
+    def test_is_out_of_range(self):
+        self.assertFalse(self.overflow.is_out_of_range())
+        self.assertTrue(self.out_of_range.is_out_of_range())
+
+    def test_is_zero(self):
+        self.assertFalse(self.overflow.is_zero())
+        self.assertTrue(self.zero.is_zero())
+
+    def test_is_invalid_significant_digits(self):
+        self.assertFalse(self.overflow.is_invalid_significant_digits())
+        self.assertTrue(self.invalid_significant_digits.is_invalid_significant_digits())
+
 
 class ResultTests(unittest.TestCase):
     '''Test complete and partial result types.'''
@@ -0,0 +1,499 @@
+//! Find the floats bracketing the exact value of a decimal string.
+//!
+//! The nearest float is parsed with the correct algorithm, and the
+//! exact significant digits are compared to the nearest float, scaled
+//! to big integers, to determine the neighboring float on the other
+//! side of the exact value.
+
+use crate::float::*;
+use crate::lib::cmp;
+use crate::util::*;
+use super::bhcomp;
+use super::bignum::*;
+use super::math::*;
+
+// HELPERS
+
+/// Decimal digits and exponent of a valid, decimal float string.
+struct Decimal<'a> {
+    /// Sign of the float.
+    sign: Sign,
+    /// Digits before the decimal point.
+    integer: &'a [u8],
+    /// Digits after the decimal point.
+    fraction: &'a [u8],
+    /// Exponent, saturated to avoid overflow.
+    exponent: i64,
+}
+
+/// Split a valid, decimal float string into the digits and exponent.
+///
+/// The string must already be parsed successfully as a float.
+fn split_decimal<'a>(bytes: &'a [u8]) -> Decimal<'a> {
+    let (sign, bytes) = match bytes.first() {
+        Some(&b'-') => (Sign::Negative, &index!(bytes[1..])),
+        Some(&b'+') => (Sign::Positive, &index!(bytes[1..])),
+        _           => (Sign::Positive, bytes),
+    };
+    let is_digit = |c: &u8| c.is_ascii_digit();
+    let integer_len = bytes.iter().take_while(|c| is_digit(c)).count();
+    let (integer, bytes) = bytes.split_at(integer_len);
+    let (fraction, bytes) = match bytes.first() {
+        Some(&b'.') => {
+            let bytes = &index!(bytes[1..]);
+            let fraction_len = bytes.iter().take_while(|c| is_digit(c)).count();
+            bytes.split_at(fraction_len)
+        },
+        _           => (&index!(bytes[..0]), bytes),
+    };
+
+    // Skip the exponent character, and parse the exponent.
+    let mut exponent: i64 = 0;
+    if !bytes.is_empty() {
+        let bytes = &index!(bytes[1..]);
+        let (is_negative, digits) = match bytes.first() {
+            Some(&b'-') => (true, &index!(bytes[1..])),
+            Some(&b'+') => (false, &index!(bytes[1..])),
+            _           => (false, bytes),
+        };
+        for &c in digits {
+            // Saturate far beyond any exponent of a finite, non-zero float.
+            exponent = (exponent * 10 + (c - b'0') as i64).min(1 << 40);
+        }
+        if is_negative {
+            exponent = -exponent;
+        }
+    }
+
+    Decimal { sign, integer, fraction, exponent }
+}
+
+/// Parse the significant digits into a big integer.
+fn parse_digits<'a, Iter>(digits: Iter) -> Bigint
+    where Iter: Iterator<Item=&'a u8>
+{
+    let small_powers = Bigint::small_powers(10);
+    let step = small_powers.len() - 2;
+    let mut result = Bigint::default();
+    let mut value: Limb = 0;
+    let mut counter = 0;
+    for &c in digits {
+        if counter == step {
+            result.imul_small(small_powers[counter]);
+            result.iadd_small(value);
+            counter = 0;
+            value = 0;
+        }
+        value = value * 10 + as_limb(c - b'0');
+        counter += 1;
+    }
+    if counter != 0 {
+        result.imul_small(small_powers[counter]);
+        result.iadd_small(value);
+    }
+
+    result
+}
+
+/// Get the decimal exponent of the first non-zero digit.
+///
+/// The digits must not all be zero.
+fn magnitude(decimal: &Decimal) -> i64 {
+    let digits = decimal.integer.iter().chain(decimal.fraction.iter());
+    let leading = digits.take_while(|&&c| c == b'0').count() as i64;
+    decimal.integer.len() as i64 - 1 - leading + decimal.exponent
+}
+
+/// Compare the exact decimal value to a positive, finite, non-zero float.
+fn compare_exact<F: Float>(decimal: &Decimal, value: F) -> cmp::Ordering {
+    compare_scaled::<F>(decimal, value.mantissa().as_u64(), value.exponent())
+}
+
+/// Compare the exact decimal value to the positive value `mantissa * 2^binary_exponent`.
+///
+/// The value must be between zero and the largest finite float,
+/// exclusive, or the halfway point between the largest finite float and
+/// infinity, and the decimal value must be non-zero and within a few
+/// orders of magnitude of the value.
+fn compare_scaled<F: Float>(decimal: &Decimal, mantissa: u64, binary_exponent: i32) -> cmp::Ordering {
+    // Get the significant digits, without leading or trailing zeros.
+    let digits = || decimal.integer.iter().chain(decimal.fraction.iter());
+    let count = decimal.integer.len() + decimal.fraction.len();
+    let leading = digits().take_while(|&&c| c == b'0').count();
+    let trailing = digits().rev().take_while(|&&c| c == b'0').count();
+    let significant = count - leading - trailing;
+    let mut exponent = decimal.exponent - decimal.fraction.len() as i64 + trailing as i64;
+
+    // The float has fewer significant digits than the maximum, so any
+    // truncated digits can be replaced by a single non-zero digit.
+    let max_digits = bhcomp::max_digits::<F>(10).unwrap();
+    let mut real = match significant > max_digits {
+        true  => {
+            let mut real = parse_digits(digits().skip(leading).take(max_digits));
+            real.imul_small(10);
+            real.iadd_small(1);
+            exponent += (significant - max_digits - 1) as i64;
+            real
+        },
+        false => parse_digits(digits().skip(leading).take(significant)),
+    };
+
+    // Scale both values to integers, exactly.
+    // The exponent is bounded, since the float is finite and non-zero.
+    let mut theoretical = Bigint::from_u64(mantissa);
+    match exponent >= 0 {
+        true  => real.imul_pow10(exponent as u32),
+        false => theoretical.imul_pow10((-exponent) as u32),
+    }
+    match binary_exponent >= 0 {
+        true  => theoretical.imul_pow2(binary_exponent as u32),
+        false => real.imul_pow2((-binary_exponent) as u32),
+    }
+
+    real.compare(&theoretical)
+}
+
+/// Find the floats bracketing the exact decimal value.
+///
+/// The value is the nearest float to the absolute exact value.
+fn bracket<F: Float>(decimal: &Decimal, value: F) -> (F, F) {
+    let is_zero = decimal.integer.iter().chain(decimal.fraction.iter()).all(|&c| c == b'0');
+    if is_zero {
+        (value, value)
+    } else if value.is_zero() {
+        // Underflow, the exact value is above zero.
+        (value, value.next_positive())
+    } else if value.is_inf() {
+        // Overflow, the exact value is below infinity.
+        (F::MAX, value)
+    } else {
+        match compare_exact(decimal, value) {
+            cmp::Ordering::Equal   => (value, value),
+            cmp::Ordering::Greater => (value, value.next_positive()),
+            cmp::Ordering::Less    => (value.prev_positive(), value),
+        }
+    }
+}
+
+/// Compare the exact decimal value to the halfway point between the bracketing floats.
+///
+/// The value is the nearest float to the absolute exact value, and the
+/// floats are the positive bracket of the absolute exact value.
+fn compare_halfway<F: Float>(decimal: &Decimal, lower: F, upper: F) -> cmp::Ordering {
+    if lower == upper {
+        return cmp::Ordering::Equal;
+    }
+
+    // The halfway point is above zero, and does not exceed halfway to
+    // the float after the largest finite float, so the exact value is
+    // far above or below it if the decimal exponent is very large or
+    // small, since `10^x > 2^(3x)` if `x > 0`, and `10^x < 2^(3x)` if
+    // `x < 0`. Otherwise, the values are scaled to big integers.
+    let magnitude = magnitude(decimal);
+    if 3 * magnitude > (F::MAX_EXPONENT + F::MANTISSA_SIZE + 2) as i64 {
+        cmp::Ordering::Greater
+    } else if 3 * (magnitude + 1) < (F::DENORMAL_EXPONENT - 1) as i64 {
+        cmp::Ordering::Less
+    } else {
+        // The next float always has the same exponent, but an extra bit
+        // in the mantissa, so the halfway point is `(2m + 1) * 2^(e - 1)`.
+        let mantissa = 2 * lower.mantissa().as_u64() + 1;
+        compare_scaled::<F>(decimal, mantissa, lower.exponent() - 1)
+    }
+}
+
+// API
+
+/// Parse the floats bracketing the exact value of a decimal string.
+///
+/// Returns the greatest float less than or equal to the exact value,
+/// and the least float greater than or equal to the exact value. If
+/// the exact value is representable, both floats are equal to it.
+/// Values above the largest finite float are bracketed by it and
+/// infinity, and values below the smallest denormal float are
+/// bracketed by zero and the smallest denormal float. Special values
+/// are returned as both floats.
+///
+/// The bytes are parsed the same as [`parse`], and the bracket is
+/// computed with exact, big-integer arithmetic.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # pub fn main() {
+/// assert_eq!(lexical_core::parse_bracket::<f64>(b"0.5"), Ok((0.5, 0.5)));
+///
+/// let (floor, ceil) = lexical_core::parse_bracket::<f64>(b"0.1").unwrap();
+/// assert_eq!(floor, 0.09999999999999999);
+/// assert_eq!(ceil, 0.1);
+/// # }
+/// ```
+///
+/// [`parse`]: fn.parse.html
+pub fn parse_bracket<F: FromLexical + Float>(bytes: &[u8])
+    -> Result<(F, F)>
+{
+    let value = F::from_lexical(bytes)?;
+    let decimal = split_decimal(bytes);
+    let has_digits = !decimal.integer.is_empty() || !decimal.fraction.is_empty();
+    if value.is_nan() || !has_digits {
+        // Special values.
+        return Ok((value, value));
+    }
+
+    let (lower, upper) = bracket(&decimal, value.abs());
+    match decimal.sign {
+        Sign::Negative => Ok((-upper, -lower)),
+        Sign::Positive => Ok((lower, upper)),
+    }
+}
+
+/// Parse the floats bracketing the exact value, and compare the exact value to the halfway point.
+///
+/// Returns the floats from [`parse_bracket`], and the ordering of the
+/// exact value relative to the halfway point between the floats, so
+/// `Less` if the exact value is nearer the lower float, `Greater` if it
+/// is nearer the upper float, and `Equal` if it is halfway between the
+/// floats. If the floats are equal, the ordering is `Equal`. These are
+/// the inputs to [`reround`], to round the exact value with any
+/// rounding scheme, without parsing the string again.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use std::cmp::Ordering;
+/// # pub fn main() {
+/// let (floor, ceil, halfway) = lexical_core::parse_bracket_halfway::<f64>(b"0.1").unwrap();
+/// assert_eq!((floor, ceil), (0.09999999999999999, 0.1));
+/// assert_eq!(halfway, Ordering::Greater);
+///
+/// let (_, _, halfway) = lexical_core::parse_bracket_halfway::<f64>(b"9007199254740993").unwrap();
+/// assert_eq!(halfway, Ordering::Equal);
+/// # }
+/// ```
+///
+/// [`parse_bracket`]: fn.parse_bracket.html
+/// [`reround`]: fn.reround.html
+pub fn parse_bracket_halfway<F: FromLexical + Float>(bytes: &[u8])
+    -> Result<(F, F, cmp::Ordering)>
+{
+    let value = F::from_lexical(bytes)?;
+    let decimal = split_decimal(bytes);
+    let has_digits = !decimal.integer.is_empty() || !decimal.fraction.is_empty();
+    if value.is_nan() || !has_digits {
+        // Special values.
+        return Ok((value, value, cmp::Ordering::Equal));
+    }
+
+    let (lower, upper) = bracket(&decimal, value.abs());
+    let halfway = compare_halfway(&decimal, lower, upper);
+    match decimal.sign {
+        Sign::Negative => Ok((-upper, -lower, halfway.reverse())),
+        Sign::Positive => Ok((lower, upper, halfway)),
+    }
+}
+
+/// Round the exact value between the bracketing floats with a rounding scheme.
+///
+/// Returns the exact value rounded to a float, from the floats bracketing
+/// the exact value, and the ordering of the exact value relative to the
+/// halfway point between the floats, as returned by [`parse_bracket_halfway`].
+/// This is pure arithmetic, so the exact value can be rounded with
+/// multiple rounding schemes without parsing the string again.
+///
+/// The inputs must be:
+/// * `floor`   - Greatest float less than or equal to the exact value.
+/// * `ceil`    - Least float greater than or equal to the exact value,
+///               which is equal to `floor` if the exact value is a float,
+///               and otherwise is the float after `floor`.
+/// * `halfway` - `Less` if the exact value is nearer `floor`, `Greater`
+///               if it is nearer `ceil`, and `Equal` if it is halfway
+///               between them. This is ignored if the floats are equal,
+///               or for directed rounding schemes.
+/// * `mode`    - Rounding scheme for the exact value.
+///
+/// If the floats are equal or NaN, `floor` is returned. Like parsing, an
+/// exact value above the largest finite float may round to infinity, and
+/// ties round to the float with an even mantissa or away from zero.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::RoundingMode;
+/// # pub fn main() {
+/// let (floor, ceil, halfway) = lexical_core::parse_bracket_halfway::<f64>(b"0.1").unwrap();
+/// assert_eq!(lexical_core::reround(floor, ceil, halfway, RoundingMode::NearestTieEven), 0.1);
+/// assert_eq!(lexical_core::reround(floor, ceil, halfway, RoundingMode::TowardZero), 0.09999999999999999);
+/// # }
+/// ```
+///
+/// [`parse_bracket_halfway`]: fn.parse_bracket_halfway.html
+pub fn reround<F: Float>(floor: F, ceil: F, halfway: cmp::Ordering, mode: RoundingMode) -> F {
+    if floor == ceil || floor.is_nan() {
+        return floor;
+    }
+
+    // The floats have the same sign, except for zero, which is positive
+    // for a positive exact value, and negative otherwise.
+    let is_positive = floor.is_sign_positive();
+    let toward_zero = if is_positive { floor } else { ceil };
+    let away_zero = if is_positive { ceil } else { floor };
+    match (mode, halfway) {
+        (RoundingMode::NearestTieEven, cmp::Ordering::Less)         => floor,
+        (RoundingMode::NearestTieEven, cmp::Ordering::Greater)      => ceil,
+        (RoundingMode::NearestTieEven, cmp::Ordering::Equal)        => if floor.is_even() { floor } else { ceil },
+        (RoundingMode::NearestTieAwayZero, cmp::Ordering::Less)     => floor,
+        (RoundingMode::NearestTieAwayZero, cmp::Ordering::Greater)  => ceil,
+        (RoundingMode::NearestTieAwayZero, cmp::Ordering::Equal)    => away_zero,
+        (RoundingMode::TowardPositiveInfinity, _)                   => ceil,
+        (RoundingMode::TowardNegativeInfinity, _)                   => floor,
+        (RoundingMode::TowardZero, _)                               => toward_zero,
+    }
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_bracket_exact_test() {
+        assert_eq!(parse_bracket::<f64>(b"0"), Ok((0.0, 0.0)));
+        assert_eq!(parse_bracket::<f64>(b"0.000e10"), Ok((0.0, 0.0)));
+        assert_eq!(parse_bracket::<f64>(b"1"), Ok((1.0, 1.0)));
+        assert_eq!(parse_bracket::<f64>(b"-1.5"), Ok((-1.5, -1.5)));
+        assert_eq!(parse_bracket::<f64>(b"0.0625"), Ok((0.0625, 0.0625)));
+        assert_eq!(parse_bracket::<f64>(b"9007199254740992"), Ok((9007199254740992.0, 9007199254740992.0)));
+        assert_eq!(parse_bracket::<f64>(b"1e22"), Ok((1e22, 1e22)));
+        assert_eq!(parse_bracket::<f64>(b"100000000000000000000000e-1"), Ok((1e22, 1e22)));
+        assert_eq!(parse_bracket::<f32>(b"0.75"), Ok((0.75, 0.75)));
+
+        // Exact value of the smallest denormal float.
+        let denormal = b"4.940656458412465441765687928682213723650598026143247644255856825006755072702087518652998363616359923797965646954457177309266567103559397963987747960107818781263007131903114045278458171678489821036887186360569987307230500063874091535649843873124733972731696151400317153853980741262385655911710266585566867681870395603106249319452715914924553293054565444011274801297099995419319894090804165633245247571478690147267801593552386115501348035264934720193790268107107491703332226844753335720832431936092382893458368060106011506169809753078342277318329247904982524730776375927247874656084778203734469699533647017972677717585125660551199131504891101451037862738167250955837389733598993664809941164205702637090279242767544565229087538682506419718265533447265625e-324";
+        let value = f64::from_bits(1);
+        assert_eq!(parse_bracket::<f64>(denormal), Ok((value, value)));
+    }
+
+    #[test]
+    fn parse_bracket_between_test() {
+        let value = 0.1f64;
+        assert_eq!(parse_bracket::<f64>(b"0.1"), Ok((value.prev_positive(), value)));
+        assert_eq!(parse_bracket::<f64>(b"-0.1"), Ok((-value, -value.prev_positive())));
+        let value = 0.3f64;
+        assert_eq!(parse_bracket::<f64>(b"0.3"), Ok((value, value.next_positive())));
+        let value = 0.1f32;
+        assert_eq!(parse_bracket::<f32>(b"0.1"), Ok((value.prev_positive(), value)));
+
+        // Halfway between 1 and the next float, and just above.
+        let value = 1.0f64;
+        assert_eq!(parse_bracket::<f64>(b"1.00000000000000011102230246251565404236316680908203125"), Ok((value, value.next_positive())));
+        assert_eq!(parse_bracket::<f64>(b"1.00000000000000011102230246251565404236316680908203126"), Ok((value, value.next_positive())));
+
+        // Truncated digits beyond the maximum.
+        let mut bytes = [b'0'; 1000];
+        bytes[0] = b'1';
+        bytes[1] = b'.';
+        bytes[999] = b'1';
+        assert_eq!(parse_bracket::<f64>(&bytes), Ok((value, value.next_positive())));
+        bytes[0] = b'0';
+        bytes[2] = b'9';
+        assert_eq!(parse_bracket::<f64>(&bytes), parse_bracket::<f64>(b"0.9"));
+    }
+
+    #[test]
+    fn parse_bracket_limits_test() {
+        assert_eq!(parse_bracket::<f64>(b"1e400"), Ok((f64::MAX, f64::INFINITY)));
+        assert_eq!(parse_bracket::<f64>(b"-1e400"), Ok((f64::NEG_INFINITY, -f64::MAX)));
+        assert_eq!(parse_bracket::<f64>(b"1e-400"), Ok((0.0, f64::from_bits(1))));
+        assert_eq!(parse_bracket::<f64>(b"1.7976931348623157e308"), Ok((f64::MAX.prev_positive(), f64::MAX)));
+        assert_eq!(parse_bracket::<f64>(b"5e-324"), Ok((f64::from_bits(1), f64::from_bits(2))));
+        assert_eq!(parse_bracket::<f64>(b"inf"), Ok((f64::INFINITY, f64::INFINITY)));
+        assert!(parse_bracket::<f64>(b"NaN").unwrap().0.is_nan());
+    }
+
+    #[test]
+    fn parse_bracket_halfway_test() {
+        let value = 1.0f64;
+        let halfway = b"1.00000000000000011102230246251565404236316680908203125";
+        assert_eq!(parse_bracket_halfway::<f64>(halfway), Ok((value, value.next_positive(), cmp::Ordering::Equal)));
+        assert_eq!(parse_bracket_halfway::<f64>(b"1.00000000000000011102230246251565404236316680908203124"), Ok((value, value.next_positive(), cmp::Ordering::Less)));
+        assert_eq!(parse_bracket_halfway::<f64>(b"1.00000000000000011102230246251565404236316680908203126"), Ok((value, value.next_positive(), cmp::Ordering::Greater)));
+        assert_eq!(parse_bracket_halfway::<f64>(b"-1.00000000000000011102230246251565404236316680908203124"), Ok((-value.next_positive(), -value, cmp::Ordering::Greater)));
+        assert_eq!(parse_bracket_halfway::<f64>(b"9007199254740993"), Ok((9007199254740992.0, 9007199254740994.0, cmp::Ordering::Equal)));
+        assert_eq!(parse_bracket_halfway::<f64>(b"0.1"), Ok((0.1f64.prev_positive(), 0.1, cmp::Ordering::Greater)));
+        assert_eq!(parse_bracket_halfway::<f32>(b"0.1"), Ok((0.1f32.prev_positive(), 0.1, cmp::Ordering::Greater)));
+        assert_eq!(parse_bracket_halfway::<f64>(b"0.5"), Ok((0.5, 0.5, cmp::Ordering::Equal)));
+        assert_eq!(parse_bracket_halfway::<f64>(b"0"), Ok((0.0, 0.0, cmp::Ordering::Equal)));
+
+        // Underflow and overflow.
+        assert_eq!(parse_bracket_halfway::<f64>(b"2e-324"), Ok((0.0, f64::from_bits(1), cmp::Ordering::Less)));
+        assert_eq!(parse_bracket_halfway::<f64>(b"3e-324"), Ok((0.0, f64::from_bits(1), cmp::Ordering::Greater)));
+        assert_eq!(parse_bracket_halfway::<f64>(b"1e-99999"), Ok((0.0, f64::from_bits(1), cmp::Ordering::Less)));
+        assert_eq!(parse_bracket_halfway::<f64>(b"1e400"), Ok((f64::MAX, f64::INFINITY, cmp::Ordering::Greater)));
+        assert_eq!(parse_bracket_halfway::<f64>(b"1e99999"), Ok((f64::MAX, f64::INFINITY, cmp::Ordering::Greater)));
+        assert_eq!(parse_bracket_halfway::<f64>(b"1.7976931348623158e308"), Ok((f64::MAX, f64::INFINITY, cmp::Ordering::Less)));
+        assert_eq!(parse_bracket_halfway::<f64>(b"179769313486231580793728971405303415079934132710037826936173778980444968292764750946649017977587207096330286416692887910946555547851940402630657488671505820681908902000708383676273854845817711531764475730270069855571366959622842914819860834936475292719074168444365510704342711559699508093042880177904174497792"), Ok((f64::MAX, f64::INFINITY, cmp::Ordering::Equal)));
+    }
+
+    #[test]
+    fn reround_test() {
+        let kinds = [
+            RoundingMode::NearestTieEven,
+            RoundingMode::NearestTieAwayZero,
+            RoundingMode::TowardPositiveInfinity,
+            RoundingMode::TowardNegativeInfinity,
+            RoundingMode::TowardZero,
+        ];
+        let round = |bytes: &[u8], kind| {
+            let (floor, ceil, halfway) = parse_bracket_halfway::<f64>(bytes).unwrap();
+            reround(floor, ceil, halfway, kind)
+        };
+
+        // Exact values.
+        for &kind in kinds.iter() {
+            assert_eq!(round(b"0.5", kind), 0.5);
+            assert_eq!(round(b"-0.5", kind), -0.5);
+            assert!(round(b"NaN", kind).is_nan());
+        }
+
+        // Nearest rounding matches the parser.
+        for &bytes in [&b"0.1"[..], b"-0.1", b"0.3", b"9007199254740993", b"9007199254740995", b"1e400", b"-1e-400", b"3e-324"].iter() {
+            assert_eq!(round(bytes, RoundingMode::NearestTieEven), f64::from_lexical(bytes).unwrap());
+        }
+
+        // Ties.
+        assert_eq!(round(b"9007199254740993", RoundingMode::NearestTieEven), 9007199254740992.0);
+        assert_eq!(round(b"9007199254740993", RoundingMode::NearestTieAwayZero), 9007199254740994.0);
+        assert_eq!(round(b"-9007199254740993", RoundingMode::NearestTieAwayZero), -9007199254740994.0);
+        assert_eq!(round(b"9007199254740995", RoundingMode::NearestTieEven), 9007199254740996.0);
+
+        // Directed rounding.
+        let value = 0.1f64;
+        assert_eq!(round(b"0.1", RoundingMode::TowardPositiveInfinity), value);
+        assert_eq!(round(b"0.1", RoundingMode::TowardNegativeInfinity), value.prev_positive());
+        assert_eq!(round(b"0.1", RoundingMode::TowardZero), value.prev_positive());
+        assert_eq!(round(b"-0.1", RoundingMode::TowardPositiveInfinity), -value.prev_positive());
+        assert_eq!(round(b"-0.1", RoundingMode::TowardNegativeInfinity), -value);
+        assert_eq!(round(b"-0.1", RoundingMode::TowardZero), -value.prev_positive());
+        assert_eq!(round(b"1e400", RoundingMode::TowardZero), f64::MAX);
+        assert_eq!(round(b"-1e400", RoundingMode::TowardPositiveInfinity), -f64::MAX);
+        assert_eq!(round(b"1e-400", RoundingMode::TowardPositiveInfinity), f64::from_bits(1));
+        assert_eq!(round(b"-1e-400", RoundingMode::NearestTieAwayZero), -0.0);
+        assert_eq!(round(b"-1e-400", RoundingMode::TowardNegativeInfinity), -f64::from_bits(1));
+    }
+
+    #[test]
+    fn parse_bracket_error_test() {
+        assert_eq!(parse_bracket::<f64>(b""), Err(Error::from((ErrorCode::Empty, 0))));
+        assert_eq!(parse_bracket::<f64>(b"1.5a"), Err(Error::from((ErrorCode::InvalidDigit, 3, b'a'))));
+    }
+}
@@ -256,6 +256,40 @@ pub(super) fn moderate_path<F, M>(mantissa: M, radix: u32, exponent: i32, trunca
     (fp, valid)
 }}
 
+// METRICS
+// -------
+
+// Record the significant digits and the exponent of the parsed data.
+perftools_inline!{
+#[cfg(feature = "metrics")]
+fn record_digits<'a, Data>(metrics: &mut ParseMetrics, data: &Data)
+    where Data: FastDataInterface<'a>
+{
+    metrics.digits_consumed = data.integer_iter().count() + data.fraction_iter().count();
+    metrics.exponent = data.raw_exponent();
+}}
+
+// Metrics are not collected without the `metrics` feature.
+perftools_inline!{
+#[cfg(not(feature = "metrics"))]
+fn record_digits<'a, Data>(_: &mut ParseMetrics, _: &Data)
+    where Data: FastDataInterface<'a>
+{
+}}
+
+// Record that the value required the moderate or slow path.
+perftools_inline!{
+#[cfg(feature = "metrics")]
+fn record_slow_path(metrics: &mut ParseMetrics) {
+    metrics.used_slow_path = true;
+}}
+
+// Metrics are not collected without the `metrics` feature.
+perftools_inline!{
+#[cfg(not(feature = "metrics"))]
+fn record_slow_path(_: &mut ParseMetrics) {
+}}
+
 // TO NATIVE
 // ---------
 
@@ -291,14 +325,15 @@ fn pown_fallback<'a, F, Data>(data: Data, mantissa: u64, radix: u32, lossy: bool
 }
 
 /// Parse non-power-of-two radix string to native float.
-fn pown_to_native<'a, F, Data>(mut data: Data, bytes: &'a [u8], radix: u32, lossy: bool, sign: Sign)
+fn pown_to_native<'a, F, Data>(mut data: Data, bytes: &'a [u8], radix: u32, exponent: &[u8], lossy: bool, sign: Sign, metrics: &mut ParseMetrics)
     -> ParseResult<(F, *const u8)>
     where F: FloatType,
           Data: FastDataInterface<'a>
 {
     // Parse the mantissa and exponent.
-    let ptr = data.extract(bytes, radix)?;
+    let ptr = data.extract(bytes, radix, exponent)?;
     let (mantissa, truncated) = process_mantissa::<u64, _>(&data, radix);
+    record_digits(metrics, &data);
 
     // Process the state to a float.
     let float = if mantissa.is_zero() {
@@ -312,11 +347,13 @@ fn pown_to_native<'a, F, Data>(mut data: Data, bytes: &'a [u8], radix: u32, loss
         if let Some(float) = fast_path::<F>(mantissa, radix, mant_exp) {
             float
         } else {
+            record_slow_path(metrics);
             let slow = data.to_slow(truncated);
             pown_fallback(slow, mantissa, radix, lossy, sign)
         }
     } else {
         // Can only use the moderate/slow path.
+        record_slow_path(metrics);
         let slow = data.to_slow(truncated);
         pown_fallback(slow, mantissa, radix, lossy, sign)
     };
@@ -326,15 +363,18 @@ fn pown_to_native<'a, F, Data>(mut data: Data, bytes: &'a [u8], radix: u32, loss
 // POW2
 
 /// Parse power-of-two radix string to native float.
+///
+/// Power-of-two radixes are exact, so the slow path is never recorded.
 #[cfg(feature = "radix")]
-fn pow2_to_native<'a, F, Data>(mut data: Data, bytes: &'a [u8], radix: u32, pow2_exp: i32, sign: Sign)
+fn pow2_to_native<'a, F, Data>(mut data: Data, bytes: &'a [u8], radix: u32, exponent: &[u8], pow2_exp: i32, sign: Sign, metrics: &mut ParseMetrics)
     -> ParseResult<(F, *const u8)>
     where F: FloatType,
           Data: FastDataInterface<'a>
 {
     // Parse the mantissa and exponent.
-    let ptr = data.extract(bytes, radix)?;
+    let ptr = data.extract(bytes, radix, exponent)?;
     let (mut mantissa, truncated) = process_mantissa::<u64, _>(&data, radix);
+    record_digits(metrics, &data);
 
     // We have a power of 2, can get an exact value even if the mantissa
     // was truncated. Check to see if there are any truncated digits, depending
@@ -344,28 +384,26 @@ fn pow2_to_native<'a, F, Data>(mut data: Data, bytes: &'a [u8], radix: u32, pow2
         // Truncated mantissa.
         let kind = global_rounding(sign);
         let slow = data.to_slow(truncated);
-        if kind != RoundingKind::Downward {
-            if cfg!(feature = "rounding") || kind == RoundingKind::NearestTieEven {
-                // Need to check if we're exactly halfway and if there are truncated digits.
-                if is_halfway::<F>(mantissa) && is_odd::<F>(mantissa) {
-                    mantissa += 1;
-                }
-            } else if kind == RoundingKind::NearestTieAwayZero {
-                // Need to check if we're exactly halfway and if there are truncated digits.
-                if is_halfway::<F>(mantissa) {
-                    mantissa += 1;
-                }
-            } else {
-                // Need to check if there are any bytes present.
-                // Check if there were any truncated bytes.
-                let index = slow.mantissa_digits() - slow.truncated_digits();
-                let iter = slow.integer_iter().chain(slow.fraction_iter()).skip(index);
-                let count = iter.take_while(|&&c| c == b'0').count();
-                let is_truncated = count < slow.truncated_digits();
-                if is_truncated {
-                    mantissa += 1;
-                }
-            }
+
+        // Check if any of the truncated digits are non-zero.
+        let index = slow.mantissa_digits() - slow.truncated_digits();
+        let iter = slow.integer_iter().chain(slow.fraction_iter()).skip(index);
+        let count = iter.take_while(|&&c| c == b'0').count();
+        let is_truncated = count < slow.truncated_digits();
+
+        // The truncated mantissa has at least 56 significant bits, so
+        // the lowest bit is always below the halfway bit, and setting it
+        // marks the value as above the truncated mantissa.
+        let is_above = match kind {
+            // Exactly halfway rounds to even, unless the truncated
+            // digits are above halfway.
+            RoundingKind::NearestTieEven     => is_halfway::<F>(mantissa) && (is_odd::<F>(mantissa) || is_truncated),
+            RoundingKind::NearestTieAwayZero => is_halfway::<F>(mantissa),
+            RoundingKind::Downward           => false,
+            _                                => is_truncated,
+        };
+        if is_above {
+            mantissa |= 1;
         }
 
         // Create exact representation and return.
@@ -406,19 +444,19 @@ fn pow2_exponent(radix: u32) -> i32 {
 //
 // The float string must be non-special, non-zero, and positive.
 perftools_inline!{
-fn to_native<F>(bytes: &[u8], radix: u32, lossy: bool, sign: Sign, format: NumberFormat)
+fn to_native<F>(bytes: &[u8], radix: u32, lossy: bool, sign: Sign, format: NumberFormat, exponent: &[u8], metrics: &mut ParseMetrics)
     -> ParseResult<(F, *const u8)>
     where F: FloatType
 {
     #[cfg(not(feature = "radix"))] {
-        apply_interface!(pown_to_native, format, bytes, radix,  lossy, sign)
+        apply_interface!(pown_to_native, format, bytes, radix, exponent, lossy, sign, metrics)
     }
 
     #[cfg(feature = "radix")] {
         let pow2_exp = pow2_exponent(radix);
         match pow2_exp {
-            0 => apply_interface!(pown_to_native, format, bytes, radix, lossy, sign),
-            _ => apply_interface!(pow2_to_native, format, bytes, radix, pow2_exp, sign)
+            0 => apply_interface!(pown_to_native, format, bytes, radix, exponent, lossy, sign, metrics),
+            _ => apply_interface!(pow2_to_native, format, bytes, radix, exponent, pow2_exp, sign, metrics)
         }
     }
 }}
@@ -428,18 +466,18 @@ fn to_native<F>(bytes: &[u8], radix: u32, lossy: bool, sign: Sign, format: Numbe
 
 // Parse 32-bit float from string.
 perftools_inline!{
-pub(crate) fn atof(bytes: &[u8], radix: u32, lossy: bool, sign: Sign, format: NumberFormat)
+pub(crate) fn atof(bytes: &[u8], radix: u32, lossy: bool, sign: Sign, format: NumberFormat, exponent: &[u8], metrics: &mut ParseMetrics)
     -> ParseResult<(f32, *const u8)>
 {
-    to_native::<f32>(bytes, radix, lossy, sign, format)
+    to_native::<f32>(bytes, radix, lossy, sign, format, exponent, metrics)
 }}
 
 // Parse 64-bit float from string.
 perftools_inline!{
-pub(crate) fn atod(bytes: &[u8], radix: u32, lossy: bool, sign: Sign, format: NumberFormat)
+pub(crate) fn atod(bytes: &[u8], radix: u32, lossy: bool, sign: Sign, format: NumberFormat, exponent: &[u8], metrics: &mut ParseMetrics)
     -> ParseResult<(f64, *const u8)>
 {
-    to_native::<f64>(bytes, radix, lossy, sign, format)
+    to_native::<f64>(bytes, radix, lossy, sign, format, exponent, metrics)
 }}
 
 // TESTS
@@ -726,7 +764,7 @@ mod tests {
 
     #[test]
     fn atof_test() {
-        let atof10 = move |x| match atof(x, 10, false, Sign::Positive, NumberFormat::standard().unwrap()) {
+        let atof10 = move |x| match atof(x, 10, false, Sign::Positive, NumberFormat::standard().unwrap(), b"e", &mut ParseMetrics::default()) {
             Ok((v, p))  => Ok((v, distance(x.as_ptr(), p))),
             Err((v, p)) => Err((v, distance(x.as_ptr(), p))),
         };
@@ -780,7 +818,7 @@ mod tests {
 
     #[test]
     fn atod_test() {
-        let adod_impl = move | x, r | match atod(x, r, false, Sign::Positive, NumberFormat::standard().unwrap()) {
+        let adod_impl = move | x, r | match atod(x, r, false, Sign::Positive, NumberFormat::standard().unwrap(), &[exponent_notation_char(r)], &mut ParseMetrics::default()) {
             Ok((v, p))  => Ok((v, distance(x.as_ptr(), p))),
             Err((v, p)) => Err((v, distance(x.as_ptr(), p))),
         };
@@ -881,7 +919,7 @@ mod tests {
 
     #[test]
     fn atof_lossy_test() {
-        let atof10 = move |x| match atof(x, 10, true, Sign::Positive, NumberFormat::standard().unwrap()) {
+        let atof10 = move |x| match atof(x, 10, true, Sign::Positive, NumberFormat::standard().unwrap(), b"e", &mut ParseMetrics::default()) {
             Ok((v, p))  => Ok((v, distance(x.as_ptr(), p))),
             Err((v, p)) => Err((v, distance(x.as_ptr(), p))),
         };
@@ -894,7 +932,7 @@ mod tests {
 
     #[test]
     fn atod_lossy_test() {
-        let atod10 = move |x| match atod(x, 10, true, Sign::Positive, NumberFormat::standard().unwrap()) {
+        let atod10 = move |x| match atod(x, 10, true, Sign::Positive, NumberFormat::standard().unwrap(), b"e", &mut ParseMetrics::default()) {
             Ok((v, p))  => Ok((v, distance(x.as_ptr(), p))),
             Err((v, p)) => Err((v, distance(x.as_ptr(), p))),
         };
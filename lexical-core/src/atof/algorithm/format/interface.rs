@@ -3,7 +3,7 @@
 /// Convert format to interface, and call function with new item as first argument.
 #[cfg(not(feature = "format"))]
 macro_rules! apply_interface {
-    ($fn:ident, $format:expr $(,$args:ident)*) => {
+    ($fn:expr, $format:expr $(,$args:ident)*) => {
         $fn(StandardFastDataInterface::new($format) $(,$args)*)
     };
 }
@@ -11,7 +11,7 @@ macro_rules! apply_interface {
 /// Convert format to interface, and call function with new item as first argument.
 #[cfg(feature = "format")]
 macro_rules! apply_interface {
-    ($fn:ident, $format:expr $(,$args:ident)*) => {
+    ($fn:expr, $format:expr $(,$args:ident)*) => {
         match $format.interface_flags() {
             NumberFormat::PERMISSIVE_INTERFACE  => $fn(PermissiveFastDataInterface::new($format) $(,$args)*),
             NumberFormat::STANDARD_INTERFACE    => $fn(StandardFastDataInterface::new($format) $(,$args)*),
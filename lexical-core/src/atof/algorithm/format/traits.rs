@@ -248,25 +248,81 @@ pub(crate) trait FastDataInterface<'a>: FastDataInterfaceImpl<'a> {
         self.set_fraction(self.fraction().map(|x| self.rtrim_zero(x).0));
     }}
 
+    // Remove digit separators trailing the integer digits, before the
+    // decimal point, so the float ends at the first digit separator.
+    //
+    //  Preconditions:
+    //      `digits[0] == b'.'`, and `digits` follows the integer in `bytes`.
+    perftools_inline!{
+    #[cfg(feature = "format")]
+    fn trim_integer_decimal_point(&mut self, bytes: &'a [u8], digits: &'a [u8])
+        -> &'a [u8]
+    {
+        let (integer, count) = self.rtrim_separator(self.integer());
+        if count == 0 {
+            digits
+        } else {
+            self.set_integer(integer);
+            &index!(bytes[integer.len()..])
+        }
+    }}
+
+    // Remove the fraction if it has leading digit separators, after the
+    // decimal point, so the float ends at the first digit separator.
+    //
+    //  Preconditions:
+    //      The fraction was extracted, and `digits` follows the fraction.
+    perftools_inline!{
+    #[cfg(feature = "format")]
+    fn trim_fraction_decimal_point(&mut self, digits: &'a [u8])
+        -> &'a [u8]
+    {
+        let fraction = self.fraction().unwrap();
+        if self.ltrim_separator(fraction).1 == 0 {
+            digits
+        } else {
+            let (empty, rest) = fraction.split_at(0);
+            self.set_fraction(Some(empty));
+            rest
+        }
+    }}
+
     /// Extract float subcomponents from input bytes.
+    ///
+    /// The exponent string is matched case-insensitively, and must
+    /// not be empty.
     perftools_inline!{
-    fn extract(&mut self, bytes: &'a [u8], radix: u32) -> ParseResult<*const u8> {
+    fn extract(&mut self, bytes: &'a [u8], radix: u32, exponent: &[u8]) -> ParseResult<*const u8> {
         // Parse the integer, aka, the digits preceding any control characters.
         let mut digits = bytes;
         digits = self.extract_integer(digits, radix);
 
+        // End the float at any digit separators before the decimal point.
+        #[cfg(feature = "format")] {
+            if digits.first() == Some(&b'.') && !self.format().separator_around_decimal_point() {
+                digits = self.trim_integer_decimal_point(bytes, digits);
+            }
+        }
+
         // Parse and validate a fraction, if present.
-        let exp_char = exponent_notation_char(radix).to_ascii_lowercase();
         if let Some(&b'.') = digits.first() {
             digits = self.extract_fraction(digits, radix);
+
+            // End the float at any digit separators after the decimal point.
+            #[cfg(feature = "format")] {
+                if !self.format().separator_around_decimal_point() {
+                    digits = self.trim_fraction_decimal_point(digits);
+                }
+            }
         }
         self.validate_mantissa()?;
 
         // Parse and validate an exponent, if present.
-        if let Some(&c) = digits.first() {
-            if c.to_ascii_lowercase() == exp_char {
-                digits = self.extract_exponent(digits, radix);
-            }
+        // Only the last byte of the exponent string is left for
+        // `extract_exponent`, which skips the exponent character.
+        if case_insensitive_starts_with_iter(digits.iter(), exponent.iter()).0 {
+            let skip = exponent.len() - 1;
+            digits = self.extract_exponent(&index!(digits[skip..]), radix);
         }
         self.validate_exponent()?;
         self.validate_exponent_fraction()?;
@@ -312,7 +368,7 @@ pub(crate) trait FastDataInterface<'a>: FastDataInterfaceImpl<'a> {
     #[cfg(test)]
     fn check_extract(&mut self, digits: &'a [u8], expected: &ParseTestResult<Self>) {
         let expected = expected.as_ref();
-        match self.extract(digits, 10) {
+        match self.extract(digits, 10, b"e") {
             Ok(_)       => {
                 let expected = expected.unwrap();
                 assert_eq!(self.integer(), expected.integer());
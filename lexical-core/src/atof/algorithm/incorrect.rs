@@ -50,12 +50,12 @@ fn process_fraction<'a, F, Data>(data: &Data, radix: u32)
 
 // Convert the float string to a native floating-point number.
 perftools_inline!{
-fn to_native<'a, F, Data>(mut data: Data, bytes: &'a [u8], radix: u32)
+fn to_native<'a, F, Data>(mut data: Data, bytes: &'a [u8], radix: u32, exponent: &[u8])
     -> ParseResult<(F, *const u8)>
     where F: StablePower,
           Data: FastDataInterface<'a>
 {
-    let ptr = data.extract(bytes, radix)?;
+    let ptr = data.extract(bytes, radix, exponent)?;
     let integer: F = process_integer(&data, radix);
     let fraction: F = process_fraction(&data, radix);
     let mut value = integer + fraction;
@@ -66,11 +66,11 @@ fn to_native<'a, F, Data>(mut data: Data, bytes: &'a [u8], radix: u32)
 }}
 
 perftools_inline!{
-pub(crate) fn atof_generic<'a, F>(bytes: &'a [u8], radix: u32, _: bool, _: Sign, format: NumberFormat)
+pub(crate) fn atof_generic<'a, F>(bytes: &'a [u8], radix: u32, _: bool, _: Sign, format: NumberFormat, exponent: &[u8], _: &mut ParseMetrics)
     -> ParseResult<(F, *const u8)>
     where F: StablePower
 {
-    apply_interface!(to_native, format, bytes, radix)
+    apply_interface!(to_native, format, bytes, radix, exponent)
 }}
 
 // ATOF/ATOD
@@ -78,18 +78,18 @@ pub(crate) fn atof_generic<'a, F>(bytes: &'a [u8], radix: u32, _: bool, _: Sign,
 
 // Parse 32-bit float from string.
 perftools_inline!{
-pub(crate) fn atof<'a>(bytes: &'a [u8], radix: u32, lossy: bool, sign: Sign, format: NumberFormat)
+pub(crate) fn atof<'a>(bytes: &'a [u8], radix: u32, lossy: bool, sign: Sign, format: NumberFormat, exponent: &[u8], metrics: &mut ParseMetrics)
     -> ParseResult<(f32, *const u8)>
 {
-    atof_generic(bytes, radix, lossy, sign, format)
+    atof_generic(bytes, radix, lossy, sign, format, exponent, metrics)
 }}
 
 // Parse 64-bit float from string.
 perftools_inline!{
-pub(crate) fn atod<'a>(bytes: &'a [u8], radix: u32, lossy: bool, sign: Sign, format: NumberFormat)
+pub(crate) fn atod<'a>(bytes: &'a [u8], radix: u32, lossy: bool, sign: Sign, format: NumberFormat, exponent: &[u8], metrics: &mut ParseMetrics)
     -> ParseResult<(f64, *const u8)>
 {
-    atof_generic(bytes, radix, lossy, sign, format)
+    atof_generic(bytes, radix, lossy, sign, format, exponent, metrics)
 }}
 
 // TESTS
@@ -129,7 +129,7 @@ mod tests {
 
     #[test]
     fn atof_test() {
-        let atof10 = move |x| match atof(x, 10, false, Sign::Positive, NumberFormat::standard().unwrap()) {
+        let atof10 = move |x| match atof(x, 10, false, Sign::Positive, NumberFormat::standard().unwrap(), b"e", &mut ParseMetrics::default()) {
             Ok((v, p))  => Ok((v, distance(x.as_ptr(), p))),
             Err((v, p)) => Err((v, distance(x.as_ptr(), p))),
         };
@@ -142,7 +142,7 @@ mod tests {
 
     #[test]
     fn atod_test() {
-        let atod10 = move |x| match atod(x, 10, false, Sign::Positive, NumberFormat::standard().unwrap()) {
+        let atod10 = move |x| match atod(x, 10, false, Sign::Positive, NumberFormat::standard().unwrap(), b"e", &mut ParseMetrics::default()) {
             Ok((v, p))  => Ok((v, distance(x.as_ptr(), p))),
             Err((v, p)) => Err((v, distance(x.as_ptr(), p))),
         };
@@ -159,7 +159,7 @@ mod tests {
 
     #[test]
     fn atof_lossy_test() {
-        let atof10 = move |x| match atof(x, 10, true, Sign::Positive, NumberFormat::standard().unwrap()) {
+        let atof10 = move |x| match atof(x, 10, true, Sign::Positive, NumberFormat::standard().unwrap(), b"e", &mut ParseMetrics::default()) {
             Ok((v, p))  => Ok((v, distance(x.as_ptr(), p))),
             Err((v, p)) => Err((v, distance(x.as_ptr(), p))),
         };
@@ -172,7 +172,7 @@ mod tests {
 
     #[test]
     fn atod_lossy_test() {
-        let atod10 = move |x| match atod(x, 10, true, Sign::Positive, NumberFormat::standard().unwrap()) {
+        let atod10 = move |x| match atod(x, 10, true, Sign::Positive, NumberFormat::standard().unwrap(), b"e", &mut ParseMetrics::default()) {
             Ok((v, p))  => Ok((v, distance(x.as_ptr(), p))),
             Err((v, p)) => Err((v, distance(x.as_ptr(), p))),
         };
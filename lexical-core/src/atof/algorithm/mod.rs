@@ -33,6 +33,9 @@ mod small_powers_64;
 }}  // cfg_if
 
 // Export algorithms.
+#[cfg(feature = "correct")]
+pub(crate) mod bracket;
+
 #[cfg(feature = "correct")]
 pub(crate) mod correct;
 
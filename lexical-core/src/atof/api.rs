@@ -9,33 +9,54 @@ use crate::util::*;
 cfg_if! {
 if #[cfg(feature = "correct")] {
     use super::algorithm::correct as algorithm;
+    const PARSE_BACKEND: ParseBackend = ParseBackend::Correct;
 } else {
     use super::algorithm::incorrect as algorithm;
+    const PARSE_BACKEND: ParseBackend = ParseBackend::Lossy;
 }}  // cfg_if
 
+/// Get the algorithm used to parse floats.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::ParseBackend;
+/// # pub fn main() {
+/// const BACKEND: ParseBackend = lexical_core::parse_backend();
+/// if cfg!(feature = "correct") {
+///     assert_eq!(BACKEND, ParseBackend::Correct);
+/// }
+/// # }
+/// ```
+#[inline]
+pub const fn parse_backend() -> ParseBackend {
+    PARSE_BACKEND
+}
+
 // TRAITS
 
 /// Trait to define parsing of a string to float.
 trait StringToFloat: Float {
     /// Serialize string to float, favoring correctness.
-    fn default(bytes: &[u8], radix: u32, lossy: bool, sign: Sign, format: NumberFormat) -> ParseResult<(Self, *const u8)>;
+    fn default(bytes: &[u8], radix: u32, lossy: bool, sign: Sign, format: NumberFormat, exponent: &[u8], metrics: &mut ParseMetrics) -> ParseResult<(Self, *const u8)>;
 }
 
 impl StringToFloat for f32 {
     perftools_inline_always!{
-    fn default(bytes: &[u8], radix: u32, lossy: bool, sign: Sign, format: NumberFormat)
+    fn default(bytes: &[u8], radix: u32, lossy: bool, sign: Sign, format: NumberFormat, exponent: &[u8], metrics: &mut ParseMetrics)
         -> ParseResult<(f32, *const u8)>
     {
-        algorithm::atof(bytes, radix, lossy, sign, format)
+        algorithm::atof(bytes, radix, lossy, sign, format, exponent, metrics)
     }}
 }
 
 impl StringToFloat for f64 {
     perftools_inline_always!{
-    fn default(bytes: &[u8], radix: u32, lossy: bool, sign: Sign, format: NumberFormat)
+    fn default(bytes: &[u8], radix: u32, lossy: bool, sign: Sign, format: NumberFormat, exponent: &[u8], metrics: &mut ParseMetrics)
         -> ParseResult<(f64, *const u8)>
     {
-        algorithm::atod(bytes, radix, lossy, sign, format)
+        algorithm::atod(bytes, radix, lossy, sign, format, exponent, metrics)
     }}
 }
 
@@ -55,6 +76,41 @@ fn to_iter_s<'a>(bytes: &'a [u8], digit_separator: u8) -> SkipValueIterator<'a,
     SkipValueIterator::new(bytes, digit_separator)
 }}
 
+// Find a digit separator embedded in a partially-matched special value.
+//
+// Unless digit separators are allowed in special values, specials are
+// compared against the raw bytes, so `"n_an"` must not match `NaN`. If
+// the bytes match a prefix of the special string up to a digit separator,
+// return a pointer to the separator so it can be reported as the invalid
+// digit.
+perftools_inline!{
+#[cfg(feature = "format")]
+fn special_separator(bytes: &[u8], special: &[u8], format: NumberFormat)
+    -> Option<*const u8>
+{
+    let digit_separator = format.digit_separator();
+    if digit_separator == 0 || format.special_digit_separator() {
+        return None;
+    }
+    for (index, (&c, &s)) in bytes.iter().zip(special.iter()).enumerate() {
+        if c == digit_separator {
+            return Some(index!(bytes[index..]).as_ptr());
+        } else if !c.eq_ignore_ascii_case(&s) {
+            return None;
+        }
+    }
+    None
+}}
+
+// Find a digit separator embedded in a partially-matched special value.
+perftools_inline!{
+#[cfg(not(feature = "format"))]
+fn special_separator(_: &[u8], _: &[u8], _: NumberFormat)
+    -> Option<*const u8>
+{
+    None
+}}
+
 // PARSER
 
 // Parse infinity from string.
@@ -65,6 +121,8 @@ fn parse_infinity<'a, ToIter, StartsWith, Iter, F>(
     lossy: bool,
     sign: Sign,
     format: NumberFormat,
+    exponent: &[u8],
+    metrics: &mut ParseMetrics,
     to_iter: ToIter,
     starts_with: StartsWith
 )
@@ -80,10 +138,12 @@ fn parse_infinity<'a, ToIter, StartsWith, Iter, F>(
         Ok((F::INFINITY, iter.as_ptr()))
     } else if let (true, iter) = starts_with(to_iter(bytes, format.digit_separator()), inf.iter()) {
         Ok((F::INFINITY, iter.as_ptr()))
+    } else if let Some(ptr) = special_separator(bytes, infinity, format) {
+        Err((ErrorCode::InvalidDigit, ptr))
     } else {
         // Not infinity, may be valid with a different radix.
         if cfg!(feature = "radix"){
-            F::default(bytes, radix, lossy, sign, format)
+            F::default(bytes, radix, lossy, sign, format, exponent, metrics)
         } else {
             Err((ErrorCode::InvalidDigit, bytes.as_ptr()))
         }
@@ -98,6 +158,8 @@ fn parse_nan<'a, ToIter, StartsWith, Iter, F>(
     lossy: bool,
     sign: Sign,
     format: NumberFormat,
+    exponent: &[u8],
+    metrics: &mut ParseMetrics,
     to_iter: ToIter,
     starts_with: StartsWith
 )
@@ -110,10 +172,12 @@ fn parse_nan<'a, ToIter, StartsWith, Iter, F>(
     let nan = get_nan_string();
     if let (true, iter) = starts_with(to_iter(bytes, format.digit_separator()), nan.iter()) {
         Ok((F::NAN, iter.as_ptr()))
+    } else if let Some(ptr) = special_separator(bytes, nan, format) {
+        Err((ErrorCode::InvalidDigit, ptr))
     } else {
         // Not NaN, may be valid with a different radix.
         if cfg!(feature = "radix"){
-            F::default(bytes, radix, lossy, sign, format)
+            F::default(bytes, radix, lossy, sign, format, exponent, metrics)
         } else {
             Err((ErrorCode::InvalidDigit, bytes.as_ptr()))
         }
@@ -126,16 +190,16 @@ fn parse_nan<'a, ToIter, StartsWith, Iter, F>(
 // Special values are allowed, the match is case-insensitive,
 // and no digit separators are allowed.
 perftools_inline!{
-fn parse_float_standard<F: StringToFloat>(bytes: &[u8], radix: u32, lossy: bool, sign: Sign, format: NumberFormat)
+fn parse_float_standard<F: StringToFloat>(bytes: &[u8], radix: u32, lossy: bool, sign: Sign, format: NumberFormat, exponent: &[u8], metrics: &mut ParseMetrics)
     -> ParseResult<(F, *const u8)>
 {
     // Use predictive parsing to filter special cases. This leads to
     // dramatic performance gains.
     let starts_with = case_insensitive_starts_with_iter;
     match index!(bytes[0]) {
-        b'i' | b'I' => parse_infinity(bytes, radix, lossy, sign, format, to_iter, starts_with),
-        b'N' | b'n' => parse_nan(bytes, radix, lossy, sign, format, to_iter, starts_with),
-        _           => F::default(bytes, radix, lossy, sign, format),
+        b'i' | b'I' => parse_infinity(bytes, radix, lossy, sign, format, exponent, metrics, to_iter, starts_with),
+        b'N' | b'n' => parse_nan(bytes, radix, lossy, sign, format, exponent, metrics, to_iter, starts_with),
+        _           => F::default(bytes, radix, lossy, sign, format, exponent, metrics),
     }
 }}
 
@@ -144,15 +208,15 @@ fn parse_float_standard<F: StringToFloat>(bytes: &[u8], radix: u32, lossy: bool,
 // and digit separators are allowed.
 perftools_inline!{
 #[cfg(feature = "format")]
-fn parse_float_cs<F: StringToFloat>(bytes: &[u8], radix: u32, lossy: bool, sign: Sign, format: NumberFormat)
+fn parse_float_cs<F: StringToFloat>(bytes: &[u8], radix: u32, lossy: bool, sign: Sign, format: NumberFormat, exponent: &[u8], metrics: &mut ParseMetrics)
     -> ParseResult<(F, *const u8)>
 {
     let digit_separator = format.digit_separator();
     let starts_with = starts_with_iter;
     match SkipValueIterator::new(bytes, digit_separator).next()  {
-        Some(&b'i') | Some(&b'I')   => parse_infinity(bytes, radix, lossy, sign, format, to_iter_s, starts_with),
-        Some(&b'n') | Some(&b'N')   => parse_nan(bytes, radix, lossy, sign, format, to_iter_s, starts_with),
-        _                           => F::default(bytes, radix, lossy, sign, format),
+        Some(&b'i') | Some(&b'I')   => parse_infinity(bytes, radix, lossy, sign, format, exponent, metrics, to_iter_s, starts_with),
+        Some(&b'n') | Some(&b'N')   => parse_nan(bytes, radix, lossy, sign, format, exponent, metrics, to_iter_s, starts_with),
+        _                           => F::default(bytes, radix, lossy, sign, format, exponent, metrics),
     }
 }}
 
@@ -161,16 +225,16 @@ fn parse_float_cs<F: StringToFloat>(bytes: &[u8], radix: u32, lossy: bool, sign:
 // and no digit separators are allowed.
 perftools_inline!{
 #[cfg(feature = "format")]
-fn parse_float_c<F: StringToFloat>(bytes: &[u8], radix: u32, lossy: bool, sign: Sign, format: NumberFormat)
+fn parse_float_c<F: StringToFloat>(bytes: &[u8], radix: u32, lossy: bool, sign: Sign, format: NumberFormat, exponent: &[u8], metrics: &mut ParseMetrics)
     -> ParseResult<(F, *const u8)>
 {
     // Use predictive parsing to filter special cases. This leads to
     // dramatic performance gains.
     let starts_with = starts_with_iter;
     match index!(bytes[0]) {
-        b'i' | b'I' => parse_infinity(bytes, radix, lossy, sign, format, to_iter, starts_with),
-        b'N' | b'n' => parse_nan(bytes, radix, lossy, sign, format, to_iter, starts_with),
-        _           => F::default(bytes, radix, lossy, sign, format),
+        b'i' | b'I' => parse_infinity(bytes, radix, lossy, sign, format, exponent, metrics, to_iter, starts_with),
+        b'N' | b'n' => parse_nan(bytes, radix, lossy, sign, format, exponent, metrics, to_iter, starts_with),
+        _           => F::default(bytes, radix, lossy, sign, format, exponent, metrics),
     }
 }}
 
@@ -179,31 +243,31 @@ fn parse_float_c<F: StringToFloat>(bytes: &[u8], radix: u32, lossy: bool, sign:
 // and digit separators are allowed.
 perftools_inline!{
 #[cfg(feature = "format")]
-fn parse_float_s<F: StringToFloat>(bytes: &[u8], radix: u32, lossy: bool, sign: Sign, format: NumberFormat)
+fn parse_float_s<F: StringToFloat>(bytes: &[u8], radix: u32, lossy: bool, sign: Sign, format: NumberFormat, exponent: &[u8], metrics: &mut ParseMetrics)
     -> ParseResult<(F, *const u8)>
 {
     let digit_separator = format.digit_separator();
     let starts_with = case_insensitive_starts_with_iter;
     match SkipValueIterator::new(bytes, digit_separator).next()  {
-        Some(&b'i') | Some(&b'I')   => parse_infinity(bytes, radix, lossy, sign, format, to_iter_s, starts_with),
-        Some(&b'n') | Some(&b'N')   => parse_nan(bytes, radix, lossy, sign, format, to_iter_s, starts_with),
-        _                           => F::default(bytes, radix, lossy, sign, format),
+        Some(&b'i') | Some(&b'I')   => parse_infinity(bytes, radix, lossy, sign, format, exponent, metrics, to_iter_s, starts_with),
+        Some(&b'n') | Some(&b'N')   => parse_nan(bytes, radix, lossy, sign, format, exponent, metrics, to_iter_s, starts_with),
+        _                           => F::default(bytes, radix, lossy, sign, format, exponent, metrics),
     }
 }}
 
 // Parse special or float values with the default formatter.
 perftools_inline!{
 #[cfg(not(feature = "format"))]
-fn parse_float<F: StringToFloat>(bytes: &[u8], radix: u32, lossy: bool, sign: Sign, format: NumberFormat)
+fn parse_float<F: StringToFloat>(bytes: &[u8], radix: u32, lossy: bool, sign: Sign, format: NumberFormat, exponent: &[u8], metrics: &mut ParseMetrics)
     -> ParseResult<(F, *const u8)>
 {
-    parse_float_standard(bytes, radix, lossy, sign, format)
+    parse_float_standard(bytes, radix, lossy, sign, format, exponent, metrics)
 }}
 
 // Parse special or float values with the default formatter.
 perftools_inline!{
 #[cfg(feature = "format")]
-fn parse_float<F: StringToFloat>(bytes: &[u8], radix: u32, lossy: bool, sign: Sign, format: NumberFormat)
+fn parse_float<F: StringToFloat>(bytes: &[u8], radix: u32, lossy: bool, sign: Sign, format: NumberFormat, exponent: &[u8], metrics: &mut ParseMetrics)
     -> ParseResult<(F, *const u8)>
 {
     // Need to consider 3 possibilities:
@@ -214,11 +278,11 @@ fn parse_float<F: StringToFloat>(bytes: &[u8], radix: u32, lossy: bool, sign: Si
     let case = format.case_sensitive_special();
     let has_sep = format.special_digit_separator();
     match (no_special, case, has_sep) {
-        (true, _, _)            => F::default(bytes, radix, lossy, sign, format),
-        (false, true, true)     => parse_float_cs(bytes, radix, lossy, sign, format),
-        (false, false, true)    => parse_float_s(bytes, radix, lossy, sign, format),
-        (false, true, false)    => parse_float_c(bytes, radix, lossy, sign, format),
-        (false, false, false)   => parse_float_standard(bytes, radix, lossy, sign, format),
+        (true, _, _)            => F::default(bytes, radix, lossy, sign, format, exponent, metrics),
+        (false, true, true)     => parse_float_cs(bytes, radix, lossy, sign, format, exponent, metrics),
+        (false, false, true)    => parse_float_s(bytes, radix, lossy, sign, format, exponent, metrics),
+        (false, true, false)    => parse_float_c(bytes, radix, lossy, sign, format, exponent, metrics),
+        (false, false, false)   => parse_float_standard(bytes, radix, lossy, sign, format, exponent, metrics),
     }
 }}
 
@@ -257,27 +321,36 @@ fn to_signed<F: StringToFloat>(float: F, sign: Sign) -> F
     }
 }}
 
-// Standalone atof processor.
+// Standalone atof processor, collecting metrics on how the float was parsed.
 perftools_inline!{
-fn atof<F: StringToFloat>(bytes: &[u8], radix: u32, lossy: bool, format: NumberFormat)
+fn atof_with_metrics<F: StringToFloat>(bytes: &[u8], radix: u32, lossy: bool, format: NumberFormat, exponent: &[u8], metrics: &mut ParseMetrics)
     -> ParseResult<(F, *const u8)>
 {
     let (sign, digits) = parse_sign::<F>(bytes, format);
     if digits.is_empty() {
         return Err((ErrorCode::Empty, digits.as_ptr()));
     }
-    let (float, ptr): (F, *const u8) = parse_float(digits, radix, lossy, sign, format)?;
+    let (float, ptr): (F, *const u8) = parse_float(digits, radix, lossy, sign, format, exponent, metrics)?;
     validate_sign(bytes, digits, sign, format)?;
 
     Ok((to_signed(float, sign), ptr))
 }}
 
+// Standalone atof processor.
+perftools_inline!{
+fn atof<F: StringToFloat>(bytes: &[u8], radix: u32, lossy: bool, format: NumberFormat, exponent: &[u8])
+    -> ParseResult<(F, *const u8)>
+{
+    atof_with_metrics(bytes, radix, lossy, format, exponent, &mut ParseMetrics::default())
+}}
+
 perftools_inline!{
 fn atof_lossy<F: StringToFloat>(bytes: &[u8], radix: u32)
     -> Result<(F, usize)>
 {
     let index = | ptr | distance(bytes.as_ptr(), ptr);
-    match atof::<F>(bytes, radix, true, NumberFormat::standard().unwrap()) {
+    let exponent = [exponent_notation_char(radix)];
+    match atof::<F>(bytes, radix, true, NumberFormat::standard().unwrap(), &exponent) {
         Ok((value, ptr)) => Ok((value, index(ptr))),
         Err((code, ptr)) => Err((code, index(ptr)).into()),
     }
@@ -288,36 +361,393 @@ fn atof_nonlossy<F: StringToFloat>(bytes: &[u8], radix: u32)
     -> Result<(F, usize)>
 {
     let index = | ptr | distance(bytes.as_ptr(), ptr);
-    match atof::<F>(bytes, radix, false, NumberFormat::standard().unwrap()) {
+    let exponent = [exponent_notation_char(radix)];
+    match atof::<F>(bytes, radix, false, NumberFormat::standard().unwrap(), &exponent) {
         Ok((value, ptr)) => Ok((value, index(ptr))),
         Err((code, ptr)) => Err((code, index(ptr)).into()),
     }
 }}
 
+// Find a redundant leading zero in the exponent of the parsed bytes.
+//
+// Returns the index of the digit following the leading zero, if the
+// exponent digits start with a zero and have more than one digit.
+perftools_inline!{
+fn find_exponent_leading_zero(bytes: &[u8], exponent: &[u8])
+    -> Option<usize>
+{
+    let start = bytes.windows(exponent.len())
+        .position(|window| window.eq_ignore_ascii_case(exponent))?
+        + exponent.len();
+    let start = match bytes.get(start) {
+        Some(&b'+') | Some(&b'-')   => start + 1,
+        _                           => start,
+    };
+    match (bytes.get(start), bytes.get(start + 1)) {
+        (Some(&b'0'), Some(_))  => Some(start + 1),
+        _                       => None,
+    }
+}}
+
+// Find the index of the exponent string in the parsed bytes, ignoring case.
+perftools_inline!{
+fn find_exponent(bytes: &[u8], exponent: &[u8])
+    -> Option<usize>
+{
+    bytes.windows(exponent.len()).position(|window| window.eq_ignore_ascii_case(exponent))
+}}
+
+// Count the significant digits in the mantissa of the parsed bytes.
+//
+// Leading zeros are not significant, while all digits after the first
+// non-zero digit are, and zero has a single significant digit. Returns
+// None if the mantissa has no digits, like for special values.
+perftools_inline!{
+fn count_significant_digits(bytes: &[u8], radix: u32)
+    -> Option<usize>
+{
+    let bytes = match bytes.first() {
+        Some(&b'+') | Some(&b'-')   => &index!(bytes[1..]),
+        _                           => bytes,
+    };
+    let mut digits = bytes.iter()
+        .take_while(|&&c| c == b'.' || (c as char).is_digit(radix))
+        .filter(|&&c| c != b'.');
+    let count = digits.clone().count();
+    match count {
+        0 => None,
+        _ => Some(count - digits.position(|&c| c != b'0').unwrap_or(count - 1)),
+    }
+}}
+
+// Parse a decimal point without digits as zero.
+//
+// Returns the signed zero, if the bytes start with an optional sign
+// and a decimal point, followed by an optional exponent. Otherwise,
+// the mantissa is not a bare decimal point, and returns None.
+perftools_inline!{
+fn parse_bare_point<F: StringToFloat>(bytes: &[u8], radix: u32, exponent: &[u8], lenient_exponent: bool)
+    -> Option<ParseResult<(F, *const u8)>>
+{
+    let (sign, point) = match bytes.first() {
+        Some(&b'+') => (Sign::Positive, 1),
+        Some(&b'-') => (Sign::Negative, 1),
+        _           => (Sign::Positive, 0),
+    };
+    if bytes.get(point) != Some(&b'.') {
+        return None;
+    }
+
+    // Parse the optional exponent, which is discarded for zero.
+    let zero = to_signed(F::ZERO, sign);
+    let ptr = | index | index!(bytes[index..]).as_ptr();
+    let start = point + 1;
+    let rest = &index!(bytes[start..]);
+    if rest.len() < exponent.len() || !index!(rest[..exponent.len()]).eq_ignore_ascii_case(exponent) {
+        return Some(Ok((zero, ptr(start))));
+    }
+    let mut index = start + exponent.len();
+    if let Some(&b'+') | Some(&b'-') = bytes.get(index) {
+        index += 1;
+    }
+    let digits = index!(bytes[index..]).iter()
+        .take_while(|&&c| (c as char).to_digit(radix).is_some())
+        .count();
+    match (digits, lenient_exponent) {
+        (0, true)   => Some(Ok((zero, ptr(start)))),
+        (0, false)  => Some(Err((ErrorCode::EmptyExponent, ptr(index)))),
+        _           => Some(Ok((zero, ptr(index + digits)))),
+    }
+}}
+
+// Maximum number of significant exponent digits parsed for an implicit
+// mantissa. Any exponent with more digits, in any radix, overflows or
+// underflows, like an exponent with this many digits.
+const IMPLICIT_MANTISSA_EXPONENT_DIGITS: usize = 16;
+
+// Parse a mantissa without digits before the exponent as one.
+//
+// Returns the signed power of the radix, if the bytes start with an
+// optional sign and the exponent string, followed by an optional sign
+// and exponent digits. Otherwise, returns None.
+perftools_inline!{
+fn parse_implicit_mantissa<F: StringToFloat>(bytes: &[u8], radix: u32, exponent: &[u8])
+    -> Option<ParseResult<(F, *const u8)>>
+{
+    let (sign, start) = match bytes.first() {
+        Some(&b'+') => (Sign::Positive, 1),
+        Some(&b'-') => (Sign::Negative, 1),
+        _           => (Sign::Positive, 0),
+    };
+    let rest = &index!(bytes[start..]);
+    if rest.len() < exponent.len() || !index!(rest[..exponent.len()]).eq_ignore_ascii_case(exponent) {
+        return None;
+    }
+    let mut index = start + exponent.len();
+    let exponent_sign = match bytes.get(index) {
+        Some(&b'+') | Some(&b'-')   => bytes.get(index),
+        _                           => None,
+    };
+    index += exponent_sign.iter().count();
+    let digits = &index!(bytes[index..]);
+    let count = digits.iter()
+        .take_while(|&&c| (c as char).is_digit(radix))
+        .count();
+    if count == 0 {
+        return None;
+    }
+
+    // Parse `1`, with the default exponent character for the radix and
+    // the significant exponent digits, which fit on the stack.
+    let digits = &index!(digits[..count]);
+    let zeros = digits.iter().take_while(|&&c| c == b'0').count().min(count - 1);
+    let digits = &index!(digits[zeros..]);
+    let digits = &index!(digits[..digits.len().min(IMPLICIT_MANTISSA_EXPONENT_DIGITS)]);
+    let default = [exponent_notation_char(radix)];
+    let mut buffer = [0u8; IMPLICIT_MANTISSA_EXPONENT_DIGITS + 3];
+    let mut length = 0;
+    for &c in [b'1', default[0]].iter().chain(exponent_sign).chain(digits) {
+        index_mut!(buffer[length] = c);
+        length += 1;
+    }
+    let format = NumberFormat::standard().unwrap();
+    let parsed = atof::<F>(&index!(buffer[..length]), radix, false, format, &default);
+    let ptr = index!(bytes[index + count..]).as_ptr();
+    Some(parsed.map(|(value, _)| (to_signed(value, sign), ptr)).map_err(|(code, _)| (code, ptr)))
+}}
+
+// Get the number format of the float grammar for the options.
 perftools_inline!{
 #[cfg(feature = "format")]
-fn atof_format<F: StringToFloat>(bytes: &[u8], radix: u32, format: NumberFormat)
+fn options_format(options: &ParseFloatOptions) -> NumberFormat {
+    options.format()
+}}
+
+// Get the number format of the float grammar for the options.
+perftools_inline!{
+#[cfg(not(feature = "format"))]
+fn options_format(_: &ParseFloatOptions) -> NumberFormat {
+    NumberFormat::standard().unwrap()
+}}
+
+// Get the value and the sentinel string for the options.
+perftools_inline!{
+#[cfg(feature = "optional")]
+fn options_sentinel(options: &ParseFloatOptions) -> Option<(f64, &'static [u8])> {
+    options.sentinel()
+}}
+
+// Get the value and the sentinel string for the options.
+perftools_inline!{
+#[cfg(not(feature = "optional"))]
+fn options_sentinel(_: &ParseFloatOptions) -> Option<(f64, &'static [u8])> {
+    None
+}}
+
+perftools_inline!{
+fn atof_options<F: StringToFloat>(bytes: &[u8], radix: u32, options: &ParseFloatOptions)
     -> Result<(F, usize)>
 {
+    match options.trim_chars().is_empty() {
+        true  => atof_untrimmed(bytes, radix, options),
+        false => parse_trimmed(bytes, options.trim_chars(), |bytes| atof_untrimmed(bytes, radix, options)),
+    }
+}}
+
+perftools_inline!{
+fn atof_untrimmed<F: StringToFloat>(bytes: &[u8], radix: u32, options: &ParseFloatOptions)
+    -> Result<(F, usize)>
+{
+    let result = atof_number::<F>(bytes, radix, options);
+    match options_sentinel(options) {
+        // Parse the sentinel, unless the bytes start with a longer float.
+        Some((value, sentinel)) if bytes.starts_with(sentinel) => match result {
+            Ok((_, count)) if count > sentinel.len() => result,
+            _ => Ok((as_cast(value), sentinel.len())),
+        },
+        _ => result,
+    }
+}}
+
+perftools_inline!{
+fn atof_number<F: StringToFloat>(bytes: &[u8], radix: u32, options: &ParseFloatOptions)
+    -> Result<(F, usize)>
+{
+    if options.strict_sign() {
+        if let Some(index) = find_second_sign::<F>(bytes) {
+            return Err((ErrorCode::InvalidSign, index).into());
+        }
+    }
+    if !options.sign_before_point() {
+        if let Some(index) = find_sign_before_point::<F>(bytes) {
+            return Err((ErrorCode::EmptyInteger, index).into());
+        }
+    }
+
     let index = | ptr | distance(bytes.as_ptr(), ptr);
-    match atof::<F>(bytes, radix, false, format) {
+    let default = [exponent_notation_char(radix)];
+    let exponent = match exponent_string_starts_with_digit(options.exponent_string(), radix) {
+        true  => &default[..],
+        false => options.exponent_string(),
+    };
+    let format = options_format(options);
+    let parse = | bytes | atof::<F>(bytes, radix, false, format, exponent);
+    let mut result = parse(bytes);
+    if let Err((ErrorCode::EmptyMantissa, _)) = result {
+        if options.lenient_bare_point() {
+            if let Some(bare) = parse_bare_point::<F>(bytes, radix, exponent, options.lenient_exponent()) {
+                result = bare;
+            }
+        }
+    }
+    if let Err((ErrorCode::EmptyMantissa, _)) = result {
+        if options.implicit_mantissa_one() {
+            if let Some(implicit) = parse_implicit_mantissa::<F>(bytes, radix, exponent) {
+                result = implicit;
+            }
+        }
+    }
+    if let Err((ErrorCode::EmptyExponent, ptr)) = result {
+        if options.lenient_exponent() {
+            // Parse the mantissa before the exponent string, which the
+            // error points after.
+            result = parse(&index!(bytes[..index(ptr) - exponent.len()]));
+        }
+    }
+    if let Ok((_, ptr)) = result {
+        if options.case_sensitive_exponent() {
+            // Parse the mantissa before an exponent string with a
+            // different case, so the exponent is an invalid digit.
+            let parsed = &index!(bytes[..index(ptr)]);
+            match find_exponent(parsed, exponent) {
+                Some(start) if &index!(parsed[start..start + exponent.len()]) != exponent => {
+                    result = parse(&index!(bytes[..start]));
+                },
+                _ => (),
+            }
+        }
+    }
+    if let Ok((_, ptr)) = result {
+        if options.reject_exponent_leading_zeros() {
+            if let Some(index) = find_exponent_leading_zero(&index!(bytes[..index(ptr)]), exponent) {
+                return Err((ErrorCode::InvalidLeadingZeros, index).into());
+            }
+        }
+        if let Some(required) = options.require_significant_digits() {
+            match count_significant_digits(&index!(bytes[..index(ptr)]), radix) {
+                Some(count) if count != required => return Err((ErrorCode::InvalidSignificantDigits, 0).into()),
+                _ => (),
+            }
+        }
+        if options.require_exponent() {
+            let parsed = &index!(bytes[..index(ptr)]);
+            if count_significant_digits(parsed, radix).is_some() && find_exponent(parsed, exponent).is_none() {
+                return Err((ErrorCode::EmptyExponent, index(ptr)).into());
+            }
+        }
+        #[cfg(feature = "format")] {
+            if let Some(index) = find_misplaced_group(&index!(bytes[..index(ptr)]), radix, options.format(), options.grouping_sizes()) {
+                return Err((ErrorCode::InvalidDigit, index).into());
+            }
+        }
+    }
+    match result {
+        Ok((value, ptr)) if value.is_zero() && !options.parse_negative_zero() => Ok((F::ZERO, index(ptr))),
         Ok((value, ptr)) => Ok((value, index(ptr))),
         Err((code, ptr)) => Err((code, index(ptr)).into()),
     }
 }}
 
+perftools_inline!{
+#[cfg(feature = "format")]
+fn atof_format<F: StringToFloat>(bytes: &[u8], radix: u32, format: NumberFormat)
+    -> Result<(F, usize)>
+{
+    let index = | ptr | distance(bytes.as_ptr(), ptr);
+    let exponent = [exponent_notation_char(radix)];
+    match atof::<F>(bytes, radix, false, format, &exponent) {
+        Ok((value, ptr)) => match find_misplaced_group(&index!(bytes[..index(ptr)]), radix, format, b"") {
+            Some(index) => Err((ErrorCode::InvalidDigit, index).into()),
+            None        => Ok((value, index(ptr))),
+        },
+        Err((code, ptr)) => Err((code, index(ptr)).into()),
+    }
+}}
+
 perftools_inline!{
 #[cfg(feature = "format")]
 fn atof_lossy_format<F: StringToFloat>(bytes: &[u8], radix: u32, format: NumberFormat)
     -> Result<(F, usize)>
 {
     let index = | ptr | distance(bytes.as_ptr(), ptr);
-    match atof::<F>(bytes, radix, true, format) {
-        Ok((value, ptr)) => Ok((value, index(ptr))),
+    let exponent = [exponent_notation_char(radix)];
+    match atof::<F>(bytes, radix, true, format, &exponent) {
+        Ok((value, ptr)) => match find_misplaced_group(&index!(bytes[..index(ptr)]), radix, format, b"") {
+            Some(index) => Err((ErrorCode::InvalidDigit, index).into()),
+            None        => Ok((value, index(ptr))),
+        },
         Err((code, ptr)) => Err((code, index(ptr)).into()),
     }
 }}
 
+// Find the first digit separator that does not end a group of integer digits.
+perftools_inline!{
+#[cfg(feature = "format")]
+fn find_misplaced_group(bytes: &[u8], radix: u32, format: NumberFormat, grouping_sizes: &'static [u8])
+    -> Option<usize>
+{
+    let grouping_sizes = grouping_sizes_or_default(format, grouping_sizes);
+    find_misplaced_separator(bytes, radix, format.digit_separator(), grouping_sizes)
+}}
+
+perftools_inline!{
+#[cfg(feature = "metrics")]
+fn atof_metrics<F: StringToFloat>(bytes: &[u8], radix: u32)
+    -> Result<(F, ParseMetrics)>
+{
+    let index = | ptr | distance(bytes.as_ptr(), ptr);
+    let exponent = [exponent_notation_char(radix)];
+    let mut metrics = ParseMetrics::default();
+    // Special values are not parsed by the float algorithm, so they
+    // report the default metrics.
+    match atof_with_metrics::<F>(bytes, radix, false, NumberFormat::standard().unwrap(), &exponent, &mut metrics) {
+        Ok((value, ptr)) if index(ptr) == bytes.len() => Ok((value, metrics)),
+        Ok((_, ptr))                                   => Err((ErrorCode::InvalidDigit, index(ptr)).into()),
+        Err((code, ptr))                               => Err((code, index(ptr)).into()),
+    }
+}}
+
+// SIGNIFICANT DIGITS
+// ------------------
+
+/// Count the significant digits of a decimal float string.
+///
+/// The bytes are validated like [`parse`](fn.parse.html) for an `f64`,
+/// and the digits of the mantissa are counted without the value.
+/// Leading zeros and the decimal point are not significant, while every
+/// digit after the first non-zero digit is, including the trailing zeros
+/// of the fraction and of the integer, so `"0.00120"` has 3 significant
+/// digits, and `"100"` also has 3. The exponent is never counted, so
+/// `"1e2"` has 1. Zero has a single significant digit, like `"0.000"`,
+/// and special values, like `"NaN"`, have none.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # pub fn main() {
+/// assert_eq!(lexical_core::significant_digits(b"0.00120"), Ok(3));
+/// assert_eq!(lexical_core::significant_digits(b"100"), Ok(3));
+/// assert_eq!(lexical_core::significant_digits(b"-1.50e-7"), Ok(3));
+/// # }
+/// ```
+pub fn significant_digits(bytes: &[u8]) -> Result<usize> {
+    to_complete!(atof_nonlossy::<f64>, bytes, 10)?;
+    Ok(count_significant_digits(bytes, 10).unwrap_or(0))
+}
+
 // FROM LEXICAL
 // ------------
 
@@ -325,6 +755,14 @@ from_lexical!(atof_nonlossy, f32);
 from_lexical!(atof_nonlossy, f64);
 from_lexical_lossy!(atof_lossy, f32);
 from_lexical_lossy!(atof_lossy, f64);
+from_lexical_with_options!(atof_options, f32, ParseFloatOptions);
+from_lexical_with_options!(atof_options, f64, ParseFloatOptions);
+
+cfg_if!{
+if #[cfg(feature = "metrics")] {
+    from_lexical_metrics!(atof_metrics, f32);
+    from_lexical_metrics!(atof_metrics, f64);
+}}
 
 cfg_if!{
 if #[cfg(feature = "format")] {
@@ -340,6 +778,15 @@ if #[cfg(feature = "format")] {
 #[cfg(test)]
 mod tests {
     use crate::util::*;
+    use super::*;
+
+    #[test]
+    fn parse_backend_test() {
+        #[cfg(feature = "correct")]
+        assert_eq!(parse_backend(), ParseBackend::Correct);
+        #[cfg(not(feature = "correct"))]
+        assert_eq!(parse_backend(), ParseBackend::Lossy);
+    }
 
     #[test]
     fn f32_decimal_test() {
@@ -440,6 +887,8 @@ mod tests {
         assert_f64_eq!(1234567.0, f64::from_lexical(b"1234567.").unwrap());
 
         // No integer before decimal point test
+        assert_f64_eq!(-0.5, f64::from_lexical(b"-.5").unwrap());
+        assert_f64_eq!(0.25, f64::from_lexical(b"+.25").unwrap());
         assert_f64_eq!(0.1, f64::from_lexical(b".1").unwrap());
         assert_f64_eq!(0.12, f64::from_lexical(b".12").unwrap());
         assert_f64_eq!(0.1234567, f64::from_lexical(b".1234567").unwrap());
@@ -561,12 +1010,66 @@ mod tests {
         assert_f64_eq!(1234.0, f64::from_lexical_lossy_radix(b"YA", 36).unwrap());
     }
 
+    // Write the significant bits of a float in [1, 2), followed by the
+    // extra bits, in a power-of-two radix.
+    #[cfg(all(feature = "correct", feature = "radix"))]
+    fn pow2_radix_string(bits: u64, extra: &str, radix: u32) -> String {
+        let mut fraction: String = (0..52).rev()
+            .map(|i| if (bits >> i) & 1 == 1 { '1' } else { '0' })
+            .collect();
+        fraction.push_str(extra);
+        let width = radix.trailing_zeros() as usize;
+        while fraction.len() % width != 0 {
+            fraction.push('0');
+        }
+        let digits: String = fraction.as_bytes().chunks(width)
+            .map(|chunk| {
+                let digit = u32::from_str_radix(std::str::from_utf8(chunk).unwrap(), 2).unwrap();
+                std::char::from_digit(digit, radix).unwrap()
+            })
+            .collect();
+        format!("1.{}", digits)
+    }
+
+    #[cfg(all(feature = "correct", feature = "radix"))]
+    #[test]
+    fn f64_pow2_radix_rounding_test() {
+        assert_eq!(Ok(1.5), f64::from_lexical_radix(b"1.1", 2));
+        assert_eq!(Ok(1.5), f64::from_lexical_radix(b"1.4", 8));
+        assert_eq!(Ok(1.5), f64::from_lexical_radix(b"1.8", 16));
+
+        // Compare halfway cases against the exact, bitwise result.
+        let zeros = "0".repeat(70);
+        let ones = "1".repeat(70);
+        let mut state: u64 = 0x2545F4914F6CDD1D;
+        for _ in 0..500 {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            let bits = 0x3FF0_0000_0000_0000 | (state & 0x000F_FFFF_FFFF_FFFF);
+            let is_odd = bits & 1 == 1;
+            let cases = [
+                (String::new(), bits),
+                (format!("1{}", zeros), if is_odd { bits + 1 } else { bits }),
+                (format!("1{}1", zeros), bits + 1),
+                (format!("0{}", ones), bits),
+            ];
+            for radix in [2, 8, 16].iter().cloned() {
+                for (extra, expected) in cases.iter() {
+                    let string = pow2_radix_string(bits, extra, radix);
+                    let value = f64::from_lexical_radix(string.as_bytes(), radix as u8).unwrap();
+                    assert_eq!(value.to_bits(), *expected, "{} in radix {}", string, radix);
+                }
+            }
+        }
+    }
+
     #[test]
     fn f32_lossy_decimal_test() {
         assert_eq!(Err(ErrorCode::EmptyMantissa.into()), f32::from_lexical_lossy(b"."));
         assert_eq!(Err(ErrorCode::Empty.into()), f32::from_lexical_lossy(b""));
         assert_eq!(Ok(0.0), f32::from_lexical_lossy(b"0.0"));
-        assert_eq!(Err((ErrorCode::InvalidDigit, 1).into()), f32::from_lexical_lossy(b"1a"));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1, b'a').into()), f32::from_lexical_lossy(b"1a"));
 
         // Bug fix for Issue #8
         assert_eq!(Ok(5.002868148396374), f32::from_lexical_lossy(b"5.002868148396374"));
@@ -577,12 +1080,456 @@ mod tests {
         assert_eq!(Err(ErrorCode::EmptyMantissa.into()), f64::from_lexical_lossy(b"."));
         assert_eq!(Err(ErrorCode::Empty.into()), f64::from_lexical_lossy(b""));
         assert_eq!(Ok(0.0), f64::from_lexical_lossy(b"0.0"));
-        assert_eq!(Err((ErrorCode::InvalidDigit, 1).into()), f64::from_lexical_lossy(b"1a"));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1, b'a').into()), f64::from_lexical_lossy(b"1a"));
 
         // Bug fix for Issue #8
         assert_eq!(Ok(5.002868148396374), f64::from_lexical_lossy(b"5.002868148396374"));
     }
 
+    #[test]
+    fn f64_options_test() {
+        let options = ParseFloatOptions::new();
+        assert_f64_eq!(1.5e103, f64::from_lexical_with_options(b"1.5E103", &options).unwrap());
+        assert_eq!(Ok(1.5e-3), f64::from_lexical_with_options(b"1.5e-3", &options));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 3, b'x').into()), f64::from_lexical_with_options(b"1.5x3", &options));
+
+        let options = ParseFloatOptions::builder().exponent_string(b"x").build().unwrap();
+        assert_eq!(Ok(1.5e3), f64::from_lexical_with_options(b"1.5x3", &options));
+        assert_eq!(Ok(1.5e3), f64::from_lexical_with_options(b"1.5X3", &options));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 3, b'e').into()), f64::from_lexical_with_options(b"1.5e3", &options));
+        assert_eq!(Err((ErrorCode::EmptyExponent, 4).into()), f64::from_lexical_with_options(b"1.5x", &options));
+
+        let options = ParseFloatOptions::builder().exponent_string(b"*10^").build().unwrap();
+        assert_eq!(Ok(1.5e3), f64::from_lexical_with_options(b"1.5*10^3", &options));
+        assert_eq!(Ok(-2.5e-7), f64::from_lexical_with_options(b"-2.5*10^-7", &options));
+        assert_eq!(Ok((1.5, 3)), f64::from_lexical_partial_with_options(b"1.5*10", &options));
+        assert_eq!(Ok(f64::INFINITY), f64::from_lexical_with_options(b"inf", &options));
+    }
+
+    #[test]
+    #[cfg(feature = "format")]
+    fn f64_options_format_test() {
+        let format = NumberFormat::ignore(b'_').unwrap();
+        let options = ParseFloatOptions::builder().format(format).build().unwrap();
+        assert_eq!(Ok(1234.5), f64::from_lexical_with_options(b"1_234.5", &options));
+        assert_eq!(Ok(1.5e10), f64::from_lexical_with_options(b"1.5e1_0", &options));
+        assert_eq!(Ok((1.5, 5)), f64::from_lexical_partial_with_options(b"1._5_x", &options));
+
+        let format = NumberFormat::grouping(b',', true).unwrap();
+        let options = ParseFloatOptions::builder().format(format).build().unwrap();
+        assert_eq!(Ok(-1234567.0), f64::from_lexical_with_options(b"-1,234,567", &options));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 4, b',').into()), f64::from_lexical_with_options(b"1.25,0", &options));
+        assert_eq!(Ok((1234.0, 5)), f64::from_lexical_partial_with_options(b"1,234 5", &options));
+    }
+
+    #[test]
+    #[cfg(feature = "radix")]
+    fn f64_options_radix_test() {
+        let options = ParseFloatOptions::builder().exponent_string(b"x").build().unwrap();
+        assert_eq!(Ok(6.0), f64::from_lexical_radix_with_options(b"11x1", 2, &options));
+        assert_eq!(Ok((6.0, 4)), f64::from_lexical_partial_radix_with_options(b"11x1", 2, &options));
+
+        // Exponent strings starting with a digit use the default character.
+        let options = ParseFloatOptions::builder().exponent_string(b"e").build().unwrap();
+        assert_eq!(Ok(3806.0), f64::from_lexical_radix_with_options(b"EDE", 16, &options));
+        assert_eq!(Ok(3806.0), f64::from_lexical_radix_with_options(b"ED.E^1", 16, &options));
+    }
+
+    #[test]
+    fn f64_strict_sign_test() {
+        let options = ParseFloatOptions::new();
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 1).into()), f64::from_lexical_with_options(b"--5", &options));
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 1).into()), f64::from_lexical_with_options(b"+-5", &options));
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 1).into()), f64::from_lexical_with_options(b"-+5", &options));
+
+        let options = ParseFloatOptions::builder().strict_sign(true).build().unwrap();
+        assert_eq!(Err((ErrorCode::InvalidSign, 1).into()), f64::from_lexical_with_options(b"--5", &options));
+        assert_eq!(Err((ErrorCode::InvalidSign, 1).into()), f64::from_lexical_with_options(b"+-5", &options));
+        assert_eq!(Err((ErrorCode::InvalidSign, 1).into()), f64::from_lexical_with_options(b"-+5", &options));
+        assert_eq!(Err((ErrorCode::InvalidSign, 1).into()), f64::from_lexical_partial_with_options(b"--5", &options));
+        assert_eq!(Ok(-5.0), f64::from_lexical_with_options(b"-5", &options));
+        assert_eq!(Ok(-5e-5), f64::from_lexical_with_options(b"-5e-5", &options));
+    }
+
+    #[test]
+    fn f64_trim_chars_test() {
+        let options = ParseFloatOptions::builder().trim_chars(b"[]\"").build().unwrap();
+        assert_eq!(Ok(5.0), f64::from_lexical_with_options(b"[5]", &options));
+        assert_eq!(Ok(-1.5e3), f64::from_lexical_with_options(b"\"-1.5e3\"", &options));
+        assert_eq!(Ok(f64::INFINITY), f64::from_lexical_with_options(b"[inf]", &options));
+
+        // Nested and unbalanced bytes are trimmed, without checking the balance.
+        assert_eq!(Ok(0.5), f64::from_lexical_with_options(b"[[\"0.5\"]]", &options));
+        assert_eq!(Ok(0.5), f64::from_lexical_with_options(b"[0.5", &options));
+        assert_eq!(Ok(0.5), f64::from_lexical_with_options(b"0.5]]", &options));
+
+        // Errors and processed bytes are relative to the untrimmed bytes.
+        assert_eq!(Err((ErrorCode::Empty, 3).into()), f64::from_lexical_with_options(b"[\"]", &options));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 4, b']').into()), f64::from_lexical_with_options(b"[1.5]x", &options));
+        assert_eq!(Ok((1.5, 5)), f64::from_lexical_partial_with_options(b"[1.5]", &options));
+        assert_eq!(Ok((1.5, 4)), f64::from_lexical_partial_with_options(b"[1.5,2]", &options));
+    }
+
+    #[test]
+    #[cfg(feature = "optional")]
+    fn f64_sentinel_test() {
+        let missing = f64::from_bits(0x7FF8_0000_0000_0ABC);
+        let options = ParseFloatOptions::builder().sentinel_for(missing, b"--").build().unwrap();
+        assert_eq!(missing.to_bits(), f64::from_lexical_with_options(b"--", &options).unwrap().to_bits());
+        assert!(f32::from_lexical_with_options(b"--", &options).unwrap().is_nan());
+        assert_eq!(Ok(-1.5), f64::from_lexical_with_options(b"-1.5", &options));
+        assert_eq!(Ok((missing.to_bits(), 2)), f64::from_lexical_partial_with_options(b"--5", &options).map(|(x, n)| (x.to_bits(), n)));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 2, b'5').into()), f64::from_lexical_with_options(b"--5", &options));
+
+        // The sentinel takes precedence over NaN and infinity.
+        let options = ParseFloatOptions::builder().sentinel_for(-9999.0, b"NaN").build().unwrap();
+        assert_eq!(Ok(-9999.0), f64::from_lexical_with_options(b"NaN", &options));
+        assert_eq!(Ok(-9999.0f32), f32::from_lexical_with_options(b"NaN", &options));
+        assert!(f64::from_lexical_with_options(b"nan", &options).unwrap().is_nan());
+
+        // Longer floats take precedence over the sentinel.
+        let options = ParseFloatOptions::builder().sentinel_for(-9999.0, b"-").build().unwrap();
+        assert_eq!(Ok(-9999.0), f64::from_lexical_with_options(b"-", &options));
+        assert_eq!(Ok(-5.0), f64::from_lexical_with_options(b"-5", &options));
+        assert_eq!(Ok((-9999.0, 1)), f64::from_lexical_partial_with_options(b"-x", &options));
+
+        // The sentinel is matched after trimming.
+        let options = ParseFloatOptions::builder()
+            .sentinel_for(-9999.0, b"--")
+            .trim_chars(b"\"")
+            .build()
+            .unwrap();
+        assert_eq!(Ok(-9999.0), f64::from_lexical_with_options(b"\"--\"", &options));
+    }
+
+    #[test]
+    fn f64_significant_digits_test() {
+        let options = ParseFloatOptions::builder().require_significant_digits(Some(7)).build().unwrap();
+        assert_eq!(Ok(3.141593), f64::from_lexical_with_options(b"3.141593", &options));
+        assert_eq!(Err((ErrorCode::InvalidSignificantDigits, 0).into()), f64::from_lexical_with_options(b"3.14", &options));
+        assert_eq!(Err((ErrorCode::InvalidSignificantDigits, 0).into()), f64::from_lexical_with_options(b"3.1415926", &options));
+
+        // Leading zeros are not significant, trailing zeros are.
+        assert_eq!(Ok(-0.001234567), f64::from_lexical_with_options(b"-0.001234567", &options));
+        assert_eq!(Ok(3.140000), f64::from_lexical_with_options(b"3.140000", &options));
+        assert_eq!(Ok(1234500.0), f64::from_lexical_with_options(b"001234500", &options));
+        assert_eq!(Ok(125.0), f64::from_lexical_with_options(b"1.250000e2", &options));
+
+        // Zero has a single significant digit, and special values are not checked.
+        let options = ParseFloatOptions::builder().require_significant_digits(Some(1)).build().unwrap();
+        assert_eq!(Ok(0.0), f64::from_lexical_with_options(b"0.000", &options));
+        assert_eq!(Ok(0.05), f64::from_lexical_with_options(b"0.05", &options));
+        assert_eq!(Ok(f64::INFINITY), f64::from_lexical_with_options(b"inf", &options));
+        assert_eq!(Err((ErrorCode::InvalidSignificantDigits, 0).into()), f64::from_lexical_with_options(b"0.50", &options));
+
+        // Only the processed bytes are counted.
+        assert_eq!(Ok((5.0, 1)), f64::from_lexical_partial_with_options(b"5,25", &options));
+    }
+
+    #[test]
+    fn significant_digits_test() {
+        assert_eq!(significant_digits(b"1"), Ok(1));
+        assert_eq!(significant_digits(b"3.14"), Ok(3));
+        assert_eq!(significant_digits(b"+3.140"), Ok(4));
+        assert_eq!(significant_digits(b"0.00120"), Ok(3));
+        assert_eq!(significant_digits(b"-0.00120"), Ok(3));
+        assert_eq!(significant_digits(b"00123"), Ok(3));
+        assert_eq!(significant_digits(b"100"), Ok(3));
+        assert_eq!(significant_digits(b"100.0"), Ok(4));
+        assert_eq!(significant_digits(b"1.20e5"), Ok(3));
+        assert_eq!(significant_digits(b"1e-300"), Ok(1));
+        assert_eq!(significant_digits(b"123456789012345678901234567890"), Ok(30));
+
+        // Zero has a single significant digit, and special values have none.
+        assert_eq!(significant_digits(b"0"), Ok(1));
+        assert_eq!(significant_digits(b"-0.000"), Ok(1));
+        assert_eq!(significant_digits(b"NaN"), Ok(0));
+        assert_eq!(significant_digits(b"-inf"), Ok(0));
+
+        // Errors.
+        assert_eq!(significant_digits(b""), Err((ErrorCode::Empty, 0).into()));
+        assert_eq!(significant_digits(b"1.2.3"), Err((ErrorCode::InvalidDigit, 3, b'.').into()));
+        assert_eq!(significant_digits(b"12a"), Err((ErrorCode::InvalidDigit, 2, b'a').into()));
+    }
+
+    #[test]
+    fn f64_lenient_exponent_test() {
+        let options = ParseFloatOptions::new();
+        assert_eq!(Err((ErrorCode::EmptyExponent, 2).into()), f64::from_lexical_partial_with_options(b"1e", &options));
+        assert_eq!(Err((ErrorCode::EmptyExponent, 2).into()), f64::from_lexical_partial_with_options(b"1e+", &options));
+        assert_eq!(Err((ErrorCode::EmptyExponent, 2).into()), f64::from_lexical_partial_with_options(b"1efoo", &options));
+        assert_eq!(Err((ErrorCode::EmptyExponent, 2).into()), f64::from_lexical_with_options(b"1e", &options));
+
+        let options = ParseFloatOptions::builder().lenient_exponent(true).build().unwrap();
+        assert_eq!(Ok((1.0, 1)), f64::from_lexical_partial_with_options(b"1e", &options));
+        assert_eq!(Ok((1.0, 1)), f64::from_lexical_partial_with_options(b"1e+", &options));
+        assert_eq!(Ok((1.0, 1)), f64::from_lexical_partial_with_options(b"1efoo", &options));
+        assert_eq!(Ok((-1.5, 4)), f64::from_lexical_partial_with_options(b"-1.5E-", &options));
+        assert_eq!(Ok((1e5, 3)), f64::from_lexical_partial_with_options(b"1e5", &options));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1, b'e').into()), f64::from_lexical_with_options(b"1e", &options));
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), f64::from_lexical_partial_with_options(b"e5", &options));
+
+        let options = ParseFloatOptions::builder().exponent_string(b"*10^").lenient_exponent(true).build().unwrap();
+        assert_eq!(Ok((2.5, 3)), f64::from_lexical_partial_with_options(b"2.5*10^", &options));
+    }
+
+    #[test]
+    fn f64_case_sensitive_exponent_test() {
+        let options = ParseFloatOptions::new();
+        assert_eq!(Ok(1e5), f64::from_lexical_with_options(b"1e5", &options));
+        assert_eq!(Ok(1e5), f64::from_lexical_with_options(b"1E5", &options));
+
+        let options = ParseFloatOptions::builder().case_sensitive_exponent(true).build().unwrap();
+        assert_eq!(Ok(1e5), f64::from_lexical_with_options(b"1e5", &options));
+        assert_eq!(Ok(-1.5e-5), f64::from_lexical_with_options(b"-1.5e-5", &options));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1, b'E').into()), f64::from_lexical_with_options(b"1E5", &options));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 4, b'E').into()), f64::from_lexical_with_options(b"-1.5E-5", &options));
+        assert_eq!(Ok((1.0, 1)), f64::from_lexical_partial_with_options(b"1E5", &options));
+
+        // The case of the exponent string is used, including uppercase and mixed case.
+        let options = ParseFloatOptions::builder().exponent_string(b"E").case_sensitive_exponent(true).build().unwrap();
+        assert_eq!(Ok(1e5), f64::from_lexical_with_options(b"1E5", &options));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1, b'e').into()), f64::from_lexical_with_options(b"1e5", &options));
+        let options = ParseFloatOptions::builder().exponent_string(b"x10^").case_sensitive_exponent(true).build().unwrap();
+        assert_eq!(Ok(1e5), f64::from_lexical_with_options(b"1x10^5", &options));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1, b'X').into()), f64::from_lexical_with_options(b"1X10^5", &options));
+    }
+
+    #[test]
+    fn f64_require_exponent_test() {
+        let options = ParseFloatOptions::builder().require_exponent(true).build().unwrap();
+        assert_eq!(Ok(3.14), f64::from_lexical_with_options(b"3.14e0", &options));
+        assert_eq!(Ok(3e5), f64::from_lexical_with_options(b"3e5", &options));
+        assert_eq!(Ok(-2.5e-3), f64::from_lexical_with_options(b"-2.5E-3", &options));
+        assert_eq!(Ok(f64::INFINITY), f64::from_lexical_with_options(b"inf", &options));
+        assert_eq!(Err((ErrorCode::EmptyExponent, 4).into()), f64::from_lexical_with_options(b"3.14", &options));
+        assert_eq!(Err((ErrorCode::EmptyExponent, 1).into()), f64::from_lexical_with_options(b"3", &options));
+        assert_eq!(Err((ErrorCode::EmptyExponent, 4).into()), f64::from_lexical_partial_with_options(b"3.14,1e0", &options));
+
+        // Custom exponent strings must be present.
+        let options = ParseFloatOptions::builder().exponent_string(b"x10^").require_exponent(true).build().unwrap();
+        assert_eq!(Ok(3e5), f64::from_lexical_with_options(b"3x10^5", &options));
+        assert_eq!(Err((ErrorCode::EmptyExponent, 1).into()), f64::from_lexical_with_options(b"3", &options));
+    }
+
+    #[test]
+    fn f64_reject_exponent_leading_zeros_test() {
+        let options = ParseFloatOptions::new();
+        assert_eq!(Ok(1.0), f64::from_lexical_with_options(b"1e00", &options));
+        assert_eq!(Ok(1e7), f64::from_lexical_with_options(b"1e007", &options));
+
+        let options = ParseFloatOptions::builder().reject_exponent_leading_zeros(true).build().unwrap();
+        assert_eq!(Err((ErrorCode::InvalidLeadingZeros, 3).into()), f64::from_lexical_with_options(b"1e00", &options));
+        assert_eq!(Err((ErrorCode::InvalidLeadingZeros, 3).into()), f64::from_lexical_with_options(b"1e01", &options));
+        assert_eq!(Err((ErrorCode::InvalidLeadingZeros, 3).into()), f64::from_lexical_with_options(b"1e007", &options));
+        assert_eq!(Err((ErrorCode::InvalidLeadingZeros, 7).into()), f64::from_lexical_with_options(b"-1.5E-01", &options));
+        assert_eq!(Err((ErrorCode::InvalidLeadingZeros, 3).into()), f64::from_lexical_partial_with_options(b"1e01,", &options));
+        assert_eq!(Ok(1.0), f64::from_lexical_with_options(b"1e0", &options));
+        assert_eq!(Ok(1e10), f64::from_lexical_with_options(b"1e10", &options));
+        assert_eq!(Ok(1e-10), f64::from_lexical_with_options(b"1e-10", &options));
+        assert_eq!(Ok(100.0), f64::from_lexical_with_options(b"00100", &options));
+        assert_eq!(Ok((1.0, 3)), f64::from_lexical_partial_with_options(b"1e0,0", &options));
+
+        let options = ParseFloatOptions::builder().exponent_string(b"x10^").reject_exponent_leading_zeros(true).build().unwrap();
+        assert_eq!(Err((ErrorCode::InvalidLeadingZeros, 6).into()), f64::from_lexical_with_options(b"1x10^05", &options));
+        assert_eq!(Ok(1e5), f64::from_lexical_with_options(b"1x10^5", &options));
+    }
+
+    #[test]
+    fn f64_lenient_bare_point_test() {
+        let options = ParseFloatOptions::new();
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), f64::from_lexical_with_options(b".", &options));
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), f64::from_lexical_with_options(b".e5", &options));
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 1).into()), f64::from_lexical_with_options(b"+.", &options));
+
+        let sign_bit = 0x8000000000000000u64;
+        let options = ParseFloatOptions::builder().lenient_bare_point(true).build().unwrap();
+        assert_eq!(Ok(0.0), f64::from_lexical_with_options(b".", &options));
+        assert_eq!(Ok(0.0), f64::from_lexical_with_options(b".e5", &options));
+        assert_eq!(Ok(0.0), f64::from_lexical_with_options(b".E-5", &options));
+        assert_eq!(Ok(0.0), f64::from_lexical_with_options(b"+.", &options));
+        assert_eq!(Ok(sign_bit), f64::from_lexical_with_options(b"-.", &options).map(f64::to_bits));
+        assert_eq!(Ok((0.0, 1)), f64::from_lexical_partial_with_options(b".,", &options));
+        assert_eq!(Ok((0.0, 4)), f64::from_lexical_partial_with_options(b"+.e5,", &options));
+        assert_eq!(Ok(0.5), f64::from_lexical_with_options(b".5", &options));
+
+        // Errors unrelated to the decimal point are unchanged.
+        assert_eq!(Err((ErrorCode::Empty, 0).into()), f64::from_lexical_with_options(b"", &options));
+        assert_eq!(Err((ErrorCode::Empty, 1).into()), f64::from_lexical_with_options(b"+", &options));
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), f64::from_lexical_with_options(b"e5", &options));
+        assert_eq!(Err((ErrorCode::EmptyExponent, 2).into()), f64::from_lexical_with_options(b".e", &options));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1, b'.').into()), f64::from_lexical_with_options(b"..", &options));
+
+        // Combined with the other options.
+        let options = ParseFloatOptions::builder()
+            .lenient_bare_point(true)
+            .lenient_exponent(true)
+            .parse_negative_zero(false)
+            .build()
+            .unwrap();
+        assert_eq!(Ok((0.0, 1)), f64::from_lexical_partial_with_options(b".e", &options));
+        assert_eq!(Ok(0), f64::from_lexical_with_options(b"-.", &options).map(f64::to_bits));
+
+        let options = ParseFloatOptions::builder()
+            .lenient_bare_point(true)
+            .reject_exponent_leading_zeros(true)
+            .build()
+            .unwrap();
+        assert_eq!(Err((ErrorCode::InvalidLeadingZeros, 3).into()), f64::from_lexical_with_options(b".e05", &options));
+    }
+
+    #[test]
+    fn f64_implicit_mantissa_one_test() {
+        let options = ParseFloatOptions::new();
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), f64::from_lexical_with_options(b"e3", &options));
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 1).into()), f64::from_lexical_with_options(b"-e3", &options));
+
+        let options = ParseFloatOptions::builder().implicit_mantissa_one(true).build().unwrap();
+        assert_eq!(Ok(1000.0), f64::from_lexical_with_options(b"e3", &options));
+        assert_eq!(Ok(-1000.0), f64::from_lexical_with_options(b"-e3", &options));
+        assert_eq!(Ok(1000.0), f64::from_lexical_with_options(b"+e+3", &options));
+        assert_eq!(Ok(0.01), f64::from_lexical_with_options(b"E-2", &options));
+        #[cfg(feature = "correct")]
+        assert_eq!(Ok(1e-300), f64::from_lexical_with_options(b"e-300", &options));
+        assert_eq!(Ok(1.0), f64::from_lexical_with_options(b"e0", &options));
+        assert_eq!(Ok(1e22), f64::from_lexical_with_options(b"e00000000000000000000022", &options));
+        assert_eq!(Ok(f64::INFINITY), f64::from_lexical_with_options(b"e99999999999999999999", &options));
+        assert_eq!(Ok(0.0), f64::from_lexical_with_options(b"e-99999999999999999999", &options));
+        assert_eq!(Ok((1e3, 2)), f64::from_lexical_partial_with_options(b"e3,", &options));
+        assert_eq!(Ok(1e3), f32::from_lexical_with_options(b"e3", &options));
+        assert_eq!(Ok(1.5), f64::from_lexical_with_options(b"1.5", &options));
+
+        // The exponent must have digits.
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), f64::from_lexical_with_options(b"e", &options));
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), f64::from_lexical_with_options(b"e-", &options));
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 1).into()), f64::from_lexical_with_options(b"-e", &options));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 2, b'.').into()), f64::from_lexical_with_options(b"e3.5", &options));
+
+        // Combined with the other options.
+        let options = ParseFloatOptions::builder()
+            .implicit_mantissa_one(true)
+            .exponent_string(b"x10^")
+            .build()
+            .unwrap();
+        assert_eq!(Ok(1e-5), f64::from_lexical_with_options(b"x10^-5", &options));
+        assert_eq!(Ok(-1e2), f64::from_lexical_with_options(b"-X10^2", &options));
+    }
+
+    #[test]
+    fn f64_sign_before_point_test() {
+        let options = ParseFloatOptions::new();
+        assert_eq!(Ok(-0.5), f64::from_lexical_with_options(b"-.5", &options));
+        assert_eq!(Ok(0.25), f64::from_lexical_with_options(b"+.25", &options));
+        assert_eq!(Ok((-0.5, 3)), f64::from_lexical_partial_with_options(b"-.5,", &options));
+
+        let options = ParseFloatOptions::builder().sign_before_point(false).build().unwrap();
+        assert_eq!(Err((ErrorCode::EmptyInteger, 1).into()), f64::from_lexical_with_options(b"-.5", &options));
+        assert_eq!(Err((ErrorCode::EmptyInteger, 1).into()), f64::from_lexical_with_options(b"+.25", &options));
+        assert_eq!(Err((ErrorCode::EmptyInteger, 1).into()), f64::from_lexical_partial_with_options(b"-.5,", &options));
+        assert_eq!(Ok(0.5), f64::from_lexical_with_options(b".5", &options));
+        assert_eq!(Ok(-0.5), f64::from_lexical_with_options(b"-0.5", &options));
+        assert_eq!(Ok(-5.0), f64::from_lexical_with_options(b"-5.", &options));
+
+        // Combined with the other options.
+        let options = ParseFloatOptions::builder()
+            .sign_before_point(false)
+            .lenient_bare_point(true)
+            .trim_chars(b" ")
+            .build()
+            .unwrap();
+        assert_eq!(Ok(0.0), f64::from_lexical_with_options(b".", &options));
+        assert_eq!(Err((ErrorCode::EmptyInteger, 1).into()), f64::from_lexical_with_options(b"-.", &options));
+        assert_eq!(Err((ErrorCode::EmptyInteger, 2).into()), f64::from_lexical_with_options(b" -.5 ", &options));
+    }
+
+    #[test]
+    fn f64_negative_zero_test() {
+        let sign_bit = 0x8000000000000000u64;
+        let options = ParseFloatOptions::new();
+        assert_eq!(Ok(sign_bit), f64::from_lexical_with_options(b"-0", &options).map(f64::to_bits));
+        assert_eq!(Ok(sign_bit), f64::from_lexical_with_options(b"-0.0e5", &options).map(f64::to_bits));
+        assert_eq!(Ok(0), f64::from_lexical_with_options(b"0", &options).map(f64::to_bits));
+
+        let options = ParseFloatOptions::builder().parse_negative_zero(false).build().unwrap();
+        assert_eq!(Ok(0), f64::from_lexical_with_options(b"-0", &options).map(f64::to_bits));
+        assert_eq!(Ok(0), f64::from_lexical_with_options(b"-0.0e5", &options).map(f64::to_bits));
+        assert_eq!(Ok(0), f64::from_lexical_with_options(b"-1e-400", &options).map(f64::to_bits));
+        assert_eq!(Ok((0, 2)), f64::from_lexical_partial_with_options(b"-0,", &options).map(|(v, n)| (v.to_bits(), n)));
+        assert_eq!(Ok(-1.5), f64::from_lexical_with_options(b"-1.5", &options));
+    }
+
+    #[test]
+    #[cfg(feature = "metrics")]
+    fn f64_metrics_test() {
+        let metrics = |digits_consumed, used_slow_path, exponent| ParseMetrics { digits_consumed, used_slow_path, exponent };
+        assert_eq!(Ok((1.5, metrics(2, false, 0))), f64::from_lexical_metrics(b"1.5"));
+        assert_eq!(Ok((-1500.0, metrics(2, false, 3))), f64::from_lexical_metrics(b"-1.50e3"));
+        assert_eq!(Ok((0.001, metrics(3, false, 0))), f64::from_lexical_metrics(b"000.001"));
+        assert_eq!(Ok((0.0, metrics(0, false, 0))), f64::from_lexical_metrics(b"0.0"));
+        assert_eq!(Ok((1e300, metrics(1, true, 300))), f64::from_lexical_metrics(b"1e300"));
+        assert_eq!(Ok((f64::INFINITY, metrics(1, true, 400))), f64::from_lexical_metrics(b"1e400"));
+        assert_eq!(Ok((2.2250738585072014e-308, metrics(17, true, -308))), f64::from_lexical_metrics(b"2.2250738585072014e-308"));
+        assert_eq!(Ok((9007199254740993.0, metrics(16, true, 0))), f64::from_lexical_metrics(b"9007199254740993"));
+        assert_eq!(Ok((f64::INFINITY, ParseMetrics::default())), f64::from_lexical_metrics(b"inf"));
+        assert!(f64::from_lexical_metrics(b"NaN").unwrap().0.is_nan());
+        assert_eq!(Err((ErrorCode::InvalidDigit, 3, b'x').into()), f64::from_lexical_metrics(b"1.5x"));
+        assert_eq!(Err(ErrorCode::Empty.into()), f64::from_lexical_metrics(b""));
+    }
+
+    // Generate every placement of the decimal point in the first `count`
+    // digits of `digits`, including leading and trailing points.
+    #[cfg(feature = "metrics")]
+    fn fast_path_corpus(digits: &str, count: usize) -> Vec<String> {
+        let mut corpus = Vec::new();
+        for length in 1..=count {
+            let digits = &digits[..length];
+            for point in 0..=length {
+                corpus.push(format!("{}.{}", &digits[..point], &digits[point..]));
+                corpus.push(format!("-{}.{}", &digits[..point], &digits[point..]));
+            }
+            corpus.push(digits.to_string());
+        }
+        corpus
+    }
+
+    #[test]
+    #[cfg(feature = "metrics")]
+    fn f32_metrics_fast_path_test() {
+        // Values with at most 7 digits and no exponent fit in the
+        // mantissa, with a scaled exponent of at least -7, so they
+        // must never require the moderate or slow path.
+        for string in fast_path_corpus("9876543", 7) {
+            let (_, metrics) = f32::from_lexical_metrics(string.as_bytes()).unwrap();
+            assert!(!metrics.used_slow_path, "{} used the slow path", string);
+        }
+
+        // Leading zeros of the fraction scale the exponent.
+        assert!(!f32::from_lexical_metrics(b"0.0000000001").unwrap().1.used_slow_path);
+        assert!(f32::from_lexical_metrics(b"0.00000000001").unwrap().1.used_slow_path);
+        assert!(!f32::from_lexical_metrics(b"1e12").unwrap().1.used_slow_path);
+    }
+
+    #[test]
+    #[cfg(feature = "metrics")]
+    fn f64_metrics_fast_path_test() {
+        // Values with at most 15 digits and no exponent fit in the
+        // mantissa, with a scaled exponent of at least -15, so they
+        // must never require the moderate or slow path.
+        for string in fast_path_corpus("987654321098765", 15) {
+            let (_, metrics) = f64::from_lexical_metrics(string.as_bytes()).unwrap();
+            assert!(!metrics.used_slow_path, "{} used the slow path", string);
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "metrics", feature = "radix"))]
+    fn f64_metrics_radix_test() {
+        let metrics = |digits_consumed, used_slow_path, exponent| ParseMetrics { digits_consumed, used_slow_path, exponent };
+        assert_eq!(Ok((2.5, metrics(3, false, 0))), f64::from_lexical_metrics_radix(b"10.1", 2));
+        assert_eq!(Ok((1e300, metrics(1, true, 300))), f64::from_lexical_metrics_radix(b"1e300", 10));
+        assert_eq!(Ok((4.0, metrics(1, false, 2))), f64::from_lexical_metrics_radix(b"1e10", 2));
+    }
+
     #[test]
     #[cfg(feature = "format")]
     fn f64_special_test() {
@@ -622,6 +1569,24 @@ mod tests {
         assert!(f64::from_lexical_format(b"n_an", f5).is_err());
     }
 
+    #[test]
+    #[cfg(feature = "format")]
+    fn f64_special_separator_test() {
+        // Digit separators are only skipped in special values with SPECIAL_DIGIT_SEPARATOR.
+        let format = NumberFormat::ignore(b'_').unwrap() - NumberFormat::SPECIAL_DIGIT_SEPARATOR;
+        assert_eq!(f64::from_lexical_format(b"n_an", format), Err((ErrorCode::InvalidDigit, 1, b'_').into()));
+        assert_eq!(f64::from_lexical_format(b"i_nf", format), Err((ErrorCode::InvalidDigit, 1, b'_').into()));
+        assert_eq!(f64::from_lexical_format(b"inf_inity", format), Err((ErrorCode::InvalidDigit, 3, b'_').into()));
+        assert!(f64::from_lexical_format(b"nan", format).unwrap().is_nan());
+        assert_eq!(f64::from_lexical_format(b"inf", format), Ok(f64::INFINITY));
+        assert_eq!(f64::from_lexical_format(b"infinity", format), Ok(f64::INFINITY));
+
+        let format = NumberFormat::ignore(b'_').unwrap();
+        assert!(f64::from_lexical_format(b"n_an", format).unwrap().is_nan());
+        assert_eq!(f64::from_lexical_format(b"i_nf", format), Ok(f64::INFINITY));
+        assert_eq!(f64::from_lexical_format(b"inf_inity", format), Ok(f64::INFINITY));
+    }
+
     #[test]
     #[cfg(feature = "format")]
     fn f64_required_integer_digits_test() {
@@ -747,6 +1712,67 @@ mod tests {
         assert!(f64::from_lexical_format(b"010.0", format).is_err());
     }
 
+    #[test]
+    #[cfg(feature = "format")]
+    fn f64_grouping_test() {
+        let strict = NumberFormat::grouping(b' ', true).unwrap();
+        assert_eq!(Ok(1234.5), f64::from_lexical_format(b"1 234.5", strict));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1, b' ').into()), f64::from_lexical_format(b"1 2", strict));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1, b' ').into()), f64::from_lexical_partial_format(b"1 2.5", strict));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 5, b' ').into()), f64::from_lexical_format(b"1 234 56.5", strict));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1, b' ').into()), f64::from_lexical_format(b"1  2", strict));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 7, b' ').into()), f64::from_lexical_format(b"1 234.5 6", strict));
+        assert_eq!(Ok((1234.0, 5)), f64::from_lexical_partial_format(b"1 234 ", strict));
+
+        let lenient = NumberFormat::grouping(b' ', false).unwrap();
+        assert_eq!(Ok(1234.5), f64::from_lexical_format(b"1  234 .5", lenient));
+        assert_eq!(Ok((1234.0, 6)), f64::from_lexical_partial_format(b"1 234 ", lenient));
+    }
+
+    #[test]
+    #[cfg(feature = "format")]
+    fn f64_signed_grouping_test() {
+        // The sign is parsed before the groups, and the first group may be 1 to 3 digits.
+        let strict = NumberFormat::grouping(b',', true).unwrap();
+        assert_eq!(Ok(-1234.0), f64::from_lexical_format(b"-1,234", strict));
+        assert_eq!(Ok(1234.5), f64::from_lexical_format(b"+1,234.5", strict));
+        assert_eq!(Ok(-12345.5), f64::from_lexical_format(b"-12,345.5", strict));
+        assert_eq!(Ok(-123456.25), f64::from_lexical_format(b"-123,456.25", strict));
+        assert_eq!(Ok(123450.0), f64::from_lexical_format(b"+1,234.5e2", strict));
+        assert_eq!(Ok((-1234.5, 8)), f64::from_lexical_partial_format(b"-1,234.5,", strict));
+
+        // Separators directly after the sign are not between two digits.
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 1).into()), f64::from_lexical_format(b"-,123.5", strict));
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 1).into()), f64::from_lexical_format(b"+,1", strict));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 2, b',').into()), f64::from_lexical_format(b"-1,.5", strict));
+
+        let lenient = NumberFormat::grouping(b',', false).unwrap();
+        assert_eq!(Ok(-1234.5), f64::from_lexical_format(b"-1,,234,.5", lenient));
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 1).into()), f64::from_lexical_format(b"-,123.5", lenient));
+    }
+
+    #[test]
+    #[cfg(feature = "format")]
+    fn f64_grouping_sizes_test() {
+        let format = NumberFormat::grouping(b',', true).unwrap();
+        let western = ParseFloatOptions::builder().format(format).grouping_sizes(&[3]).build().unwrap();
+        let indian = ParseFloatOptions::builder().format(format).grouping_sizes(&[3, 2]).build().unwrap();
+        assert_eq!(Ok(1234567.5), f64::from_lexical_with_options(b"12,34,567.5", &indian));
+        assert_eq!(Ok(-1234567.0), f64::from_lexical_with_options(b"-12,34,567", &indian));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 2, b',').into()), f64::from_lexical_with_options(b"12,34,567.5", &western));
+        assert_eq!(Ok(1234567.5), f64::from_lexical_with_options(b"1,234,567.5", &western));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1, b',').into()), f64::from_lexical_with_options(b"1,234,567.5", &indian));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 5, b',').into()), f64::from_lexical_with_options(b"1,234,56e3", &western));
+        assert_eq!(Ok(1234567.5), f64::from_lexical_with_options(b"1234567.5", &western));
+
+        // Without grouping sizes, strict formats check groups of 3 digits.
+        let options = ParseFloatOptions::builder().format(format).build().unwrap();
+        assert_eq!(Err((ErrorCode::InvalidDigit, 2, b',').into()), f64::from_lexical_with_options(b"12,34,567.5", &options));
+        let lenient = NumberFormat::grouping(b',', false).unwrap();
+        let options = ParseFloatOptions::builder().format(lenient).build().unwrap();
+        assert_eq!(Ok(1234567.5), f64::from_lexical_with_options(b"12,34,567.5", &options));
+    }
+
     #[test]
     #[cfg(feature = "format")]
     fn f64_integer_internal_digit_separator_test() {
@@ -861,6 +1887,47 @@ mod tests {
         assert!(f64::from_lexical_format(b"31.01e71_", format).is_err());
     }
 
+    #[test]
+    #[cfg(feature = "format")]
+    fn f64_digit_separator_scope_test() {
+        let format = NumberFormat::ignore_scoped(b'_', true, true).unwrap();
+        assert_eq!(Ok(1e11), f64::from_lexical_format(b"1_0e1_0", format));
+        assert_eq!(Ok(1000.5), f64::from_lexical_format(b"1_000.5", format));
+
+        let format = NumberFormat::ignore_scoped(b'_', true, false).unwrap();
+        assert_eq!(Err((ErrorCode::InvalidDigit, 5, b'_').into()), f64::from_lexical_format(b"1_0e1_0", format));
+        assert_eq!(Ok(1000.5), f64::from_lexical_format(b"1_000.5", format));
+        assert_eq!(Ok(1e11), f64::from_lexical_format(b"1_0e10", format));
+
+        let format = NumberFormat::ignore_scoped(b'_', false, true).unwrap();
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1, b'_').into()), f64::from_lexical_format(b"1_0e1_0", format));
+        assert_eq!(Ok(1e11), f64::from_lexical_format(b"10e1_0", format));
+
+        let format = NumberFormat::ignore_scoped(b'_', false, false).unwrap();
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1, b'_').into()), f64::from_lexical_format(b"1_0e1_0", format));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 4, b'_').into()), f64::from_lexical_format(b"10e1_0", format));
+        assert_eq!(Ok(1e11), f64::from_lexical_format(b"10e10", format));
+    }
+
+    #[test]
+    #[cfg(feature = "format")]
+    fn f64_separator_around_decimal_point_test() {
+        let format = NumberFormat::ignore(b'_').unwrap();
+        assert_eq!(Ok(1.5), f64::from_lexical_format(b"1_.5", format));
+        assert_eq!(Ok(1.5), f64::from_lexical_format(b"1._5", format));
+        assert_eq!(Ok(10.5), f64::from_lexical_format(b"1_0.5", format));
+
+        let format = format | NumberFormat::NO_DECIMAL_POINT_DIGIT_SEPARATOR;
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1, b'_').into()), f64::from_lexical_format(b"1_.5", format));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 2, b'_').into()), f64::from_lexical_format(b"1._5", format));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 3, b'_').into()), f64::from_lexical_format(b"1_0_.5", format));
+        assert_eq!(Ok(10.5), f64::from_lexical_format(b"1_0.5", format));
+        assert_eq!(Ok(10.5), f64::from_lexical_format(b"1_0.5_", format));
+        assert_eq!(Ok(1.05), f64::from_lexical_format(b"1.0_5", format));
+        assert_eq!(Ok((1.0, 1)), f64::from_lexical_partial_format(b"1_.5", format));
+        assert_eq!(Ok((1.0, 2)), f64::from_lexical_partial_format(b"1._5", format));
+    }
+
     #[test]
     #[cfg(feature = "format")]
     fn f64_json_exponent_without_dot() {
@@ -6,3 +6,6 @@ mod api;
 
 // Re-exports
 pub use self::api::*;
+
+#[cfg(feature = "correct")]
+pub use self::algorithm::bracket::{parse_bracket, parse_bracket_halfway, reround};
@@ -76,6 +76,16 @@ impl Atoi for i128 {
 // ATOI
 // ----
 
+// Get the number of bytes in the sign of the integer.
+perftools_inline!{
+fn sign_length<T: Integer>(bytes: &[u8]) -> usize {
+    match bytes.first() {
+        Some(&b'+')                 => 1,
+        Some(&b'-') if T::IS_SIGNED => 1,
+        _                           => 0,
+    }
+}}
+
 perftools_inline!{
 pub(crate) fn atoi<'a, T>(bytes: &'a [u8], radix: u32)
     -> Result<(T, usize)>
@@ -83,6 +93,10 @@ pub(crate) fn atoi<'a, T>(bytes: &'a [u8], radix: u32)
 {
     let index = | ptr | distance(bytes.as_ptr(), ptr);
     match T::atoi(bytes, radix) {
+        // No digits were consumed after the sign, like `"-x"`, so the
+        // first byte after the sign is an invalid digit, even for
+        // partial parsers.
+        Ok((_, ptr)) if index(ptr) == sign_length::<T>(bytes) => Err((ErrorCode::InvalidDigit, index(ptr)).into()),
         Ok((value, ptr)) => Ok((value, index(ptr))),
         Err((code, ptr)) => Err((code, index(ptr)).into()),
     }
@@ -93,11 +107,56 @@ perftools_inline!{
 pub(crate) fn atoi_format<'a, T>(bytes: &'a [u8], radix: u32, format: NumberFormat)
     -> Result<(T, usize)>
     where T: Atoi
+{
+    atoi_grouped(bytes, radix, format, b"")
+}}
+
+perftools_inline!{
+pub(crate) fn atoi_options<'a, T>(bytes: &'a [u8], radix: u32, options: &ParseIntegerOptions)
+    -> Result<(T, usize)>
+    where T: Atoi
+{
+    match options.trim_chars().is_empty() {
+        true  => atoi_untrimmed(bytes, radix, options),
+        false => parse_trimmed(bytes, options.trim_chars(), |bytes| atoi_untrimmed(bytes, radix, options)),
+    }
+}}
+
+perftools_inline!{
+fn atoi_untrimmed<'a, T>(bytes: &'a [u8], radix: u32, options: &ParseIntegerOptions)
+    -> Result<(T, usize)>
+    where T: Atoi
+{
+    if options.strict_sign() {
+        if let Some(index) = find_second_sign::<T>(bytes) {
+            return Err((ErrorCode::InvalidSign, index).into());
+        }
+    }
+
+    #[cfg(not(feature = "format"))]
+    return atoi(bytes, radix);
+
+    #[cfg(feature = "format")]
+    return atoi_grouped(bytes, radix, options.format(), options.grouping_sizes());
+}}
+
+// Parse the integer with the format, and check the groups of digits.
+perftools_inline!{
+#[cfg(feature = "format")]
+fn atoi_grouped<T>(bytes: &[u8], radix: u32, format: NumberFormat, grouping_sizes: &'static [u8])
+    -> Result<(T, usize)>
+    where T: Atoi
 {
     let index = | ptr | distance(bytes.as_ptr(), ptr);
-    match T::atoi_format(bytes, radix, format) {
-        Ok((value, ptr)) => Ok((value, index(ptr))),
-        Err((code, ptr)) => Err((code, index(ptr)).into()),
+    let (value, count) = match T::atoi_format(bytes, radix, format) {
+        Ok((_, ptr)) if index(ptr) == sign_length::<T>(bytes) => return Err((ErrorCode::InvalidDigit, index(ptr)).into()),
+        Ok((value, ptr)) => (value, index(ptr)),
+        Err((code, ptr)) => return Err((code, index(ptr)).into()),
+    };
+    let grouping_sizes = grouping_sizes_or_default(format, grouping_sizes);
+    match find_misplaced_separator(&index!(bytes[..count]), radix, format.digit_separator(), grouping_sizes) {
+        Some(index) => Err((ErrorCode::InvalidDigit, index).into()),
+        None        => Ok((value, count)),
     }
 }}
 
@@ -118,6 +177,20 @@ from_lexical!(atoi, i64);
 from_lexical!(atoi, isize);
 from_lexical!(atoi, i128);
 
+from_lexical_with_options!(atoi_options, u8, ParseIntegerOptions);
+from_lexical_with_options!(atoi_options, u16, ParseIntegerOptions);
+from_lexical_with_options!(atoi_options, u32, ParseIntegerOptions);
+from_lexical_with_options!(atoi_options, u64, ParseIntegerOptions);
+from_lexical_with_options!(atoi_options, usize, ParseIntegerOptions);
+from_lexical_with_options!(atoi_options, u128, ParseIntegerOptions);
+
+from_lexical_with_options!(atoi_options, i8, ParseIntegerOptions);
+from_lexical_with_options!(atoi_options, i16, ParseIntegerOptions);
+from_lexical_with_options!(atoi_options, i32, ParseIntegerOptions);
+from_lexical_with_options!(atoi_options, i64, ParseIntegerOptions);
+from_lexical_with_options!(atoi_options, isize, ParseIntegerOptions);
+from_lexical_with_options!(atoi_options, i128, ParseIntegerOptions);
+
 cfg_if!{
 if #[cfg(feature = "format")] {
     from_lexical_format!(atoi_format, u8);
@@ -187,8 +260,8 @@ mod tests {
         assert_eq!(Ok(127), u8::from_lexical(b"127"));
         assert_eq!(Ok(128), u8::from_lexical(b"128"));
         assert_eq!(Ok(255), u8::from_lexical(b"255"));
-        assert_eq!(Err((ErrorCode::InvalidDigit, 0).into()), u8::from_lexical(b"-1"));
-        assert_eq!(Err((ErrorCode::InvalidDigit, 1).into()), u8::from_lexical(b"1a"));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 0, b'-').into()), u8::from_lexical(b"-1"));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1, b'a').into()), u8::from_lexical(b"1a"));
     }
 
     #[cfg(feature = "radix")]
@@ -206,7 +279,7 @@ mod tests {
         assert_eq!(Err((ErrorCode::Overflow, 2).into()), i8::from_lexical(b"128"));
         assert_eq!(Err((ErrorCode::Overflow, 2).into()), i8::from_lexical(b"255"));
         assert_eq!(Ok(-1), i8::from_lexical(b"-1"));
-        assert_eq!(Err((ErrorCode::InvalidDigit, 1).into()), i8::from_lexical(b"1a"));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1, b'a').into()), i8::from_lexical(b"1a"));
     }
 
     #[cfg(feature = "radix")]
@@ -217,14 +290,53 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "radix")]
+    #[test]
+    fn power_of_two_radix_test() {
+        macro_rules! check_bounds {
+            ($($t:ty)*) => ($(
+                for &radix in [2u8, 4, 8, 16, 32].iter() {
+                    let mut buffer = [b'0'; 256];
+                    for &value in [<$t>::max_value(), <$t>::min_value(), 0, 37].iter() {
+                        let len = value.to_lexical_radix(radix, &mut buffer).len();
+                        assert_eq!(<$t>::from_lexical_radix(&buffer[..len], radix), Ok(value));
+                        buffer[len] = b',';
+                        assert_eq!(<$t>::from_lexical_partial_radix(&buffer[..len+1], radix).map(|x| x.1), Ok(len));
+                    }
+
+                    let len = <$t>::max_value().to_lexical_radix(radix, &mut buffer).len();
+                    buffer[len] = b'0';
+                    let expected = Err((ErrorCode::Overflow, len).into());
+                    assert_eq!(<$t>::from_lexical_radix(&buffer[..len+1], radix), expected);
+                }
+            )*);
+        }
+        check_bounds! { u8 u16 u32 u64 i8 i16 i32 i64 }
+
+        assert_eq!(i8::from_lexical_radix(b"-10000000", 2), Ok(-128));
+        assert_eq!(i8::from_lexical_radix(b"-10000001", 2), Err((ErrorCode::Underflow, 8).into()));
+        assert_eq!(i8::from_lexical_radix(b"-100000000", 2), Err((ErrorCode::Underflow, 9).into()));
+        assert_eq!(i8::from_lexical_radix(b"1111111", 2), Ok(127));
+        assert_eq!(i8::from_lexical_radix(b"10000000", 2), Err((ErrorCode::Overflow, 7).into()));
+        assert_eq!(u8::from_lexical_radix(b"377", 8), Ok(255));
+        assert_eq!(u8::from_lexical_radix(b"400", 8), Err((ErrorCode::Overflow, 2).into()));
+        assert_eq!(u32::from_lexical_radix(b"deadBEEF", 16), Ok(0xDEADBEEF));
+        assert_eq!(u32::from_lexical_radix(b"deadBEEFg", 16), Err((ErrorCode::InvalidDigit, 8, b'g').into()));
+        assert_eq!(i32::from_lexical_radix(b"-80000000", 16), Ok(i32::min_value()));
+        assert_eq!(i32::from_lexical_radix(b"-80000001", 16), Err((ErrorCode::Underflow, 8).into()));
+        assert_eq!(u64::from_lexical_radix(b"vvvv", 32), Ok(1048575));
+        assert_eq!(u128::from_lexical_radix(b"ffffffffffffffffffffffffffffffff", 16), Ok(u128::max_value()));
+        assert_eq!(i128::from_lexical_radix(b"-80000000000000000000000000000000", 16), Ok(i128::min_value()));
+    }
+
     #[test]
     fn u16_decimal_test() {
         assert_eq!(Ok(0), u16::from_lexical(b"0"));
         assert_eq!(Ok(32767), u16::from_lexical(b"32767"));
         assert_eq!(Ok(32768), u16::from_lexical(b"32768"));
         assert_eq!(Ok(65535), u16::from_lexical(b"65535"));
-        assert_eq!(Err((ErrorCode::InvalidDigit, 0).into()), u16::from_lexical(b"-1"));
-        assert_eq!(Err((ErrorCode::InvalidDigit, 1).into()), u16::from_lexical(b"1a"));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 0, b'-').into()), u16::from_lexical(b"-1"));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1, b'a').into()), u16::from_lexical(b"1a"));
     }
 
     #[test]
@@ -234,7 +346,7 @@ mod tests {
         assert_eq!(Err((ErrorCode::Overflow, 4).into()), i16::from_lexical(b"32768"));
         assert_eq!(Err((ErrorCode::Overflow, 4).into()), i16::from_lexical(b"65535"));
         assert_eq!(Ok(-1), i16::from_lexical(b"-1"));
-        assert_eq!(Err((ErrorCode::InvalidDigit, 1).into()), i16::from_lexical(b"1a"));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1, b'a').into()), i16::from_lexical(b"1a"));
     }
 
     #[cfg(feature = "radix")]
@@ -252,8 +364,8 @@ mod tests {
         assert_eq!(Ok(2147483647), u32::from_lexical(b"2147483647"));
         assert_eq!(Ok(2147483648), u32::from_lexical(b"2147483648"));
         assert_eq!(Ok(4294967295), u32::from_lexical(b"4294967295"));
-        assert_eq!(Err((ErrorCode::InvalidDigit, 0).into()), u32::from_lexical(b"-1"));
-        assert_eq!(Err((ErrorCode::InvalidDigit, 1).into()), u32::from_lexical(b"1a"));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 0, b'-').into()), u32::from_lexical(b"-1"));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1, b'a').into()), u32::from_lexical(b"1a"));
     }
 
     #[test]
@@ -263,7 +375,7 @@ mod tests {
         assert_eq!(Err((ErrorCode::Overflow, 9).into()), i32::from_lexical(b"2147483648"));
         assert_eq!(Err((ErrorCode::Overflow, 9).into()), i32::from_lexical(b"4294967295"));
         assert_eq!(Ok(-1), i32::from_lexical(b"-1"));
-        assert_eq!(Err((ErrorCode::InvalidDigit, 1).into()), i32::from_lexical(b"1a"));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1, b'a').into()), i32::from_lexical(b"1a"));
     }
 
     #[test]
@@ -272,8 +384,8 @@ mod tests {
         assert_eq!(Ok(9223372036854775807), u64::from_lexical(b"9223372036854775807"));
         assert_eq!(Ok(9223372036854775808), u64::from_lexical(b"9223372036854775808"));
         assert_eq!(Ok(18446744073709551615), u64::from_lexical(b"18446744073709551615"));
-        assert_eq!(Err((ErrorCode::InvalidDigit, 0).into()), u64::from_lexical(b"-1"));
-        assert_eq!(Err((ErrorCode::InvalidDigit, 1).into()), u64::from_lexical(b"1a"));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 0, b'-').into()), u64::from_lexical(b"-1"));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1, b'a').into()), u64::from_lexical(b"1a"));
     }
 
     #[test]
@@ -283,7 +395,7 @@ mod tests {
         assert_eq!(Err((ErrorCode::Overflow, 18).into()), i64::from_lexical(b"9223372036854775808"));
         assert_eq!(Err((ErrorCode::Overflow, 19).into()), i64::from_lexical(b"18446744073709551615"));
         assert_eq!(Ok(-1), i64::from_lexical(b"-1"));
-        assert_eq!(Err((ErrorCode::InvalidDigit, 1).into()), i64::from_lexical(b"1a"));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1, b'a').into()), i64::from_lexical(b"1a"));
 
         // Add tests discovered via fuzzing.
         assert_eq!(Err((ErrorCode::Overflow, 19).into()), i64::from_lexical(b"406260572150672006000066000000060060007667760000000000000000000+00000006766767766666767665670000000000000000000000666"));
@@ -295,8 +407,8 @@ mod tests {
         assert_eq!(Ok(170141183460469231731687303715884105727), u128::from_lexical(b"170141183460469231731687303715884105727"));
         assert_eq!(Ok(170141183460469231731687303715884105728), u128::from_lexical(b"170141183460469231731687303715884105728"));
         assert_eq!(Ok(340282366920938463463374607431768211455), u128::from_lexical(b"340282366920938463463374607431768211455"));
-        assert_eq!(Err((ErrorCode::InvalidDigit, 0).into()), u128::from_lexical(b"-1"));
-        assert_eq!(Err((ErrorCode::InvalidDigit, 1).into()), u128::from_lexical(b"1a"));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 0, b'-').into()), u128::from_lexical(b"-1"));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1, b'a').into()), u128::from_lexical(b"1a"));
     }
 
     #[test]
@@ -306,7 +418,71 @@ mod tests {
         assert_eq!(Err((ErrorCode::Overflow, 39).into()), i128::from_lexical(b"170141183460469231731687303715884105728"));
         assert_eq!(Err((ErrorCode::Overflow, 39).into()), i128::from_lexical(b"340282366920938463463374607431768211455"));
         assert_eq!(Ok(-1), i128::from_lexical(b"-1"));
-        assert_eq!(Err((ErrorCode::InvalidDigit, 1).into()), i128::from_lexical(b"1a"));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1, b'a').into()), i128::from_lexical(b"1a"));
+    }
+
+    #[test]
+    fn i32_sign_without_digits_test() {
+        assert_eq!(Err((ErrorCode::Empty, 0).into()), i32::from_lexical(b""));
+        assert_eq!(Err((ErrorCode::Empty, 1).into()), i32::from_lexical(b"-"));
+        assert_eq!(Err((ErrorCode::Empty, 1).into()), i32::from_lexical(b"+"));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1, b'x').into()), i32::from_lexical(b"-x"));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1, b'x').into()), i32::from_lexical(b"+x"));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 0, b'x').into()), i32::from_lexical(b"x"));
+
+        // Partial parsers do not parse a sign without digits as zero.
+        assert_eq!(Err((ErrorCode::Empty, 0).into()), i32::from_lexical_partial(b""));
+        assert_eq!(Err((ErrorCode::Empty, 1).into()), i32::from_lexical_partial(b"-"));
+        assert_eq!(Err((ErrorCode::Empty, 1).into()), i32::from_lexical_partial(b"+"));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1, b'x').into()), i32::from_lexical_partial(b"-x"));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1, b'x').into()), i32::from_lexical_partial(b"+x"));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 0, b'x').into()), i32::from_lexical_partial(b"x"));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 0, b'-').into()), u32::from_lexical_partial(b"-5"));
+        assert_eq!(Ok((-5, 2)), i32::from_lexical_partial(b"-5x"));
+        assert_eq!(Ok((0, 1)), i32::from_lexical_partial(b"0x"));
+    }
+
+    #[test]
+    fn i32_strict_sign_test() {
+        let options = ParseIntegerOptions::new();
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1, b'-').into()), i32::from_lexical_with_options(b"--5", &options));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1, b'-').into()), i32::from_lexical_with_options(b"+-5", &options));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1, b'+').into()), i32::from_lexical_with_options(b"-+5", &options));
+
+        let options = ParseIntegerOptions::builder().strict_sign(true).build().unwrap();
+        assert_eq!(Err((ErrorCode::InvalidSign, 1).into()), i32::from_lexical_with_options(b"--5", &options));
+        assert_eq!(Err((ErrorCode::InvalidSign, 1).into()), i32::from_lexical_with_options(b"+-5", &options));
+        assert_eq!(Err((ErrorCode::InvalidSign, 1).into()), i32::from_lexical_with_options(b"-+5", &options));
+        assert_eq!(Err((ErrorCode::InvalidSign, 1).into()), i32::from_lexical_partial_with_options(b"--5", &options));
+        assert_eq!(Err((ErrorCode::InvalidSign, 1).into()), u32::from_lexical_with_options(b"+-5", &options));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 0, b'-').into()), u32::from_lexical_with_options(b"-+5", &options));
+        assert_eq!(Ok(-5), i32::from_lexical_with_options(b"-5", &options));
+        assert_eq!(Ok((5, 2)), i32::from_lexical_partial_with_options(b"+5-", &options));
+    }
+
+    #[test]
+    fn i32_trim_chars_test() {
+        let options = ParseIntegerOptions::builder().trim_chars(b"[]\"").build().unwrap();
+        assert_eq!(Ok(5), i32::from_lexical_with_options(b"[5]", &options));
+        assert_eq!(Ok(-5), i32::from_lexical_with_options(b"\"-5\"", &options));
+        assert_eq!(Ok(5), i32::from_lexical_with_options(b"5", &options));
+
+        // Nested and unbalanced bytes are trimmed, without checking the balance.
+        assert_eq!(Ok(5), i32::from_lexical_with_options(b"[[\"5\"]]", &options));
+        assert_eq!(Ok(5), i32::from_lexical_with_options(b"[[5", &options));
+        assert_eq!(Ok(5), i32::from_lexical_with_options(b"5]\"", &options));
+        assert_eq!(Ok(5), i32::from_lexical_with_options(b"]5[", &options));
+
+        // Errors and processed bytes are relative to the untrimmed bytes.
+        assert_eq!(Err((ErrorCode::Empty, 2).into()), i32::from_lexical_with_options(b"[]", &options));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 2, b'[').into()), i32::from_lexical_with_options(b"[5[5]", &options));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 2, b']').into()), i32::from_lexical_with_options(b"[5]x", &options));
+        assert_eq!(Ok((5, 3)), i32::from_lexical_partial_with_options(b"[5]", &options));
+        assert_eq!(Ok((5, 2)), i32::from_lexical_partial_with_options(b"[5,6]", &options));
+
+        // The bytes are not trimmed by default.
+        let options = ParseIntegerOptions::new();
+        assert_eq!(Err((ErrorCode::InvalidDigit, 0, b'[').into()), i32::from_lexical_with_options(b"[5]", &options));
     }
 
     #[test]
@@ -359,6 +535,92 @@ mod tests {
         assert!(i32::from_lexical_format(b"31_", format).is_err());
     }
 
+    #[test]
+    #[cfg(feature = "format")]
+    fn i32_grouping_test() {
+        let strict = NumberFormat::grouping(b' ', true).unwrap();
+        assert_eq!(Ok(1234), i32::from_lexical_format(b"1 234", strict));
+        assert_eq!(Ok(-1234567), i32::from_lexical_format(b"-1 234 567", strict));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1, b' ').into()), i32::from_lexical_format(b"1 2", strict));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1, b' ').into()), i32::from_lexical_partial_format(b"1 2", strict));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 2, b' ').into()), i32::from_lexical_format(b"12 34", strict));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1, b' ').into()), i32::from_lexical_format(b"1  2", strict));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 0, b' ').into()), i32::from_lexical_format(b" 1", strict));
+        assert_eq!(Ok((1234, 5)), i32::from_lexical_partial_format(b"1 234  5", strict));
+        assert_eq!(Ok((1234, 5)), i32::from_lexical_partial_format(b"1 234 ", strict));
+
+        let lenient = NumberFormat::grouping(b' ', false).unwrap();
+        assert_eq!(Ok(1234), i32::from_lexical_format(b"1 234", lenient));
+        assert_eq!(Ok(12), i32::from_lexical_format(b"1 2", lenient));
+        assert_eq!(Ok(12), i32::from_lexical_format(b"1  2", lenient));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 0, b' ').into()), i32::from_lexical_format(b" 1", lenient));
+        assert_eq!(Ok((1234, 6)), i32::from_lexical_partial_format(b"1 234 ", lenient));
+    }
+
+    #[test]
+    #[cfg(feature = "format")]
+    fn i32_signed_grouping_test() {
+        // The sign is parsed before the groups, and the first group may be 1 to 3 digits.
+        let strict = NumberFormat::grouping(b',', true).unwrap();
+        assert_eq!(Ok(-1234), i32::from_lexical_format(b"-1,234", strict));
+        assert_eq!(Ok(1234), i32::from_lexical_format(b"+1,234", strict));
+        assert_eq!(Ok(-12345), i32::from_lexical_format(b"-12,345", strict));
+        assert_eq!(Ok(-123456), i32::from_lexical_format(b"-123,456", strict));
+        assert_eq!(Ok(-1234567), i32::from_lexical_format(b"-1,234,567", strict));
+        assert_eq!(Ok(-2147483648), i32::from_lexical_format(b"-2,147,483,648", strict));
+        assert_eq!(Ok(1234), u32::from_lexical_format(b"+1,234", strict));
+        assert_eq!(Ok((-1234, 6)), i32::from_lexical_partial_format(b"-1,234.5", strict));
+
+        // Separators directly after the sign are not between two digits.
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1, b',').into()), i32::from_lexical_format(b"-,123", strict));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1, b',').into()), i32::from_lexical_format(b"+,123", strict));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 2, b',').into()), i32::from_lexical_format(b"-1,", strict));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 0, b'-').into()), u32::from_lexical_format(b"-1,234", strict));
+        assert_eq!(Err((ErrorCode::Underflow, 13).into()), i32::from_lexical_format(b"-2,147,483,649", strict));
+
+        let lenient = NumberFormat::grouping(b',', false).unwrap();
+        assert_eq!(Ok(-1234), i32::from_lexical_format(b"-1,234", lenient));
+        assert_eq!(Ok(-1234), i32::from_lexical_format(b"-1,,234,", lenient));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1, b',').into()), i32::from_lexical_format(b"-,123", lenient));
+    }
+
+    #[test]
+    #[cfg(feature = "format")]
+    fn i32_grouping_sizes_test() {
+        let format = NumberFormat::grouping(b',', true).unwrap();
+        let western = ParseIntegerOptions::builder().format(format).grouping_sizes(&[3]).build().unwrap();
+        let indian = ParseIntegerOptions::builder().format(format).grouping_sizes(&[3, 2]).build().unwrap();
+        assert_eq!(Ok(1234567), i32::from_lexical_with_options(b"12,34,567", &indian));
+        assert_eq!(Ok(-1234567), i32::from_lexical_with_options(b"-12,34,567", &indian));
+        assert_eq!(Ok(123456789), i32::from_lexical_with_options(b"12,34,56,789", &indian));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 2, b',').into()), i32::from_lexical_with_options(b"12,34,567", &western));
+        assert_eq!(Ok(1234567), i32::from_lexical_with_options(b"1,234,567", &western));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1, b',').into()), i32::from_lexical_with_options(b"1,234,567", &indian));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 4, b',').into()), i32::from_lexical_with_options(b"1234,567", &western));
+        assert_eq!(Ok(1234567), i32::from_lexical_with_options(b"1234567", &western));
+
+        // Without grouping sizes, strict formats check groups of 3 digits.
+        let options = ParseIntegerOptions::builder().format(format).build().unwrap();
+        assert_eq!(Err((ErrorCode::InvalidDigit, 2, b',').into()), i32::from_lexical_with_options(b"12,34,567", &options));
+        assert_eq!(Ok(1234567), i32::from_lexical_with_options(b"1,234,567", &options));
+        let lenient = NumberFormat::grouping(b',', false).unwrap();
+        let options = ParseIntegerOptions::builder().format(lenient).build().unwrap();
+        assert_eq!(Ok(1234567), i32::from_lexical_with_options(b"1,,2345,67,", &options));
+    }
+
+    #[test]
+    #[cfg(all(feature = "format", feature = "radix"))]
+    fn u32_hex_grouping_test() {
+        // Groups of any size, like spaced hex dumps.
+        let lenient = NumberFormat::grouping(b' ', false).unwrap();
+        assert_eq!(Ok(0xDEADBEEF), u32::from_lexical_format_radix(b"DE AD BE EF", 16, lenient));
+        assert_eq!(Ok(0xDEADBEEF), u32::from_lexical_format_radix(b"de ad  beef ", 16, lenient));
+        assert_eq!(Ok(0xDEA), u32::from_lexical_format_radix(b"DE A", 16, lenient));
+        assert_eq!(Ok((0xDEADBEEF, 11)), u32::from_lexical_partial_format_radix(b"DE AD BE EF|", 16, lenient));
+        assert_eq!(Err((ErrorCode::Overflow, 12).into()), u32::from_lexical_format_radix(b"DE AD BE EF 00", 16, lenient));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 0, b' ').into()), u32::from_lexical_format_radix(b" DE", 16, lenient));
+    }
+
     #[test]
     #[cfg(feature = "format")]
     fn i32_json_no_leading_zero() {
@@ -136,6 +136,52 @@ macro_rules! parse_digits {
     );
 }
 
+/// Iterate over the digits and iteratively process them, for radixes that are powers of two.
+///
+/// Multiplying by the radix is a shift, and the shift overflows
+/// if any of the shifted-out bits are set, which is identical to
+/// checking if the value is out of the range of the bound shifted
+/// by the radix. Positive values have no set bits after the shift,
+/// so the digit is merged with a bitwise or.
+#[cfg(feature = "radix")]
+macro_rules! parse_digits_shift {
+    ($value:ident, $iter:ident, $radix:ident, $shift:ident, $code:ident, $overflows:expr, $merge:expr) => (
+        while let Some(c) = $iter.next() {
+            let digit = match to_digit!(*c, $radix) {
+                Some(v) => v,
+                None    => return Ok(($value, c)),
+            };
+            if $overflows($value) {
+                return Err((ErrorCode::$code, c));
+            }
+            $value = match $merge($value << $shift, as_cast(digit)) {
+                Some(v) => v,
+                None    => return Err((ErrorCode::$code, c)),
+            };
+        }
+    );
+}
+
+// Parse the digits for the atoi processor, for radixes that are powers of two.
+perftools_inline_always!{
+#[cfg(feature = "radix")]
+fn parse_digits_shift<'a, T, Iter>(digits: &[u8], mut iter: Iter, radix: u32, sign: Sign)
+    -> ParseResult<(T, *const u8)>
+    where T: Integer,
+          Iter: AsPtrIterator<'a, u8>
+{
+    let shift = radix.trailing_zeros();
+    let mut value = T::ZERO;
+    if sign == Sign::Positive {
+        let max = T::MAX >> shift;
+        parse_digits_shift!(value, iter, radix, shift, Overflow, |v| v > max, |v: T, d| Some(v | d));
+    } else {
+        let min = T::MIN >> shift;
+        parse_digits_shift!(value, iter, radix, shift, Underflow, |v| v < min, |v: T, d| v.checked_sub(d));
+    }
+    Ok((value, last_ptr(digits)))
+}}
+
 // Parse the digits for the atoi processor.
 perftools_inline_always!{
 fn parse_digits<'a, T, Iter>(digits: &[u8], mut iter: Iter, radix: u32, sign: Sign)
@@ -143,7 +189,87 @@ fn parse_digits<'a, T, Iter>(digits: &[u8], mut iter: Iter, radix: u32, sign: Si
     where T: Integer,
           Iter: AsPtrIterator<'a, u8>
 {
+    #[cfg(feature = "radix")]
+    {
+        if radix.is_power_of_two() {
+            return parse_digits_shift(digits, iter, radix, sign);
+        }
+    }
+
+    let mut value = T::ZERO;
+    if sign == Sign::Positive {
+        parse_digits!(value, iter, radix, checked_add, Overflow);
+    } else {
+        parse_digits!(value, iter, radix, checked_sub, Underflow);
+    }
+    Ok((value, last_ptr(digits)))
+}}
+
+// SWAR
+
+// Check if 8 bytes, read as a little-endian integer, are all decimal digits.
+//
+// Subtracting `0x30` from a byte below `'0'`, or adding `0x46` to a byte
+// above `'9'`, sets the high bit of the byte. Bytes of `0xB0` or more
+// already set the high bit after the subtraction, so carries between
+// bytes only occur if a byte is invalid.
+perftools_inline_always!{
+#[cfg(feature = "fast_parse")]
+fn is_8digits(chunk: u64) -> bool {
+    let below = chunk.wrapping_sub(0x3030_3030_3030_3030);
+    let above = chunk.wrapping_add(0x4646_4646_4646_4646);
+    (below | above) & 0x8080_8080_8080_8080 == 0
+}}
+
+// Parse 8 decimal digits, read as a little-endian integer.
+//
+// Adjacent digits are combined into pairs, then into groups of 4
+// digits, and then into the 8-digit value, each with a single multiply.
+perftools_inline_always!{
+#[cfg(feature = "fast_parse")]
+fn parse_8digits(chunk: u64) -> u64 {
+    let chunk = (chunk & 0x0F0F_0F0F_0F0F_0F0F).wrapping_mul(10 << 8 | 1) >> 8;
+    let chunk = (chunk & 0x00FF_00FF_00FF_00FF).wrapping_mul(100 << 16 | 1) >> 16;
+    (chunk & 0x0000_FFFF_0000_FFFF).wrapping_mul(10000 << 32 | 1) >> 32
+}}
+
+// Parse the decimal digits for the atoi processor, 8 digits at a time.
+//
+// Chunks of 8 digits are read as a `u64`, validated and parsed without
+// branching on each digit. The first chunk with an invalid digit, or
+// that overflows the integer, and the remaining digits, are parsed one
+// digit at a time, so the value and the error are identical to the
+// scalar processor.
+perftools_inline_always!{
+#[cfg(feature = "fast_parse")]
+fn parse_digits_swar<T>(digits: &[u8], sign: Sign)
+    -> ParseResult<(T, *const u8)>
+    where T: Integer
+{
+    let radix: u32 = 10;
+    let power: T = as_cast(100_000_000u32);
     let mut value = T::ZERO;
+    let mut index = 0;
+    while digits.len() - index >= 8 {
+        let mut bytes = [0u8; 8];
+        bytes.copy_from_slice(&index!(digits[index..index+8]));
+        let chunk = u64::from_le_bytes(bytes);
+        if !is_8digits(chunk) {
+            break;
+        }
+        let chunk: T = as_cast(parse_8digits(chunk));
+        let next = match sign {
+            Sign::Positive => value.checked_mul(power).and_then(|v| v.checked_add(chunk)),
+            Sign::Negative => value.checked_mul(power).and_then(|v| v.checked_sub(chunk)),
+        };
+        value = match next {
+            Some(v) => v,
+            None    => break,
+        };
+        index += 8;
+    }
+
+    let mut iter = index!(digits[index..]).iter();
     if sign == Sign::Positive {
         parse_digits!(value, iter, radix, checked_add, Overflow);
     } else {
@@ -161,6 +287,14 @@ fn standalone<T>(bytes: &[u8], radix: u32)
     where T: Integer
 {
     let (sign, digits) = parse_sign!(bytes, T::IS_SIGNED, Empty);
+
+    // Chunks of 8 decimal digits fit in integers of at least 32 bits.
+    #[cfg(feature = "fast_parse")] {
+        if radix == 10 && T::BITS >= 32 {
+            return parse_digits_swar(digits, sign);
+        }
+    }
+
     let iter = iterate_digits_no_separator(digits, b'\x00');
     parse_digits(digits, iter, radix, sign)
 }}
@@ -678,3 +812,108 @@ pub(crate) fn standalone_128_separator<W, N>(bytes: &[u8], radix: u32, format: N
 
     Ok((value, ptr))
 }}
+
+// TESTS
+// -----
+
+#[cfg(all(test, feature = "fast_parse"))]
+mod tests {
+    use super::*;
+
+    type Parsed<T> = crate::lib::result::Result<(T, usize), (ErrorCode, usize)>;
+
+    // Parse the digits with the SWAR and scalar processors, as indexes.
+    fn parse_both<T: Integer>(digits: &[u8], sign: Sign)
+        -> (Parsed<T>, Parsed<T>)
+    {
+        let index = | ptr | distance(digits.as_ptr(), ptr);
+        let iter = iterate_digits_no_separator(digits, b'\x00');
+        let swar = parse_digits_swar::<T>(digits, sign);
+        let scalar = parse_digits::<T, _>(digits, iter, 10, sign);
+        (
+            swar.map(|(v, p)| (v, index(p))).map_err(|(c, p)| (c, index(p))),
+            scalar.map(|(v, p)| (v, index(p))).map_err(|(c, p)| (c, index(p))),
+        )
+    }
+
+    #[test]
+    fn is_8digits_test() {
+        assert!(is_8digits(u64::from_le_bytes(*b"01234567")));
+        assert!(is_8digits(u64::from_le_bytes(*b"99999999")));
+        assert!(!is_8digits(u64::from_le_bytes(*b"0123456/")));
+        assert!(!is_8digits(u64::from_le_bytes(*b":1234567")));
+        assert!(!is_8digits(u64::from_le_bytes(*b"0123.567")));
+        assert!(!is_8digits(u64::from_le_bytes(*b"\xB0\xB1\xB2\xB3\xB4\xB5\xB6\xB7")));
+        assert!(!is_8digits(u64::from_le_bytes(*b"\xFF1234567")));
+    }
+
+    #[test]
+    fn parse_8digits_test() {
+        assert_eq!(parse_8digits(u64::from_le_bytes(*b"00000000")), 0);
+        assert_eq!(parse_8digits(u64::from_le_bytes(*b"01234567")), 1234567);
+        assert_eq!(parse_8digits(u64::from_le_bytes(*b"12345678")), 12345678);
+        assert_eq!(parse_8digits(u64::from_le_bytes(*b"99999999")), 99999999);
+    }
+
+    #[test]
+    fn parse_digits_swar_test() {
+        let (swar, scalar) = parse_both::<u64>(b"1234567890123456", Sign::Positive);
+        assert_eq!(swar, Ok((1234567890123456, 16)));
+        assert_eq!(swar, scalar);
+
+        // Invalid digits within and after the chunks.
+        let (swar, scalar) = parse_both::<u64>(b"12345.67890123456", Sign::Positive);
+        assert_eq!(swar, Ok((12345, 5)));
+        assert_eq!(swar, scalar);
+        let (swar, scalar) = parse_both::<u64>(b"1234567890123456a", Sign::Positive);
+        assert_eq!(swar, Ok((1234567890123456, 16)));
+        assert_eq!(swar, scalar);
+
+        // Overflow is reported at the same digit.
+        let (swar, scalar) = parse_both::<u64>(b"18446744073709551616", Sign::Positive);
+        assert_eq!(swar, Err((ErrorCode::Overflow, 19)));
+        assert_eq!(swar, scalar);
+        let (swar, scalar) = parse_both::<i32>(b"2147483648", Sign::Negative);
+        assert_eq!(swar, Ok((i32::min_value(), 10)));
+        assert_eq!(swar, scalar);
+        let (swar, scalar) = parse_both::<i32>(b"214748364800000000", Sign::Negative);
+        assert_eq!(swar, Err((ErrorCode::Underflow, 10)));
+        assert_eq!(swar, scalar);
+    }
+
+    #[cfg(feature = "std")]
+    proptest! {
+        #[test]
+        fn u32_swar_proptest(i in r"[0-9]{0,24}[^0-9]?[0-9]{0,12}") {
+            let (swar, scalar) = parse_both::<u32>(i.as_bytes(), Sign::Positive);
+            prop_assert_eq!(swar, scalar);
+        }
+
+        #[test]
+        fn i32_swar_proptest(i in r"[0-9]{0,24}[^0-9]?[0-9]{0,12}") {
+            let (swar, scalar) = parse_both::<i32>(i.as_bytes(), Sign::Negative);
+            prop_assert_eq!(swar, scalar);
+        }
+
+        #[test]
+        fn u64_swar_proptest(i in r"[0-9]{0,24}[^0-9]?[0-9]{0,12}") {
+            let (swar, scalar) = parse_both::<u64>(i.as_bytes(), Sign::Positive);
+            prop_assert_eq!(swar, scalar);
+        }
+
+        #[test]
+        fn i64_swar_proptest(i in r"[0-9]{0,24}[^0-9]?[0-9]{0,12}", negative: bool) {
+            let sign = if negative { Sign::Negative } else { Sign::Positive };
+            let (swar, scalar) = parse_both::<i64>(i.as_bytes(), sign);
+            prop_assert_eq!(swar, scalar);
+        }
+
+        #[test]
+        fn u64_swar_value_proptest(i: u64) {
+            let digits = i.to_string();
+            let (swar, scalar) = parse_both::<u64>(digits.as_bytes(), Sign::Positive);
+            prop_assert_eq!(swar, Ok((i, digits.len())));
+            prop_assert_eq!(swar, scalar);
+        }
+    }
+}
@@ -20,8 +20,17 @@ use super::shift::*;
 
 /// Extended precision floating-point type.
 ///
-/// Private implementation, exposed only for testing purposes.
-#[doc(hidden)]
+/// The value is `mant * 2^exp`, and has no sign bit, so it only
+/// represents positive floats. This is the type used internally by the
+/// correct parser to scale floats without intermediate rounding, and
+/// is public with the `internals` feature. The internals are not
+/// covered by the semantic versioning guarantees of the crate, and
+/// may change in any release.
+///
+/// Operations are most precise with normalized floats, where the most
+/// significant bit of the mantissa is set, so floats should be
+/// normalized before being multiplied.
+#[cfg_attr(not(feature = "internals"), doc(hidden))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct ExtendedFloat<M: Mantissa> {
     /// Mantissa for the extended-precision float.
@@ -196,22 +205,28 @@ impl<M: Mantissa> ExtendedFloat<M> {
         round_to_native::<F, M, _>(self, cb)
     }}
 
-    /// Lossy round float-point number to f32 mantissa boundaries.
+    /// Round to the nearest 32-bit float, with ties to even.
+    ///
+    /// Unlike [`as_f32`](#method.as_f32), this ignores the global
+    /// rounding scheme of the `rounding` feature.
     perftools_inline!{
-    pub(crate) fn round_to_f32<Cb>(&mut self, cb: Cb)
-        where f32: FloatRounding<M>,
-              Cb: FnOnce(&mut ExtendedFloat<M>, i32)
+    pub fn round_to_f32(&self)
+        -> f32
+        where f32: FloatRounding<M>
     {
-        self.round_to_native::<f32, Cb>(cb)
+        self.as_rounded_f32(RoundingKind::NearestTieEven, Sign::Positive)
     }}
 
-    /// Lossy round float-point number to f64 mantissa boundaries.
+    /// Round to the nearest 64-bit float, with ties to even.
+    ///
+    /// Unlike [`as_f64`](#method.as_f64), this ignores the global
+    /// rounding scheme of the `rounding` feature.
     perftools_inline!{
-    pub(crate) fn round_to_f64<Cb>(&mut self, cb: Cb)
-        where f64: FloatRounding<M>,
-              Cb: FnOnce(&mut ExtendedFloat<M>, i32)
+    pub fn round_to_f64(&self)
+        -> f64
+        where f64: FloatRounding<M>
     {
-        self.round_to_native::<f64, Cb>(cb)
+        self.as_rounded_f64(RoundingKind::NearestTieEven, Sign::Positive)
     }}
 
     // FROM
@@ -633,11 +648,11 @@ mod tests {
     fn check_round_to_f32(mant: u64, exp: i32, r_mant: u64, r_exp: i32)
     {
         let mut x = ExtendedFloat {mant: mant, exp: exp};
-        x.round_to_f32(round_nearest_tie_even);
+        x.round_to_native::<f32, _>(round_nearest_tie_even);
         assert_eq!(x, ExtendedFloat {mant: r_mant, exp: r_exp});
 
         let mut x = ExtendedFloat {mant: (mant as u128) << 64, exp: exp-64};
-        x.round_to_f32(round_nearest_tie_even);
+        x.round_to_native::<f32, _>(round_nearest_tie_even);
         assert_eq!(x, ExtendedFloat {mant: r_mant as u128, exp: r_exp});
     }
 
@@ -673,11 +688,11 @@ mod tests {
     fn check_round_to_f64(mant: u64, exp: i32, r_mant: u64, r_exp: i32)
     {
         let mut x = ExtendedFloat {mant: mant, exp: exp};
-        x.round_to_f64(round_nearest_tie_even);
+        x.round_to_native::<f64, _>(round_nearest_tie_even);
         assert_eq!(x, ExtendedFloat {mant: r_mant, exp: r_exp});
 
         let mut x = ExtendedFloat {mant: (mant as u128) << 64, exp: exp-64};
-        x.round_to_f64(round_nearest_tie_even);
+        x.round_to_native::<f64, _>(round_nearest_tie_even);
         assert_eq!(x, ExtendedFloat {mant: r_mant as u128, exp: r_exp});
     }
 
@@ -729,6 +744,29 @@ mod tests {
         check_round_to_f64(10234494226754558294, -1086, 2498655817078750, -1074)
     }
 
+    #[test]
+    fn round_to_nearest_test() {
+        // 1.0
+        let x = ExtendedFloat80 {mant: 9223372036854775808, exp: -63};
+        assert_eq!(x.round_to_f32(), 1.0f32);
+        assert_eq!(x.round_to_f64(), 1.0f64);
+
+        // Halfway between 1.0 and the next float, ties to even.
+        let x = ExtendedFloat80 {mant: 9223372036854776832, exp: -63};
+        assert_eq!(x.round_to_f64(), 1.0f64);
+        let x = ExtendedFloat80 {mant: 9223372036854776833, exp: -63};
+        assert_eq!(x.round_to_f64(), 1.0000000000000002f64);
+
+        // Scale 0.1 by 10 without intermediate rounding.
+        let mut x = ExtendedFloat80::from_f64(0.1);
+        let mut y = ExtendedFloat80::from_u64(10);
+        x.normalize();
+        y.normalize();
+        let mut z = x.mul(&y);
+        z.normalize();
+        assert_eq!(z.round_to_f64(), 1.0f64);
+    }
+
     // FROM
 
     #[test]
@@ -59,12 +59,34 @@ use super::radix::{double_radix, float_radix};
 cfg_if! {
 if #[cfg(feature = "grisu3")] {
     use super::grisu3::{double_decimal, float_decimal};
+    const FLOAT_BACKEND: FloatBackend = FloatBackend::Grisu3;
 } else if #[cfg(feature = "ryu")] {
     use super::ryu::{double_decimal, float_decimal};
+    const FLOAT_BACKEND: FloatBackend = FloatBackend::Ryu;
 } else {
     use super::grisu2::{double_decimal, float_decimal};
+    const FLOAT_BACKEND: FloatBackend = FloatBackend::Grisu2;
 }}  //cfg_if
 
+/// Get the algorithm used to write decimal floats.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::FloatBackend;
+/// # pub fn main() {
+/// const BACKEND: FloatBackend = lexical_core::float_backend();
+/// if cfg!(feature = "ryu") {
+///     assert_eq!(BACKEND, FloatBackend::Ryu);
+/// }
+/// # }
+/// ```
+#[inline]
+pub const fn float_backend() -> FloatBackend {
+    FLOAT_BACKEND
+}
+
 // TRAITS
 
 /// Trait to define serialization of a float to string.
@@ -196,6 +218,32 @@ fn ftoa<F: FloatToString>(value: F, radix: u32, bytes: &mut [u8])
     trim(bytes)
 }}
 
+// Get the size of the buffer to write a float with the default write options.
+#[cfg(feature = "std")]
+#[inline]
+fn formatted_size_default<F>(options: &Option<WriteFloatOptions>)
+    -> usize
+    where F: ToLexicalWithOptions<Options = WriteFloatOptions>
+{
+    match options {
+        Some(options) => F::formatted_size_with_options(10, options),
+        None          => F::FORMATTED_SIZE_DECIMAL,
+    }
+}
+
+// Write float to string, with the default write options, if set.
+#[cfg(feature = "std")]
+#[inline]
+fn ftoa_default<'a, F>(value: F, bytes: &'a mut [u8], options: &Option<WriteFloatOptions>)
+    -> &'a mut [u8]
+    where F: ToLexicalWithOptions<Options = WriteFloatOptions>
+{
+    match options {
+        Some(options) => value.to_lexical_with_options(bytes, options),
+        None          => value.to_lexical(bytes),
+    }
+}
+
 // Trim a trailing ".0" from a float.
 perftools_inline!{
 fn trim<'a>(bytes: &'a mut [u8])
@@ -209,10 +257,673 @@ fn trim<'a>(bytes: &'a mut [u8])
     }
 }}
 
+// Pad the fraction with trailing zeros to the minimum number of digits.
+//
+// If the minimum is 0, trim a trailing ".0" instead. Floats in
+// scientific notation have the mantissa padded, before the exponent.
+perftools_inline!{
+fn pad_fraction<'a>(bytes: &'a mut [u8], len: usize, radix: u32, min_digits: usize)
+    -> usize
+{
+    let exponent = exponent_notation_char(radix);
+    let mantissa_len = index!(bytes[..len]).iter()
+        .position(|&c| c == exponent)
+        .unwrap_or(len);
+    let dot = index!(bytes[..mantissa_len]).iter().position(|&c| c == b'.');
+    let digits = match dot {
+        Some(index) => mantissa_len - index - 1,
+        None        => 0,
+    };
+
+    if min_digits == 0 && digits == 1 && index!(bytes[mantissa_len-1]) == b'0' {
+        // Remove the trailing ".0" from the mantissa.
+        bytes.copy_within(mantissa_len..len, mantissa_len - 2);
+        len - 2
+    } else if digits < min_digits {
+        // Shift the exponent and write the decimal point and zeros.
+        let zeros = min_digits - digits;
+        let shift = zeros + dot.is_none() as usize;
+        bytes.copy_within(mantissa_len..len, mantissa_len + shift);
+        let mut index = mantissa_len;
+        if dot.is_none() {
+            index_mut!(bytes[index] = b'.');
+            index += 1;
+        }
+        write_bytes(&mut index_mut!(bytes[index..index+zeros]), b'0');
+        len + shift
+    } else {
+        len
+    }
+}}
+
+// Write the separator between every group of fraction digits.
+//
+// The fraction digits end at the exponent character, so floats in
+// scientific notation have the mantissa grouped, before the exponent.
+perftools_inline!{
+fn group_fraction<'a>(bytes: &'a mut [u8], len: usize, radix: u32, separator: u8, size: usize)
+    -> usize
+{
+    let exponent = exponent_notation_char(radix);
+    let mantissa_len = index!(bytes[..len]).iter()
+        .position(|&c| c == exponent)
+        .unwrap_or(len);
+    let start = match index!(bytes[..mantissa_len]).iter().position(|&c| c == b'.') {
+        Some(index) => index + 1,
+        None        => return len,
+    };
+    let digits = mantissa_len - start;
+    if digits <= size {
+        return len;
+    }
+
+    // Shift the exponent, and move the digits from last to first,
+    // writing the separator before the first digit of each group.
+    let count = (digits - 1) / size;
+    bytes.copy_within(mantissa_len..len, mantissa_len + count);
+    let mut index = mantissa_len + count;
+    for digit in (0..digits).rev() {
+        index -= 1;
+        index_mut!(bytes[index] = index!(bytes[start + digit]));
+        if digit != 0 && digit % size == 0 {
+            index -= 1;
+            index_mut!(bytes[index] = separator);
+        }
+    }
+    len + count
+}}
+
+// Get the length of an integral float written without the decimal point.
+//
+// Returns None if the float has a fraction or is in scientific notation.
+// The trailing ".0" may have already been trimmed by `trim_floats`.
+perftools_inline!{
+fn integer_len(bytes: &[u8], radix: u32)
+    -> Option<usize>
+{
+    let exponent = exponent_notation_char(radix);
+    if ends_with_slice(bytes, b".0") {
+        Some(bytes.len() - 2)
+    } else if bytes.iter().all(|&c| c != b'.' && c != exponent) {
+        Some(bytes.len())
+    } else {
+        None
+    }
+}}
+
+// Replace the exponent character with the exponent string.
+//
+// Exponent strings starting with a valid digit for the radix are
+// ambiguous, so the exponent character is kept.
+perftools_inline!{
+fn write_exponent_string<'a>(bytes: &'a mut [u8], len: usize, radix: u32, exponent_string: &[u8])
+    -> usize
+{
+    let exponent = exponent_notation_char(radix);
+    if exponent_string == [exponent] || exponent_string_starts_with_digit(exponent_string, radix) {
+        return len;
+    }
+
+    match index!(bytes[..len]).iter().position(|&c| c == exponent) {
+        Some(index) => {
+            let shift = exponent_string.len() - 1;
+            bytes.copy_within(index+1..len, index + 1 + shift);
+            copy_to_dst(&mut index_mut!(bytes[index..]), exponent_string);
+            len + shift
+        },
+        None        => len,
+    }
+}}
+
+// Parse the digits of an exponent, with an optional sign, in the radix.
+perftools_inline!{
+fn parse_exponent(digits: &[u8], radix: u32)
+    -> i32
+{
+    let (is_negative, digits) = match digits.first() {
+        Some(&b'-') => (true, &index!(digits[1..])),
+        Some(&b'+') => (false, &index!(digits[1..])),
+        _           => (false, digits),
+    };
+    let mut exponent: i32 = 0;
+    for &c in digits {
+        let digit = (c as char).to_digit(radix).unwrap();
+        exponent = exponent * radix as i32 + digit as i32;
+    }
+    match is_negative {
+        true  => -exponent,
+        false => exponent,
+    }
+}}
+
+// Get the number of digits of an exponent, without the sign, in the radix.
+perftools_inline!{
+fn exponent_digits(exponent: i32, radix: u32)
+    -> usize
+{
+    let mut digits = 1;
+    let mut rest = exponent.abs() as u32 / radix;
+    while rest != 0 {
+        digits += 1;
+        rest /= radix;
+    }
+    digits
+}}
+
+// Write the digits of an exponent, with a sign if negative, in the radix.
+perftools_inline!{
+fn write_exponent(bytes: &mut [u8], exponent: i32, radix: u32)
+    -> usize
+{
+    let mut count = 0;
+    if exponent < 0 {
+        index_mut!(bytes[0] = b'-');
+        count += 1;
+    }
+    let mut value = exponent.abs() as u32;
+    let digits = exponent_digits(exponent, radix);
+    for index in (count..count+digits).rev() {
+        index_mut!(bytes[index] = digit_to_char(value % radix));
+        value /= radix;
+    }
+
+    count + digits
+}}
+
+// Write the shortest digits as an integer, followed by an exponent.
+//
+// The decimal point, the trailing zeros of the fraction, and any leading
+// zeros are removed, and the exponent is adjusted by the number of
+// fraction digits. The exponent is always written, in the radix.
+perftools_inline!{
+fn scaled_integer<'a>(bytes: &'a mut [u8], len: usize, radix: u32)
+    -> usize
+{
+    let exponent_char = exponent_notation_char(radix);
+    let start = (index!(bytes[0]) == b'-') as usize;
+    let mantissa_len = index!(bytes[..len]).iter()
+        .position(|&c| c == exponent_char)
+        .unwrap_or(len);
+
+    // Parse the exponent, if present.
+    let mut exponent = match mantissa_len < len {
+        true  => parse_exponent(&index!(bytes[mantissa_len+1..len]), radix),
+        false => 0,
+    };
+
+    // Remove the decimal point and the trailing zeros of the fraction.
+    let mut count = start;
+    let mut fraction_digits = 0;
+    let mut is_fraction = false;
+    for index in start..mantissa_len {
+        let c = index!(bytes[index]);
+        if c == b'.' {
+            is_fraction = true;
+        } else {
+            index_mut!(bytes[count] = c);
+            count += 1;
+            fraction_digits += is_fraction as i32;
+        }
+    }
+    while fraction_digits > 0 && index!(bytes[count-1]) == b'0' {
+        count -= 1;
+        fraction_digits -= 1;
+    }
+
+    // Remove leading zeros, keeping at least 1 digit.
+    let leading = index!(bytes[start..count-1]).iter()
+        .take_while(|&&c| c == b'0')
+        .count();
+    bytes.copy_within(start+leading..count, start);
+    count -= leading;
+    exponent -= fraction_digits;
+
+    // Write the exponent.
+    index_mut!(bytes[count] = exponent_char);
+    count += 1;
+    count + write_exponent(&mut index_mut!(bytes[count..]), exponent, radix)
+}}
+
+// Write the shortest digits in scientific notation.
+//
+// The decimal point is moved after the first significant digit, the
+// leading and trailing zeros are removed, and the exponent is adjusted
+// by the number of digits moved. The exponent is always written, in
+// the radix, and the fraction is omitted if it has no digits.
+perftools_inline!{
+fn scientific<'a>(bytes: &'a mut [u8], len: usize, radix: u32)
+    -> usize
+{
+    let exponent_char = exponent_notation_char(radix);
+    let start = (index!(bytes[0]) == b'-') as usize;
+    let mantissa_len = index!(bytes[..len]).iter()
+        .position(|&c| c == exponent_char)
+        .unwrap_or(len);
+
+    // Parse the exponent, if present.
+    let mut exponent = match mantissa_len < len {
+        true  => parse_exponent(&index!(bytes[mantissa_len+1..len]), radix),
+        false => 0,
+    };
+
+    // Remove the decimal point, and count the integer digits.
+    let mut count = start;
+    let mut integer_digits = 0;
+    let mut is_fraction = false;
+    for index in start..mantissa_len {
+        let c = index!(bytes[index]);
+        if c == b'.' {
+            is_fraction = true;
+        } else {
+            index_mut!(bytes[count] = c);
+            count += 1;
+            integer_digits += !is_fraction as i32;
+        }
+    }
+
+    // Remove leading and trailing zeros, keeping at least 1 digit.
+    let leading = index!(bytes[start..count-1]).iter()
+        .take_while(|&&c| c == b'0')
+        .count();
+    bytes.copy_within(start+leading..count, start);
+    count -= leading;
+    while count > start + 1 && index!(bytes[count-1]) == b'0' {
+        count -= 1;
+    }
+    exponent = match index!(bytes[start]) {
+        b'0' => 0,
+        _    => exponent + integer_digits - 1 - leading as i32,
+    };
+
+    // Write the decimal point after the first digit, and the exponent.
+    if count > start + 1 {
+        bytes.copy_within(start+1..count, start+2);
+        index_mut!(bytes[start+1] = b'.');
+        count += 1;
+    }
+    index_mut!(bytes[count] = exponent_char);
+    count += 1;
+    count + write_exponent(&mut index_mut!(bytes[count..]), exponent, radix)
+}}
+
+// Get the number of significant digits and the exponent of a float in scientific notation.
+perftools_inline!{
+fn scientific_parts(bytes: &[u8], radix: u32)
+    -> (usize, i32)
+{
+    let exponent_char = exponent_notation_char(radix);
+    let start = (index!(bytes[0]) == b'-') as usize;
+    let mantissa_len = bytes.iter()
+        .position(|&c| c == exponent_char)
+        .unwrap_or(bytes.len());
+    let digits = mantissa_len - start - (mantissa_len > start + 1) as usize;
+    let exponent = parse_exponent(&index!(bytes[mantissa_len+1..]), radix);
+    (digits, exponent)
+}}
+
+// Write a float in scientific notation in fixed notation.
+//
+// The digits are moved around the decimal point, and padded with zeros
+// up to the decimal point or after it, so there is at least 1 integer
+// digit. Integral floats are written without the decimal point.
+perftools_inline!{
+fn fixed<'a>(bytes: &'a mut [u8], len: usize, radix: u32)
+    -> usize
+{
+    let start = (index!(bytes[0]) == b'-') as usize;
+    let (digits, exponent) = scientific_parts(&index!(bytes[..len]), radix);
+
+    // Remove the decimal point after the first digit.
+    let end = start + digits;
+    if digits > 1 {
+        bytes.copy_within(start+2..end+1, start+1);
+    }
+
+    if exponent < 0 {
+        // Shift the digits after the decimal point and leading zeros.
+        let shift = (1 - exponent) as usize;
+        bytes.copy_within(start..end, start + shift);
+        index_mut!(bytes[start] = b'0');
+        index_mut!(bytes[start+1] = b'.');
+        write_bytes(&mut index_mut!(bytes[start+2..start+shift]), b'0');
+        end + shift
+    } else if exponent as usize + 1 >= digits {
+        // Pad the integer digits with trailing zeros.
+        let integer_end = start + exponent as usize + 1;
+        write_bytes(&mut index_mut!(bytes[end..integer_end]), b'0');
+        integer_end
+    } else {
+        // Write the decimal point after the integer digits.
+        let point = start + exponent as usize + 1;
+        bytes.copy_within(point..end, point + 1);
+        index_mut!(bytes[point] = b'.');
+        end + 1
+    }
+}}
+
+// Get the number of zeros to position the significant digits in fixed notation.
+perftools_inline!{
+fn fixed_zeros(digits: usize, exponent: i32)
+    -> usize
+{
+    match exponent {
+        e if e < 0  => (-e) as usize,
+        e           => (e as usize + 1).saturating_sub(digits),
+    }
+}}
+
+// Get the number of zeros to position the significant digits of a float in fixed notation.
+//
+// These are the leading zeros of floats without integer digits, and
+// the trailing zeros of the integer digits of integral floats. Zero
+// has none.
+perftools_inline!{
+fn count_fixed_zeros(bytes: &[u8])
+    -> usize
+{
+    let start = (index!(bytes[0]) == b'-') as usize;
+    let bytes = &index!(bytes[start..]);
+    let point = bytes.iter().position(|&c| c == b'.').unwrap_or(bytes.len());
+    let (integer, fraction) = bytes.split_at(point);
+    let fraction = fraction.get(1..).unwrap_or(&[]);
+    if integer == b"0" {
+        match fraction.iter().position(|&c| c != b'0') {
+            Some(index) => index + 1,
+            None        => 0,
+        }
+    } else if fraction.iter().all(|&c| c == b'0') {
+        integer.iter().rev().take_while(|&&c| c == b'0').count()
+    } else {
+        0
+    }
+}}
+
+// Check if a float in scientific notation is written in fixed notation.
+//
+// Compact floats compare the lengths of both notations without the sign,
+// after padding the fraction, and the remaining layouts check the
+// exponent is in range. Every layout checks the zeros are within the cap.
+perftools_inline!{
+fn is_fixed(digits: usize, exponent: i32, radix: u32, options: &WriteFloatOptions)
+    -> bool
+{
+    if let Some(cap) = options.fixed_zero_cap() {
+        if fixed_zeros(digits, exponent) > cap {
+            return false;
+        }
+    }
+    match options.layout() {
+        WriteFloatLayout::FixedRange(min, max) => exponent >= min && exponent <= max,
+        _ => {
+            let min_digits = options.min_fraction_digits();
+            let (integer_digits, fraction_digits) = match exponent {
+                e if e < 0  => (1, digits + (-e) as usize - 1),
+                e           => (e as usize + 1, digits.saturating_sub(e as usize + 1)),
+            };
+            let fraction_digits = match options.integer_as_integer() && fraction_digits == 0 {
+                true  => 0,
+                false => fraction_digits.max(min_digits),
+            };
+            let fixed_len = integer_digits + fraction_digits + (fraction_digits > 0) as usize;
+            let fraction_digits = (digits - 1).max(min_digits);
+            let sign = exponent < 0 || options.positive_exponent_sign();
+            let exponent_len = exponent_digits(exponent, radix).max(options.min_exponent_digits());
+            let scientific_len = 1 + fraction_digits + (fraction_digits > 0) as usize
+                + options.exponent_string().len() + sign as usize + exponent_len;
+            fixed_len <= scientific_len
+        },
+    }
+}}
+
+// Write the sign of positive exponents, and pad the exponent with leading zeros.
+perftools_inline!{
+fn format_exponent<'a>(bytes: &'a mut [u8], len: usize, radix: u32, positive_sign: bool, min_digits: usize)
+    -> usize
+{
+    let exponent_char = exponent_notation_char(radix);
+    let mut count = match index!(bytes[..len]).iter().position(|&c| c == exponent_char) {
+        Some(index) => index + 1,
+        None        => return len,
+    };
+    let exponent = parse_exponent(&index!(bytes[count..len]), radix);
+    if exponent < 0 {
+        index_mut!(bytes[count] = b'-');
+        count += 1;
+    } else if positive_sign {
+        index_mut!(bytes[count] = b'+');
+        count += 1;
+    }
+    let zeros = min_digits.saturating_sub(exponent_digits(exponent, radix));
+    write_bytes(&mut index_mut!(bytes[count..count+zeros]), b'0');
+    count += zeros;
+    count + write_exponent(&mut index_mut!(bytes[count..]), exponent.abs(), radix)
+}}
+
+// Step the last significant digit toward or away from zero.
+//
+// The digit is stepped while the digits still parse to the same float,
+// so the number of significant digits is unchanged. Stepping to a `0`
+// or past a `9` would change the number of significant digits, and
+// those digits cannot parse to the float, since the digits are the
+// shortest, so the step stops at `1` or `9`. The exponent character
+// is temporarily replaced with `e`, so the digits are parsed with the
+// default options rather than the global exponent character.
+perftools_inline!{
+#[cfg(feature = "correct")]
+fn shortest_tie<F>(value: F, bytes: &mut [u8], len: usize, tie: ShortestTie)
+    where F: FloatToString + FromLexicalWithOptions<Options = ParseFloatOptions>
+{
+    let step: i8 = match tie {
+        ShortestTie::Nearest    => return,
+        ShortestTie::TowardZero => -1,
+        ShortestTie::AwayZero   => 1,
+    };
+    let mantissa_len = index!(bytes[..len]).iter()
+        .position(|&c| !c.is_ascii_digit() && c != b'.' && c != b'-')
+        .unwrap_or(len);
+    let index = match index!(bytes[..mantissa_len]).iter().rposition(|&c| c >= b'1' && c <= b'9') {
+        Some(index) => index,
+        None        => return,
+    };
+    let exponent_char = match mantissa_len < len {
+        true  => Some(index!(bytes[mantissa_len])),
+        false => None,
+    };
+    if exponent_char.is_some() {
+        index_mut!(bytes[mantissa_len] = b'e');
+    }
+
+    let options = ParseFloatOptions::new();
+    loop {
+        let digit = index!(bytes[index]);
+        let next = (digit as i8 + step) as u8;
+        if next < b'1' || next > b'9' {
+            break;
+        }
+        index_mut!(bytes[index] = next);
+        if F::from_lexical_with_options(&index!(bytes[..len]), &options) != Ok(value) {
+            index_mut!(bytes[index] = digit);
+            break;
+        }
+    }
+
+    if let Some(exponent_char) = exponent_char {
+        index_mut!(bytes[mantissa_len] = exponent_char);
+    }
+}}
+
+// Get the maximum number of bytes required to write a float with custom options.
+perftools_inline!{
+fn formatted_size<F: FloatToString>(radix: u32, options: &WriteFloatOptions)
+    -> usize
+{
+    let size = match radix {
+        10 => F::FORMATTED_SIZE_DECIMAL,
+        _  => F::FORMATTED_SIZE,
+    };
+    // Scaled integers ignore the minimum fraction digits, and the few
+    // extra bytes for the exponent, in scaled integers or scientific
+    // notation, always fit within the formatted size, which is much
+    // larger than the shortest representation. The shortest
+    // representation has a fraction digit, so the first digit of
+    // padding also fits within the formatted size.
+    let padding = match options.layout() {
+        WriteFloatLayout::ScaledInteger => 0,
+        _                               => options.min_fraction_digits().saturating_sub(1),
+    };
+    // Fixed notation within the range has a zero for every exponent, at
+    // most, and the exponent has a sign and padding digits. Like the
+    // other extra bytes for the exponent, narrow ranges and short
+    // exponents fit within the formatted size, and compact floats are
+    // never longer than in scientific notation.
+    let fixed = match options.layout() {
+        WriteFloatLayout::FixedRange(min, max) => (min.abs().max(max.abs()) as usize).saturating_sub(32),
+        _                                      => 0,
+    };
+    // Every group of fraction digits has a separator, at most.
+    let separators = match options.fraction_grouping() {
+        Some((_, group)) => (size + padding + fixed) / group as usize,
+        None             => 0,
+    };
+    let exponent = (options.positive_exponent_sign() as usize + options.min_exponent_digits()).saturating_sub(4);
+    // The formatted size has a byte for the `-` sign, at most.
+    let minus_len = options.minus_sign().len() - 1;
+    let plus_len = options.plus_sign().map_or(0, |s| s.len());
+    let sign = minus_len.max(plus_len);
+    let size = size + padding + fixed + separators + exponent + sign + options.exponent_string().len() - 1;
+    let zero_len = options.zero_string().map_or(0, |s| s.len());
+    let negative_zero_len = options.negative_zero_string().map_or(0, |s| s.len());
+    let nan_len = options.nan_string().map_or(0, |s| s.len());
+    let inf_len = options.inf_string().map_or(0, |s| s.len() + 1 + sign);
+    let sentinel_len = options_sentinel(options).map_or(0, |(_, s)| s.len());
+    size.max(zero_len).max(negative_zero_len).max(nan_len).max(inf_len).max(sentinel_len)
+}}
+
+// Get the value and the sentinel string for the options.
+perftools_inline!{
+#[cfg(feature = "optional")]
+fn options_sentinel(options: &WriteFloatOptions) -> Option<(f64, &'static [u8])> {
+    options.sentinel()
+}}
+
+// Get the value and the sentinel string for the options.
+perftools_inline!{
+#[cfg(not(feature = "optional"))]
+fn options_sentinel(_: &WriteFloatOptions) -> Option<(f64, &'static [u8])> {
+    None
+}}
+
+// Write float to string with custom options.
+perftools_inline!{
+fn ftoa_with_options<F>(value: F, radix: u32, bytes: &mut [u8], options: &WriteFloatOptions)
+    -> usize
+    where F: FloatToString + FromLexicalWithOptions<Options = ParseFloatOptions>
+{
+    // Write the sentinel value as the sentinel string, ignoring every other option.
+    if let Some((sentinel, string)) = options_sentinel(options) {
+        if value.to_bits() == as_cast::<F, _>(sentinel).to_bits() {
+            return copy_to_dst(bytes, string);
+        }
+    }
+
+    // Write zero as the custom string, ignoring every other option.
+    if value.is_zero() {
+        let zero_string = match value.is_sign_negative() {
+            true  => options.negative_zero_string(),
+            false => options.zero_string(),
+        };
+        if let Some(zero_string) = zero_string {
+            return copy_to_dst(bytes, zero_string);
+        }
+    }
+
+    // Write special floats as the custom strings, if set.
+    if value.is_nan() {
+        if let Some(nan_string) = options.nan_string() {
+            return copy_to_dst(bytes, nan_string);
+        }
+    } else if value.is_inf() {
+        if let Some(inf_string) = options.inf_string() {
+            let start = value.is_sign_negative() as usize;
+            if start == 1 {
+                index_mut!(bytes[0] = b'-');
+            }
+            let len = start + copy_to_dst(&mut index_mut!(bytes[start..]), inf_string);
+            return write_sign(bytes, len, options.minus_sign(), options.plus_sign());
+        }
+    }
+
+    let len = ftoa(value, radix, bytes);
+    if value.is_nan() {
+        write_sign(bytes, len, options.minus_sign(), None)
+    } else if value.is_special() {
+        write_sign(bytes, len, options.minus_sign(), options.plus_sign())
+    } else {
+        #[cfg(feature = "correct")] {
+            if radix == 10 {
+                shortest_tie(value, bytes, len, options.shortest_tie());
+            }
+        }
+        // Write floats in the standard layout with too many zeros in
+        // scientific notation.
+        let exponent_char = exponent_notation_char(radix);
+        let len = match (options.layout(), options.fixed_zero_cap()) {
+            (WriteFloatLayout::Standard, Some(cap)) if !index!(bytes[..len]).contains(&exponent_char) => {
+                match count_fixed_zeros(&index!(bytes[..len])) > cap {
+                    true  => scientific(bytes, len, radix),
+                    false => len,
+                }
+            },
+            _ => len,
+        };
+        let integer_len = match options.integer_as_integer() {
+            true  => integer_len(&index!(bytes[..len]), radix),
+            false => None,
+        };
+        let len = match (options.layout(), integer_len) {
+            // Write the digits as an integer, ignoring the padding.
+            (WriteFloatLayout::ScaledInteger, _) => scaled_integer(bytes, len, radix),
+            // Write the digits in scientific notation, and pad the mantissa.
+            (WriteFloatLayout::Scientific, _) => {
+                let len = scientific(bytes, len, radix);
+                pad_fraction(bytes, len, radix, options.min_fraction_digits())
+            },
+            // Write the digits in fixed or scientific notation, and write
+            // integral floats in fixed notation like the standard layout.
+            (WriteFloatLayout::Compact, _) | (WriteFloatLayout::FixedRange(_, _), _) => {
+                let len = scientific(bytes, len, radix);
+                let (digits, exponent) = scientific_parts(&index!(bytes[..len]), radix);
+                let len = match is_fixed(digits, exponent, radix, options) {
+                    true  => fixed(bytes, len, radix),
+                    false => len,
+                };
+                let is_integer = self::integer_len(&index!(bytes[..len]), radix).is_some();
+                match options.integer_as_integer() && is_integer {
+                    true  => len,
+                    false => pad_fraction(bytes, len, radix, options.min_fraction_digits()),
+                }
+            },
+            // Write integral floats without the decimal point or padding.
+            (_, Some(len)) => len,
+            (_, None)      => pad_fraction(bytes, len, radix, options.min_fraction_digits()),
+        };
+        let len = match options.fraction_grouping() {
+            Some((separator, size)) => group_fraction(bytes, len, radix, separator, size as usize),
+            None                    => len,
+        };
+        let len = format_exponent(bytes, len, radix, options.positive_exponent_sign(), options.min_exponent_digits());
+        let len = write_exponent_string(bytes, len, radix, options.exponent_string());
+        write_sign(bytes, len, options.minus_sign(), options.plus_sign())
+    }
+}}
+
 // TO LEXICAL
 
-to_lexical!(ftoa, f32);
-to_lexical!(ftoa, f64);
+to_lexical!(ftoa, f32, formatted_size_default::<f32>, ftoa_default);
+to_lexical!(ftoa, f64, formatted_size_default::<f64>, ftoa_default);
+
+to_lexical_with_options!(ftoa_with_options, formatted_size::<f32>, f32, WriteFloatOptions);
+to_lexical_with_options!(ftoa_with_options, formatted_size::<f64>, f64, WriteFloatOptions);
 
 // TESTS
 // -----
@@ -221,9 +932,14 @@ to_lexical!(ftoa, f64);
 mod tests {
     use crate::util::*;
     use crate::util::test::*;
+    use super::*;
 
     // Test data for roundtrips.
     const F32_DATA : [f32; 31] = [0., 0.1, 1., 1.1, 12., 12.1, 123., 123.1, 1234., 1234.1, 12345., 12345.1, 123456., 123456.1, 1234567., 1234567.1, 12345678., 12345678.1, 123456789., 123456789.1, 123456789.12, 123456789.123, 123456789.1234, 123456789.12345, 1.2345678912345e8, 1.2345e+8, 1.2345e+11, 1.2345e+38, 1.2345e-8, 1.2345e-11, 1.2345e-38];
+    // Denormal floats, floats near the smallest normal float, and floats
+    // near the largest float, as bit patterns.
+    const F32_EXTREME_BITS: [u32; 16] = [0x1, 0x2, 0x3, 0x7, 0xFF, 0x1234, 0x12345, 0x3FFFFF, 0x400000, 0x555555, 0x7FFFFE, 0x7FFFFF, 0x800000, 0x800001, 0xFFFFFF, 0x7F7FFFFF];
+    const F64_EXTREME_BITS: [u64; 20] = [0x1, 0x2, 0x3, 0x7, 0xFF, 0x1234, 0x123456789, 0x3FFFFFFFFFFFF, 0x8000000000000, 0x5555555555555, 0xFFFFFFFFFFFFE, 0xFFFFFFFFFFFFF, 0x10000000000000, 0x10000000000001, 0x1FFFFFFFFFFFFF, 0x20000000000000, 0x7FE0000000000000, 0x7FEFFFFFFFFFFFFE, 0x7FEFFFFFFFFFFFFF, 0x4340000000000000];
     const F64_DATA: [f64; 33] = [0., 0.1, 1., 1.1, 12., 12.1, 123., 123.1, 1234., 1234.1, 12345., 12345.1, 123456., 123456.1, 1234567., 1234567.1, 12345678., 12345678.1, 123456789., 123456789.1, 123456789.12, 123456789.123, 123456789.1234, 123456789.12345, 1.2345678912345e8, 1.2345e+8, 1.2345e+11, 1.2345e+38, 1.2345e+308, 1.2345e-8, 1.2345e-11, 1.2345e-38, 1.2345e-299];
 
     #[cfg(feature = "radix")]
@@ -315,9 +1031,13 @@ mod tests {
         let mut buffer = new_buffer();
         for &f in F32_DATA.iter() {
             for radix in 2..37 {
-                // The lower accuracy is due to slight rounding errors of
-                // ftoa for the Grisu method with non-10 bases.
                 let s = f.to_lexical_radix(radix, &mut buffer);
+                #[cfg(feature = "correct")]
+                assert_eq!(f32::from_lexical_radix(s, radix), Ok(f));
+
+                // The lower accuracy is due to rounding errors of the
+                // incorrect parser with non-10 bases.
+                #[cfg(not(feature = "correct"))]
                 assert_relative_eq!(f32::from_lexical_radix(s, radix).unwrap(), f, max_relative=2e-5);
             }
         }
@@ -407,14 +1127,44 @@ mod tests {
         let mut buffer = new_buffer();
         for &f in F64_DATA.iter() {
             for radix in 2..37 {
-                // The lower accuracy is due to slight rounding errors of
-                // ftoa for the Grisu method with non-10 bases.
                 let s = f.to_lexical_radix(radix, &mut buffer);
+                #[cfg(feature = "correct")]
+                assert_eq!(f64::from_lexical_radix(s, radix), Ok(f));
+
+                // The lower accuracy is due to rounding errors of the
+                // incorrect parser with non-10 bases.
+                #[cfg(not(feature = "correct"))]
                 assert_relative_eq!(f64::from_lexical_radix(s, radix).unwrap(), f, max_relative=3e-5);
             }
         }
     }
 
+    #[cfg(all(feature = "correct", feature = "radix"))]
+    #[test]
+    fn f32_radix_denormal_roundtrip_test() {
+        let mut buffer = new_buffer();
+        for &bits in F32_EXTREME_BITS.iter() {
+            let f = f32::from_bits(bits);
+            for radix in 2..37 {
+                let s = f.to_lexical_radix(radix, &mut buffer);
+                assert_eq!(f32::from_lexical_radix(s, radix), Ok(f), "radix={} bits={:#x}", radix, bits);
+            }
+        }
+    }
+
+    #[cfg(all(feature = "correct", feature = "radix"))]
+    #[test]
+    fn f64_radix_denormal_roundtrip_test() {
+        let mut buffer = new_buffer();
+        for &bits in F64_EXTREME_BITS.iter() {
+            let f = f64::from_bits(bits);
+            for radix in 2..37 {
+                let s = f.to_lexical_radix(radix, &mut buffer);
+                assert_eq!(f64::from_lexical_radix(s, radix), Ok(f), "radix={} bits={:#x}", radix, bits);
+            }
+        }
+    }
+
     #[cfg(feature = "correct")]
     quickcheck! {
         fn f32_quickcheck(f: f32) -> bool {
@@ -443,6 +1193,326 @@ mod tests {
         }
     }
 
+    #[test]
+    fn float_backend_test() {
+        #[cfg(feature = "grisu3")]
+        assert_eq!(float_backend(), FloatBackend::Grisu3);
+        #[cfg(all(feature = "ryu", not(feature = "grisu3")))]
+        assert_eq!(float_backend(), FloatBackend::Ryu);
+        #[cfg(not(any(feature = "ryu", feature = "grisu3")))]
+        assert_eq!(float_backend(), FloatBackend::Grisu2);
+    }
+
+    #[test]
+    fn f64_min_fraction_digits_test() {
+        let mut buffer = [b'\0'; BUFFER_SIZE + 8];
+        let options = WriteFloatOptions::builder().min_fraction_digits(0).build().unwrap();
+        assert_eq!(as_slice(b"1"), 1.0f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"0"), 0.0f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"1.5"), 1.5f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"1e"), &1e100f64.to_lexical_with_options(&mut buffer, &options)[..2]);
+
+        let options = WriteFloatOptions::builder().min_fraction_digits(1).build().unwrap();
+        assert_eq!(as_slice(b"1.0"), 1.0f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"0.0"), 0.0f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"1.5"), 1.5f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"1.25"), 1.25f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"1.0e"), &1e100f64.to_lexical_with_options(&mut buffer, &options)[..4]);
+
+        let options = WriteFloatOptions::builder().min_fraction_digits(2).build().unwrap();
+        assert_eq!(as_slice(b"1.00"), 1.0f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"-1.50"), (-1.5f64).to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"1.25"), 1.25f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"1.125"), 1.125f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"1.00e-"), &1e-8f64.to_lexical_with_options(&mut buffer, &options)[..6]);
+        assert_eq!(as_slice(b"1.50e-"), &1.5e-8f64.to_lexical_with_options(&mut buffer, &options)[..6]);
+
+        // special
+        assert_eq!(as_slice(b"NaN"), f64::NAN.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"inf"), f64::INFINITY.to_lexical_with_options(&mut buffer, &options));
+    }
+
+    #[test]
+    fn f64_integer_as_integer_test() {
+        let mut buffer = [b'\0'; BUFFER_SIZE + 8];
+        let options = WriteFloatOptions::builder()
+            .min_fraction_digits(1)
+            .integer_as_integer(true)
+            .build()
+            .unwrap();
+        assert_eq!(as_slice(b"42"), 42.0f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"-42"), (-42.0f64).to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"42.5"), 42.5f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"0"), 0.0f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"1.0e"), &1e100f64.to_lexical_with_options(&mut buffer, &options)[..4]);
+        assert_eq!(as_slice(b"NaN"), f64::NAN.to_lexical_with_options(&mut buffer, &options));
+
+        // The minimum fraction digits only apply to non-integral floats.
+        let options = WriteFloatOptions::builder()
+            .min_fraction_digits(2)
+            .integer_as_integer(true)
+            .build()
+            .unwrap();
+        assert_eq!(as_slice(b"42"), 42.0f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"42.50"), 42.5f64.to_lexical_with_options(&mut buffer, &options));
+
+        // Roundtrip, which requires the correct parser.
+        #[cfg(feature = "correct")]
+        for &value in [42.0f64, -42.0, 42.5, 0.0, -0.0, 1e100, 1.5e-8, 9007199254740992.0].iter() {
+            let bytes = value.to_lexical_with_options(&mut buffer, &options);
+            let result = f64::from_lexical_with_options(bytes, &ParseFloatOptions::new());
+            assert_eq!(result, Ok(value));
+        }
+    }
+
+    #[test]
+    fn f64_scaled_integer_test() {
+        let mut buffer = [b'\0'; BUFFER_SIZE + 8];
+        let options = WriteFloatOptions::builder()
+            .layout(WriteFloatLayout::ScaledInteger)
+            .build()
+            .unwrap();
+        assert_eq!(as_slice(b"12345e-2"), 123.45f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"-12345e-2"), (-123.45f64).to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"12300e0"), 12300.0f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"1e0"), 1.0f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"1e-3"), 0.001f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"15e-9"), 1.5e-8f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"1e100"), 1e100f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"12345e296"), 1.2345e300f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"NaN"), f64::NAN.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"inf"), f64::INFINITY.to_lexical_with_options(&mut buffer, &options));
+
+        // Zero, which may have the sign and fraction trimmed.
+        #[cfg(not(feature = "trim_floats"))] {
+            assert_eq!(as_slice(b"0e0"), 0.0f64.to_lexical_with_options(&mut buffer, &options));
+            assert_eq!(as_slice(b"-0e0"), (-0.0f64).to_lexical_with_options(&mut buffer, &options));
+        }
+        #[cfg(feature = "trim_floats")]
+        assert_eq!(as_slice(b"0e0"), 0.0f64.to_lexical_with_options(&mut buffer, &options));
+
+        // The padding is ignored, but the exponent string is not.
+        let options = WriteFloatOptions::builder()
+            .layout(WriteFloatLayout::ScaledInteger)
+            .min_fraction_digits(3)
+            .integer_as_integer(true)
+            .exponent_string(b"E")
+            .build()
+            .unwrap();
+        assert_eq!(as_slice(b"12345E-2"), 123.45f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"42E0"), 42.0f64.to_lexical_with_options(&mut buffer, &options));
+
+        // Roundtrip, which requires the correct parser.
+        #[cfg(feature = "correct")] {
+            let options = WriteFloatOptions::builder()
+                .layout(WriteFloatLayout::ScaledInteger)
+                .build()
+                .unwrap();
+            let mut buffer = [b'\0'; f64::FORMATTED_SIZE_DECIMAL];
+            for &value in F64_DATA.iter() {
+                let bytes = value.to_lexical_with_options(&mut buffer, &options);
+                assert!(!bytes.contains(&b'.'));
+                assert_eq!(f64::from_lexical(bytes), Ok(value));
+            }
+            for &bits in F64_EXTREME_BITS.iter() {
+                let value = f64::from_bits(bits);
+                let bytes = value.to_lexical_with_options(&mut buffer, &options);
+                assert_eq!(f64::from_lexical(bytes), Ok(value));
+            }
+        }
+    }
+
+    #[cfg(all(feature = "correct", feature = "radix"))]
+    #[test]
+    fn f64_scaled_integer_radix_test() {
+        let mut buffer = [b'\0'; BUFFER_SIZE];
+        let options = WriteFloatOptions::builder()
+            .layout(WriteFloatLayout::ScaledInteger)
+            .build()
+            .unwrap();
+        assert_eq!(as_slice(b"11e-1"), 1.5f64.to_lexical_radix_with_options(2, &mut buffer, &options));
+        for &radix in [2, 3, 8, 16, 32, 36].iter() {
+            for &value in F64_DATA.iter() {
+                let bytes = value.to_lexical_radix_with_options(radix, &mut buffer, &options);
+                assert_eq!(f64::from_lexical_radix(bytes, radix), Ok(value));
+            }
+            for &bits in F64_EXTREME_BITS.iter() {
+                let value = f64::from_bits(bits);
+                let bytes = value.to_lexical_radix_with_options(radix, &mut buffer, &options);
+                assert_eq!(f64::from_lexical_radix(bytes, radix), Ok(value));
+            }
+        }
+    }
+
+    #[test]
+    fn f64_scientific_test() {
+        let mut buffer = [b'\0'; BUFFER_SIZE + 8];
+        let builder = WriteFloatOptions::builder().layout(WriteFloatLayout::Scientific);
+        let options = builder.min_fraction_digits(0).build().unwrap();
+        assert_eq!(as_slice(b"1.2e3"), 1200.0f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"-1.2e3"), (-1200.0f64).to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"1e0"), 1.0f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"1.2345e2"), 123.45f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"1.5e-8"), 1.5e-8f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"1.2e-4"), 0.00012f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"1.2345e300"), 1.2345e300f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"0e0"), 0.0f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"NaN"), f64::NAN.to_lexical_with_options(&mut buffer, &options));
+
+        // The minimum fraction digits pad the mantissa, not the value.
+        let options = builder.min_fraction_digits(6).build().unwrap();
+        assert_eq!(as_slice(b"1.200000e3"), 1200.0f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"1.234500e2"), 123.45f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"1.000000e0"), 1.0f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"1.234567891e8"), 123456789.1f64.to_lexical_with_options(&mut buffer, &options));
+        let options = WriteFloatOptions::builder().min_fraction_digits(6).build().unwrap();
+        assert_eq!(as_slice(b"1200.000000"), 1200.0f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"123.450000"), 123.45f64.to_lexical_with_options(&mut buffer, &options));
+
+        // Integral floats are still written in scientific notation.
+        let options = builder.integer_as_integer(true).exponent_string(b"E").build().unwrap();
+        assert_eq!(as_slice(b"4.2E1"), 42.0f64.to_lexical_with_options(&mut buffer, &options));
+
+        // Roundtrip, which requires the correct parser.
+        #[cfg(feature = "correct")] {
+            let options = builder.build().unwrap();
+            for &value in F64_DATA.iter() {
+                let bytes = value.to_lexical_with_options(&mut buffer, &options);
+                assert_eq!(f64::from_lexical(bytes), Ok(value));
+            }
+            for &bits in F64_EXTREME_BITS.iter() {
+                let value = f64::from_bits(bits);
+                let bytes = value.to_lexical_with_options(&mut buffer, &options);
+                assert_eq!(f64::from_lexical(bytes), Ok(value));
+            }
+        }
+    }
+
+    #[cfg(all(feature = "correct", feature = "radix"))]
+    #[test]
+    fn f64_scientific_radix_test() {
+        let mut buffer = [b'\0'; BUFFER_SIZE];
+        let options = WriteFloatOptions::builder()
+            .layout(WriteFloatLayout::Scientific)
+            .min_fraction_digits(0)
+            .build()
+            .unwrap();
+        assert_eq!(as_slice(b"1.1e1"), 3.0f64.to_lexical_radix_with_options(2, &mut buffer, &options));
+        assert_eq!(as_slice(b"1.1e-1"), 0.75f64.to_lexical_radix_with_options(2, &mut buffer, &options));
+        for &radix in [2, 3, 8, 16, 32, 36].iter() {
+            for &value in F64_DATA.iter() {
+                let bytes = value.to_lexical_radix_with_options(radix, &mut buffer, &options);
+                assert_eq!(f64::from_lexical_radix(bytes, radix), Ok(value));
+            }
+            for &bits in F64_EXTREME_BITS.iter() {
+                let value = f64::from_bits(bits);
+                let bytes = value.to_lexical_radix_with_options(radix, &mut buffer, &options);
+                assert_eq!(f64::from_lexical_radix(bytes, radix), Ok(value));
+            }
+        }
+    }
+
+    #[cfg(feature = "correct")]
+    #[test]
+    fn shortest_tie_test() {
+        let mut buffer = [b'\0'; BUFFER_SIZE + 8];
+        let builder = WriteFloatOptions::builder().min_fraction_digits(0);
+        let nearest = builder.shortest_tie(ShortestTie::Nearest).build().unwrap();
+        let toward = builder.shortest_tie(ShortestTie::TowardZero).build().unwrap();
+        let away = builder.shortest_tie(ShortestTie::AwayZero).build().unwrap();
+
+        // The smallest denormal floats have many equally-short digits.
+        assert_eq!(as_slice(b"5e-324"), 5e-324f64.to_lexical_with_options(&mut buffer, &nearest));
+        assert_eq!(as_slice(b"3e-324"), 5e-324f64.to_lexical_with_options(&mut buffer, &toward));
+        assert_eq!(as_slice(b"7e-324"), 5e-324f64.to_lexical_with_options(&mut buffer, &away));
+        assert_eq!(as_slice(b"-3e-324"), (-5e-324f64).to_lexical_with_options(&mut buffer, &toward));
+        assert_eq!(as_slice(b"1.5e-323"), 1.5e-323f64.to_lexical_with_options(&mut buffer, &nearest));
+        assert_eq!(as_slice(b"1.3e-323"), 1.5e-323f64.to_lexical_with_options(&mut buffer, &toward));
+        assert_eq!(as_slice(b"1.7e-323"), 1.5e-323f64.to_lexical_with_options(&mut buffer, &away));
+        assert_eq!(as_slice(b"1e-45"), 1e-45f32.to_lexical_with_options(&mut buffer, &toward));
+        assert_eq!(as_slice(b"2e-45"), 1e-45f32.to_lexical_with_options(&mut buffer, &away));
+
+        // Most floats have unique shortest digits.
+        assert_eq!(as_slice(b"0.1"), 0.1f64.to_lexical_with_options(&mut buffer, &toward));
+        assert_eq!(as_slice(b"0.1"), 0.1f64.to_lexical_with_options(&mut buffer, &away));
+        assert_eq!(as_slice(b"inf"), f64::INFINITY.to_lexical_with_options(&mut buffer, &away));
+        assert_eq!(as_slice(b"0"), 0.0f64.to_lexical_with_options(&mut buffer, &away));
+
+        // The digits always parse to the same float.
+        for options in [toward, away].iter() {
+            for &value in F64_DATA.iter() {
+                let bytes = value.to_lexical_with_options(&mut buffer, options);
+                assert_eq!(f64::from_lexical(bytes), Ok(value));
+                let len = bytes.len();
+                assert_eq!(len, value.to_lexical_with_options(&mut buffer, &nearest).len());
+            }
+            for &bits in F64_EXTREME_BITS.iter() {
+                let value = f64::from_bits(bits);
+                let bytes = value.to_lexical_with_options(&mut buffer, options);
+                assert_eq!(f64::from_lexical(bytes), Ok(value));
+            }
+        }
+    }
+
+    #[test]
+    fn f64_exponent_string_test() {
+        let mut buffer = [b'\0'; BUFFER_SIZE + 8];
+        let options = WriteFloatOptions::builder().exponent_string(b"E").build().unwrap();
+        assert_eq!(as_slice(b"1.5E-8"), 1.5e-8f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"1.5"), 1.5f64.to_lexical_with_options(&mut buffer, &options));
+
+        let options = WriteFloatOptions::builder().exponent_string(b"x10^").build().unwrap();
+        assert_eq!(as_slice(b"1.5x10^-8"), 1.5e-8f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"-1.5x10^-8"), (-1.5e-8f64).to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"inf"), f64::INFINITY.to_lexical_with_options(&mut buffer, &options));
+
+        let options = WriteFloatOptions::builder()
+            .min_fraction_digits(2)
+            .exponent_string(b"x10^")
+            .build()
+            .unwrap();
+        assert_eq!(as_slice(b"1.50x10^-8"), 1.5e-8f64.to_lexical_with_options(&mut buffer, &options));
+    }
+
+    #[test]
+    #[should_panic]
+    fn f64_exponent_string_buffer_test() {
+        let options = WriteFloatOptions::builder().exponent_string(b"x10^").build().unwrap();
+        let mut buffer = [b'0'; f64::FORMATTED_SIZE_DECIMAL + 1];
+        1.5e-8f64.to_lexical_with_options(&mut buffer, &options);
+    }
+
+    #[cfg(feature = "radix")]
+    #[test]
+    fn f64_exponent_string_radix_test() {
+        let mut buffer = [b'\0'; BUFFER_SIZE + 8];
+        let builder = WriteFloatOptions::builder().min_fraction_digits(1);
+        let options = builder.exponent_string(b"x").build().unwrap();
+        assert_eq!(as_slice(b"1.0x-10100"), 9.5367431640625e-7f64.to_lexical_radix_with_options(2, &mut buffer, &options));
+        assert_eq!(as_slice(b"1.0x-5"), 9.5367431640625e-7f64.to_lexical_radix_with_options(16, &mut buffer, &options));
+
+        // Exponent strings starting with a digit use the default character.
+        let options = builder.exponent_string(b"e").build().unwrap();
+        assert_eq!(as_slice(b"1.0^-5"), 9.5367431640625e-7f64.to_lexical_radix_with_options(16, &mut buffer, &options));
+    }
+
+    #[cfg(feature = "radix")]
+    #[test]
+    fn f32_min_fraction_digits_radix_test() {
+        let mut buffer = [b'\0'; BUFFER_SIZE + 8];
+        let options = WriteFloatOptions::builder().min_fraction_digits(3).build().unwrap();
+        assert_eq!(as_slice(b"10.000"), 2.0f32.to_lexical_radix_with_options(2, &mut buffer, &options));
+        assert_eq!(as_slice(b"1.100"), 1.5f32.to_lexical_radix_with_options(2, &mut buffer, &options));
+    }
+
+    #[test]
+    #[should_panic]
+    fn f64_min_fraction_digits_buffer_test() {
+        let options = WriteFloatOptions::builder().min_fraction_digits(64).build().unwrap();
+        let mut buffer = [b'0'; f64::FORMATTED_SIZE_DECIMAL + 62];
+        1.0f64.to_lexical_with_options(&mut buffer, &options);
+    }
+
     #[test]
     #[should_panic]
     fn f32_buffer_test() {
@@ -456,4 +1526,376 @@ mod tests {
         let mut buffer = [b'0'; f64::FORMATTED_SIZE_DECIMAL-1];
         1.2345f64.to_lexical(&mut buffer);
     }
+
+    #[test]
+    fn zero_string_test() {
+        let mut buffer = [b'\0'; BUFFER_SIZE + 8];
+        let builder = WriteFloatOptions::builder()
+            .layout(WriteFloatLayout::Scientific)
+            .min_fraction_digits(2);
+        let options = builder.zero_string(Some(b"0E0")).build().unwrap();
+        assert_eq!(as_slice(b"0E0"), 0.0f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"0E0"), 0.0f32.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"1.00e0"), 1.0f64.to_lexical_with_options(&mut buffer, &options));
+
+        // Negative zero is independent of positive zero.
+        let options = builder.negative_zero_string(Some(b"-0")).build().unwrap();
+        assert_eq!(as_slice(b"-0"), (-0.0f64).to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"0.00e0"), 0.0f64.to_lexical_with_options(&mut buffer, &options));
+
+        // Long strings fit within the formatted size.
+        let zero = b"0.000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000";
+        let options = WriteFloatOptions::builder().zero_string(Some(zero)).build().unwrap();
+        let mut buffer = [b'\0'; 200];
+        assert_eq!(as_slice(zero), 0.0f64.to_lexical_with_options(&mut buffer, &options));
+    }
+
+    #[test]
+    fn fraction_grouping_test() {
+        let mut buffer = [b'\0'; 128];
+        let builder = WriteFloatOptions::builder()
+            .min_fraction_digits(9)
+            .fraction_grouping(Some((b' ', 3)));
+        let options = builder.build().unwrap();
+        assert_eq!(as_slice(b"0.123 456 789"), 0.123456789f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"1234.500 000 000"), 1234.5f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"-0.100 000 000"), (-0.1f64).to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"0.333 333 333 333 333 3"), (1.0f64 / 3.0).to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"inf"), f64::INFINITY.to_lexical_with_options(&mut buffer, &options));
+
+        // The exponent is never grouped.
+        assert_eq!(as_slice(b"1.234 567 890e-300"), 1.23456789e-300f64.to_lexical_with_options(&mut buffer, &options));
+        let options = builder.layout(WriteFloatLayout::Scientific).exponent_string(b"E").build().unwrap();
+        assert_eq!(as_slice(b"1.234 567 890E3"), 1234.56789f64.to_lexical_with_options(&mut buffer, &options));
+
+        // Short fractions and integers are unchanged.
+        let options = WriteFloatOptions::builder().fraction_grouping(Some((b'_', 2))).build().unwrap();
+        assert_eq!(as_slice(b"0.12"), 0.12f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"0.12_3"), 0.123f64.to_lexical_with_options(&mut buffer, &options));
+        let options = WriteFloatOptions::builder()
+            .layout(WriteFloatLayout::ScaledInteger)
+            .fraction_grouping(Some((b'_', 2)))
+            .build()
+            .unwrap();
+        assert_eq!(as_slice(b"12345e-3"), 12.345f64.to_lexical_with_options(&mut buffer, &options));
+
+        // The formatted size includes every separator.
+        let options = WriteFloatOptions::builder()
+            .min_fraction_digits(40)
+            .fraction_grouping(Some((b' ', 1)))
+            .build()
+            .unwrap();
+        let mut buffer = [b'\0'; 256];
+        let bytes = f64::MIN_POSITIVE.to_lexical_with_options(&mut buffer, &options);
+        assert_eq!(bytes.iter().filter(|&&c| c == b' ').count(), 39);
+    }
+
+    #[test]
+    fn exponent_format_test() {
+        let mut buffer = [b'\0'; BUFFER_SIZE + 8];
+        let builder = WriteFloatOptions::builder()
+            .min_fraction_digits(1)
+            .positive_exponent_sign(true)
+            .min_exponent_digits(3);
+        let options = builder.build().unwrap();
+        assert_eq!(as_slice(b"1.0e+030"), 1e30f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"1.5e-007"), 1.5e-7f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"1.5e+300"), 1.5e300f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"12.5"), 12.5f64.to_lexical_with_options(&mut buffer, &options));
+
+        // The exponent is formatted in every layout.
+        let options = builder.layout(WriteFloatLayout::ScaledInteger).exponent_string(b"E").build().unwrap();
+        assert_eq!(as_slice(b"125E-001"), 12.5f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"0E+000"), 0.0f64.to_lexical_with_options(&mut buffer, &options));
+    }
+
+    #[test]
+    fn special_string_test() {
+        let mut buffer = [b'\0'; BUFFER_SIZE + 8];
+        let options = WriteFloatOptions::builder()
+            .nan_string(Some(b"NA"))
+            .inf_string(Some(b"Infinity"))
+            .build()
+            .unwrap();
+        assert_eq!(as_slice(b"NA"), f64::NAN.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"Infinity"), f64::INFINITY.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"-Infinity"), f64::NEG_INFINITY.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"-Infinity"), f32::NEG_INFINITY.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"1.5"), 1.5f64.to_lexical_with_options(&mut buffer, &options));
+
+        // Long strings fit within the formatted size.
+        let inf = b"infinityinfinityinfinityinfinityinfinityinfinityinfinityinfinityinfinityinfinityinfinityinfinity";
+        let options = WriteFloatOptions::builder().inf_string(Some(inf)).build().unwrap();
+        let mut buffer = [b'\0'; 200];
+        assert_eq!(inf.len() + 1, f32::NEG_INFINITY.to_lexical_with_options(&mut buffer, &options).len());
+    }
+
+    #[test]
+    #[cfg(feature = "optional")]
+    fn sentinel_test() {
+        let mut buffer = [b'\0'; BUFFER_SIZE + 8];
+        let missing = f64::from_bits(0x7FF8_0000_0000_0ABC);
+        let options = WriteFloatOptions::builder()
+            .sentinel_for(missing, b"--")
+            .nan_string(Some(b"NA"))
+            .build()
+            .unwrap();
+        assert_eq!(as_slice(b"--"), missing.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"NA"), f64::NAN.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"1.5"), 1.5f64.to_lexical_with_options(&mut buffer, &options));
+
+        // The sentinel takes precedence over the zero strings, matching the sign.
+        let options = WriteFloatOptions::builder()
+            .sentinel_for(0.0, b"--")
+            .zero_string(Some(b"0"))
+            .build()
+            .unwrap();
+        assert_eq!(as_slice(b"--"), 0.0f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"--"), 0.0f32.to_lexical_with_options(&mut buffer, &options));
+        #[cfg(not(feature = "trim_floats"))]
+        assert_eq!(as_slice(b"-0.0"), (-0.0f64).to_lexical_with_options(&mut buffer, &options));
+        #[cfg(feature = "trim_floats")]
+        assert_eq!(as_slice(b"0"), (-0.0f64).to_lexical_with_options(&mut buffer, &options));
+
+        // Magic numbers are converted to f32.
+        let options = WriteFloatOptions::builder().sentinel_for(-9999.9, b"N/A").build().unwrap();
+        assert_eq!(as_slice(b"N/A"), (-9999.9f64).to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"N/A"), (-9999.9f32).to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"-9999.8"), (-9999.8f64).to_lexical_with_options(&mut buffer, &options));
+
+        // Long strings fit within the formatted size.
+        let sentinel = b"missingmissingmissingmissingmissingmissingmissingmissingmissingmissingmissingmissingmissing";
+        let options = WriteFloatOptions::builder().sentinel_for(1.0, sentinel).build().unwrap();
+        let mut buffer = [b'\0'; 200];
+        assert_eq!(as_slice(sentinel), 1.0f32.to_lexical_with_options(&mut buffer, &options));
+    }
+
+    #[test]
+    fn sign_test() {
+        let mut buffer = [b'\0'; BUFFER_SIZE + 8];
+        let options = WriteFloatOptions::builder()
+            .minus_sign(b"\xE2\x88\x92")
+            .plus_sign(Some(b"+"))
+            .build()
+            .unwrap();
+        assert_eq!(as_slice(b"\xE2\x88\x921.5"), (-1.5f64).to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"\xE2\x88\x921.5e-7"), (-1.5e-7f64).to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"+1.5"), 1.5f32.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"\xE2\x88\x92inf"), f64::NEG_INFINITY.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"+inf"), f64::INFINITY.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"NaN"), f64::NAN.to_lexical_with_options(&mut buffer, &options));
+        #[cfg(not(feature = "trim_floats"))]
+        assert_eq!(as_slice(b"\xE2\x88\x920.0"), (-0.0f64).to_lexical_with_options(&mut buffer, &options));
+
+        // The custom strings are written exactly.
+        let options = WriteFloatOptions::builder()
+            .minus_sign(b"\xE2\x88\x92")
+            .plus_sign(Some(b"+"))
+            .zero_string(Some(b"0"))
+            .inf_string(Some(b"Inf"))
+            .build()
+            .unwrap();
+        assert_eq!(as_slice(b"0"), 0.0f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"\xE2\x88\x92Inf"), f64::NEG_INFINITY.to_lexical_with_options(&mut buffer, &options));
+
+        // The formatted size has room for the multibyte sign.
+        let default_size = f64::formatted_size_with_options(10, &WriteFloatOptions::new());
+        assert_eq!(f64::formatted_size_with_options(10, &options), default_size + 2);
+        let mut buffer = [b'\0'; BUFFER_SIZE + 8];
+        let len = f64::formatted_size_with_options(10, &options);
+        let value = (-f64::MIN_POSITIVE).to_lexical_with_options(&mut buffer[..len], &options);
+        assert_eq!(value.len(), "-2.2250738585072014e-308".len() + 2);
+    }
+
+    #[test]
+    fn f64_compact_test() {
+        let mut buffer = [b'\0'; BUFFER_SIZE + 8];
+        let builder = WriteFloatOptions::builder()
+            .layout(WriteFloatLayout::Compact)
+            .min_fraction_digits(1);
+        let options = builder.build().unwrap();
+        assert_eq!(as_slice(b"123456.0"), 123456.0f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"1.0e5"), 1e5f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"123.45"), 123.45f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"0.0001"), 0.0001f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"1.0e-5"), 0.00001f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"-0.0012"), (-0.0012f64).to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"1.2345e-5"), 1.2345e-5f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"1.2345e300"), 1.2345e300f64.to_lexical_with_options(&mut buffer, &options));
+
+        // Integral floats are shorter without the padding.
+        assert_eq!(as_slice(b"1.234567e9"), 1234567000.0f64.to_lexical_with_options(&mut buffer, &options));
+        let builder = builder.integer_as_integer(true);
+        let options = builder.build().unwrap();
+        assert_eq!(as_slice(b"1234567000"), 1234567000.0f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"10000"), 1e4f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"1.0e5"), 1e5f64.to_lexical_with_options(&mut buffer, &options));
+
+        // The formatted exponent is compared.
+        let options = builder.min_exponent_digits(2).build().unwrap();
+        assert_eq!(as_slice(b"100000"), 1e5f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"1.0e06"), 1e6f64.to_lexical_with_options(&mut buffer, &options));
+    }
+
+    #[test]
+    fn f64_fixed_range_test() {
+        let mut buffer = [b'\0'; 512];
+        let options = WriteFloatOptions::builder()
+            .layout(WriteFloatLayout::FixedRange(-2, 2))
+            .min_fraction_digits(1)
+            .build()
+            .unwrap();
+        assert_eq!(as_slice(b"0.01"), 0.01f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"1.0e-3"), 0.001f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"999.0"), 999.0f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"1.0e3"), 1000.0f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"-1.5e3"), (-1500.0f64).to_lexical_with_options(&mut buffer, &options));
+
+        // Wide ranges write every digit in fixed notation.
+        let options = WriteFloatOptions::builder()
+            .layout(WriteFloatLayout::FixedRange(-400, 400))
+            .integer_as_integer(true)
+            .build()
+            .unwrap();
+        let bytes = 1e300f64.to_lexical_with_options(&mut buffer, &options);
+        assert_eq!(bytes.len(), 301);
+        assert!(bytes.starts_with(b"10000"));
+        let bytes = 5e-324f64.to_lexical_with_options(&mut buffer, &options);
+        assert_eq!(bytes.len(), 326);
+        assert!(bytes.starts_with(b"0.0000") && bytes.ends_with(b"05"));
+    }
+
+    #[test]
+    fn fixed_zeros_test() {
+        assert_eq!(fixed_zeros(1, -3), 3);
+        assert_eq!(fixed_zeros(2, -1), 1);
+        assert_eq!(fixed_zeros(1, 3), 3);
+        assert_eq!(fixed_zeros(2, 3), 2);
+        assert_eq!(fixed_zeros(5, 3), 0);
+
+        assert_eq!(count_fixed_zeros(b"0.001"), 3);
+        assert_eq!(count_fixed_zeros(b"-0.0012"), 3);
+        assert_eq!(count_fixed_zeros(b"0.5"), 1);
+        assert_eq!(count_fixed_zeros(b"1000.0"), 3);
+        assert_eq!(count_fixed_zeros(b"1000"), 3);
+        assert_eq!(count_fixed_zeros(b"1200.5"), 0);
+        assert_eq!(count_fixed_zeros(b"1.5"), 0);
+        assert_eq!(count_fixed_zeros(b"0.0"), 0);
+        assert_eq!(count_fixed_zeros(b"0"), 0);
+    }
+
+    #[test]
+    fn f64_fixed_zero_cap_test() {
+        let mut buffer = [b'\0'; 512];
+        let builder = WriteFloatOptions::builder().min_fraction_digits(1);
+
+        // Wide ranges switch to scientific notation past the cap.
+        let options = builder.layout(WriteFloatLayout::FixedRange(-400, 400)).build().unwrap();
+        assert_eq!(as_slice(b"0.00000000000000000001"), 1e-20f64.to_lexical_with_options(&mut buffer, &options));
+        let options = builder.layout(WriteFloatLayout::FixedRange(-400, 400)).fixed_zero_cap(Some(3)).build().unwrap();
+        assert_eq!(as_slice(b"1.0e-20"), 1e-20f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"1.0e300"), 1e300f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"0.001"), 0.001f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"-1.0e-4"), (-0.0001f64).to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"1000.0"), 1000.0f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"1.0e4"), 10000.0f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"12345.6"), 12345.6f64.to_lexical_with_options(&mut buffer, &options));
+
+        // The standard layout switches to scientific notation past the cap.
+        let options = builder.fixed_zero_cap(Some(2)).build().unwrap();
+        assert_eq!(as_slice(b"0.01"), 0.01f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"1.2e-3"), 0.0012f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"100.0"), 100.0f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"-1500.0"), (-1500.0f64).to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"-1.5e4"), (-15000.0f64).to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"1234.5"), 1234.5f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"0.0"), 0.0f64.to_lexical_with_options(&mut buffer, &options));
+        let options = builder.fixed_zero_cap(Some(0)).build().unwrap();
+        assert_eq!(as_slice(b"5.0e-1"), 0.5f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"1.5"), 1.5f64.to_lexical_with_options(&mut buffer, &options));
+
+        // Combined with the other options.
+        let options = builder
+            .fixed_zero_cap(Some(2))
+            .integer_as_integer(true)
+            .exponent_string(b"E")
+            .build()
+            .unwrap();
+        assert_eq!(as_slice(b"100"), 100.0f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"1.0E3"), 1000.0f64.to_lexical_with_options(&mut buffer, &options));
+        let options = builder.layout(WriteFloatLayout::Compact).fixed_zero_cap(Some(2)).build().unwrap();
+        assert_eq!(as_slice(b"1.0e-3"), 0.001f64.to_lexical_with_options(&mut buffer, &options));
+    }
+
+    #[test]
+    fn f64_r_compatible_test() {
+        // Outputs of `print` in R, for values with few significant digits.
+        let data: [(f64, &[u8]); 24] = [
+            (0.0, b"0"),
+            (-0.0, b"0"),
+            (1.0, b"1"),
+            (-2.5, b"-2.5"),
+            (0.1, b"0.1"),
+            (3.14, b"3.14"),
+            (100.0, b"100"),
+            (1000.0, b"1000"),
+            (1e4, b"10000"),
+            (1e5, b"1e+05"),
+            (123456.0, b"123456"),
+            (1234567.1, b"1234567.1"),
+            (123456789012.0, b"123456789012"),
+            (1e15, b"1e+15"),
+            (1.5e300, b"1.5e+300"),
+            (0.001, b"0.001"),
+            (0.0001, b"1e-04"),
+            (0.00012, b"0.00012"),
+            (1.5e-8, b"1.5e-08"),
+            (-1e-300, b"-1e-300"),
+            (1e100, b"1e+100"),
+            (f64::INFINITY, b"Inf"),
+            (f64::NEG_INFINITY, b"-Inf"),
+            (f64::NAN, b"NaN"),
+        ];
+        let mut buffer = [b'\0'; BUFFER_SIZE + 8];
+        let options = WriteFloatOptions::r_compatible();
+        for &(value, expected) in data.iter() {
+            assert_eq!(as_slice(expected), value.to_lexical_with_options(&mut buffer, &options));
+        }
+    }
+
+    #[test]
+    fn f64_julia_compatible_test() {
+        // Outputs of `show` in Julia, for `Float64` values.
+        let data: [(f64, &[u8]); 24] = [
+            (0.0, b"0.0"),
+            (-0.0, b"-0.0"),
+            (1.0, b"1.0"),
+            (-2.5, b"-2.5"),
+            (0.1, b"0.1"),
+            (0.3, b"0.3"),
+            (100.0, b"100.0"),
+            (1e5, b"100000.0"),
+            (123456.7, b"123456.7"),
+            (1e6, b"1.0e6"),
+            (1234567.0, b"1.234567e6"),
+            (-1e6, b"-1.0e6"),
+            (1e16, b"1.0e16"),
+            (1.5e300, b"1.5e300"),
+            (0.0001, b"0.0001"),
+            (0.00012, b"0.00012"),
+            (1e-5, b"1.0e-5"),
+            (1.5e-8, b"1.5e-8"),
+            (1e-300, b"1.0e-300"),
+            (5e-324, b"5.0e-324"),
+            (f64::MAX, b"1.7976931348623157e308"),
+            (f64::INFINITY, b"Inf"),
+            (f64::NEG_INFINITY, b"-Inf"),
+            (f64::NAN, b"NaN"),
+        ];
+        let mut buffer = [b'\0'; BUFFER_SIZE + 8];
+        let options = WriteFloatOptions::julia_compatible();
+        for &(value, expected) in data.iter() {
+            assert_eq!(as_slice(expected), value.to_lexical_with_options(&mut buffer, &options));
+        }
+    }
 }
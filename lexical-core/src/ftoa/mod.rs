@@ -14,3 +14,6 @@ if #[cfg(feature = "grisu3")] {
 }}  // cfg_if
 
 mod api;
+
+// Re-exports
+pub use self::api::float_backend;
@@ -2,10 +2,143 @@
 //!
 //! This algorithm is adapted from the V8 codebase,
 //! and may be found [here](https://github.com/v8/v8).
+//!
+//! Unlike the V8 algorithm, the digits are generated using exact,
+//! fixed-width integer arithmetic, rather than float arithmetic, which
+//! accumulates rounding error for tiny, denormal, and huge floats.
 
 use crate::itoa;
+use crate::lib::cmp;
 use crate::util::*;
 
+// FIXED INT
+// ---------
+
+// Number of 32-bit limbs to store the scaled float components.
+//
+// The largest value stored is a float scaled by `2^1077`, or the
+// power of the radix immediately above the largest float.
+const FIXED_LIMBS: usize = 36;
+
+/// Fixed-width, little-endian integer for exact digit generation.
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct FixedInt {
+    data: [u32; FIXED_LIMBS],
+}
+
+impl FixedInt {
+    /// Create integer from a 64-bit value.
+    fn from_u64(x: u64) -> FixedInt {
+        let mut data = [0; FIXED_LIMBS];
+        data[0] = x as u32;
+        data[1] = (x >> 32) as u32;
+        FixedInt { data }
+    }
+
+    /// Create integer from a power of two.
+    fn pow2(n: usize) -> FixedInt {
+        let mut data = [0; FIXED_LIMBS];
+        data[n / 32] = 1 << (n % 32);
+        FixedInt { data }
+    }
+
+    /// Check if the integer is zero.
+    fn is_zero(&self) -> bool {
+        self.data.iter().all(|&x| x == 0)
+    }
+
+    /// Shift left by `n` bits in-place.
+    fn ishl(&mut self, n: usize) {
+        let limbs = n / 32;
+        let bits = n % 32;
+        for i in (0..FIXED_LIMBS).rev() {
+            let hi = if i >= limbs { self.data[i - limbs] } else { 0 };
+            let lo = if i > limbs { self.data[i - limbs - 1] } else { 0 };
+            self.data[i] = match bits {
+                0 => hi,
+                _ => (hi << bits) | (lo >> (32 - bits)),
+            };
+        }
+    }
+
+    /// Clear all bits at or above bit `n`.
+    fn truncate(&mut self, n: usize) {
+        let index = n / 32;
+        if index < FIXED_LIMBS {
+            self.data[index] &= (1 << (n % 32)) - 1;
+            for limb in self.data[index+1..].iter_mut() {
+                *limb = 0;
+            }
+        }
+    }
+
+    /// Remove and return the small value stored at or above bit `n`.
+    fn split_digit(&mut self, n: usize) -> u32 {
+        let index = n / 32;
+        let lo = self.data[index] as u64;
+        let hi = self.data.get(index+1).map_or(0, |&x| x as u64);
+        let digit = ((hi << 32) | lo) >> (n % 32);
+        self.truncate(n);
+        digit as u32
+    }
+
+    /// Multiply by a small value in-place.
+    fn imul_small(&mut self, y: u32) {
+        let mut carry = 0;
+        for limb in self.data.iter_mut() {
+            let z = (*limb as u64) * (y as u64) + carry;
+            *limb = z as u32;
+            carry = z >> 32;
+        }
+        debug_assert!(carry == 0);
+    }
+
+    /// Divide by a small value in-place, returning the remainder.
+    fn idiv_small(&mut self, y: u32) -> u32 {
+        let mut rem = 0;
+        for limb in self.data.iter_mut().rev() {
+            let z = (rem << 32) | (*limb as u64);
+            *limb = (z / y as u64) as u32;
+            rem = z % y as u64;
+        }
+        rem as u32
+    }
+
+    /// Add another integer in-place.
+    fn iadd(&mut self, y: &FixedInt) {
+        let mut carry = 0;
+        for (x, &y) in self.data.iter_mut().zip(y.data.iter()) {
+            let z = (*x as u64) + (y as u64) + carry;
+            *x = z as u32;
+            carry = z >> 32;
+        }
+        debug_assert!(carry == 0);
+    }
+
+    /// Subtract a smaller integer in-place.
+    fn isub(&mut self, y: &FixedInt) {
+        let mut borrow = 0;
+        for (x, &y) in self.data.iter_mut().zip(y.data.iter()) {
+            let z = (*x as u64).wrapping_sub(y as u64).wrapping_sub(borrow);
+            *x = z as u32;
+            borrow = (z >> 63) & 1;
+        }
+        debug_assert!(borrow == 0);
+    }
+}
+
+impl PartialOrd for FixedInt {
+    fn partial_cmp(&self, other: &FixedInt) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for FixedInt {
+    fn cmp(&self, other: &FixedInt) -> cmp::Ordering {
+        self.data.iter().rev().cmp(other.data.iter().rev())
+    }
+}
+
 // FTOA BASEN
 // ----------
 
@@ -14,19 +147,151 @@ macro_rules! to_digit {
     ($c:expr, $radix:ident) => (($c as char).to_digit($radix));
 }
 
-// Calculate the naive exponent from a minimal value.
+// Get the scaled distances to the halfway points to the adjacent floats.
 //
-// Don't export this for float, since it's specialized for radix.
+// Returns the distances below and above the float, which differ if the
+// float is a power of two, since the float below it has a smaller ulp.
+// The distances are scaled by `2^(shift-exponent)`, and `shift` must
+// be large enough so the distances are integers.
 perftools_inline!{
-pub(crate) fn naive_exponent(d: f64, radix: u32) -> i32
+fn halfway_distances(value: f64, shift: i32) -> (FixedInt, FixedInt)
 {
-    // floor returns the minimal value, which is our
-    // desired exponent
-    // ln(1.1e-5) -> -4.95 -> -5
-    // ln(1.1e5) -> -5.04 -> 5
-    (d.ln() / (radix as f64).ln()).floor() as i32
+    let exponent = value.exponent();
+    let upper = FixedInt::pow2((shift - 1) as usize);
+    let is_boundary = value.mantissa() == f64::HIDDEN_BIT_MASK && exponent > f64::DENORMAL_EXPONENT;
+    let lower = match is_boundary {
+        true  => FixedInt::pow2((shift - 2) as usize),
+        false => upper,
+    };
+    (lower, upper)
 }}
 
+// Write the shortest fraction digits, returning the fraction cursor
+// and if the rounded fraction carried over to the integer part.
+//
+// The fraction and distances are stored exactly, as integers scaled
+// by `2^(bits+2)`, where `bits` is the number of fractional bits
+// in the float.
+fn write_fraction(value: f64, radix: u32, buffer: &mut [u8], initial_position: usize)
+    -> (usize, bool)
+{
+    let mut cursor = initial_position;
+    let exponent = value.exponent();
+    if exponent >= 0 {
+        return (cursor, false);
+    }
+
+    let bits = (-exponent) as usize;
+    let mut fraction = FixedInt::from_u64(value.mantissa());
+    fraction.truncate(bits);
+    fraction.ishl(2);
+    let (mut lower, mut upper) = halfway_distances(value, 2);
+    let one = FixedInt::pow2(bits + 2);
+    let half = FixedInt::pow2(bits + 1);
+
+    // Don't remove bounds checks, for a few reasons.
+    //  1. Difficult to determine statically.
+    //  2. Algorithm is fairly slow, in general, so performance isn't a major deal.
+    if !fraction.is_zero() {
+        loop {
+            // Shift up by one digit.
+            fraction.imul_small(radix);
+            lower.imul_small(radix);
+            upper.imul_small(radix);
+            // Write digit, and calculate remainder.
+            let digit = fraction.split_digit(bits + 2);
+            buffer[cursor] = digit_to_char(digit);
+            cursor += 1;
+            // Round to even.
+            if fraction > half || (fraction == half && (digit & 1) != 0) {
+                let mut rounded = fraction;
+                rounded.iadd(&upper);
+                if rounded > one {
+                    // We need to back trace already written digits in case of carry-over.
+                    loop {
+                        if cursor == initial_position {
+                            // Carry over to the integer part.
+                            return (cursor, true);
+                        }
+                        cursor -= 1;
+                        // Reconstruct digit, dropping digits that overflow the radix.
+                        let c = buffer[cursor];
+                        if let Some(digit) = to_digit!(c, radix) {
+                            if digit + 1 < radix {
+                                buffer[cursor] = digit_to_char(digit + 1);
+                                cursor += 1;
+                                break;
+                            }
+                        }
+                    }
+                    break;
+                }
+            }
+
+            if lower > fraction {
+                break;
+            }
+        }
+    }
+
+    (cursor, false)
+}
+
+// Write the integer digits for an integral float too large to be exact.
+//
+// Writes the fewest significant digits, followed by zeros, that
+// round to the float, and returns the integer cursor. The value and
+// distances are stored exactly, as integers scaled by `2`.
+fn write_large_integer(value: f64, radix: u32, buffer: &mut [u8], initial_position: usize)
+    -> usize
+{
+    let exponent = value.exponent();
+    debug_assert!(exponent > 0);
+
+    let (lower, upper) = halfway_distances(value, exponent + 1);
+    let mut quotient = FixedInt::from_u64(value.mantissa());
+    quotient.ishl(exponent as usize);
+    let mut remainder = FixedInt::from_u64(0);
+    let mut power = FixedInt::from_u64(2);
+
+    // Find the largest number of trailing zeros, `zeros`, such that
+    // rounding the value to a multiple of `radix^zeros` is within
+    // the halfway point to the adjacent floats.
+    let mut zeros = 0;
+    let mut digits = quotient;
+    while !quotient.is_zero() {
+        let mut addend = power;
+        addend.imul_small(quotient.idiv_small(radix));
+        remainder.iadd(&addend);
+        power.imul_small(radix);
+        let mut distance = power;
+        distance.isub(&remainder);
+        if remainder < lower {
+            digits = quotient;
+        } else if distance < upper {
+            digits = quotient;
+            digits.iadd(&FixedInt::from_u64(1));
+        } else {
+            break;
+        }
+        zeros += 1;
+    }
+
+    let mut cursor = initial_position;
+    for _ in 0..zeros {
+        cursor -= 1;
+        buffer[cursor] = b'0';
+    }
+    loop {
+        cursor -= 1;
+        buffer[cursor] = digit_to_char(digits.idiv_small(radix));
+        if digits.is_zero() {
+            break;
+        }
+    }
+    cursor
+}
+
 /// Naive algorithm for converting a floating point to a custom radix.
 ///
 /// `d` must be non-special (NaN or infinite), non-negative,
@@ -69,101 +334,58 @@ fn ftoa_naive<'a>(value: f64, radix: u32, bytes: &'a mut [u8])
     // termination should be sufficient.
     const SIZE: usize = 2200;
     let mut buffer: [u8; SIZE] = [b'\0'; SIZE];
-    //let buffer = buffer.as_mut_ptr();
     let initial_position: usize = SIZE / 2;
-    let mut integer_cursor = initial_position;
-    let mut fraction_cursor = initial_position;
     let base = radix as f64;
 
     // Split the value into an integer part and a fractional part.
     let mut integer = value.floor();
-    let mut fraction = value - integer;
-
-    // We only compute fractional digits up to the input double's precision.
-    let mut delta = 0.5 * (value.next_positive() - value);
-    delta = 0.0.next_positive().max_finite(delta);
-    debug_assert!(delta > 0.0);
+    let (fraction_cursor, carry) = write_fraction(value, radix, &mut buffer, initial_position);
+    if carry {
+        integer += 1.0;
+    }
 
-    // Don't remove bounds checks, for a few reasons.
-    //  1. Difficult to determine statically.
-    //  2. Algorithm is fairly slow, in general, so performance isn't a major deal.
-    if fraction > delta {
+    // Compute integer digits. Integers below `2^53` are exact.
+    let integer_cursor = if value.exponent() > 0 {
+        write_large_integer(value, radix, &mut buffer, initial_position)
+    } else {
+        let mut cursor = initial_position;
         loop {
-            // Shift up by one digit.
-            fraction *= base;
-            delta *= base;
-            // Write digit.
-            let digit = fraction as i32;
-            buffer[fraction_cursor] = digit_to_char(digit);
-            fraction_cursor += 1;
-            // Calculate remainder.
-            fraction -= digit as f64;
-            // Round to even.
-            if fraction > 0.5 || (fraction == 0.5 && (digit & 1) != 0) {
-                if fraction + delta > 1.0 {
-                    // We need to back trace already written digits in case of carry-over.
-                    loop {
-                        fraction_cursor -= 1;
-                        if fraction_cursor == initial_position-1 {
-                            // Carry over to the integer part.
-                            integer += 1.0;
-                            break;
-                        }
-                        // Reconstruct digit.
-                        let c = buffer[fraction_cursor];
-                        if let Some(digit) = to_digit!(c, radix) {
-                            let idx = (digit + 1) as usize;
-                            buffer[fraction_cursor] = digit_to_char(idx);
-                            fraction_cursor += 1;
-                            break;
-                        }
-                    }
-                    break;
-                }
-            }
+            let remainder = integer % base;
+            cursor -= 1;
+            let idx = remainder as u32;
+            buffer[cursor] = digit_to_char(idx);
+            integer = (integer - remainder) / base;
 
-            if delta >= fraction {
+            if integer <= 0.0 {
                 break;
             }
         }
-    }
-
-    // Compute integer digits. Fill unrepresented digits with zero.
-    while (integer / base).exponent() > 0 {
-        integer /= base;
-        integer_cursor -= 1;
-        buffer[integer_cursor] = b'0';
-    }
-
-    loop {
-        let remainder = integer % base;
-        integer_cursor -= 1;
-        let idx = remainder as usize;
-        buffer[integer_cursor] = digit_to_char(idx);
-        integer = (integer - remainder) / base;
-
-        if integer <= 0.0 {
-            break;
-        }
+        cursor
     };
 
     if value <= 1e-5 || value >= 1e9 {
         // write scientific notation with negative exponent
-        let exponent = naive_exponent(value, radix);
-
-        // Non-exponent portion.
-        // 1.   Get as many digits as possible, up to `MAX_DIGIT_LENGTH+1`
-        //      (since we are ignoring the digit for the first digit),
-        //      or the number of written digits
+        //
+        // Calculate the exponent from the written digits, rather than
+        // from the logarithm of the value, since the logarithm may be
+        // off-by-one for values near a power of the radix.
         let start: usize;
-        let end: usize;
+        let exponent: i32;
         if value <= 1e-5 {
-            start = ((initial_position as i32) - exponent - 1) as usize;
-            end = fraction_cursor.min(start + MAX_DIGIT_LENGTH + 1);
+            let fraction = &buffer[initial_position..fraction_cursor];
+            let zeros = ltrim_char_slice(fraction, b'0').1;
+            start = initial_position + zeros;
+            exponent = -(zeros as i32) - 1;
         } else {
             start = integer_cursor;
-            end = fraction_cursor.min(start + MAX_DIGIT_LENGTH + 1);
+            exponent = (initial_position - integer_cursor) as i32 - 1;
         }
+
+        // Non-exponent portion.
+        // 1.   Get as many digits as possible, up to `MAX_DIGIT_LENGTH+1`
+        //      (since we are ignoring the digit for the first digit),
+        //      or the number of written digits
+        let end = fraction_cursor.min(start + MAX_DIGIT_LENGTH + 1);
         let buffer = &buffer[start..end];
 
         // 2.   Remove any trailing 0s in the selected range.
@@ -104,6 +104,9 @@ pub(crate) fn itoa_positive<T>(value: T, radix: u32, buffer: &mut [u8])
 // TO LEXICAL
 
 // Callback for unsigned integer formatter.
+//
+// Unsigned values are never negative, so they are written directly,
+// without checking for or writing a sign.
 perftools_inline!{
 fn unsigned<Narrow, Wide>(value: Narrow, radix: u32, buffer: &mut [u8])
     -> usize
@@ -169,6 +172,105 @@ signed_to_lexical!(isize, i32, u32);
 #[cfg(target_pointer_width = "64")]
 signed_to_lexical!(isize, i64, u64);
 
+// TO LEXICAL WITH OPTIONS
+
+// Get the maximum number of bytes required to write an integer with custom options.
+perftools_inline!{
+fn formatted_size<T: Integer>(radix: u32, options: &WriteIntegerOptions)
+    -> usize
+{
+    let size = match radix {
+        10 => T::FORMATTED_SIZE_DECIMAL,
+        _  => T::FORMATTED_SIZE,
+    };
+    // The formatted size has a byte for the `-` sign, at most.
+    let minus_len = options.minus_sign().len() - 1;
+    let plus_len = options.plus_sign().map_or(0, |s| s.len());
+    size + minus_len.max(plus_len)
+}}
+
+// Callback for unsigned integer formatter with custom options.
+perftools_inline!{
+fn unsigned_with_options<Narrow, Wide>(value: Narrow, radix: u32, buffer: &mut [u8], options: &WriteIntegerOptions)
+    -> usize
+    where Narrow: UnsignedInteger,
+          Wide: Itoa
+{
+    let len = unsigned::<Narrow, Wide>(value, radix, buffer);
+    write_sign(buffer, len, options.minus_sign(), options.plus_sign())
+}}
+
+macro_rules! unsigned_to_lexical_with_options {
+    ($narrow:ty, $wide:ty) => (
+        to_lexical_with_options!(
+            unsigned_with_options::<$narrow, $wide>,
+            formatted_size::<$narrow>,
+            $narrow,
+            WriteIntegerOptions
+        );
+    );
+}
+
+unsigned_to_lexical_with_options!(u8, u32);
+unsigned_to_lexical_with_options!(u16, u32);
+unsigned_to_lexical_with_options!(u32, u32);
+unsigned_to_lexical_with_options!(u64, u64);
+unsigned_to_lexical_with_options!(u128, u128);
+
+#[cfg(any(target_pointer_width = "16", target_pointer_width = "32"))]
+unsigned_to_lexical_with_options!(usize, u32);
+
+#[cfg(target_pointer_width = "64")]
+unsigned_to_lexical_with_options!(usize, u64);
+
+// Callback for signed integer formatter with custom options.
+//
+// Negative values written as two's complement are reinterpreted as the
+// unsigned type of the same width, so the bit pattern has the width
+// of the signed type, and is written without a sign.
+perftools_inline!{
+fn signed_with_options<Narrow, Wide, NarrowUnsigned, Unsigned>(value: Narrow, radix: u32, buffer: &mut [u8], options: &WriteIntegerOptions)
+    -> usize
+    where Narrow: SignedInteger,
+          Wide: SignedInteger,
+          NarrowUnsigned: UnsignedInteger,
+          Unsigned: Itoa
+{
+    #[cfg(feature = "radix")] {
+        if options.two_complement() && radix != 10 && value < Narrow::ZERO {
+            let value: NarrowUnsigned = as_cast(value);
+            let value: Unsigned = as_cast(value);
+            return itoa_positive(value, radix, buffer);
+        }
+    }
+
+    let len = signed::<Narrow, Wide, Unsigned>(value, radix, buffer);
+    write_sign(buffer, len, options.minus_sign(), options.plus_sign())
+}}
+
+macro_rules! signed_to_lexical_with_options {
+    ($narrow:ty, $wide:ty, $narrow_unsigned:ty, $unsigned:ty) => (
+        to_lexical_with_options!(
+            signed_with_options::<$narrow, $wide, $narrow_unsigned, $unsigned>,
+            formatted_size::<$narrow>,
+            $narrow,
+            WriteIntegerOptions
+        );
+    );
+}
+
+signed_to_lexical_with_options!(i8, i32, u8, u32);
+signed_to_lexical_with_options!(i16, i32, u16, u32);
+signed_to_lexical_with_options!(i32, i32, u32, u32);
+signed_to_lexical_with_options!(i64, i64, u64, u64);
+signed_to_lexical_with_options!(i128, i128, u128, u128);
+
+#[cfg(any(target_pointer_width = "16", target_pointer_width = "32"))]
+signed_to_lexical_with_options!(isize, i32, usize, u32);
+
+#[cfg(target_pointer_width = "64")]
+signed_to_lexical_with_options!(isize, i64, usize, u64);
+
 // TESTS
 // -----
 
@@ -347,6 +449,54 @@ mod tests {
         }
     }
 
+    #[test]
+    fn with_options_test() {
+        let mut buffer = new_buffer();
+        let options = WriteIntegerOptions::new();
+        assert_eq!(b"37", 37u8.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(b"-37", (-37i8).to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(b"-1", (-1i128).to_lexical_with_options(&mut buffer, &options));
+    }
+
+    #[test]
+    fn sign_options_test() {
+        let mut buffer = new_buffer();
+        let options = WriteIntegerOptions::builder()
+            .minus_sign(b"\xE2\x88\x92")
+            .plus_sign(Some(b"+"))
+            .build()
+            .unwrap();
+        assert_eq!(b"\xE2\x88\x9237", (-37i8).to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(b"+37", 37i8.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(b"+0", 0i32.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(b"+255", 255u8.to_lexical_with_options(&mut buffer, &options));
+
+        // The formatted size has room for the multibyte sign.
+        assert_eq!(i8::formatted_size_with_options(10, &options), i8::FORMATTED_SIZE_DECIMAL + 2);
+        assert_eq!(u8::formatted_size_with_options(10, &options), u8::FORMATTED_SIZE_DECIMAL + 2);
+        let mut buffer = [b'\0'; 6];
+        assert_eq!(b"\xE2\x88\x92128", (-128i8).to_lexical_with_options(&mut buffer, &options));
+    }
+
+    #[cfg(feature = "radix")]
+    #[test]
+    fn two_complement_test() {
+        let mut buffer = new_buffer();
+        let options = WriteIntegerOptions::builder().two_complement(true).build().unwrap();
+        assert_eq!(b"11111111", (-1i8).to_lexical_radix_with_options(2, &mut buffer, &options));
+        assert_eq!(b"10000000", (-128i8).to_lexical_radix_with_options(2, &mut buffer, &options));
+        assert_eq!(b"1111111", 127i8.to_lexical_radix_with_options(2, &mut buffer, &options));
+        assert_eq!(b"FFFF", (-1i16).to_lexical_radix_with_options(16, &mut buffer, &options));
+        assert_eq!(b"FFFFFFFE", (-2i32).to_lexical_radix_with_options(16, &mut buffer, &options));
+        assert_eq!(b"1777777777777777777777", (-1i64).to_lexical_radix_with_options(8, &mut buffer, &options));
+        assert_eq!(&b"ffffffffffffffffffffffffffffffff"[..], &(-1i128).to_lexical_radix_with_options(16, &mut buffer, &options).to_ascii_lowercase()[..]);
+        assert_eq!(b"-1", (-1i8).to_lexical_radix_with_options(10, &mut buffer, &options));
+        assert_eq!(b"FF", 255u8.to_lexical_radix_with_options(16, &mut buffer, &options));
+
+        let options = WriteIntegerOptions::new();
+        assert_eq!(b"-1", (-1i8).to_lexical_radix_with_options(2, &mut buffer, &options));
+    }
+
     // Extensive tests
 
     #[test]
@@ -510,73 +660,73 @@ mod tests {
         #[test]
         fn u8_proptest(i in u8::min_value()..u8::max_value()) {
             let mut buffer = new_buffer();
-            i == u8::from_lexical(i.to_lexical(&mut buffer)).unwrap()
+            prop_assert_eq!(i, u8::from_lexical(i.to_lexical(&mut buffer)).unwrap());
         }
 
         #[test]
         fn i8_proptest(i in i8::min_value()..i8::max_value()) {
             let mut buffer = new_buffer();
-            i == i8::from_lexical(i.to_lexical(&mut buffer)).unwrap()
+            prop_assert_eq!(i, i8::from_lexical(i.to_lexical(&mut buffer)).unwrap());
         }
 
         #[test]
         fn u16_proptest(i in u16::min_value()..u16::max_value()) {
             let mut buffer = new_buffer();
-            i == u16::from_lexical(i.to_lexical(&mut buffer)).unwrap()
+            prop_assert_eq!(i, u16::from_lexical(i.to_lexical(&mut buffer)).unwrap());
         }
 
         #[test]
         fn i16_proptest(i in i16::min_value()..i16::max_value()) {
             let mut buffer = new_buffer();
-            i == i16::from_lexical(i.to_lexical(&mut buffer)).unwrap()
+            prop_assert_eq!(i, i16::from_lexical(i.to_lexical(&mut buffer)).unwrap());
         }
 
         #[test]
         fn u32_proptest(i in u32::min_value()..u32::max_value()) {
             let mut buffer = new_buffer();
-            i == u32::from_lexical(i.to_lexical(&mut buffer)).unwrap()
+            prop_assert_eq!(i, u32::from_lexical(i.to_lexical(&mut buffer)).unwrap());
         }
 
         #[test]
         fn i32_proptest(i in i32::min_value()..i32::max_value()) {
             let mut buffer = new_buffer();
-            i == i32::from_lexical(i.to_lexical(&mut buffer)).unwrap()
+            prop_assert_eq!(i, i32::from_lexical(i.to_lexical(&mut buffer)).unwrap());
         }
 
         #[test]
         fn u64_proptest(i in u64::min_value()..u64::max_value()) {
             let mut buffer = new_buffer();
-            i == u64::from_lexical(i.to_lexical(&mut buffer)).unwrap()
+            prop_assert_eq!(i, u64::from_lexical(i.to_lexical(&mut buffer)).unwrap());
         }
 
         #[test]
         fn i64_proptest(i in i64::min_value()..i64::max_value()) {
             let mut buffer = new_buffer();
-            i == i64::from_lexical(i.to_lexical(&mut buffer)).unwrap()
+            prop_assert_eq!(i, i64::from_lexical(i.to_lexical(&mut buffer)).unwrap());
         }
 
         #[test]
         fn u128_proptest(i in u128::min_value()..u128::max_value()) {
             let mut buffer = new_buffer();
-            i == u128::from_lexical(i.to_lexical(&mut buffer)).unwrap()
+            prop_assert_eq!(i, u128::from_lexical(i.to_lexical(&mut buffer)).unwrap());
         }
 
         #[test]
         fn i128_proptest(i in i128::min_value()..i128::max_value()) {
             let mut buffer = new_buffer();
-            i == i128::from_lexical(i.to_lexical(&mut buffer)).unwrap()
+            prop_assert_eq!(i, i128::from_lexical(i.to_lexical(&mut buffer)).unwrap());
         }
 
         #[test]
         fn usize_proptest(i in usize::min_value()..usize::max_value()) {
             let mut buffer = new_buffer();
-            i == usize::from_lexical(i.to_lexical(&mut buffer)).unwrap()
+            prop_assert_eq!(i, usize::from_lexical(i.to_lexical(&mut buffer)).unwrap());
         }
 
         #[test]
         fn isize_proptest(i in isize::min_value()..isize::max_value()) {
             let mut buffer = new_buffer();
-            i == isize::from_lexical(i.to_lexical(&mut buffer)).unwrap()
+            prop_assert_eq!(i, isize::from_lexical(i.to_lexical(&mut buffer)).unwrap());
         }
     }
 
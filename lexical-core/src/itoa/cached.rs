@@ -0,0 +1,43 @@
+//! Cached decimal strings for small integers.
+
+use crate::util::*;
+
+/// Get the decimal string for a `u8`, without writing to a buffer.
+///
+/// The strings are stored in a static table, and are identical to
+/// the strings written by [`write`], so small integers may be
+/// converted to strings without an allocation or a buffer.
+///
+/// * `n`   - Number to convert to string.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # pub fn main() {
+/// assert_eq!(lexical_core::to_string_cached(0), "0");
+/// assert_eq!(lexical_core::to_string_cached(255), "255");
+/// # }
+/// ```
+///
+/// [`write`]: fn.write.html
+#[inline]
+pub fn to_string_cached(n: u8) -> &'static str {
+    index!(SMALL_INTEGER_STRINGS[n as usize])
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_string_cached_test() {
+        let mut buffer = [b'\0'; u8::FORMATTED_SIZE_DECIMAL];
+        for n in 0..=255u8 {
+            assert_eq!(to_string_cached(n).as_bytes(), n.to_lexical(&mut buffer));
+        }
+    }
+}
@@ -11,6 +11,15 @@ mod generic;
 mod naive;
 
 mod api;
+mod cached;
+
+#[cfg(feature = "simd")]
+mod slice;
 
 #[cfg(feature = "radix")]
 pub(crate) use self::api::itoa_positive;
+
+pub use self::cached::to_string_cached;
+
+#[cfg(feature = "simd")]
+pub use self::slice::format_u32_slice;
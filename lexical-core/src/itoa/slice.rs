@@ -0,0 +1,278 @@
+//! Bulk formatting of slices of integers.
+//!
+//! On x86 and x86-64 with SSE2, the low 8 digits of values with at
+//! least 8 digits are written in parallel, using the algorithm from
+//! Wojciech Muła, "SSE: conversion integers to decimal representation",
+//! as adapted in Milo Yip's itoa-benchmark. Smaller values and other
+//! targets use the scalar formatter, so the output is always identical.
+
+use crate::util::*;
+use super::api::itoa_positive;
+
+// HELPERS
+
+// Get the number of decimal digits in the value.
+perftools_inline!{
+fn digit_count(value: u32) -> usize {
+    match value {
+        0..=9                   => 1,
+        10..=99                 => 2,
+        100..=999               => 3,
+        1000..=9999             => 4,
+        10000..=99999           => 5,
+        100000..=999999         => 6,
+        1000000..=9999999       => 7,
+        10000000..=99999999     => 8,
+        100000000..=999999999   => 9,
+        _                       => 10,
+    }
+}}
+
+// Get the number of bytes required to write the values, with separators.
+perftools_inline!{
+fn formatted_size(values: &[u32]) -> usize {
+    let digits: usize = values.iter().map(|&value| digit_count(value)).sum();
+    digits + values.len().saturating_sub(1)
+}}
+
+// Write the value with the scalar formatter, returning the number of bytes written.
+perftools_inline!{
+fn write_scalar(value: u32, buffer: &mut [u8]) -> usize {
+    itoa_positive(value, 10, buffer)
+}}
+
+// SIMD
+
+cfg_if! {
+if #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "sse2"))] {
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::*;
+
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::*;
+
+    // Write a value less than 10^8 as exactly 8 digits, with leading zeros.
+    perftools_inline!{
+    fn write_8_digits(value: u32, buffer: &mut [u8]) {
+        let buffer = &mut index_mut!(buffer[..8]);
+        // This is safe, since SSE2 is enabled for the target, and the
+        // unaligned store writes 8 bytes into the 8-byte buffer.
+        unsafe {
+            // abcd, efgh = abcdefgh divmod 10000
+            let abcdefgh = _mm_cvtsi32_si128(value as i32);
+            let abcd = _mm_srli_epi64(_mm_mul_epu32(abcdefgh, _mm_cvtsi32_si128(0xD1B71759u32 as i32)), 45);
+            let efgh = _mm_sub_epi32(abcdefgh, _mm_mul_epu32(abcd, _mm_cvtsi32_si128(10000)));
+
+            // v2 = [abcd * 4; 4] ++ [efgh * 4; 4]
+            let v1 = _mm_unpacklo_epi16(abcd, efgh);
+            let v1a = _mm_slli_epi64(v1, 2);
+            let v2a = _mm_unpacklo_epi16(v1a, v1a);
+            let v2 = _mm_unpacklo_epi32(v2a, v2a);
+
+            // v4 = v2 / [10^3, 10^2, 10^1, 10^0; 2] = [a, ab, abc, abcd, e, ef, efg, efgh]
+            let div_powers = _mm_setr_epi16(8389, 5243, 13108, -32768, 8389, 5243, 13108, -32768);
+            let shift_powers = _mm_setr_epi16(1 << 7, 1 << 11, 1 << 13, -32768, 1 << 7, 1 << 11, 1 << 13, -32768);
+            let v3 = _mm_mulhi_epu16(v2, div_powers);
+            let v4 = _mm_mulhi_epu16(v3, shift_powers);
+
+            // v7 = v4 - (v4 * 10 << 16) = [a, b, c, d, e, f, g, h]
+            let v5 = _mm_mullo_epi16(v4, _mm_set1_epi16(10));
+            let v6 = _mm_slli_epi64(v5, 16);
+            let v7 = _mm_sub_epi16(v4, v6);
+
+            // Narrow to bytes and convert to ASCII.
+            let ascii = _mm_add_epi8(_mm_packus_epi16(v7, _mm_setzero_si128()), _mm_set1_epi8(b'0' as i8));
+            _mm_storel_epi64(buffer.as_mut_ptr() as *mut __m128i, ascii);
+        }
+    }}
+
+    // Write the value, converting the low 8 digits in parallel.
+    //
+    // Values with fewer than 8 digits use the scalar formatter, since
+    // the leading zeros would need to be removed.
+    perftools_inline!{
+    fn write_value(value: u32, buffer: &mut [u8]) -> usize {
+        if value < 10000000 {
+            write_scalar(value, buffer)
+        } else if value < 100000000 {
+            write_8_digits(value, buffer);
+            8
+        } else {
+            let high = value / 100000000;
+            let low = value - high * 100000000;
+            let offset = if high < 10 {
+                index_mut!(buffer[0] = b'0' + high as u8);
+                1
+            } else {
+                index_mut!(buffer[0] = b'0' + (high / 10) as u8);
+                index_mut!(buffer[1] = b'0' + (high % 10) as u8);
+                2
+            };
+            write_8_digits(low, &mut index_mut!(buffer[offset..]));
+            offset + 8
+        }
+    }}
+} else {
+    // Write the value with the scalar formatter.
+    perftools_inline!{
+    fn write_value(value: u32, buffer: &mut [u8]) -> usize {
+        write_scalar(value, buffer)
+    }}
+}}  // cfg_if
+
+// API
+
+/// Write a slice of 32-bit unsigned integers to bytes, delimited by a separator.
+///
+/// Returns the number of bytes required to write the values. If the
+/// buffer is large enough, the values are written and the number of
+/// bytes written is returned, otherwise, nothing is written, and the
+/// returned size is larger than the buffer. The separator is written
+/// between values, without a trailing separator.
+///
+/// On x86 and x86-64 targets with SSE2, multiple digits are written in
+/// parallel, and other targets fall back to the scalar formatter. The
+/// output is identical to writing each value with [`write`].
+///
+/// * `values`  - Values to write.
+/// * `sep`     - Separator between values.
+/// * `buf`     - Buffer to write the values to.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # pub fn main() {
+/// let mut buffer = [b'0'; 32];
+/// let count = lexical_core::format_u32_slice(&[1, 23, 4567890], b',', &mut buffer);
+/// assert_eq!(&buffer[..count], b"1,23,4567890");
+///
+/// let mut buffer = [b'0'; 4];
+/// assert_eq!(lexical_core::format_u32_slice(&[1, 23, 4567890], b',', &mut buffer), 12);
+/// # }
+/// ```
+///
+/// [`write`]: fn.write.html
+pub fn format_u32_slice(values: &[u32], sep: u8, buf: &mut [u8])
+    -> usize
+{
+    // Only calculate the exact size if the buffer may be too small.
+    if buf.len() < values.len() * u32::FORMATTED_SIZE_DECIMAL + values.len() {
+        let size = formatted_size(values);
+        if size > buf.len() {
+            return size;
+        }
+    }
+
+    let (first, rest) = match values.split_first() {
+        Some(split) => split,
+        None        => return 0,
+    };
+    let mut index = write_value(*first, buf);
+    for &value in rest {
+        index_mut!(buf[index] = sep);
+        index += 1;
+        index += write_value(value, &mut index_mut!(buf[index..]));
+    }
+    index
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::*;
+
+    // Write each value with `to_lexical`, delimited by a separator.
+    fn format_scalar(values: &[u32], sep: u8, buffer: &mut [u8]) -> usize {
+        let mut index = 0;
+        for (i, &value) in values.iter().enumerate() {
+            if i != 0 {
+                buffer[index] = sep;
+                index += 1;
+            }
+            let mut digits = [b'\0'; u32::FORMATTED_SIZE_DECIMAL];
+            let digits = value.to_lexical(&mut digits);
+            buffer[index..index+digits.len()].copy_from_slice(digits);
+            index += digits.len();
+        }
+        index
+    }
+
+    #[test]
+    fn digit_count_test() {
+        let mut power = 1u64;
+        for count in 1..=10 {
+            assert_eq!(digit_count(power as u32), count);
+            assert_eq!(digit_count((power * 10 - 1).min(u32::max_value() as u64) as u32), count);
+            power *= 10;
+        }
+        assert_eq!(digit_count(0), 1);
+    }
+
+    #[test]
+    fn format_u32_slice_test() {
+        let mut buffer = [b'\0'; 64];
+        assert_eq!(format_u32_slice(&[], b',', &mut buffer), 0);
+
+        let count = format_u32_slice(&[0], b',', &mut buffer);
+        assert_eq!(&buffer[..count], b"0");
+
+        let values = [0, 9, 10, 9999, 10000, 12345678, 99999999, 100000000, 4294967295];
+        let count = format_u32_slice(&values, b' ', &mut buffer);
+        assert_eq!(&buffer[..count], &b"0 9 10 9999 10000 12345678 99999999 100000000 4294967295"[..]);
+    }
+
+    #[test]
+    fn format_u32_slice_buffer_test() {
+        let values = [1, 23, 4567890];
+        let mut buffer = [b'\0'; 12];
+        assert_eq!(format_u32_slice(&values, b',', &mut buffer), 12);
+        assert_eq!(&buffer, b"1,23,4567890");
+
+        // The buffer is unchanged if it is too small.
+        let mut buffer = [b'\0'; 11];
+        assert_eq!(format_u32_slice(&values, b',', &mut buffer), 12);
+        assert_eq!(buffer, [b'\0'; 11]);
+    }
+
+    #[test]
+    fn format_u32_slice_scalar_test() {
+        // Compare powers of 10, their neighbors, and pseudo-random values.
+        let mut values = [0u32; 256];
+        let mut power = 1u32;
+        for (i, chunk) in values[..30].chunks_mut(3).enumerate() {
+            chunk[0] = power - 1;
+            chunk[1] = power;
+            chunk[2] = power.saturating_add(1);
+            if i < 9 {
+                power *= 10;
+            }
+        }
+        let mut seed = 3937647125u32;
+        for value in values[30..].iter_mut() {
+            seed = seed.wrapping_mul(1664525).wrapping_add(1013904223);
+            *value = seed >> (seed % 32);
+        }
+
+        let mut expected = [b'\0'; 256 * 11];
+        let mut actual = [b'\0'; 256 * 11];
+        let count = format_scalar(&values, b';', &mut expected);
+        assert_eq!(format_u32_slice(&values, b';', &mut actual), count);
+        assert_eq!(&actual[..count], &expected[..count]);
+    }
+
+    #[cfg(feature = "std")]
+    proptest! {
+        #[test]
+        fn format_u32_slice_proptest(values in proptest::collection::vec(proptest::num::u32::ANY, 0..16)) {
+            let mut expected = [b'\0'; 16 * 11];
+            let mut actual = [b'\0'; 16 * 11];
+            let count = format_scalar(&values, b',', &mut expected);
+            prop_assert_eq!(format_u32_slice(&values, b',', &mut actual), count);
+            prop_assert_eq!(&actual[..count], &expected[..count]);
+        }
+    }
+}
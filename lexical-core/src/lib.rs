@@ -56,6 +56,22 @@
 //! **To String**
 //! - [`write`]
 #![cfg_attr(feature = "radix", doc = " - [`write_radix`]")]
+//! - [`write_with_options`]
+#![cfg_attr(feature = "radix", doc = " - [`write_radix_with_options`]")]
+//! - [`write_fmt`]
+#![cfg_attr(feature = "radix", doc = " - [`write_fmt_radix`]")]
+#![cfg_attr(feature = "std", doc = " - [`writeln_to`]")]
+#![cfg_attr(all(feature = "std", feature = "radix"), doc = " - [`writeln_to_radix`]")]
+//! - [`write_wrapping`]
+#![cfg_attr(feature = "radix", doc = " - [`write_wrapping_radix`]")]
+//! - [`write_utf16le`]
+#![cfg_attr(feature = "optional", doc = " - [`write_optional`]")]
+#![cfg_attr(feature = "simd", doc = " - [`format_u32_slice`]")]
+//! - [`to_string_cached`]
+//! - [`quantize`]
+//! - [`format_qfixed`]
+//! - [`write_from_bits`]
+//! - [`write_duration_secs`]
 //!
 //! **From String**
 //! - [`parse`]
@@ -74,6 +90,51 @@
 #![cfg_attr(feature = "radix", doc = " - [`parse_partial_lossy_radix`]")]
 #![cfg_attr(feature = "format", doc = " - [`parse_partial_lossy_format`]")]
 #![cfg_attr(all(feature = "format", feature = "radix"), doc = " - [`parse_partial_lossy_format_radix`]")]
+//! - [`parse_with_options`]
+#![cfg_attr(feature = "radix", doc = " - [`parse_radix_with_options`]")]
+//! - [`parse_partial_with_options`]
+#![cfg_attr(feature = "radix", doc = " - [`parse_partial_radix_with_options`]")]
+//! - [`parse_int_or_float`]
+//! - [`parse_partial_int_or_float`]
+//! - [`parse_strict_number`]
+//! - [`parse_float_typed`]
+//! - [`parse_int_clamped`]
+//! - [`parse_partial_int_clamped`]
+//! - [`parse_saturating_float`]
+//! - [`parse_bounded`]
+//! - [`parse_partial_bounded`]
+//! - [`parse_nonzero`]
+//! - [`parse_hex`]
+//! - [`parse_hex_fixed`]
+//! - [`parse_hex_grouped`]
+#![cfg_attr(feature = "correct", doc = " - [`parse_bracket`]")]
+#![cfg_attr(feature = "correct", doc = " - [`parse_bracket_halfway`]")]
+#![cfg_attr(feature = "correct", doc = " - [`reround`]")]
+#![cfg_attr(feature = "optional", doc = " - [`parse_or_missing`]")]
+//! - [`parse_to_bits`]
+//! - [`parse_canonical`]
+//! - [`parse_fractional_nanos`]
+//! - [`parse_duration_secs`]
+//! - [`parse_with_magnitude`]
+//! - [`significant_digits`]
+//! - [`decompose_decimal`]
+//! - [`parse_spans`]
+//! - [`parse_quantity`]
+//! - [`parse_quantity_delimited`]
+//! - [`parse_colon_ratio`]
+//! - [`parse_rational`]
+//! - [`parse_each`]
+#![cfg_attr(feature = "radix", doc = " - [`parse_each_radix`]")]
+//! - [`parse_into_histogram`]
+//! - [`parse_fixed_width_column`]
+#![cfg_attr(feature = "radix", doc = " - [`parse_flexible`]")]
+#![cfg_attr(feature = "radix", doc = " - [`parse_flexible_with_options`]")]
+//! - [`parse_signed_parts`]
+#![cfg_attr(feature = "currency", doc = " - [`parse_currency`]")]
+#![cfg_attr(all(feature = "currency", feature = "format"), doc = " - [`parse_currency_format`]")]
+#![cfg_attr(feature = "find", doc = " - [`find_number`]")]
+#![cfg_attr(feature = "metrics", doc = " - [`parse_with_metrics`]")]
+#![cfg_attr(all(feature = "metrics", feature = "radix"), doc = " - [`parse_with_metrics_radix`]")]
 //!
 //! # Configuration Settings
 //!
@@ -84,6 +145,9 @@
 //! - [`get_nan_string`]
 //! - [`get_inf_string`]
 //! - [`get_infinity_string`]
+#![cfg_attr(feature = "std", doc = " - [`get_default_write_options`]")]
+//! - [`float_backend`]
+//! - [`parse_backend`]
 //!
 //! **Set Configuration**
 //! - [`set_exponent_default_char`]
@@ -92,9 +156,34 @@
 //! - [`set_nan_string`]
 //! - [`set_inf_string`]
 //! - [`set_infinity_string`]
+#![cfg_attr(feature = "std", doc = " - [`set_default_write_options`]")]
+//!
+//! The configuration is global, so set it before parsing or writing
+//! any numbers. The exponent characters and the special strings are
+//! frozen once any of them is read, so afterwards, their setters panic
+//! unless the value is unchanged, and the default write options may
+//! only be set once. The float rounding is read without synchronization,
+//! so its setter is `unsafe`: set it before any other thread parses
+//! floats.
 //!
 //! [`write`]: fn.write.html
 #![cfg_attr(feature = "radix", doc = " [`write_radix`]: fn.write_radix.html")]
+//! [`write_with_options`]: fn.write_with_options.html
+#![cfg_attr(feature = "radix", doc = " [`write_radix_with_options`]: fn.write_radix_with_options.html")]
+//! [`write_fmt`]: fn.write_fmt.html
+#![cfg_attr(feature = "radix", doc = " [`write_fmt_radix`]: fn.write_fmt_radix.html")]
+#![cfg_attr(feature = "std", doc = " [`writeln_to`]: fn.writeln_to.html")]
+#![cfg_attr(all(feature = "std", feature = "radix"), doc = " [`writeln_to_radix`]: fn.writeln_to_radix.html")]
+//! [`write_wrapping`]: fn.write_wrapping.html
+#![cfg_attr(feature = "radix", doc = " [`write_wrapping_radix`]: fn.write_wrapping_radix.html")]
+//! [`write_utf16le`]: fn.write_utf16le.html
+#![cfg_attr(feature = "optional", doc = " [`write_optional`]: fn.write_optional.html")]
+#![cfg_attr(feature = "simd", doc = " [`format_u32_slice`]: fn.format_u32_slice.html")]
+//! [`to_string_cached`]: fn.to_string_cached.html
+//! [`quantize`]: fn.quantize.html
+//! [`format_qfixed`]: fn.format_qfixed.html
+//! [`write_from_bits`]: fn.write_from_bits.html
+//! [`write_duration_secs`]: fn.write_duration_secs.html
 //! [`parse`]: fn.parse.html
 #![cfg_attr(feature = "radix", doc = " [`parse_radix`]: fn.parse_radix.html")]
 #![cfg_attr(feature = "format", doc = " [`parse_format`]: fn.parse_format.html")]
@@ -111,6 +200,51 @@
 #![cfg_attr(feature = "radix", doc = " [`parse_partial_lossy_radix`]: fn.parse_partial_lossy_radix.html")]
 #![cfg_attr(feature = "format", doc = " [`parse_partial_lossy_format`]: fn.parse_partial_lossy_format.html")]
 #![cfg_attr(all(feature = "format", feature = "radix"), doc = " [`parse_partial_lossy_format_radix`]: fn.parse_partial_lossy_format_radix.html")]
+//! [`parse_with_options`]: fn.parse_with_options.html
+#![cfg_attr(feature = "radix", doc = " [`parse_radix_with_options`]: fn.parse_radix_with_options.html")]
+//! [`parse_partial_with_options`]: fn.parse_partial_with_options.html
+#![cfg_attr(feature = "radix", doc = " [`parse_partial_radix_with_options`]: fn.parse_partial_radix_with_options.html")]
+//! [`parse_int_or_float`]: fn.parse_int_or_float.html
+//! [`parse_partial_int_or_float`]: fn.parse_partial_int_or_float.html
+//! [`parse_strict_number`]: fn.parse_strict_number.html
+//! [`parse_float_typed`]: fn.parse_float_typed.html
+//! [`parse_int_clamped`]: fn.parse_int_clamped.html
+//! [`parse_partial_int_clamped`]: fn.parse_partial_int_clamped.html
+//! [`parse_saturating_float`]: fn.parse_saturating_float.html
+//! [`parse_bounded`]: fn.parse_bounded.html
+//! [`parse_partial_bounded`]: fn.parse_partial_bounded.html
+//! [`parse_nonzero`]: fn.parse_nonzero.html
+//! [`parse_hex`]: fn.parse_hex.html
+//! [`parse_hex_fixed`]: fn.parse_hex_fixed.html
+//! [`parse_hex_grouped`]: fn.parse_hex_grouped.html
+#![cfg_attr(feature = "correct", doc = " [`parse_bracket`]: fn.parse_bracket.html")]
+#![cfg_attr(feature = "correct", doc = " [`parse_bracket_halfway`]: fn.parse_bracket_halfway.html")]
+#![cfg_attr(feature = "correct", doc = " [`reround`]: fn.reround.html")]
+#![cfg_attr(feature = "optional", doc = " [`parse_or_missing`]: fn.parse_or_missing.html")]
+//! [`parse_to_bits`]: fn.parse_to_bits.html
+//! [`parse_canonical`]: fn.parse_canonical.html
+//! [`parse_fractional_nanos`]: fn.parse_fractional_nanos.html
+//! [`parse_duration_secs`]: fn.parse_duration_secs.html
+//! [`parse_with_magnitude`]: fn.parse_with_magnitude.html
+//! [`significant_digits`]: fn.significant_digits.html
+//! [`decompose_decimal`]: fn.decompose_decimal.html
+//! [`parse_spans`]: fn.parse_spans.html
+//! [`parse_quantity`]: fn.parse_quantity.html
+//! [`parse_quantity_delimited`]: fn.parse_quantity_delimited.html
+//! [`parse_colon_ratio`]: fn.parse_colon_ratio.html
+//! [`parse_rational`]: fn.parse_rational.html
+//! [`parse_each`]: fn.parse_each.html
+#![cfg_attr(feature = "radix", doc = " [`parse_each_radix`]: fn.parse_each_radix.html")]
+//! [`parse_into_histogram`]: fn.parse_into_histogram.html
+//! [`parse_fixed_width_column`]: fn.parse_fixed_width_column.html
+#![cfg_attr(feature = "radix", doc = " [`parse_flexible`]: fn.parse_flexible.html")]
+#![cfg_attr(feature = "radix", doc = " [`parse_flexible_with_options`]: fn.parse_flexible_with_options.html")]
+//! [`parse_signed_parts`]: fn.parse_signed_parts.html
+#![cfg_attr(feature = "currency", doc = " [`parse_currency`]: fn.parse_currency.html")]
+#![cfg_attr(all(feature = "currency", feature = "format"), doc = " [`parse_currency_format`]: fn.parse_currency_format.html")]
+#![cfg_attr(feature = "find", doc = " [`find_number`]: fn.find_number.html")]
+#![cfg_attr(feature = "metrics", doc = " [`parse_with_metrics`]: fn.parse_with_metrics.html")]
+#![cfg_attr(all(feature = "metrics", feature = "radix"), doc = " [`parse_with_metrics_radix`]: fn.parse_with_metrics_radix.html")]
 //!
 //! [`get_exponent_default_char`]: fn.get_exponent_default_char.html
 #![cfg_attr(feature = "radix", doc = " [`get_exponent_backup_char`]: fn.get_exponent_backup_char.html")]
@@ -118,6 +252,9 @@
 //! [`get_nan_string`]: fn.get_nan_string.html
 //! [`get_inf_string`]: fn.get_inf_string.html
 //! [`get_infinity_string`]: fn.get_infinity_string.html
+#![cfg_attr(feature = "std", doc = " [`get_default_write_options`]: fn.get_default_write_options.html")]
+//! [`float_backend`]: fn.float_backend.html
+//! [`parse_backend`]: fn.parse_backend.html
 //!
 //! [`set_exponent_default_char`]: fn.set_exponent_default_char.html
 #![cfg_attr(feature = "radix", doc = " [`set_exponent_backup_char`]: fn.set_exponent_backup_char.html")]
@@ -125,6 +262,7 @@
 //! [`set_nan_string`]: fn.set_nan_string.html
 //! [`set_inf_string`]: fn.set_inf_string.html
 //! [`set_infinity_string`]: fn.set_infinity_string.html
+#![cfg_attr(feature = "std", doc = " [`set_default_write_options`]: fn.set_default_write_options.html")]
 
 // FEATURES
 
@@ -215,6 +353,28 @@ mod itoa;
 // Re-export configuration and utilities globally.
 pub use util::*;
 
+// Re-export the algorithms selected to parse and write floats.
+pub use atof::parse_backend;
+
+// Re-export the significant digit counter.
+pub use atof::significant_digits;
+pub use ftoa::float_backend;
+
+// Re-export the bracketing float parser.
+#[cfg(feature = "correct")]
+pub use atof::{parse_bracket, parse_bracket_halfway, reround};
+
+// Re-export the extended-precision float, without stability guarantees.
+#[cfg(feature = "internals")]
+pub use float::{ExtendedFloat, ExtendedFloat80, ExtendedFloat160, Mantissa};
+
+// Re-export the cached strings for small integers.
+pub use itoa::to_string_cached;
+
+// Re-export the bulk integer writers.
+#[cfg(feature = "simd")]
+pub use itoa::format_u32_slice;
+
 /// Write number to string.
 ///
 /// Returns a subslice of the input buffer containing the written bytes,
@@ -263,6 +423,303 @@ pub fn write_radix<'a, N: ToLexical>(n: N, radix: u8, bytes: &'a mut [u8])
     n.to_lexical_radix(radix, bytes)
 }
 
+/// Write number to string with custom options.
+///
+/// Returns a subslice of the input buffer containing the written bytes,
+/// starting from the same address in memory as the input slice.
+///
+/// * `value`   - Number to serialize.
+/// * `bytes`   - Slice containing a numeric string.
+/// * `options` - Options to customize the serialized number.
+///
+/// # Panics
+///
+/// Panics if the buffer may not be large enough to hold the serialized
+/// number. In order to ensure the function will not panic, provide a
+/// buffer with at least [`formatted_size_with_options`] elements.
+///
+/// [`formatted_size_with_options`]: trait.ToLexicalWithOptions.html#tymethod.formatted_size_with_options
+#[inline]
+pub fn write_with_options<'a, N: ToLexicalWithOptions>(n: N, bytes: &'a mut [u8], options: &N::Options)
+    -> &'a mut [u8]
+{
+    n.to_lexical_with_options(bytes, options)
+}
+
+/// Write number to string with a custom radix and custom options.
+///
+/// Returns a subslice of the input buffer containing the written bytes,
+/// starting from the same address in memory as the input slice.
+///
+/// * `value`   - Number to serialize.
+/// * `radix`   - Radix for number encoding.
+/// * `bytes`   - Slice containing a numeric string.
+/// * `options` - Options to customize the serialized number.
+///
+/// # Panics
+///
+/// Panics if the radix is not in the range `[2, 36]`.
+///
+/// Also panics if the buffer may not be large enough to hold the
+/// serialized number. In order to ensure the function will not panic,
+/// provide a buffer with at least [`formatted_size_with_options`] elements.
+///
+/// [`formatted_size_with_options`]: trait.ToLexicalWithOptions.html#tymethod.formatted_size_with_options
+#[inline]
+#[cfg(feature = "radix")]
+pub fn write_radix_with_options<'a, N: ToLexicalWithOptions>(n: N, radix: u8, bytes: &'a mut [u8], options: &N::Options)
+    -> &'a mut [u8]
+{
+    n.to_lexical_radix_with_options(radix, bytes, options)
+}
+
+/// Write number to a formatter sink.
+///
+/// The number is written to a buffer on the stack, and then written
+/// to the sink with a single call to `write_str`, so this does not
+/// require an allocator.
+///
+/// * `value`   - Number to serialize.
+/// * `writer`  - Sink implementing `core::fmt::Write`.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use core::fmt::Write;
+/// # pub fn main() {
+/// let mut string = String::new();
+/// lexical_core::write_fmt(1.5f64, &mut string).unwrap();
+/// string.push(' ');
+/// lexical_core::write_fmt(-15i32, &mut string).unwrap();
+/// assert_eq!(string, "1.5 -15");
+/// # }
+/// ```
+#[inline]
+pub fn write_fmt<N: ToLexical, W: lib::fmt::Write>(n: N, writer: &mut W)
+    -> lib::fmt::Result
+{
+    let mut buffer = [b'0'; BUFFER_SIZE];
+    let bytes = n.to_lexical(&mut buffer);
+    // Numbers are always written as ASCII, so they are valid UTF-8.
+    writer.write_str(unsafe { lib::str::from_utf8_unchecked(bytes) })
+}
+
+/// Write number to a formatter sink with a custom radix.
+///
+/// The number is written to a buffer on the stack, and then written
+/// to the sink with a single call to `write_str`, so this does not
+/// require an allocator.
+///
+/// * `value`   - Number to serialize.
+/// * `radix`   - Radix for number encoding.
+/// * `writer`  - Sink implementing `core::fmt::Write`.
+///
+/// # Panics
+///
+/// Panics if the radix is not in the range `[2, 36]`.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # pub fn main() {
+/// let mut string = String::new();
+/// lexical_core::write_fmt_radix(255u8, 16, &mut string).unwrap();
+/// assert_eq!(string, "FF");
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "radix")]
+pub fn write_fmt_radix<N: ToLexical, W: lib::fmt::Write>(n: N, radix: u8, writer: &mut W)
+    -> lib::fmt::Result
+{
+    let mut buffer = [b'0'; BUFFER_SIZE];
+    let bytes = n.to_lexical_radix(radix, &mut buffer);
+    // Numbers are always written as ASCII, so they are valid UTF-8.
+    writer.write_str(unsafe { lib::str::from_utf8_unchecked(bytes) })
+}
+
+/// Write number followed by a newline to an I/O sink.
+///
+/// The number and the newline are written to a buffer on the stack,
+/// and then written to the sink with a single call to `write_all`.
+/// Returns the number of bytes written, including the newline.
+///
+/// * `value`   - Number to serialize.
+/// * `writer`  - Sink implementing `std::io::Write`.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # pub fn main() {
+/// let mut bytes = Vec::new();
+/// assert_eq!(lexical_core::writeln_to(1.5f64, &mut bytes).unwrap(), 4);
+/// lexical_core::writeln_to(-15i32, &mut bytes).unwrap();
+/// assert_eq!(bytes, b"1.5\n-15\n");
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "std")]
+pub fn writeln_to<N: ToLexical, W: lib::io::Write>(n: N, writer: &mut W)
+    -> lib::io::Result<usize>
+{
+    let mut buffer = [b'0'; BUFFER_SIZE + 1];
+    let count = n.to_lexical(&mut buffer).len();
+    buffer[count] = b'\n';
+    writer.write_all(&buffer[..count+1])?;
+    Ok(count + 1)
+}
+
+/// Write number followed by a newline to an I/O sink with a custom radix.
+///
+/// The number and the newline are written to a buffer on the stack,
+/// and then written to the sink with a single call to `write_all`.
+/// Returns the number of bytes written, including the newline.
+///
+/// * `value`   - Number to serialize.
+/// * `radix`   - Radix for number encoding.
+/// * `writer`  - Sink implementing `std::io::Write`.
+///
+/// # Panics
+///
+/// Panics if the radix is not in the range `[2, 36]`.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # pub fn main() {
+/// let mut bytes = Vec::new();
+/// lexical_core::writeln_to_radix(255u8, 16, &mut bytes).unwrap();
+/// lexical_core::writeln_to_radix(0.5f64, 2, &mut bytes).unwrap();
+/// assert_eq!(bytes, b"FF\n0.1\n");
+/// # }
+/// ```
+#[inline]
+#[cfg(all(feature = "std", feature = "radix"))]
+pub fn writeln_to_radix<N: ToLexical, W: lib::io::Write>(n: N, radix: u8, writer: &mut W)
+    -> lib::io::Result<usize>
+{
+    let mut buffer = [b'0'; BUFFER_SIZE + 1];
+    let count = n.to_lexical_radix(radix, &mut buffer).len();
+    buffer[count] = b'\n';
+    writer.write_all(&buffer[..count+1])?;
+    Ok(count + 1)
+}
+
+/// Write number to a ring buffer, wrapping around to the start of the buffer.
+///
+/// The number is written starting at `start`, and any bytes past the
+/// end of the buffer are written to the start of the buffer. Returns
+/// the index after the last written byte, wrapped to the start of the
+/// buffer, which is the start of the next write.
+///
+/// * `value`   - Number to serialize.
+/// * `bytes`   - Ring buffer to write the number to.
+/// * `start`   - Index in the buffer to start writing the number.
+///
+/// # Panics
+///
+/// Panics if `start` is out of bounds, or if the number is longer than
+/// the buffer.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # pub fn main() {
+/// let mut ring = [b'.'; 8];
+/// assert_eq!(lexical_core::write_wrapping(-12345i32, &mut ring, 5), 3);
+/// assert_eq!(&ring, b"345..-12");
+/// # }
+/// ```
+#[inline]
+pub fn write_wrapping<N: ToLexical>(n: N, bytes: &mut [u8], start: usize)
+    -> usize
+{
+    let mut buffer = [b'0'; BUFFER_SIZE];
+    copy_wrapping(bytes, n.to_lexical(&mut buffer), start)
+}
+
+/// Write number to a ring buffer with a custom radix, wrapping around to the start of the buffer.
+///
+/// The number is written starting at `start`, and any bytes past the
+/// end of the buffer are written to the start of the buffer. Returns
+/// the index after the last written byte, wrapped to the start of the
+/// buffer, which is the start of the next write.
+///
+/// * `value`   - Number to serialize.
+/// * `radix`   - Radix for number encoding.
+/// * `bytes`   - Ring buffer to write the number to.
+/// * `start`   - Index in the buffer to start writing the number.
+///
+/// # Panics
+///
+/// Panics if the radix is not in the range `[2, 36]`, if `start` is
+/// out of bounds, or if the number is longer than the buffer.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # pub fn main() {
+/// let mut ring = [b'.'; 8];
+/// assert_eq!(lexical_core::write_wrapping_radix(0xDEADBEEFu32, 16, &mut ring, 6), 6);
+/// assert_eq!(&ring, b"ADBEEFDE");
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "radix")]
+pub fn write_wrapping_radix<N: ToLexical>(n: N, radix: u8, bytes: &mut [u8], start: usize)
+    -> usize
+{
+    let mut buffer = [b'0'; BUFFER_SIZE];
+    copy_wrapping(bytes, n.to_lexical_radix(radix, &mut buffer), start)
+}
+
+/// Write number to a UTF-16LE string.
+///
+/// The number is written as ASCII, and each byte is widened to a
+/// UTF-16 code unit, stored in little-endian byte order, like for the
+/// wide strings of Windows APIs. No byte-order mark is written. Returns
+/// a subslice of the input buffer containing the written code units,
+/// starting from the same address in memory as the input slice.
+///
+/// * `value`   - Number to serialize.
+/// * `words`   - Buffer to write the code units to.
+///
+/// # Panics
+///
+/// Panics if the buffer may not be large enough to hold the serialized
+/// number. In order to ensure the function will not panic, provide a
+/// buffer with at least [`FORMATTED_SIZE_DECIMAL`] elements.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # pub fn main() {
+/// let mut words = [0u16; lexical_core::BUFFER_SIZE];
+/// let wide = lexical_core::write_utf16le(-12i32, &mut words);
+/// assert!(wide.iter().map(|&w| u16::from_le(w)).eq("-12".encode_utf16()));
+///
+/// let wide = lexical_core::write_utf16le(1.5f64, &mut words);
+/// assert!(wide.iter().map(|&w| u16::from_le(w)).eq("1.5".encode_utf16()));
+/// # }
+/// ```
+///
+/// [`FORMATTED_SIZE_DECIMAL`]: trait.Number.html#associatedconstant.FORMATTED_SIZE_DECIMAL
+#[inline]
+pub fn write_utf16le<'a, N: ToLexical>(n: N, words: &'a mut [u16])
+    -> &'a mut [u16]
+{
+    let mut buffer = [b'0'; BUFFER_SIZE];
+    let count = copy_utf16le(words, n.to_lexical(&mut buffer));
+    &mut index_mut!(words[..count])
+}
+
 /// Parse number from string.
 ///
 /// This method parses the entire string, returning an error if
@@ -319,6 +776,78 @@ pub fn parse_partial_lossy<N: FromLexicalLossy>(bytes: &[u8])
     N::from_lexical_partial_lossy(bytes)
 }
 
+/// Parse number from string with custom options.
+///
+/// This method parses the entire string, returning an error if
+/// any invalid digits are found during parsing.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `options` - Options to customize the number parsing.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::ParseFloatOptions;
+/// # pub fn main() {
+/// let options = ParseFloatOptions::builder()
+///     .exponent_string(b"x")
+///     .build()
+///     .unwrap();
+/// assert_eq!(lexical_core::parse_with_options::<f64>(b"1.5x3", &options), Ok(1500.0));
+/// # }
+/// ```
+#[inline]
+pub fn parse_with_options<N: FromLexicalWithOptions>(bytes: &[u8], options: &N::Options)
+    -> Result<N>
+{
+    N::from_lexical_with_options(bytes, options)
+}
+
+/// Parse number from string with custom options.
+///
+/// This method parses until an invalid digit is found (or the end
+/// of the string), returning the number of processed digits
+/// and the parsed value until that point.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `options` - Options to customize the number parsing.
+#[inline]
+pub fn parse_partial_with_options<N: FromLexicalWithOptions>(bytes: &[u8], options: &N::Options)
+    -> Result<(N, usize)>
+{
+    N::from_lexical_partial_with_options(bytes, options)
+}
+
+/// Parse float from string, and collect metrics on how it was parsed.
+///
+/// This method parses the entire string, returning an error if
+/// any invalid digits are found during parsing. The metrics report
+/// the number of significant digits consumed, the parsed exponent,
+/// and if the value required the slower, arbitrary-precision paths.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # pub fn main() {
+/// let (value, metrics) = lexical_core::parse_with_metrics::<f64>(b"1.5e3").unwrap();
+/// assert_eq!(value, 1500.0);
+/// assert_eq!(metrics.digits_consumed, 2);
+/// assert_eq!(metrics.exponent, 3);
+/// assert!(!metrics.used_slow_path);
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "metrics")]
+pub fn parse_with_metrics<N: FromLexicalMetrics>(bytes: &[u8])
+    -> Result<(N, ParseMetrics)>
+{
+    N::from_lexical_metrics(bytes)
+}
+
 /// Parse number from string with a custom radix.
 ///
 /// This method parses the entire string, returning an error if
@@ -399,6 +928,68 @@ pub fn parse_partial_lossy_radix<N: FromLexicalLossy>(bytes: &[u8], radix: u8)
     N::from_lexical_partial_lossy_radix(bytes, radix)
 }
 
+/// Parse number from string with a custom radix and custom options.
+///
+/// This method parses the entire string, returning an error if
+/// any invalid digits are found during parsing.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `radix`   - Radix for number decoding.
+/// * `options` - Options to customize the number parsing.
+///
+/// # Panics
+///
+/// Panics if the radix is not in the range `[2, 36]`.
+#[inline]
+#[cfg(feature = "radix")]
+pub fn parse_radix_with_options<N: FromLexicalWithOptions>(bytes: &[u8], radix: u8, options: &N::Options)
+    -> Result<N>
+{
+    N::from_lexical_radix_with_options(bytes, radix, options)
+}
+
+/// Parse number from string with a custom radix and custom options.
+///
+/// This method parses until an invalid digit is found (or the end
+/// of the string), returning the number of processed digits
+/// and the parsed value until that point.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `radix`   - Radix for number decoding.
+/// * `options` - Options to customize the number parsing.
+///
+/// # Panics
+///
+/// Panics if the radix is not in the range `[2, 36]`.
+#[inline]
+#[cfg(feature = "radix")]
+pub fn parse_partial_radix_with_options<N: FromLexicalWithOptions>(bytes: &[u8], radix: u8, options: &N::Options)
+    -> Result<(N, usize)>
+{
+    N::from_lexical_partial_radix_with_options(bytes, radix, options)
+}
+
+/// Parse float from string with a custom radix, and collect metrics on how it was parsed.
+///
+/// This method parses the entire string, returning an error if
+/// any invalid digits are found during parsing. The metrics report
+/// the number of significant digits consumed, the parsed exponent,
+/// and if the value required the slower, arbitrary-precision paths.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `radix`   - Radix for number decoding.
+///
+/// # Panics
+///
+/// Panics if the radix is not in the range `[2, 36]`.
+#[inline]
+#[cfg(all(feature = "metrics", feature = "radix"))]
+pub fn parse_with_metrics_radix<N: FromLexicalMetrics>(bytes: &[u8], radix: u8)
+    -> Result<(N, ParseMetrics)>
+{
+    N::from_lexical_metrics_radix(bytes, radix)
+}
+
 /// Parse number from string with a custom numerical format.
 ///
 /// This method parses the entire string, returning an error if
@@ -148,8 +148,61 @@ pub fn copy_to_dst<'a, Bytes: AsRef<[u8]>>(dst: &'a mut [u8], src: Bytes)
     src.len()
 }
 
+/// Copy from source-to-dst, wrapping around to the start of dst.
+///
+/// Returns the index in dst after the last copied byte, wrapped to the
+/// start of dst. Panics if `start` is out of bounds, or if src is
+/// longer than dst.
+#[inline]
+pub fn copy_wrapping(dst: &mut [u8], src: &[u8], start: usize)
+    -> usize
+{
+    assert!(start < dst.len() && src.len() <= dst.len());
+    let head = src.len().min(dst.len() - start);
+    let (first, second) = src.split_at(head);
+    copy_to_dst(&mut index_mut!(dst[start..]), first);
+    copy_to_dst(dst, second);
+    (start + src.len()) % dst.len()
+}
+
+/// Replace the leading `-` of the written number with the minus sign,
+/// or write the plus sign before a number without a sign.
+///
+/// Returns the length of the number with the sign. Panics if the
+/// number with the sign is longer than dst.
+#[inline]
+pub fn write_sign(dst: &mut [u8], len: usize, minus_sign: &[u8], plus_sign: Option<&[u8]>)
+    -> usize
+{
+    let (start, sign) = match (index!(dst[..len]).first(), plus_sign) {
+        (Some(&b'-'), _)        => (1, minus_sign),
+        (_, Some(plus_sign))    => (0, plus_sign),
+        _                       => return len,
+    };
+    if start == 1 && sign == b"-" {
+        return len;
+    }
+    dst.copy_within(start..len, sign.len());
+    copy_to_dst(dst, sign);
+    len - start + sign.len()
+}
+
+/// Copy from source-to-dst, widening each byte to a little-endian UTF-16 code unit.
+///
+/// Returns the number of code units copied. Panics if src is longer
+/// than dst.
+#[inline]
+pub fn copy_utf16le(dst: &mut [u16], src: &[u8])
+    -> usize
+{
+    assert!(src.len() <= dst.len());
+    for (d, &s) in dst.iter_mut().zip(src.iter()) {
+        *d = (s as u16).to_le();
+    }
+    src.len()
+}
+
 /// Length-check variant of ptr::write_bytes for a slice.
-#[cfg(not(any(feature = "grisu3", feature = "ryu")))]
 #[inline]
 pub fn write_bytes(dst: &mut [u8], byte: u8)
 {
@@ -158,6 +211,24 @@ pub fn write_bytes(dst: &mut [u8], byte: u8)
     }
 }
 
+/// Trim any of the characters from the left-side of a slice.
+#[inline]
+pub fn ltrim_chars_slice<'a>(slc: &'a [u8], chars: &[u8])
+    -> (&'a [u8], usize)
+{
+    let count = slc.iter().take_while(|si| chars.contains(si)).count();
+    (&index!(slc[count..]), count)
+}
+
+/// Trim any of the characters from the right-side of a slice.
+#[inline]
+pub fn rtrim_chars_slice<'a>(slc: &'a [u8], chars: &[u8])
+    -> (&'a [u8], usize)
+{
+    let count = slc.iter().rev().take_while(|si| chars.contains(si)).count();
+    (&index!(slc[..slc.len() - count]), count)
+}
+
 // TEST
 // ----
 
@@ -165,6 +236,51 @@ pub fn write_bytes(dst: &mut [u8], byte: u8)
 mod tests {
     use super::*;
 
+    #[test]
+    fn copy_wrapping_test() {
+        let mut dst = [b'.'; 5];
+        assert_eq!(copy_wrapping(&mut dst, b"12", 1), 3);
+        assert_eq!(&dst, b".12..");
+        assert_eq!(copy_wrapping(&mut dst, b"345", 3), 1);
+        assert_eq!(&dst, b"51234");
+        assert_eq!(copy_wrapping(&mut dst, b"abcde", 0), 0);
+        assert_eq!(&dst, b"abcde");
+        assert_eq!(copy_wrapping(&mut dst, b"", 4), 4);
+    }
+
+    #[test]
+    fn write_sign_test() {
+        let mut dst = [b'.'; 8];
+        copy_to_dst(&mut dst, b"-1.5");
+        assert_eq!(write_sign(&mut dst, 4, b"-", None), 4);
+        assert_eq!(&dst[..4], b"-1.5");
+        assert_eq!(write_sign(&mut dst, 4, b"\xE2\x88\x92", Some(b"+")), 6);
+        assert_eq!(&dst[..6], b"\xE2\x88\x921.5");
+
+        copy_to_dst(&mut dst, b"1.5");
+        assert_eq!(write_sign(&mut dst, 3, b"\xE2\x88\x92", None), 3);
+        assert_eq!(&dst[..3], b"1.5");
+        assert_eq!(write_sign(&mut dst, 3, b"-", Some(b"+")), 4);
+        assert_eq!(&dst[..4], b"+1.5");
+    }
+
+    #[test]
+    fn copy_utf16le_test() {
+        let mut dst = [0u16; 5];
+        assert_eq!(copy_utf16le(&mut dst, b"-1.5"), 4);
+        assert_eq!(dst, [0x2D_u16.to_le(), 0x31_u16.to_le(), 0x2E_u16.to_le(), 0x35_u16.to_le(), 0]);
+        assert_eq!(copy_utf16le(&mut dst, b"NaN"), 3);
+        assert_eq!(&dst[..3], &[0x4E_u16.to_le(), 0x61_u16.to_le(), 0x4E_u16.to_le()]);
+        assert_eq!(copy_utf16le(&mut dst, b""), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn copy_utf16le_overflow_test() {
+        let mut dst = [0u16; 2];
+        copy_utf16le(&mut dst, b"123");
+    }
+
     #[test]
     fn distance_test() {
         unsafe {
@@ -324,4 +440,22 @@ mod tests {
         assert_eq!(rtrim_char2_slice(a.as_bytes(), b'0', b'_').1, 0);
         assert_eq!(rtrim_char2_slice(a.as_bytes(), b'1', b'_').1, 1);
     }
+
+    #[test]
+    fn ltrim_chars_test() {
+        assert_eq!(ltrim_chars_slice(b"[5]", b"[]"), (&b"5]"[..], 1));
+        assert_eq!(ltrim_chars_slice(b"[\"[5", b"[]\""), (&b"5"[..], 3));
+        assert_eq!(ltrim_chars_slice(b"5", b"[]"), (&b"5"[..], 0));
+        assert_eq!(ltrim_chars_slice(b"[5", b""), (&b"[5"[..], 0));
+        assert_eq!(ltrim_chars_slice(b"[]", b"[]"), (&b""[..], 2));
+    }
+
+    #[test]
+    fn rtrim_chars_test() {
+        assert_eq!(rtrim_chars_slice(b"[5]", b"[]"), (&b"[5"[..], 1));
+        assert_eq!(rtrim_chars_slice(b"5]\"]", b"[]\""), (&b"5"[..], 3));
+        assert_eq!(rtrim_chars_slice(b"5", b"[]"), (&b"5"[..], 0));
+        assert_eq!(rtrim_chars_slice(b"5]", b""), (&b"5]"[..], 0));
+        assert_eq!(rtrim_chars_slice(b"[]", b"[]"), (&b""[..], 2));
+    }
 }
@@ -0,0 +1,27 @@
+//! Algorithms selected at compile time to parse and write floats.
+
+/// Algorithm used to write decimal floats.
+///
+/// The algorithm is selected by the `grisu3` and `ryu` features,
+/// and `grisu3` takes precedence over `ryu`. Floats in non-decimal
+/// radixes are always written with the same, slow algorithm.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum FloatBackend {
+    /// Internal Grisu2 implementation, used without `grisu3` or `ryu`.
+    Grisu2,
+    /// Grisu3 implementation from the `dtoa` crate.
+    Grisu3,
+    /// Ryu implementation from the `ryu` crate.
+    Ryu,
+}
+
+/// Algorithm used to parse floats.
+///
+/// The algorithm is selected by the `correct` feature.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ParseBackend {
+    /// Correct parser, rounding to the nearest float.
+    Correct,
+    /// Fast parser, which may not round to the nearest float.
+    Lossy,
+}
@@ -0,0 +1,276 @@
+//! Parse batches of independent fields.
+
+use super::error::{Error, ErrorCode};
+use super::num::Integer;
+use super::result::Result;
+use super::traits::FromLexical;
+
+/// Parse each field independently, and pass each result to a callback.
+///
+/// Every field is parsed, even if a previous field failed to parse,
+/// so all invalid fields are reported. The callback receives the
+/// index of the field and the result of parsing it, and the index of
+/// any error is relative to the start of that field.
+///
+/// * `fields`      - Slice of byte slices containing numeric strings.
+/// * `callback`    - Callback for the index and result of each field.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::{Error, ErrorCode};
+/// # pub fn main() {
+/// let mut errors = 0;
+/// lexical_core::parse_each::<u8, _>(&[b"1", b"2a", b"300"], |index, result| {
+///     match index {
+///         0 => assert_eq!(result, Ok(1)),
+///         1 => assert_eq!(result, Err(Error::from((ErrorCode::InvalidDigit, 1, b'a')))),
+///         _ => assert_eq!(result, Err(Error::from((ErrorCode::Overflow, 2)))),
+///     }
+///     errors += result.is_err() as usize;
+/// });
+/// assert_eq!(errors, 2);
+/// # }
+/// ```
+pub fn parse_each<N, Callback>(fields: &[&[u8]], mut callback: Callback)
+    where N: FromLexical,
+          Callback: FnMut(usize, Result<N>)
+{
+    for (index, field) in fields.iter().enumerate() {
+        callback(index, N::from_lexical(field));
+    }
+}
+
+/// Parse each field independently with a custom radix, and pass each result to a callback.
+///
+/// Every field is parsed, even if a previous field failed to parse,
+/// so all invalid fields are reported. The callback receives the
+/// index of the field and the result of parsing it, and the index of
+/// any error is relative to the start of that field.
+///
+/// * `fields`      - Slice of byte slices containing numeric strings.
+/// * `radix`       - Radix for the number parsing.
+/// * `callback`    - Callback for the index and result of each field.
+///
+/// # Panics
+///
+/// Panics if the radix is not in the range `[2, 36]`.
+#[cfg(feature = "radix")]
+pub fn parse_each_radix<N, Callback>(fields: &[&[u8]], radix: u8, mut callback: Callback)
+    where N: FromLexical,
+          Callback: FnMut(usize, Result<N>)
+{
+    for (index, field) in fields.iter().enumerate() {
+        callback(index, N::from_lexical_radix(field, radix));
+    }
+}
+
+/// Parse a column of adjacent, fixed-width integers without delimiters.
+///
+/// Each value is parsed from the next `width` bytes, filling every
+/// element of `values`. Any bytes after the last field are ignored.
+/// Parsing stops at the first invalid field, and the index of the
+/// error is relative to the start of the bytes, so the byte offset of
+/// the invalid field is the index rounded down to a multiple of the
+/// width. If the bytes end before the last field, the error is
+/// [`Empty`](enum.ErrorCode.html#variant.Empty) at the end of the bytes.
+///
+/// * `bytes`   - Byte slice containing adjacent numeric strings.
+/// * `width`   - Number of bytes in each field.
+/// * `values`  - Slice to fill with the parsed values.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::{Error, ErrorCode};
+/// # pub fn main() {
+/// let mut years = [0u16; 3];
+/// assert_eq!(lexical_core::parse_fixed_width_column(b"199920202021", 4, &mut years), Ok(()));
+/// assert_eq!(years, [1999, 2020, 2021]);
+///
+/// let result = lexical_core::parse_fixed_width_column(b"19992O202021", 4, &mut years);
+/// assert_eq!(result, Err(Error::from((ErrorCode::InvalidDigit, 5, b'O'))));
+/// # }
+/// ```
+pub fn parse_fixed_width_column<N>(bytes: &[u8], width: usize, values: &mut [N])
+    -> Result<()>
+    where N: FromLexical + Integer
+{
+    let length = width.checked_mul(values.len());
+    if length.map_or(true, |length| length > bytes.len()) {
+        return Err(Error::from((ErrorCode::Empty, bytes.len())));
+    }
+
+    // The length was checked once, so each field is in bounds.
+    for (index, value) in values.iter_mut().enumerate() {
+        let start = index * width;
+        let field = &index!(bytes[start..start + width]);
+        *value = match N::from_lexical(field) {
+            Ok(value)   => value,
+            Err(error)  => return Err(error.shift(start)),
+        };
+    }
+    Ok(())
+}
+
+// Find the bin for a value, as the number of edges less than or equal to it.
+#[inline]
+fn find_bin(value: f64, bins: &[f64]) -> usize {
+    let mut lo = 0;
+    let mut hi = bins.len();
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if bins[mid] <= value {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+    lo
+}
+
+/// Parse each delimited float, and count it in the bin containing it.
+///
+/// The bins are the edges between bins, in ascending order, and there
+/// must be one more count than bins. Values below the first edge are
+/// counted in the first count, values in `[bins[i-1], bins[i])` in
+/// count `i`, and values at or above the last edge, including positive
+/// infinity, in the last count. `NaN` values are not counted. Returns
+/// the number of counted values.
+///
+/// Parsing stops at the first invalid field, and the index of the
+/// error is relative to the start of the bytes. Values before the
+/// invalid field remain counted. Empty bytes contain no fields.
+///
+/// * `bytes`       - Byte slice containing delimited numeric strings.
+/// * `delimiter`   - Byte separating each field.
+/// * `bins`        - Ascending edges between the bins.
+/// * `counts`      - Counts for each bin, incremented for each value.
+///
+/// # Panics
+///
+/// Panics if `counts` does not have exactly one more element than `bins`.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # pub fn main() {
+/// let mut counts = [0u64; 4];
+/// let result = lexical_core::parse_into_histogram(b"-1,0.5,1,2.5,7", b',', &[0.0, 1.0, 5.0], &mut counts);
+/// assert_eq!(result, Ok(5));
+/// assert_eq!(counts, [1, 1, 2, 1]);
+/// # }
+/// ```
+pub fn parse_into_histogram(bytes: &[u8], delimiter: u8, bins: &[f64], counts: &mut [u64])
+    -> Result<usize>
+{
+    assert!(counts.len() == bins.len() + 1, "counts must have one more element than bins.");
+    if bytes.is_empty() {
+        return Ok(0);
+    }
+
+    let mut count = 0;
+    let mut start = 0;
+    for field in bytes.split(|&c| c == delimiter) {
+        let value = match f64::from_lexical(field) {
+            Ok(value)   => value,
+            Err(error)  => return Err(error.shift(start)),
+        };
+        if !value.is_nan() {
+            counts[find_bin(value, bins)] += 1;
+            count += 1;
+        }
+        start += field.len() + 1;
+    }
+    Ok(count)
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::*;
+
+    #[test]
+    fn parse_each_test() {
+        let fields: [&[u8]; 4] = [b"1.5", b"", b"2.5e", b"-3"];
+        let mut results = [Ok(0.0); 4];
+        parse_each::<f64, _>(&fields, |index, result| results[index] = result);
+        assert_eq!(results, [
+            Ok(1.5),
+            Err(Error::from((ErrorCode::Empty, 0))),
+            Err(Error::from((ErrorCode::EmptyExponent, 4))),
+            Ok(-3.0),
+        ]);
+
+        let mut count = 0;
+        parse_each::<u8, _>(&[], |_, _| count += 1);
+        assert_eq!(count, 0);
+    }
+
+    #[test]
+    fn parse_into_histogram_test() {
+        let bins = [0.0, 1.0, 5.0];
+        let mut counts = [0u64; 4];
+        assert_eq!(parse_into_histogram(b"-1,0,0.5,1,4.9,5,1e300,-inf,inf,NaN", b',', &bins, &mut counts), Ok(9));
+        assert_eq!(counts, [2, 2, 2, 3]);
+
+        let mut counts = [0u64; 4];
+        assert_eq!(parse_into_histogram(b"", b',', &bins, &mut counts), Ok(0));
+        assert_eq!(parse_into_histogram(b"2 3", b' ', &bins, &mut counts), Ok(2));
+        assert_eq!(counts, [0, 0, 2, 0]);
+
+        let mut counts = [0u64; 4];
+        assert_eq!(parse_into_histogram(b"2,3a,4", b',', &bins, &mut counts), Err(Error::from((ErrorCode::InvalidDigit, 3, b'a'))));
+        assert_eq!(counts, [0, 0, 1, 0]);
+        assert_eq!(parse_into_histogram(b"2,3a,4", b',', &bins, &mut counts).unwrap_err().failing_byte(), Some(b'a'));
+        assert_eq!(parse_into_histogram(b"2,", b',', &bins, &mut counts), Err(Error::from((ErrorCode::Empty, 2))));
+
+        let mut counts = [0u64; 1];
+        assert_eq!(parse_into_histogram(b"1;2", b';', &[], &mut counts), Ok(2));
+        assert_eq!(counts, [2]);
+    }
+
+    #[test]
+    fn parse_fixed_width_column_test() {
+        let mut values = [0u16; 3];
+        assert_eq!(parse_fixed_width_column(b"199920202021", 4, &mut values), Ok(()));
+        assert_eq!(values, [1999, 2020, 2021]);
+        assert_eq!(parse_fixed_width_column(b"0001+002-003xx", 4, &mut values[..2]), Ok(()));
+        assert_eq!(values, [1, 2, 2021]);
+        assert_eq!(parse_fixed_width_column(b"1999202a2021", 4, &mut values), Err(Error::from((ErrorCode::InvalidDigit, 7, b'a'))));
+        assert_eq!(parse_fixed_width_column(b"1999 2022021", 4, &mut values), Err(Error::from((ErrorCode::InvalidDigit, 4, b' '))));
+        assert_eq!(parse_fixed_width_column(b"19992020202", 4, &mut values), Err(Error::from((ErrorCode::Empty, 11))));
+        assert_eq!(parse_fixed_width_column(b"", 0, &mut values), Err(Error::from((ErrorCode::Empty, 0))));
+        assert_eq!(parse_fixed_width_column::<u8>(b"", 4, &mut []), Ok(()));
+
+        let mut values = [0u8; 2];
+        assert_eq!(parse_fixed_width_column(b"255256", 3, &mut values), Err(Error::from((ErrorCode::Overflow, 5))));
+        assert_eq!(values, [255, 0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn parse_into_histogram_counts_test() {
+        let mut counts = [0u64; 3];
+        let _ = parse_into_histogram(b"1", b',', &[0.0, 1.0, 5.0], &mut counts);
+    }
+
+    #[cfg(feature = "radix")]
+    #[test]
+    fn parse_each_radix_test() {
+        let fields: [&[u8]; 3] = [b"ff", b"fg", b"100"];
+        let mut results = [Ok(0); 3];
+        parse_each_radix::<u8, _>(&fields, 16, |index, result| results[index] = result);
+        assert_eq!(results, [
+            Ok(255),
+            Err(Error::from((ErrorCode::InvalidDigit, 1, b'g'))),
+            Err(Error::from((ErrorCode::Overflow, 2))),
+        ]);
+    }
+}
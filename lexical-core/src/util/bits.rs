@@ -0,0 +1,115 @@
+//! Parse and write floats as their exact bit patterns.
+
+use super::result::Result;
+use super::traits::{FromLexical, ToLexical};
+
+/// Parse a decimal string to the bit pattern of the nearest `f64`.
+///
+/// The bytes are parsed the same as [`parse`], and the bits of the
+/// float are returned, so the bits round-trip with [`write_from_bits`]
+/// for all finite floats and infinities, including `-0.0`. `NaN` is
+/// parsed to the canonical quiet `NaN`, `0x7FF8000000000000`, and any
+/// sign or payload of a written `NaN` is lost, since all `NaN` bit
+/// patterns are written as the same string.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # pub fn main() {
+/// assert_eq!(lexical_core::parse_to_bits(b"1.5"), Ok(0x3FF8000000000000));
+/// assert_eq!(lexical_core::parse_to_bits(b"-0.0"), Ok(0x8000000000000000));
+/// assert_eq!(lexical_core::parse_to_bits(b"NaN"), Ok(0x7FF8000000000000));
+/// # }
+/// ```
+///
+/// [`parse`]: fn.parse.html
+/// [`write_from_bits`]: fn.write_from_bits.html
+#[inline]
+pub fn parse_to_bits(bytes: &[u8]) -> Result<u64> {
+    f64::from_lexical(bytes).map(f64::to_bits)
+}
+
+/// Write the `f64` with the bit pattern to a decimal string.
+///
+/// The float is written the same as [`write`], with the shortest
+/// digits that parse to the same float, so the string round-trips
+/// with [`parse_to_bits`] for all finite floats and infinities. With
+/// the `trim_floats` feature, `-0.0` is written as `0`, and parses to
+/// the bits of `0.0`. All `NaN` bit patterns are written as the `NaN`
+/// string, without the sign or payload. Returns a subslice of the
+/// input buffer containing the written bytes, starting from the same
+/// address in memory as the input slice.
+///
+/// * `bits`    - Bit pattern of the float to serialize.
+/// * `bytes`   - Slice containing a numeric string.
+///
+/// # Panics
+///
+/// Panics if the buffer may not be large enough to hold the serialized
+/// number. In order to ensure the function will not panic, provide a
+/// buffer with at least [`FORMATTED_SIZE_DECIMAL`] elements.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # pub fn main() {
+/// let mut buffer = [b'0'; lexical_core::BUFFER_SIZE];
+/// assert_eq!(lexical_core::write_from_bits(0x3FF8000000000000, &mut buffer), b"1.5");
+/// assert_eq!(lexical_core::write_from_bits(0x7FF0000000000000, &mut buffer), b"inf");
+/// # }
+/// ```
+///
+/// [`write`]: fn.write.html
+/// [`parse_to_bits`]: fn.parse_to_bits.html
+/// [`FORMATTED_SIZE_DECIMAL`]: trait.Number.html#associatedconstant.FORMATTED_SIZE_DECIMAL
+#[inline]
+pub fn write_from_bits<'a>(bits: u64, bytes: &'a mut [u8]) -> &'a mut [u8] {
+    f64::from_bits(bits).to_lexical(bytes)
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use crate::util::*;
+    use crate::util::test::*;
+    use super::*;
+
+    #[test]
+    fn parse_to_bits_test() {
+        assert_eq!(parse_to_bits(b"0"), Ok(0));
+        assert_eq!(parse_to_bits(b"1"), Ok(0x3FF0000000000000));
+        assert_eq!(parse_to_bits(b"-2.5"), Ok(0xC004000000000000));
+        assert_eq!(parse_to_bits(b"5e-324"), Ok(1));
+        assert_eq!(parse_to_bits(b"inf"), Ok(0x7FF0000000000000));
+        assert_eq!(parse_to_bits(b"-inf"), Ok(0xFFF0000000000000));
+        assert_eq!(parse_to_bits(b"NaN"), Ok(f64::NAN.to_bits()));
+        assert_eq!(parse_to_bits(b"1.5a"), Err(Error::from((ErrorCode::InvalidDigit, 3, b'a'))));
+    }
+
+    #[test]
+    fn write_from_bits_test() {
+        let mut buffer = new_buffer();
+        assert_eq!(as_slice(b"inf"), write_from_bits(0x7FF0000000000000, &mut buffer));
+        assert_eq!(as_slice(b"-inf"), write_from_bits(0xFFF0000000000000, &mut buffer));
+        assert_eq!(as_slice(b"NaN"), write_from_bits(0x7FF8000000000001, &mut buffer));
+        #[cfg(not(feature = "trim_floats"))]
+        assert_eq!(as_slice(b"-0.0"), write_from_bits(0x8000000000000000, &mut buffer));
+    }
+
+    #[cfg(feature = "correct")]
+    #[test]
+    fn bits_roundtrip_test() {
+        let mut buffer = new_buffer();
+        let bits = [0, 1, 2, 0x000FFFFFFFFFFFFF, 0x0010000000000000, 0x3FB999999999999A, 0x3FF0000000000001, 0x7FEFFFFFFFFFFFFF, 0x7FF0000000000000, 0xBFF0000000000000];
+        for &bits in bits.iter() {
+            let bytes = write_from_bits(bits, &mut buffer);
+            assert_eq!(parse_to_bits(bytes), Ok(bits));
+        }
+    }
+}
@@ -0,0 +1,136 @@
+//! Parse numbers, and validate they are within inclusive bounds.
+
+use super::error::{Error, ErrorCode};
+use super::result::Result;
+use super::traits::FromLexical;
+
+// HELPERS
+
+// Check the value is within the inclusive bounds.
+//
+// Values that cannot be compared to a bound, like `NaN`, are outside it.
+#[inline]
+fn is_in_bounds<N: PartialOrd>(value: &N, min: Option<N>, max: Option<N>) -> bool {
+    let above_min = min.map_or(true, |min| *value >= min);
+    let below_max = max.map_or(true, |max| *value <= max);
+    above_min && below_max
+}
+
+// API
+
+/// Parse a number, and validate it is within the inclusive bounds.
+///
+/// The bytes are parsed the same as [`parse`], so values that overflow
+/// the type are still reported as
+/// [`Overflow`](enum.ErrorCode.html#variant.Overflow). Valid values
+/// below `min` or above `max` are reported as
+/// [`OutOfRange`](enum.ErrorCode.html#variant.OutOfRange) at the start
+/// of the bytes. A bound of `None` is unbounded, and if any bound is
+/// set, `NaN` is out of range.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `min`     - Inclusive lower bound, if any.
+/// * `max`     - Inclusive upper bound, if any.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::{Error, ErrorCode};
+/// # pub fn main() {
+/// assert_eq!(lexical_core::parse_bounded::<i32>(b"42", Some(0), Some(100)), Ok(42));
+/// assert_eq!(lexical_core::parse_bounded::<i32>(b"-1", Some(0), None), Err(Error::from((ErrorCode::OutOfRange, 0))));
+/// assert_eq!(lexical_core::parse_bounded::<u8>(b"256", None, Some(200)), Err(Error::from((ErrorCode::Overflow, 2))));
+/// # }
+/// ```
+///
+/// [`parse`]: fn.parse.html
+pub fn parse_bounded<N: FromLexical + PartialOrd>(bytes: &[u8], min: Option<N>, max: Option<N>)
+    -> Result<N>
+{
+    let value = N::from_lexical(bytes)?;
+    match is_in_bounds(&value, min, max) {
+        true  => Ok(value),
+        false => Err(Error::from((ErrorCode::OutOfRange, 0))),
+    }
+}
+
+/// Parse a number until an invalid digit is found, and validate it is within the inclusive bounds.
+///
+/// The bytes are parsed the same as [`parse_partial`], and returns the
+/// value and the number of processed bytes. Valid values below `min`
+/// or above `max` are reported as
+/// [`OutOfRange`](enum.ErrorCode.html#variant.OutOfRange) at the start
+/// of the bytes.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `min`     - Inclusive lower bound, if any.
+/// * `max`     - Inclusive upper bound, if any.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::{Error, ErrorCode};
+/// # pub fn main() {
+/// assert_eq!(lexical_core::parse_partial_bounded::<f64>(b"0.5,", Some(0.0), Some(1.0)), Ok((0.5, 3)));
+/// assert_eq!(lexical_core::parse_partial_bounded::<f64>(b"1.5,", Some(0.0), Some(1.0)), Err(Error::from((ErrorCode::OutOfRange, 0))));
+/// # }
+/// ```
+///
+/// [`parse_partial`]: fn.parse_partial.html
+pub fn parse_partial_bounded<N: FromLexical + PartialOrd>(bytes: &[u8], min: Option<N>, max: Option<N>)
+    -> Result<(N, usize)>
+{
+    let (value, count) = N::from_lexical_partial(bytes)?;
+    match is_in_bounds(&value, min, max) {
+        true  => Ok((value, count)),
+        false => Err(Error::from((ErrorCode::OutOfRange, 0))),
+    }
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_bounded_test() {
+        let out_of_range = Err(Error::from((ErrorCode::OutOfRange, 0)));
+        assert_eq!(parse_bounded::<i32>(b"0", Some(0), Some(100)), Ok(0));
+        assert_eq!(parse_bounded::<i32>(b"100", Some(0), Some(100)), Ok(100));
+        assert_eq!(parse_bounded::<i32>(b"101", Some(0), Some(100)), out_of_range);
+        assert_eq!(parse_bounded::<i32>(b"-1", Some(0), Some(100)), out_of_range);
+        assert_eq!(parse_bounded::<i32>(b"-1", None, Some(100)), Ok(-1));
+        assert_eq!(parse_bounded::<i32>(b"1000", Some(0), None), Ok(1000));
+        assert_eq!(parse_bounded::<i32>(b"5", None, None), Ok(5));
+        assert_eq!(parse_bounded::<i32>(b"5", Some(10), Some(0)), out_of_range);
+
+        // Parse errors are reported before the bounds.
+        assert_eq!(parse_bounded::<u8>(b"256", Some(0), Some(10)), Err(Error::from((ErrorCode::Overflow, 2))));
+        assert_eq!(parse_bounded::<i8>(b"-129", Some(0), Some(10)), Err(Error::from((ErrorCode::Underflow, 3))));
+        assert_eq!(parse_bounded::<u8>(b"1a", Some(0), Some(10)), Err(Error::from((ErrorCode::InvalidDigit, 1, b'a'))));
+        assert_eq!(parse_bounded::<u8>(b"", Some(0), Some(10)), Err(Error::from((ErrorCode::Empty, 0))));
+    }
+
+    #[test]
+    fn parse_bounded_float_test() {
+        let out_of_range = Err(Error::from((ErrorCode::OutOfRange, 0)));
+        assert_eq!(parse_bounded::<f64>(b"0.5", Some(0.0), Some(1.0)), Ok(0.5));
+        assert_eq!(parse_bounded::<f64>(b"-0.0", Some(0.0), Some(1.0)), Ok(-0.0));
+        assert_eq!(parse_bounded::<f64>(b"1e-300", None, Some(0.0)), out_of_range);
+        assert_eq!(parse_bounded::<f64>(b"inf", Some(0.0), None), Ok(f64::INFINITY));
+        assert_eq!(parse_bounded::<f64>(b"NaN", Some(0.0), None), out_of_range);
+        assert!(parse_bounded::<f64>(b"NaN", None, None).unwrap().is_nan());
+    }
+
+    #[test]
+    fn parse_partial_bounded_test() {
+        let out_of_range = Err(Error::from((ErrorCode::OutOfRange, 0)));
+        assert_eq!(parse_partial_bounded::<u16>(b"1999,", Some(1900), Some(2100)), Ok((1999, 4)));
+        assert_eq!(parse_partial_bounded::<u16>(b"1899,", Some(1900), Some(2100)), out_of_range);
+        assert_eq!(parse_partial_bounded::<u16>(b"a", Some(1900), Some(2100)), Err(Error::from((ErrorCode::InvalidDigit, 0, b'a'))));
+    }
+}
@@ -0,0 +1,94 @@
+//! Parse floats and check if the string is in the canonical form.
+
+use super::num::Number;
+use super::result::Result;
+use super::traits::{FromLexical, ToLexical};
+
+// API
+
+/// Parse a decimal string to an `f64`, and check if it is canonical.
+///
+/// The bytes are parsed the same as [`parse`], and returns the float,
+/// and if the bytes are exactly the canonical form of the float. The
+/// canonical form is the string [`write`] writes for the float, so
+/// it follows the same rules:
+///
+/// - The shortest digits that parse to the same float.
+/// - No leading `+` sign, or redundant leading or trailing zeros.
+/// - A `-` sign for negative floats, including `-0.0`.
+/// - Fixed or scientific notation, as chosen by [`write`], with the
+///   [default exponent character](fn.get_exponent_default_char.html),
+///   and no exponent sign or leading zeros for positive exponents.
+/// - A trailing `.0` for integral floats, unless the `trim_floats`
+///   feature is enabled.
+/// - The configured strings for `NaN` and infinity, with the same case.
+///
+/// For example, `"1.5"` and `"1e16"` are canonical, while `"1.50"`,
+/// `"01.5"`, `"+1.5"`, `"1.5E0"`, and `"100"` are not. The canonical
+/// form is written to a buffer on the stack, without allocating.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # pub fn main() {
+/// assert_eq!(lexical_core::parse_canonical(b"1.5"), Ok((1.5, true)));
+/// assert_eq!(lexical_core::parse_canonical(b"1.50"), Ok((1.5, false)));
+/// assert_eq!(lexical_core::parse_canonical(b"15E-1"), Ok((1.5, false)));
+/// assert!(lexical_core::parse_canonical(b"1.5x").is_err());
+/// # }
+/// ```
+///
+/// [`parse`]: fn.parse.html
+/// [`write`]: fn.write.html
+pub fn parse_canonical(bytes: &[u8]) -> Result<(f64, bool)> {
+    let value = f64::from_lexical(bytes)?;
+    let mut buffer = [b'\0'; f64::FORMATTED_SIZE_DECIMAL];
+    let canonical = value.to_lexical(&mut buffer);
+    Ok((value, canonical == bytes))
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::*;
+
+    #[test]
+    fn parse_canonical_test() {
+        // Canonical strings.
+        assert_eq!(parse_canonical(b"-2.5"), Ok((-2.5, true)));
+        assert_eq!(parse_canonical(b"0.1"), Ok((0.1, true)));
+        assert_eq!(parse_canonical(b"1.5e-7"), Ok((1.5e-7, true)));
+        assert_eq!(parse_canonical(b"inf"), Ok((f64::INFINITY, true)));
+        #[cfg(feature = "ryu")]
+        assert_eq!(parse_canonical(b"1e16"), Ok((1e16, true)));
+        #[cfg(not(feature = "trim_floats"))] {
+            assert_eq!(parse_canonical(b"0.0"), Ok((0.0, true)));
+            assert_eq!(parse_canonical(b"-0.0").map(|(v, c)| (v.to_bits(), c)), Ok((0x8000000000000000, true)));
+            assert_eq!(parse_canonical(b"100.0"), Ok((100.0, true)));
+        }
+
+        // Non-canonical strings.
+        assert_eq!(parse_canonical(b"01.5"), Ok((1.5, false)));
+        assert_eq!(parse_canonical(b"+1.5"), Ok((1.5, false)));
+        assert_eq!(parse_canonical(b"1.50"), Ok((1.5, false)));
+        assert_eq!(parse_canonical(b".5"), Ok((0.5, false)));
+        assert_eq!(parse_canonical(b"1.5E0"), Ok((1.5, false)));
+        assert_eq!(parse_canonical(b"15e-1"), Ok((1.5, false)));
+        assert_eq!(parse_canonical(b"1e5"), Ok((1e5, false)));
+        #[cfg(feature = "correct")]
+        assert_eq!(parse_canonical(b"0.10000000000000001"), Ok((0.1, false)));
+        assert_eq!(parse_canonical(b"Infinity"), Ok((f64::INFINITY, false)));
+        #[cfg(not(feature = "trim_floats"))]
+        assert_eq!(parse_canonical(b"100"), Ok((100.0, false)));
+
+        // Errors.
+        assert_eq!(parse_canonical(b""), Err(Error::from((ErrorCode::Empty, 0))));
+        assert_eq!(parse_canonical(b"1.5x"), Err(Error::from((ErrorCode::InvalidDigit, 3, b'x'))));
+    }
+}
@@ -0,0 +1,239 @@
+//! Parse decimal strings into integers and floats, with rounding and saturation.
+
+use super::cast::as_cast;
+use super::error::{Error, ErrorCode};
+use super::num::{Float, Integer};
+use super::primitive::AsPrimitive;
+use super::result::Result;
+use super::rounding::RoundingMode;
+use super::traits::FromLexical;
+
+// HELPERS
+
+// Smallest float magnitude without any fractional digits, 2^52.
+const F64_INTEGRAL: f64 = 4503599627370496.0;
+
+// Round the float to an integral value with the rounding scheme.
+#[inline]
+pub(crate) fn round_integral(value: f64, kind: RoundingMode) -> f64 {
+    // Large and special floats do not have any fractional digits.
+    if !(value.abs() < F64_INTEGRAL) {
+        return value;
+    }
+
+    // Truncation and the fractional digits are exact for small floats.
+    let truncated = value.as_i64();
+    let fraction = (value - truncated.as_f64()).abs();
+    let is_odd = truncated % 2 != 0;
+    let away_from_zero = match kind {
+        RoundingMode::NearestTieEven            => fraction > 0.5 || (fraction == 0.5 && is_odd),
+        RoundingMode::NearestTieAwayZero        => fraction >= 0.5,
+        RoundingMode::TowardPositiveInfinity    => fraction != 0.0 && !value.is_sign_negative(),
+        RoundingMode::TowardNegativeInfinity    => fraction != 0.0 && value.is_sign_negative(),
+        RoundingMode::TowardZero                => false,
+    };
+
+    match (away_from_zero, value.is_sign_negative()) {
+        (false, _)      => truncated.as_f64(),
+        (true, false)   => truncated.as_f64() + 1.0,
+        (true, true)    => truncated.as_f64() - 1.0,
+    }
+}
+
+// Round the float, and then saturate it into the range of the integer.
+#[inline]
+fn clamp<N: Integer>(value: f64, kind: RoundingMode) -> N {
+    let value = round_integral(value, kind);
+    if value >= N::MAX.as_f64() {
+        N::MAX
+    } else if value <= N::MIN.as_f64() {
+        N::MIN
+    } else {
+        as_cast(value)
+    }
+}
+
+// API
+
+/// Parse a decimal number, and round and clamp it into an integer.
+///
+/// The bytes are parsed as an `f64`, which is rounded to an integral
+/// value with the rounding scheme, and then saturated to the range
+/// of the integer. Infinities are saturated to the nearest bound,
+/// while `NaN` is an error. Since the bytes are parsed as an `f64`,
+/// integers above `2^53` may not be exact.
+///
+/// * `bytes`       - Byte slice containing a numeric string.
+/// * `rounding`    - Rounding scheme for the fractional digits.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::RoundingMode;
+/// # pub fn main() {
+/// let kind = RoundingMode::NearestTieEven;
+/// assert_eq!(lexical_core::parse_int_clamped::<u8>(b"300.7", kind), Ok(255));
+/// assert_eq!(lexical_core::parse_int_clamped::<u8>(b"-5", kind), Ok(0));
+/// assert_eq!(lexical_core::parse_int_clamped::<u8>(b"2.5", kind), Ok(2));
+/// assert_eq!(lexical_core::parse_int_clamped::<u8>(b"2.5", RoundingMode::NearestTieAwayZero), Ok(3));
+/// # }
+/// ```
+pub fn parse_int_clamped<N: Integer>(bytes: &[u8], rounding: RoundingMode)
+    -> Result<N>
+{
+    let value = f64::from_lexical(bytes)?;
+    match value.is_nan() {
+        true  => Err(Error::from((ErrorCode::InvalidDigit, 0)).with_byte(bytes)),
+        false => Ok(clamp(value, rounding)),
+    }
+}
+
+/// Parse a decimal number, and round and clamp it into an integer, until an invalid digit is found.
+///
+/// The bytes are parsed as an `f64`, which is rounded to an integral
+/// value with the rounding scheme, and then saturated to the range
+/// of the integer. Returns the clamped value and the number of
+/// processed bytes.
+///
+/// * `bytes`       - Byte slice containing a numeric string.
+/// * `rounding`    - Rounding scheme for the fractional digits.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::RoundingMode;
+/// # pub fn main() {
+/// let kind = RoundingMode::TowardZero;
+/// assert_eq!(lexical_core::parse_partial_int_clamped::<u8>(b"300.7,", kind), Ok((255, 5)));
+/// assert_eq!(lexical_core::parse_partial_int_clamped::<i8>(b"-5.9 ", kind), Ok((-5, 4)));
+/// # }
+/// ```
+pub fn parse_partial_int_clamped<N: Integer>(bytes: &[u8], rounding: RoundingMode)
+    -> Result<(N, usize)>
+{
+    let (value, count) = f64::from_lexical_partial(bytes)?;
+    match value.is_nan() {
+        true  => Err(Error::from((ErrorCode::InvalidDigit, 0)).with_byte(bytes)),
+        false => Ok((clamp(value, rounding), count)),
+    }
+}
+
+/// Parse a decimal float, and saturate it to the domain.
+///
+/// The bytes are parsed the same as [`parse`], and values above
+/// `domain_max` are clamped to `domain_max`, while values below
+/// `-domain_max` are clamped to `-domain_max`. This is saturation to
+/// the domain of the data, unlike overflow to infinity, so infinities
+/// are also clamped. `NaN` is not within any domain, and is returned
+/// unchanged, so it may still represent missing data.
+///
+/// * `bytes`       - Byte slice containing a numeric string.
+/// * `domain_max`  - Largest absolute value in the domain.
+///
+/// # Panics
+///
+/// Panics if `domain_max` is negative or `NaN`.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # pub fn main() {
+/// assert_eq!(lexical_core::parse_saturating_float(b"9999", 5000.0), Ok(5000.0));
+/// assert_eq!(lexical_core::parse_saturating_float(b"-1e400", 5000.0), Ok(-5000.0));
+/// assert_eq!(lexical_core::parse_saturating_float(b"12.5", 5000.0), Ok(12.5));
+/// assert!(lexical_core::parse_saturating_float(b"NaN", 5000.0).unwrap().is_nan());
+/// # }
+/// ```
+///
+/// [`parse`]: fn.parse.html
+pub fn parse_saturating_float(bytes: &[u8], domain_max: f64)
+    -> Result<f64>
+{
+    assert!(domain_max >= 0.0, "domain_max must be non-negative.");
+    let value = f64::from_lexical(bytes)?;
+    if value > domain_max {
+        Ok(domain_max)
+    } else if value < -domain_max {
+        Ok(-domain_max)
+    } else {
+        Ok(value)
+    }
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_int_clamped_test() {
+        let even = RoundingMode::NearestTieEven;
+        assert_eq!(parse_int_clamped::<u8>(b"0", even), Ok(0));
+        assert_eq!(parse_int_clamped::<u8>(b"300.7", even), Ok(255));
+        assert_eq!(parse_int_clamped::<u8>(b"-5", even), Ok(0));
+        assert_eq!(parse_int_clamped::<u8>(b"254.5", even), Ok(254));
+        assert_eq!(parse_int_clamped::<u8>(b"253.5", even), Ok(254));
+        assert_eq!(parse_int_clamped::<i8>(b"-128.4", even), Ok(-128));
+        assert_eq!(parse_int_clamped::<i8>(b"-1e10", even), Ok(-128));
+        assert_eq!(parse_int_clamped::<u64>(b"1e30", even), Ok(u64::max_value()));
+        assert_eq!(parse_int_clamped::<i64>(b"-1e30", even), Ok(i64::min_value()));
+        assert_eq!(parse_int_clamped::<u32>(b"inf", even), Ok(u32::max_value()));
+        assert_eq!(parse_int_clamped::<i32>(b"-inf", even), Ok(i32::min_value()));
+        assert_eq!(parse_int_clamped::<u8>(b"NaN", even), Err(Error::from((ErrorCode::InvalidDigit, 0, b'N'))));
+        assert_eq!(parse_int_clamped::<u8>(b"1.5x", even), Err(Error::from((ErrorCode::InvalidDigit, 3, b'x'))));
+        assert_eq!(parse_int_clamped::<u8>(b"", even), Err(Error::from((ErrorCode::Empty, 0))));
+    }
+
+    #[test]
+    fn parse_int_clamped_rounding_test() {
+        let values: [&[u8]; 6] = [b"2.5", b"3.5", b"2.2", b"-2.5", b"-2.2", b"-0.5"];
+        let check = |kind, expected: [i32; 6]| {
+            for (value, expected) in values.iter().zip(expected.iter()) {
+                assert_eq!(parse_int_clamped::<i32>(value, kind), Ok(*expected));
+            }
+        };
+        check(RoundingMode::NearestTieEven, [2, 4, 2, -2, -2, 0]);
+        check(RoundingMode::NearestTieAwayZero, [3, 4, 2, -3, -2, -1]);
+        check(RoundingMode::TowardPositiveInfinity, [3, 4, 3, -2, -2, 0]);
+        check(RoundingMode::TowardNegativeInfinity, [2, 3, 2, -3, -3, -1]);
+        check(RoundingMode::TowardZero, [2, 3, 2, -2, -2, 0]);
+    }
+
+    #[test]
+    fn parse_partial_int_clamped_test() {
+        let kind = RoundingMode::NearestTieEven;
+        assert_eq!(parse_partial_int_clamped::<u8>(b"300.7", kind), Ok((255, 5)));
+        assert_eq!(parse_partial_int_clamped::<u8>(b"12.5]", kind), Ok((12, 4)));
+        assert_eq!(parse_partial_int_clamped::<u8>(b"-5,", kind), Ok((0, 2)));
+        assert_eq!(parse_partial_int_clamped::<u8>(b"NaN", kind), Err(Error::from((ErrorCode::InvalidDigit, 0, b'N'))));
+    }
+
+    #[test]
+    fn parse_saturating_float_test() {
+        assert_eq!(parse_saturating_float(b"0", 100.0), Ok(0.0));
+        assert_eq!(parse_saturating_float(b"-0.0", 100.0).map(f64::is_sign_negative), Ok(true));
+        assert_eq!(parse_saturating_float(b"99.5", 100.0), Ok(99.5));
+        assert_eq!(parse_saturating_float(b"100", 100.0), Ok(100.0));
+        assert_eq!(parse_saturating_float(b"100.01", 100.0), Ok(100.0));
+        assert_eq!(parse_saturating_float(b"-100.01", 100.0), Ok(-100.0));
+        assert_eq!(parse_saturating_float(b"1e400", 100.0), Ok(100.0));
+        assert_eq!(parse_saturating_float(b"inf", 100.0), Ok(100.0));
+        assert_eq!(parse_saturating_float(b"-inf", 100.0), Ok(-100.0));
+        assert_eq!(parse_saturating_float(b"5", 0.0), Ok(0.0));
+        assert_eq!(parse_saturating_float(b"inf", f64::INFINITY), Ok(f64::INFINITY));
+        assert!(parse_saturating_float(b"NaN", 100.0).unwrap().is_nan());
+        assert_eq!(parse_saturating_float(b"1.5x", 100.0), Err(Error::from((ErrorCode::InvalidDigit, 3, b'x'))));
+        assert_eq!(parse_saturating_float(b"", 100.0), Err(Error::from((ErrorCode::Empty, 0))));
+    }
+
+    #[test]
+    #[should_panic]
+    fn parse_saturating_float_nan_domain_test() {
+        let _ = parse_saturating_float(b"1", f64::NAN);
+    }
+}
@@ -1,9 +1,28 @@
 //! Config settings for lexical-core.
+//!
+//! The settings are mutable statics, read without synchronization while
+//! parsing and writing numbers. The exponent characters and the special
+//! strings are frozen the first time any of them is read, so they are
+//! never written after they are read, and the slices returned by the
+//! getters are valid forever. The float rounding setter is still unsafe,
+//! and must only be called before any other thread reads the rounding.
 
 use crate::lib::slice;
+use crate::lib::sync::atomic::{AtomicUsize, Ordering};
 use super::algorithm::copy_to_dst;
 use super::rounding::RoundingKind;
 
+#[cfg(feature = "std")]
+use crate::lib::sync::Once;
+#[cfg(feature = "std")]
+use crate::lib::sync::atomic::AtomicBool;
+#[cfg(feature = "std")]
+use super::num::Number;
+#[cfg(feature = "std")]
+use super::options::WriteFloatOptions;
+#[cfg(feature = "std")]
+use super::traits::ToLexicalWithOptions;
+
 // HELPERS
 
 /// Determine if the character is a control character for integers or floats.
@@ -82,6 +101,18 @@ impl FloatConfigString {
 
 // GLOBALS
 
+/// The exponent characters and special strings may be written.
+const CONFIG_UNLOCKED: usize = 0;
+
+/// A setter is writing the exponent characters or special strings.
+const CONFIG_WRITING: usize = 1;
+
+/// The exponent characters and special strings were read, and are frozen.
+const CONFIG_FROZEN: usize = 2;
+
+/// State of the exponent characters and special strings.
+static CONFIG_STATE: AtomicUsize = AtomicUsize::new(CONFIG_UNLOCKED);
+
 /// Default character for scientific notation, used when the `radix < 15`.
 static mut EXPONENT_DEFAULT_CHAR: u8 = b'e';
 
@@ -93,6 +124,18 @@ static mut EXPONENT_BACKUP_CHAR: u8 = b'^';
 #[cfg(feature = "rounding")]
 static mut FLOAT_ROUNDING: RoundingKind = RoundingKind::NearestTieEven;
 
+/// Default options to write floats, set at most once.
+#[cfg(feature = "std")]
+static mut DEFAULT_WRITE_OPTIONS: Option<WriteFloatOptions> = None;
+
+/// Guard to set the default options to write floats once.
+#[cfg(feature = "std")]
+static DEFAULT_WRITE_OPTIONS_ONCE: Once = Once::new();
+
+/// If the default options to write floats were set.
+#[cfg(feature = "std")]
+static DEFAULT_WRITE_OPTIONS_SET: AtomicBool = AtomicBool::new(false);
+
 cfg_if! {
 if #[cfg(feature = "radix")] {
     /// Not a Number literal.
@@ -138,6 +181,56 @@ if #[cfg(feature = "radix")] {
     };
 }}   // cfg_if
 
+// GUARD
+
+/// Guard for writing the exponent characters and special strings.
+///
+/// Unlocks the settings when dropped, even if the setter panics.
+struct ConfigWriteGuard;
+
+impl Drop for ConfigWriteGuard {
+    #[inline]
+    fn drop(&mut self) {
+        CONFIG_STATE.store(CONFIG_UNLOCKED, Ordering::Release);
+    }
+}
+
+// Lock the exponent characters and special strings for writing.
+//
+// Returns None if the settings are frozen, since they were already read.
+#[inline]
+fn lock_config() -> Option<ConfigWriteGuard> {
+    loop {
+        let ordering = Ordering::Acquire;
+        match CONFIG_STATE.compare_exchange_weak(CONFIG_UNLOCKED, CONFIG_WRITING, ordering, ordering) {
+            Ok(_)               => return Some(ConfigWriteGuard),
+            Err(CONFIG_FROZEN)  => return None,
+            Err(_)              => continue,
+        }
+    }
+}
+
+// Freeze the exponent characters and special strings before reading them.
+#[inline]
+fn freeze_config() {
+    if CONFIG_STATE.load(Ordering::Acquire) != CONFIG_FROZEN {
+        freeze_config_slow();
+    }
+}
+
+// Freeze the settings, waiting for any setter to finish writing.
+#[cold]
+fn freeze_config_slow() {
+    loop {
+        let ordering = Ordering::Acquire;
+        match CONFIG_STATE.compare_exchange_weak(CONFIG_UNLOCKED, CONFIG_FROZEN, ordering, ordering) {
+            Ok(_)               => return,
+            Err(CONFIG_FROZEN)  => return,
+            Err(_)              => continue,
+        }
+    }
+}
+
 // GETTERS/SETTERS
 
 /// Get default character for the exponent symbol.
@@ -146,6 +239,7 @@ if #[cfg(feature = "radix")] {
 #[inline]
 pub fn get_exponent_default_char() -> u8
 {
+    freeze_config();
     unsafe {
         EXPONENT_DEFAULT_CHAR
     }
@@ -156,23 +250,27 @@ pub fn get_exponent_default_char() -> u8
 /// Default character for scientific notation, used when the `radix < 15`.
 ///
 /// To change the expected, default character for an exponent,
-/// change this value before using lexical.
+/// change this value before using lexical. The exponent characters
+/// and the special strings are frozen once any of them is read, so
+/// they may only be set to their current values after parsing or
+/// writing any float.
 ///
 /// * `ch`      - Character for exponent symbol.
 ///
-/// # Safety
-///
-/// Do not call this function in threaded-code, as it is not thread-safe.
-/// Do not call this function after compiling any formats.
-///
 /// # Panics
 ///
-/// Panics if the character is in the character set `[A-Da-d.+\-]`.
+/// Panics if the character is in the character set `[A-Da-d.+\-]`,
+/// or if the character was already read and `ch` is different.
 #[inline]
-pub unsafe fn set_exponent_default_char(ch: u8)
+pub fn set_exponent_default_char(ch: u8)
 {
-    assert!(!is_control_character(ch, true));
-    EXPONENT_DEFAULT_CHAR = ch
+    match lock_config() {
+        Some(_guard) => unsafe {
+            assert!(!is_control_character(ch, true));
+            EXPONENT_DEFAULT_CHAR = ch
+        },
+        None => assert!(get_exponent_default_char() == ch, "the exponent character was already read"),
+    }
 }
 
 /// Get backup character for the exponent symbol.
@@ -183,6 +281,7 @@ pub unsafe fn set_exponent_default_char(ch: u8)
 #[cfg(feature ="radix")]
 pub fn get_exponent_backup_char() -> u8
 {
+    freeze_config();
     unsafe {
         EXPONENT_BACKUP_CHAR
     }
@@ -194,24 +293,29 @@ pub fn get_exponent_backup_char() -> u8
 /// and therefore may no longer be used as a marker for the exponent.
 ///
 /// To change the expected, backup character for an exponent,
-/// change this value before using lexical.
+/// change this value before using lexical. Like
+/// [`set_exponent_default_char`], it may only be set to its current
+/// value after parsing or writing any float.
 ///
 /// * `ch`      - Character for exponent symbol.
 ///
-/// # Safety
-///
-/// Do not call this function in threaded-code, as it is not thread-safe.
-/// Do not call this function after compiling any formats.
-///
 /// # Panics
 ///
-/// Panics if the character is in the character set `[A-Za-z.+\-]`.
+/// Panics if the character is in the character set `[A-Za-z.+\-]`,
+/// or if the character was already read and `ch` is different.
+///
+/// [`set_exponent_default_char`]: fn.set_exponent_default_char.html
 #[inline]
 #[cfg(feature ="radix")]
-pub unsafe fn set_exponent_backup_char(ch: u8)
+pub fn set_exponent_backup_char(ch: u8)
 {
-    assert!(!is_control_character(ch, false));
-    EXPONENT_BACKUP_CHAR = ch
+    match lock_config() {
+        Some(_guard) => unsafe {
+            assert!(!is_control_character(ch, false));
+            EXPONENT_BACKUP_CHAR = ch
+        },
+        None => assert!(get_exponent_backup_char() == ch, "the exponent character was already read"),
+    }
 }
 
 /// Get the default rounding scheme for float conversions.
@@ -245,9 +349,12 @@ pub unsafe fn set_float_rounding(rounding: RoundingKind) {
 }
 
 /// Get string representation of Not a Number as a byte slice.
+///
+/// Reading the string freezes it, so it never changes afterwards.
 #[inline]
 pub fn get_nan_string() -> &'static [u8]
 {
+    freeze_config();
     unsafe {
         NAN_STRING.as_bytes()
     }
@@ -255,11 +362,10 @@ pub fn get_nan_string() -> &'static [u8]
 
 /// Set representation of Not a Number from a byte slice.
 ///
-/// * `bytes`    - Slice of bytes to assign as NaN string representation.
-///
-/// # Safety
+/// Like [`set_exponent_default_char`], the string may only be set
+/// to its current value after parsing or writing any float.
 ///
-/// Do not call this function in threaded-code, as it is not thread-safe.
+/// * `bytes`    - Slice of bytes to assign as NaN string representation.
 ///
 /// # Panics
 ///
@@ -267,17 +373,28 @@ pub fn get_nan_string() -> &'static [u8]
 /// - `bytes.len() >= f32::FORMATTED_SIZE`
 /// - `bytes` is empty
 /// - `bytes` does not start with an `'N'` or `'n'`.
+/// - the string was already read, and `bytes` is different.
+///
+/// [`set_exponent_default_char`]: fn.set_exponent_default_char.html
 #[inline]
-pub unsafe fn set_nan_string(bytes: &[u8])
+pub fn set_nan_string(bytes: &[u8])
 {
-    assert!(starts_with_n(bytes));
-    NAN_STRING.load_bytes(bytes);
+    match lock_config() {
+        Some(_guard) => unsafe {
+            assert!(starts_with_n(bytes));
+            NAN_STRING.load_bytes(bytes);
+        },
+        None => assert!(get_nan_string() == bytes, "the NaN string was already read"),
+    }
 }
 
 /// Get the short representation of an Infinity literal as a byte slice.
+///
+/// Reading the string freezes it, so it never changes afterwards.
 #[inline]
 pub fn get_inf_string() -> &'static [u8]
 {
+    freeze_config();
     unsafe {
         INF_STRING.as_bytes()
     }
@@ -285,11 +402,10 @@ pub fn get_inf_string() -> &'static [u8]
 
 /// Set the short representation of Infinity from a byte slice.
 ///
-/// * `bytes`    - Slice of bytes to assign as Infinity string representation.
-///
-/// # Safety
+/// Like [`set_exponent_default_char`], the string may only be set
+/// to its current value after parsing or writing any float.
 ///
-/// Do not call this function in threaded-code, as it is not thread-safe.
+/// * `bytes`    - Slice of bytes to assign as Infinity string representation.
 ///
 /// # Panics
 ///
@@ -298,17 +414,28 @@ pub fn get_inf_string() -> &'static [u8]
 /// - `bytes.len() >= get_infinity_string().len()`
 /// - `bytes` is empty
 /// - `bytes` does not start with an `'I'` or `'i'`.
+/// - the string was already read, and `bytes` is different.
+///
+/// [`set_exponent_default_char`]: fn.set_exponent_default_char.html
 #[inline]
-pub unsafe fn set_inf_string(bytes: &[u8])
+pub fn set_inf_string(bytes: &[u8])
 {
-    assert!(starts_with_i(bytes) && bytes.len() <= INFINITY_STRING.length);
-    INF_STRING.load_bytes(bytes);
+    match lock_config() {
+        Some(_guard) => unsafe {
+            assert!(starts_with_i(bytes) && bytes.len() <= INFINITY_STRING.length);
+            INF_STRING.load_bytes(bytes);
+        },
+        None => assert!(get_inf_string() == bytes, "the infinity string was already read"),
+    }
 }
 
 /// Get the long representation of an Infinity literal as a byte slice.
+///
+/// Reading the string freezes it, so it never changes afterwards.
 #[inline]
 pub fn get_infinity_string() -> &'static [u8]
 {
+    freeze_config();
     unsafe {
         INFINITY_STRING.as_bytes()
     }
@@ -316,11 +443,10 @@ pub fn get_infinity_string() -> &'static [u8]
 
 /// Set the long representation of Infinity from a byte slice.
 ///
-/// * `bytes`    - Slice of bytes to assign as Infinity string representation.
-///
-/// # Safety
+/// Like [`set_exponent_default_char`], the string may only be set
+/// to its current value after parsing or writing any float.
 ///
-/// Do not call this function in threaded-code, as it is not thread-safe.
+/// * `bytes`    - Slice of bytes to assign as Infinity string representation.
 ///
 /// # Panics
 ///
@@ -329,11 +455,69 @@ pub fn get_infinity_string() -> &'static [u8]
 /// - `bytes.len() < get_inf_string().len()`
 /// - `bytes` is empty
 /// - `bytes` does not start with an `'I'` or `'i'`.
+/// - the string was already read, and `bytes` is different.
+///
+/// [`set_exponent_default_char`]: fn.set_exponent_default_char.html
 #[inline]
-pub unsafe fn set_infinity_string(bytes: &[u8])
+pub fn set_infinity_string(bytes: &[u8])
 {
-    assert!(starts_with_i(bytes) && bytes.len() >= INF_STRING.length);
-    INFINITY_STRING.load_bytes(bytes);
+    match lock_config() {
+        Some(_guard) => unsafe {
+            assert!(starts_with_i(bytes) && bytes.len() >= INF_STRING.length);
+            INFINITY_STRING.load_bytes(bytes);
+        },
+        None => assert!(get_infinity_string() == bytes, "the infinity string was already read"),
+    }
+}
+
+/// Get the default options to write floats.
+///
+/// Returns `None` unless set by [`set_default_write_options`].
+///
+/// [`set_default_write_options`]: fn.set_default_write_options.html
+#[inline]
+#[cfg(feature = "std")]
+pub fn get_default_write_options() -> Option<WriteFloatOptions> {
+    // The options are written once, before the flag is set, so they
+    // are never written after the flag is read.
+    match DEFAULT_WRITE_OPTIONS_SET.load(Ordering::Acquire) {
+        true  => unsafe { DEFAULT_WRITE_OPTIONS },
+        false => None,
+    }
+}
+
+/// Set the default options to write floats.
+///
+/// Once set, `lexical::to_string` writes floats as [`write_with_options`]
+/// does with these options, so an application may choose how floats
+/// are written once, at startup, rather than with the `trim_floats`
+/// feature. For example, [`integer_as_integer`] writes integral floats
+/// without a trailing `.0`, as if the feature was enabled. [`write`]
+/// and the other functions in lexical-core never use these options.
+///
+/// The options may only be set once. Returns the options as an error
+/// if the options were already set. `lexical::to_string` sizes its
+/// buffer with [`formatted_size_with_options`] for these options.
+///
+/// * `options` - Options to write floats with by default.
+///
+/// [`write`]: fn.write.html
+/// [`write_with_options`]: fn.write_with_options.html
+/// [`integer_as_integer`]: struct.WriteFloatOptions.html#method.integer_as_integer
+/// [`formatted_size_with_options`]: trait.ToLexicalWithOptions.html#tymethod.formatted_size_with_options
+#[cfg(feature = "std")]
+pub fn set_default_write_options(options: WriteFloatOptions)
+    -> Result<(), WriteFloatOptions>
+{
+    let mut result = Err(options);
+    DEFAULT_WRITE_OPTIONS_ONCE.call_once(|| {
+        unsafe {
+            DEFAULT_WRITE_OPTIONS = Some(options);
+        }
+        DEFAULT_WRITE_OPTIONS_SET.store(true, Ordering::Release);
+        result = Ok(());
+    });
+    result
 }
 
 // CONSTANTS
@@ -429,6 +613,18 @@ pub(crate) fn exponent_notation_char(radix: u32) -> u8 {
     }
 }
 
+/// Check if the exponent string starts with a valid digit for the radix.
+///
+/// These exponent strings are ambiguous, so the exponent notation
+/// character is used instead.
+#[inline]
+pub(crate) fn exponent_string_starts_with_digit(exponent: &[u8], radix: u32) -> bool {
+    match exponent.first() {
+        Some(&c) => (c as char).to_digit(radix).is_some(),
+        None     => false,
+    }
+}
+
 // TEST
 // ----
 
@@ -451,33 +647,49 @@ mod tests {
         assert_eq!(exponent_notation_char(32), backup);
     }
 
-    // Only enable when no other threads touch NAN_STRING or INFINITY_STRING.
     #[test]
-    #[ignore]
+    fn exponent_string_starts_with_digit_test() {
+        assert!(!exponent_string_starts_with_digit(b"e", 10));
+        assert!(!exponent_string_starts_with_digit(b"x10^", 10));
+        assert!(exponent_string_starts_with_digit(b"e", 15));
+        assert!(exponent_string_starts_with_digit(b"E", 16));
+        assert!(exponent_string_starts_with_digit(b"1", 2));
+    }
+
+    #[test]
     fn special_bytes_test() {
-        unsafe {
-            let mut buffer = new_buffer();
-            // Test serializing and deserializing special strings.
-            assert!(f32::from_lexical(b"NaN").unwrap().is_nan());
-            assert!(f32::from_lexical(b"nan").unwrap().is_nan());
-            assert!(f32::from_lexical(b"NAN").unwrap().is_nan());
-            assert!(f32::from_lexical(b"inf").unwrap().is_infinite());
-            assert!(f32::from_lexical(b"INF").unwrap().is_infinite());
-            assert!(f32::from_lexical(b"Infinity").unwrap().is_infinite());
-            assert_eq!(f64::NAN.to_lexical(&mut buffer), b"NaN");
-            assert_eq!(f64::INFINITY.to_lexical(&mut buffer), b"inf");
-
-            set_nan_string(b"nan");
-            set_inf_string(b"Infinity");
-
-            assert!(f32::from_lexical(b"inf").err().unwrap().code == ErrorCode::InvalidDigit);
-            assert!(f32::from_lexical(b"Infinity").unwrap().is_infinite());
-            assert_eq!(f64::NAN.to_lexical(&mut buffer), b"nan");
-            assert_eq!(f64::INFINITY.to_lexical(&mut buffer), b"Infinity");
-
-            set_nan_string(b"NaN");
-            set_inf_string(b"inf");
-        }
+        let mut buffer = new_buffer();
+        // Test serializing and deserializing special strings.
+        assert!(f32::from_lexical(b"NaN").unwrap().is_nan());
+        assert!(f32::from_lexical(b"nan").unwrap().is_nan());
+        assert!(f32::from_lexical(b"NAN").unwrap().is_nan());
+        assert!(f32::from_lexical(b"inf").unwrap().is_infinite());
+        assert!(f32::from_lexical(b"INF").unwrap().is_infinite());
+        assert!(f32::from_lexical(b"Infinity").unwrap().is_infinite());
+        assert_eq!(f64::NAN.to_lexical(&mut buffer), b"NaN");
+        assert_eq!(f64::INFINITY.to_lexical(&mut buffer), b"inf");
+
+        // The strings are frozen, so they may only be set to the same value.
+        set_nan_string(b"NaN");
+        set_inf_string(b"inf");
+        set_infinity_string(b"infinity");
+        set_exponent_default_char(b'e');
+        assert_eq!(get_nan_string(), b"NaN");
+        assert_eq!(get_inf_string(), b"inf");
+    }
+
+    #[test]
+    #[should_panic]
+    fn set_frozen_nan_string_test() {
+        get_nan_string();
+        set_nan_string(b"nan");
+    }
+
+    #[test]
+    #[should_panic]
+    fn set_frozen_exponent_char_test() {
+        get_exponent_default_char();
+        set_exponent_default_char(b'p');
     }
 
     // Only enable when no other threads touch FLOAT_ROUNDING.
@@ -528,6 +740,33 @@ mod tests {
         }
     }
 
+    #[test]
+    #[cfg(feature = "std")]
+    fn default_write_options_test() {
+        // The options are set once, so this is the only test to set them.
+        assert_eq!(get_default_write_options(), None);
+        let options = WriteFloatOptions::builder()
+            .integer_as_integer(true)
+            .exponent_string(b"E")
+            .min_fraction_digits(2)
+            .minus_sign("\u{2212}".as_bytes())
+            .build()
+            .unwrap();
+        assert_eq!(set_default_write_options(options), Ok(()));
+        assert_eq!(get_default_write_options(), Some(options));
+        assert_eq!(set_default_write_options(WriteFloatOptions::new()), Err(WriteFloatOptions::new()));
+        assert_eq!(get_default_write_options(), Some(options));
+
+        // Only the default serializer uses the options.
+        let options = get_default_write_options();
+        let mut buffer = vec![b'0'; f64::formatted_size_default(&options)];
+        assert!(buffer.len() >= f64::FORMATTED_SIZE_DECIMAL);
+        assert_eq!(1.0f32.to_lexical_default(&mut buffer, &options), b"1");
+        assert_eq!((-1.5e-7f64).to_lexical_default(&mut buffer, &options), "\u{2212}1.50E-7".as_bytes());
+        assert_eq!(5u32.to_lexical_default(&mut buffer, &options), b"5");
+        assert_eq!(1.5e-7f64.to_lexical(&mut buffer), b"1.5e-7");
+    }
+
     // Only enable when no other threads touch FLOAT_ROUNDING.
     #[cfg(all(feature = "correct", feature = "radix", feature = "rounding"))]
     #[test]
@@ -0,0 +1,181 @@
+//! Parse numbers with a leading or trailing currency symbol.
+
+use super::error::{Error, ErrorCode};
+use super::num::Float;
+use super::result::Result;
+use super::traits::FromLexical;
+
+#[cfg(feature = "format")]
+use super::format::NumberFormat;
+
+#[cfg(feature = "format")]
+use super::traits::FromLexicalFormat;
+
+// HELPERS
+
+// Find the first non-empty symbol that starts the bytes.
+#[inline]
+fn find_prefix(bytes: &[u8], symbols: &[&[u8]]) -> Option<(usize, usize)> {
+    symbols.iter()
+        .position(|symbol| !symbol.is_empty() && bytes.starts_with(symbol))
+        .map(|index| (index, symbols[index].len()))
+}
+
+// Find the first non-empty symbol that ends the bytes.
+#[inline]
+fn find_suffix(bytes: &[u8], symbols: &[&[u8]]) -> Option<(usize, usize)> {
+    symbols.iter()
+        .position(|symbol| !symbol.is_empty() && bytes.ends_with(symbol))
+        .map(|index| (index, symbols[index].len()))
+}
+
+// Strip a currency symbol and parse the remaining bytes.
+//
+// A leading symbol may be preceded by a sign, which is applied to the
+// parsed value, in which case the number itself may not have a sign.
+fn parse_currency_impl<N, Parse>(bytes: &[u8], symbols: &[&[u8]], parse: Parse)
+    -> Result<(N, Option<usize>)>
+    where N: Float,
+          Parse: Fn(&[u8]) -> Result<N>
+{
+    // Leading symbol, after an optional sign.
+    let (sign, unsigned) = match bytes.first() {
+        Some(&b'+') | Some(&b'-') => (bytes[0], &bytes[1..]),
+        _                         => (b'+', bytes),
+    };
+    if let Some((index, length)) = find_prefix(unsigned, symbols) {
+        let shift = bytes.len() - unsigned.len() + length;
+        let digits = &bytes[shift..];
+        let is_signed = bytes.len() != unsigned.len();
+        if is_signed && (digits.first() == Some(&b'+') || digits.first() == Some(&b'-')) {
+            return Err(Error::from((ErrorCode::InvalidDigit, shift)).with_byte(bytes));
+        }
+        let value = match parse(digits) {
+            Ok(value)   => value,
+            Err(error)  => return Err(error.shift(shift)),
+        };
+        return match sign {
+            b'-' => Ok((-value, Some(index))),
+            _    => Ok((value, Some(index))),
+        };
+    }
+
+    // Trailing symbol.
+    if let Some((index, length)) = find_suffix(bytes, symbols) {
+        let value = parse(&bytes[..bytes.len() - length])?;
+        return Ok((value, Some(index)));
+    }
+
+    // No symbol.
+    parse(bytes).map(|value| (value, None))
+}
+
+// API
+
+/// Parse float from bytes with a leading or trailing currency symbol.
+///
+/// The first symbol that starts or ends the bytes is removed, and
+/// the remaining bytes are parsed. Leading symbols are checked before
+/// trailing symbols, and empty symbols are ignored. A sign may come
+/// before or after a leading symbol, like `-$5` or `$-5`, but not
+/// both. Returns the value and the index of the symbol in `symbols`,
+/// or `None` if the bytes did not have a symbol. The index of any
+/// error is relative to the start of the bytes, including the symbol.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `symbols` - Currency symbols to remove.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # pub fn main() {
+/// let symbols: &[&[u8]] = &[b"$", "€".as_bytes()];
+/// assert_eq!(lexical_core::parse_currency::<f64>(b"$1234.56", symbols), Ok((1234.56, Some(0))));
+/// assert_eq!(lexical_core::parse_currency::<f64>("1234.56€".as_bytes(), symbols), Ok((1234.56, Some(1))));
+/// assert_eq!(lexical_core::parse_currency::<f64>(b"-$5", symbols), Ok((-5.0, Some(0))));
+/// assert_eq!(lexical_core::parse_currency::<f64>(b"5", symbols), Ok((5.0, None)));
+/// # }
+/// ```
+pub fn parse_currency<N: FromLexical + Float>(bytes: &[u8], symbols: &[&[u8]])
+    -> Result<(N, Option<usize>)>
+{
+    parse_currency_impl(bytes, symbols, N::from_lexical)
+}
+
+/// Parse float from bytes with a leading or trailing currency symbol, using a custom format.
+///
+/// Like [`parse_currency`], but the remaining bytes are parsed with the
+/// number format, such as [`NumberFormat::grouping`] for digit groups,
+/// like `$1,234.56`.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `symbols` - Currency symbols to remove.
+/// * `format`  - Numerical format.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::NumberFormat;
+/// # pub fn main() {
+/// let symbols: &[&[u8]] = &[b"$"];
+/// let format = NumberFormat::grouping(b',', true).unwrap();
+/// assert_eq!(lexical_core::parse_currency_format::<f64>(b"$1,234.56", symbols, format), Ok((1234.56, Some(0))));
+/// # }
+/// ```
+///
+/// [`parse_currency`]: fn.parse_currency.html
+/// [`NumberFormat::grouping`]: struct.NumberFormat.html#method.grouping
+#[cfg(feature = "format")]
+pub fn parse_currency_format<N: FromLexicalFormat + Float>(bytes: &[u8], symbols: &[&[u8]], format: NumberFormat)
+    -> Result<(N, Option<usize>)>
+{
+    parse_currency_impl(bytes, symbols, |digits| N::from_lexical_format(digits, format))
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SYMBOLS: [&[u8]; 3] = [b"$", b"", b"EUR"];
+
+    #[test]
+    fn parse_currency_test() {
+        assert_eq!(parse_currency::<f64>(b"$1234.56", &SYMBOLS), Ok((1234.56, Some(0))));
+        assert_eq!(parse_currency::<f64>(b"1234.56EUR", &SYMBOLS), Ok((1234.56, Some(2))));
+        assert_eq!(parse_currency::<f64>(b"EUR1", &SYMBOLS), Ok((1.0, Some(2))));
+        assert_eq!(parse_currency::<f64>(b"1234.56", &SYMBOLS), Ok((1234.56, None)));
+        assert_eq!(parse_currency::<f32>(b"$0.5", &SYMBOLS), Ok((0.5, Some(0))));
+        assert_eq!(parse_currency::<f64>(b"1", &[]), Ok((1.0, None)));
+
+        // Signs.
+        assert_eq!(parse_currency::<f64>(b"-$5", &SYMBOLS), Ok((-5.0, Some(0))));
+        assert_eq!(parse_currency::<f64>(b"+$5", &SYMBOLS), Ok((5.0, Some(0))));
+        assert_eq!(parse_currency::<f64>(b"$-5", &SYMBOLS), Ok((-5.0, Some(0))));
+        assert_eq!(parse_currency::<f64>(b"-5$", &SYMBOLS), Ok((-5.0, Some(0))));
+        assert!(parse_currency::<f64>(b"-$0", &SYMBOLS).unwrap().0.is_sign_negative());
+
+        // Errors are relative to the start of the bytes.
+        assert_eq!(parse_currency::<f64>(b"$", &SYMBOLS), Err(Error::from((ErrorCode::Empty, 1))));
+        assert_eq!(parse_currency::<f64>(b"-$", &SYMBOLS), Err(Error::from((ErrorCode::Empty, 2))));
+        assert_eq!(parse_currency::<f64>(b"EUR", &SYMBOLS), Err(Error::from((ErrorCode::Empty, 3))));
+        assert_eq!(parse_currency::<f64>(b"-$-5", &SYMBOLS), Err(Error::from((ErrorCode::InvalidDigit, 2, b'-'))));
+        assert_eq!(parse_currency::<f64>(b"$5$", &SYMBOLS), Err(Error::from((ErrorCode::InvalidDigit, 2, b'$'))));
+        assert_eq!(parse_currency::<f64>(b"5 $", &SYMBOLS), Err(Error::from((ErrorCode::InvalidDigit, 1, b' '))));
+        assert_eq!(parse_currency::<f64>(b"$$5", &SYMBOLS), Err(Error::from((ErrorCode::EmptyMantissa, 1))));
+    }
+
+    #[test]
+    #[cfg(feature = "format")]
+    fn parse_currency_format_test() {
+        let format = NumberFormat::grouping(b',', true).unwrap();
+        assert_eq!(parse_currency_format::<f64>(b"$1,234.56", &SYMBOLS, format), Ok((1234.56, Some(0))));
+        assert_eq!(parse_currency_format::<f64>(b"-$1,234", &SYMBOLS, format), Ok((-1234.0, Some(0))));
+        assert_eq!(parse_currency_format::<f64>(b"1,234EUR", &SYMBOLS, format), Ok((1234.0, Some(2))));
+        assert_eq!(parse_currency_format::<f64>(b"$1,,234", &SYMBOLS, format), Err(Error::from((ErrorCode::InvalidDigit, 2, b','))));
+    }
+}
@@ -0,0 +1,151 @@
+//! Decompose decimal floats into their significant digits and exponent.
+
+use super::error::{Error, ErrorCode};
+use super::result::Result;
+use super::spans::parse_spans;
+
+// HELPERS
+
+// Parse the digits of a valid, decimal exponent, saturating far beyond
+// the range of the exponent.
+#[inline]
+pub(crate) fn parse_exponent(bytes: &[u8]) -> i64 {
+    let (is_negative, digits) = match bytes.first() {
+        Some(&b'-') => (true, &index!(bytes[1..])),
+        Some(&b'+') => (false, &index!(bytes[1..])),
+        _           => (false, bytes),
+    };
+    let mut exponent: i64 = 0;
+    for &c in digits {
+        exponent = (exponent * 10 + (c - b'0') as i64).min(1 << 40);
+    }
+    if is_negative { -exponent } else { exponent }
+}
+
+// API
+
+/// Decompose a decimal float into its sign, significant digits and exponent.
+///
+/// Parses the longest valid float at the start of the bytes, like
+/// [`parse_partial`](fn.parse_partial.html) for an `f64`, without
+/// converting the digits to a float. Returns if the value is negative,
+/// the significant digits, the power-of-ten exponent, and the number of
+/// bytes consumed, so the value is exactly `digits * 10^exponent`, with
+/// the digits read as a decimal integer.
+///
+/// The digits have neither a decimal point nor leading zeros, while
+/// trailing zeros are kept, like for [`significant_digits`], so `"1.50"`
+/// has the digits `150` and an exponent of `-2`, and `"100"` has the
+/// digits `100` and an exponent of `0`. Zero, like `"-0.00e5"`, has no
+/// digits and an exponent of `0`, and keeps its sign. Special values,
+/// like `"NaN"`, have no digits and are reported as
+/// [`InvalidDigit`](enum.ErrorCode.html#variant.InvalidDigit), and an
+/// exponent that does not fit in an `i32` is reported as
+/// [`Overflow`](enum.ErrorCode.html#variant.Overflow) at the exponent.
+///
+/// The digits on either side of the decimal point are not contiguous in
+/// the bytes, so they are copied into `buffer`, without allocating, and
+/// the returned digits borrow from the buffer.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `buffer`  - Buffer to write the significant digits to.
+///
+/// # Panics
+///
+/// Panics if the buffer is too small for the significant digits. In
+/// order to ensure the function will not panic, provide a buffer at
+/// least as long as the bytes.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # pub fn main() {
+/// let mut buffer = [0u8; 16];
+/// assert_eq!(lexical_core::decompose_decimal(b"-001.50e3,", &mut buffer), Ok((true, &b"150"[..], 1, 9)));
+/// assert_eq!(lexical_core::decompose_decimal(b"0.0012", &mut buffer), Ok((false, &b"12"[..], -4, 6)));
+/// assert_eq!(lexical_core::decompose_decimal(b"0.000", &mut buffer), Ok((false, &b""[..], 0, 5)));
+/// # }
+/// ```
+///
+/// [`significant_digits`]: fn.significant_digits.html
+pub fn decompose_decimal<'a>(bytes: &[u8], buffer: &'a mut [u8])
+    -> Result<(bool, &'a [u8], i32, usize)>
+{
+    let (spans, consumed) = parse_spans(bytes)?;
+    let is_negative = bytes.first() == Some(&b'-');
+    let integer = &index!(bytes[spans.integer]);
+    let fraction = &index!(bytes[spans.fraction.clone()]);
+
+    // Copy the significant digits, without leading zeros.
+    let digits = integer.iter().chain(fraction.iter()).skip_while(|&&c| c == b'0');
+    let count = digits.clone().count();
+    if count == 0 {
+        return Ok((is_negative, &[], 0, consumed));
+    }
+    assert!(buffer.len() >= count, "buffer is too small for the significant digits.");
+    for (dst, &src) in buffer.iter_mut().zip(digits) {
+        *dst = src;
+    }
+
+    // Shift the exponent by the fraction.
+    let exponent = parse_exponent(&index!(bytes[spans.exponent])) - fraction.len() as i64;
+    if exponent < i32::min_value() as i64 || exponent > i32::max_value() as i64 {
+        return Err(Error::from((ErrorCode::Overflow, spans.fraction.end)));
+    }
+
+    Ok((is_negative, &buffer[..count], exponent as i32, consumed))
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decompose_decimal_test() {
+        let mut buffer = [0u8; 32];
+        assert_eq!(decompose_decimal(b"1", &mut buffer), Ok((false, &b"1"[..], 0, 1)));
+        assert_eq!(decompose_decimal(b"+12345", &mut buffer), Ok((false, &b"12345"[..], 0, 6)));
+        assert_eq!(decompose_decimal(b"-1.5", &mut buffer), Ok((true, &b"15"[..], -1, 4)));
+        assert_eq!(decompose_decimal(b"100", &mut buffer), Ok((false, &b"100"[..], 0, 3)));
+        assert_eq!(decompose_decimal(b"1.50", &mut buffer), Ok((false, &b"150"[..], -2, 4)));
+        assert_eq!(decompose_decimal(b"00.00120", &mut buffer), Ok((false, &b"120"[..], -5, 8)));
+        assert_eq!(decompose_decimal(b"1.", &mut buffer), Ok((false, &b"1"[..], 0, 2)));
+        assert_eq!(decompose_decimal(b".5", &mut buffer), Ok((false, &b"5"[..], -1, 2)));
+        assert_eq!(decompose_decimal(b"1.5e3", &mut buffer), Ok((false, &b"15"[..], 2, 5)));
+        assert_eq!(decompose_decimal(b"1.5E+3", &mut buffer), Ok((false, &b"15"[..], 2, 6)));
+        assert_eq!(decompose_decimal(b"15e-3", &mut buffer), Ok((false, &b"15"[..], -3, 5)));
+        assert_eq!(decompose_decimal(b"12345678901234567890123456789", &mut buffer), Ok((false, &b"12345678901234567890123456789"[..], 0, 29)));
+
+        // Partial parsing.
+        assert_eq!(decompose_decimal(b"1.5,2", &mut buffer), Ok((false, &b"15"[..], -1, 3)));
+
+        // Zero.
+        assert_eq!(decompose_decimal(b"0", &mut buffer), Ok((false, &b""[..], 0, 1)));
+        assert_eq!(decompose_decimal(b"-0.000e5", &mut buffer), Ok((true, &b""[..], 0, 8)));
+        assert_eq!(decompose_decimal(b"0e99999999999", &mut buffer), Ok((false, &b""[..], 0, 13)));
+
+        // Exponent limits.
+        assert_eq!(decompose_decimal(b"1e2147483647", &mut buffer), Ok((false, &b"1"[..], i32::max_value(), 12)));
+        assert_eq!(decompose_decimal(b"0.1e-2147483647", &mut buffer), Ok((false, &b"1"[..], i32::min_value(), 15)));
+        assert_eq!(decompose_decimal(b"1e2147483648", &mut buffer), Err(Error::from((ErrorCode::Overflow, 1))));
+        assert_eq!(decompose_decimal(b"-1.5e-2147483648", &mut buffer), Err(Error::from((ErrorCode::Overflow, 4))));
+
+        // Errors.
+        assert_eq!(decompose_decimal(b"", &mut buffer), Err(Error::from((ErrorCode::Empty, 0))));
+        assert_eq!(decompose_decimal(b".", &mut buffer), Err(Error::from((ErrorCode::EmptyMantissa, 0))));
+        assert_eq!(decompose_decimal(b"1e", &mut buffer), Err(Error::from((ErrorCode::EmptyExponent, 2))));
+        assert_eq!(decompose_decimal(b"inf", &mut buffer), Err(Error::from((ErrorCode::InvalidDigit, 0, b'i'))));
+        assert_eq!(decompose_decimal(b"-NaN", &mut buffer), Err(Error::from((ErrorCode::InvalidDigit, 1, b'N'))));
+    }
+
+    #[test]
+    #[should_panic]
+    fn decompose_decimal_buffer_test() {
+        let mut buffer = [0u8; 2];
+        decompose_decimal(b"1.25", &mut buffer).unwrap();
+    }
+}
@@ -0,0 +1,183 @@
+//! Parse and write durations as decimal seconds.
+
+use crate::lib::time::Duration;
+use super::error::{Error, ErrorCode};
+use super::nanos::{parse_fractional_nanos, NANOS_DIGITS};
+use super::result::Result;
+use super::traits::{FromLexical, ToLexical};
+
+/// Maximum number of bytes required to write any duration as decimal seconds.
+///
+/// This is the maximum number of digits of a `u64`, a decimal point,
+/// and 9 fractional digits for the nanoseconds.
+pub const DURATION_SECS_FORMATTED_SIZE: usize = 30;
+
+// API
+
+/// Parse decimal seconds, like `"1.5"`, into a duration.
+///
+/// The bytes are whole seconds, parsed like [`parse`] for a `u64`,
+/// optionally followed by a `.` and fractional seconds, parsed like
+/// [`parse_fractional_nanos`], so up to 9 fractional digits are kept
+/// exactly, with nanosecond precision, and further digits are
+/// truncated. The whole seconds may be omitted, like in `".5"`. No
+/// exponent is accepted, and every byte must be part of the seconds.
+///
+/// Seconds that do not fit in a `u64`, and therefore not in a
+/// `Duration`, are reported as
+/// [`Overflow`](enum.ErrorCode.html#variant.Overflow), and any byte
+/// after the seconds is reported as
+/// [`InvalidDigit`](enum.ErrorCode.html#variant.InvalidDigit).
+///
+/// * `bytes`   - Byte slice containing decimal seconds.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use std::time::Duration;
+/// # use lexical_core::ErrorCode;
+/// # pub fn main() {
+/// assert_eq!(lexical_core::parse_duration_secs(b"1.5"), Ok(Duration::new(1, 500_000_000)));
+/// assert_eq!(lexical_core::parse_duration_secs(b"0.000000001"), Ok(Duration::new(0, 1)));
+/// assert_eq!(lexical_core::parse_duration_secs(b"86400"), Ok(Duration::from_secs(86400)));
+/// assert_eq!(lexical_core::parse_duration_secs(b"-1.5").err().unwrap().code, ErrorCode::InvalidDigit);
+/// # }
+/// ```
+///
+/// [`parse`]: fn.parse.html
+/// [`parse_fractional_nanos`]: fn.parse_fractional_nanos.html
+pub fn parse_duration_secs(bytes: &[u8]) -> Result<Duration> {
+    let (secs, count) = match bytes.first() {
+        Some(&b'.') => (0, 0),
+        _           => u64::from_lexical_partial(bytes)?,
+    };
+    if count == bytes.len() {
+        return Ok(Duration::from_secs(secs));
+    }
+    if index!(bytes[count]) != b'.' {
+        return Err(Error::from((ErrorCode::InvalidDigit, count)).with_byte(bytes));
+    }
+
+    let (nanos, processed) = match parse_fractional_nanos(&index!(bytes[count..])) {
+        Ok(parsed)  => parsed,
+        Err(error)  => return Err(error.shift(count)),
+    };
+    let end = count + processed;
+    match end == bytes.len() {
+        true  => Ok(Duration::new(secs, nanos)),
+        false => Err(Error::from((ErrorCode::InvalidDigit, end)).with_byte(bytes)),
+    }
+}
+
+/// Write the duration as decimal seconds, like `"1.5"`.
+///
+/// The whole seconds are written like [`write`] for a `u64`, followed
+/// by a `.` and the nanoseconds as fractional seconds, without
+/// trailing zeros, so the string parses to the same duration with
+/// [`parse_duration_secs`]. Whole seconds are written without a
+/// decimal point. Returns a subslice of the input buffer containing
+/// the written bytes, starting from the same address in memory as
+/// the input slice.
+///
+/// * `duration`    - Duration to serialize.
+/// * `bytes`       - Buffer to write the decimal seconds to.
+///
+/// # Panics
+///
+/// Panics if the buffer may not be large enough to hold the serialized
+/// duration. In order to ensure the function will not panic, provide a
+/// buffer with at least [`DURATION_SECS_FORMATTED_SIZE`] elements.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use std::time::Duration;
+/// # pub fn main() {
+/// let mut buffer = [b'0'; lexical_core::DURATION_SECS_FORMATTED_SIZE];
+/// assert_eq!(lexical_core::write_duration_secs(Duration::new(1, 500_000_000), &mut buffer), b"1.5");
+/// assert_eq!(lexical_core::write_duration_secs(Duration::from_millis(1), &mut buffer), b"0.001");
+/// assert_eq!(lexical_core::write_duration_secs(Duration::from_secs(60), &mut buffer), b"60");
+/// # }
+/// ```
+///
+/// [`write`]: fn.write.html
+/// [`parse_duration_secs`]: fn.parse_duration_secs.html
+/// [`DURATION_SECS_FORMATTED_SIZE`]: constant.DURATION_SECS_FORMATTED_SIZE.html
+pub fn write_duration_secs(duration: Duration, bytes: &mut [u8]) -> &mut [u8] {
+    assert!(bytes.len() >= DURATION_SECS_FORMATTED_SIZE);
+
+    let mut index = duration.as_secs().to_lexical(bytes).len();
+    let mut nanos = duration.subsec_nanos();
+    if nanos != 0 {
+        // Write all the fractional digits, and drop the trailing zeros.
+        let mut digits = [b'0'; NANOS_DIGITS];
+        for digit in digits.iter_mut().rev() {
+            *digit = b'0' + (nanos % 10) as u8;
+            nanos /= 10;
+        }
+        let count = NANOS_DIGITS - digits.iter().rev().take_while(|&&c| c == b'0').count();
+        index_mut!(bytes[index] = b'.');
+        index += 1;
+        index_mut!(bytes[index..index + count]).copy_from_slice(&index!(digits[..count]));
+        index += count;
+    }
+
+    &mut index_mut!(bytes[..index])
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_duration_secs_test() {
+        assert_eq!(parse_duration_secs(b"0"), Ok(Duration::new(0, 0)));
+        assert_eq!(parse_duration_secs(b"1.5"), Ok(Duration::new(1, 500_000_000)));
+        assert_eq!(parse_duration_secs(b"+2.25"), Ok(Duration::new(2, 250_000_000)));
+        assert_eq!(parse_duration_secs(b".5"), Ok(Duration::new(0, 500_000_000)));
+        assert_eq!(parse_duration_secs(b"0.1"), Ok(Duration::new(0, 100_000_000)));
+        assert_eq!(parse_duration_secs(b"3.000000001"), Ok(Duration::new(3, 1)));
+        assert_eq!(parse_duration_secs(b"3.0000000019"), Ok(Duration::new(3, 1)));
+        assert_eq!(parse_duration_secs(b"18446744073709551615.999999999"), Ok(Duration::new(u64::max_value(), 999_999_999)));
+
+        // Errors.
+        assert_eq!(parse_duration_secs(b""), Err(Error::from((ErrorCode::Empty, 0))));
+        assert_eq!(parse_duration_secs(b"1."), Err(Error::from((ErrorCode::EmptyFraction, 2))));
+        assert_eq!(parse_duration_secs(b"."), Err(Error::from((ErrorCode::EmptyFraction, 1))));
+        assert_eq!(parse_duration_secs(b"-1"), Err(Error::from((ErrorCode::InvalidDigit, 0, b'-'))));
+        assert_eq!(parse_duration_secs(b"1s"), Err(Error::from((ErrorCode::InvalidDigit, 1, b's'))));
+        assert_eq!(parse_duration_secs(b"1.5s"), Err(Error::from((ErrorCode::InvalidDigit, 3, b's'))));
+        assert_eq!(parse_duration_secs(b"1.5.5"), Err(Error::from((ErrorCode::InvalidDigit, 3, b'.'))));
+        assert_eq!(parse_duration_secs(b"1e3"), Err(Error::from((ErrorCode::InvalidDigit, 1, b'e'))));
+        assert_eq!(parse_duration_secs(b"18446744073709551616"), Err(Error::from((ErrorCode::Overflow, 19))));
+    }
+
+    #[test]
+    fn write_duration_secs_test() {
+        let mut buffer = [b'\0'; DURATION_SECS_FORMATTED_SIZE];
+        assert_eq!(write_duration_secs(Duration::new(0, 0), &mut buffer), b"0");
+        assert_eq!(write_duration_secs(Duration::new(1, 500_000_000), &mut buffer), b"1.5");
+        assert_eq!(write_duration_secs(Duration::new(0, 1), &mut buffer), b"0.000000001");
+        assert_eq!(write_duration_secs(Duration::new(12, 340_000), &mut buffer), b"12.00034");
+        assert_eq!(write_duration_secs(Duration::new(u64::max_value(), 999_999_999), &mut buffer), b"18446744073709551615.999999999");
+
+        // Round-trip.
+        for &(secs, nanos) in [(0, 0), (1, 1), (59, 999_999_999), (86400, 100), (u64::max_value(), 0)].iter() {
+            let duration = Duration::new(secs, nanos);
+            let mut buffer = [b'\0'; DURATION_SECS_FORMATTED_SIZE];
+            assert_eq!(parse_duration_secs(write_duration_secs(duration, &mut buffer)), Ok(duration));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn write_duration_secs_small_buffer_test() {
+        let mut buffer = [b'\0'; DURATION_SECS_FORMATTED_SIZE - 1];
+        write_duration_secs(Duration::new(1, 0), &mut buffer);
+    }
+}
@@ -1,5 +1,7 @@
 //! C-compatible error type.
 
+use crate::lib::fmt;
+
 /// Error code, indicating failure type.
 ///
 /// Error messages are designating by an error code of less than 0.
@@ -61,6 +63,14 @@ pub enum ErrorCode {
     ExponentWithoutFraction = -14,
     /// Integer had invalid leading zeros.
     InvalidLeadingZeros = -15,
+    /// Sign was found after the leading sign.
+    InvalidSign = -16,
+    /// Parsed value was outside of the bounds.
+    OutOfRange = -17,
+    /// Parsed value was zero, but must be non-zero.
+    Zero = -18,
+    /// Parsed value had the wrong number of significant digits.
+    InvalidSignificantDigits = -19,
 
     // We may add additional variants later, so ensure that client matching
     // does not depend on exhaustive matching.
@@ -68,6 +78,34 @@ pub enum ErrorCode {
     __Nonexhaustive = -200,
 }
 
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let message = match self {
+            ErrorCode::Overflow                     => "numeric overflow",
+            ErrorCode::Underflow                    => "numeric underflow",
+            ErrorCode::InvalidDigit                 => "invalid digit",
+            ErrorCode::Empty                        => "empty input",
+            ErrorCode::EmptyMantissa                => "empty mantissa",
+            ErrorCode::EmptyExponent                => "empty exponent",
+            ErrorCode::EmptyInteger                 => "empty integer",
+            ErrorCode::EmptyFraction                => "empty fraction",
+            ErrorCode::InvalidPositiveMantissaSign  => "invalid positive mantissa sign",
+            ErrorCode::MissingMantissaSign          => "missing mantissa sign",
+            ErrorCode::InvalidExponent              => "invalid exponent",
+            ErrorCode::InvalidPositiveExponentSign  => "invalid positive exponent sign",
+            ErrorCode::MissingExponentSign          => "missing exponent sign",
+            ErrorCode::ExponentWithoutFraction      => "exponent without fraction",
+            ErrorCode::InvalidLeadingZeros          => "invalid leading zeros",
+            ErrorCode::InvalidSign                  => "invalid sign",
+            ErrorCode::OutOfRange                   => "value out of range",
+            ErrorCode::Zero                         => "zero value",
+            ErrorCode::InvalidSignificantDigits     => "invalid number of significant digits",
+            ErrorCode::__Nonexhaustive              => "unknown error",
+        };
+        f.write_str(message)
+    }
+}
+
 /// Error type for lexical parsing.
 ///
 /// This error is FFI-compatible for interfacing with C code.
@@ -78,18 +116,119 @@ pub struct Error {
     pub code: ErrorCode,
     /// Optional position within the buffer for the error.
     pub index: usize,
+    /// Byte at the index for invalid digit errors, if `has_byte`.
+    pub(crate) byte: u8,
+    /// If the byte at the index was stored.
+    pub(crate) has_byte: bool,
+}
+
+impl Error {
+    /// Get the invalid byte of an invalid digit error.
+    ///
+    /// Returns the byte at the index of the error, if the error is an
+    /// invalid digit from a parser, otherwise, returns None.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate lexical_core;
+    /// # pub fn main() {
+    /// let error = lexical_core::parse::<i32>(b"12x4").unwrap_err();
+    /// assert_eq!(error.failing_byte(), Some(b'x'));
+    /// # }
+    /// ```
+    #[inline]
+    pub fn failing_byte(&self) -> Option<u8> {
+        match self.code {
+            ErrorCode::InvalidDigit if self.has_byte => Some(self.byte),
+            _                                        => None,
+        }
+    }
+
+    /// Store the byte at the index of an invalid digit error.
+    #[inline]
+    pub(crate) fn with_byte(self, bytes: &[u8]) -> Error {
+        match self.code {
+            ErrorCode::InvalidDigit => match bytes.get(self.index) {
+                Some(&byte) => Error { byte, has_byte: true, ..self },
+                None        => self,
+            },
+            _                       => self,
+        }
+    }
+
+    /// Shift the index of the error, keeping the byte.
+    #[inline]
+    pub(crate) fn shift(self, shift: usize) -> Error {
+        Error { index: self.index + shift, ..self }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.failing_byte() {
+            Some(byte)  => {
+                let byte = (byte as char).escape_default();
+                write!(f, "{} '{}' at index {}", self.code, byte, self.index)
+            },
+            None        => write!(f, "{} at index {}", self.code, self.index),
+        }
+    }
 }
 
 impl From<ErrorCode> for Error {
     #[inline]
     fn from(code: ErrorCode) -> Self {
-        Error { code: code, index: 0 }
+        Error { code: code, index: 0, byte: 0, has_byte: false }
     }
 }
 
 impl From<(ErrorCode, usize)> for Error {
     #[inline]
     fn from(error: (ErrorCode, usize)) -> Self {
-        Error { code: error.0, index: error.1 }
+        Error { code: error.0, index: error.1, byte: 0, has_byte: false }
+    }
+}
+
+impl From<(ErrorCode, usize, u8)> for Error {
+    #[inline]
+    fn from(error: (ErrorCode, usize, u8)) -> Self {
+        Error { code: error.0, index: error.1, byte: error.2, has_byte: true }
+    }
+}
+
+// TESTS
+// -----
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_test() {
+        assert_eq!(ErrorCode::Overflow.to_string(), "numeric overflow");
+        assert_eq!(ErrorCode::EmptyExponent.to_string(), "empty exponent");
+        assert_eq!(Error::from((ErrorCode::InvalidDigit, 2, b'x')).to_string(), "invalid digit 'x' at index 2");
+        assert_eq!(Error::from((ErrorCode::InvalidDigit, 1, b'\n')).to_string(), "invalid digit '\\n' at index 1");
+        assert_eq!(Error::from((ErrorCode::InvalidDigit, 2)).to_string(), "invalid digit at index 2");
+        assert_eq!(Error::from(ErrorCode::Empty).to_string(), "empty input at index 0");
+    }
+
+    #[test]
+    fn failing_byte_test() {
+        let error = Error::from((ErrorCode::InvalidDigit, 2)).with_byte(b"12x4");
+        assert_eq!(error.failing_byte(), Some(b'x'));
+        let error = Error::from((ErrorCode::Overflow, 2)).with_byte(b"12x4");
+        assert_eq!(error.failing_byte(), None);
+        assert_eq!(Error::from((ErrorCode::InvalidDigit, 2)).failing_byte(), None);
+        assert_eq!(crate::parse::<u8>(b"1\x005").unwrap_err().failing_byte(), Some(0));
+        assert_eq!(crate::parse::<f64>(b"1.5e").unwrap_err().failing_byte(), None);
+    }
+
+    #[test]
+    fn shift_test() {
+        let error = Error::from((ErrorCode::InvalidDigit, 2)).with_byte(b"12x4").shift(3);
+        assert_eq!(error, Error::from((ErrorCode::InvalidDigit, 5, b'x')));
+        assert_eq!(error.failing_byte(), Some(b'x'));
     }
 }
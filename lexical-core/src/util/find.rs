@@ -0,0 +1,133 @@
+//! Scan a buffer for the first embedded number.
+
+use crate::lib::ops::Range;
+use super::config::get_exponent_default_char;
+use super::error::ErrorCode;
+use super::traits::FromLexical;
+
+// HELPERS
+
+// Determine if the character may start a number: a digit, sign, or `.`.
+#[inline]
+fn is_candidate(c: u8) -> bool {
+    match c {
+        b'0' ..= b'9'       => true,
+        b'+' | b'-' | b'.'  => true,
+        _                   => false,
+    }
+}
+
+// Determine if the character is a decimal digit.
+#[inline]
+fn is_digit(c: u8) -> bool {
+    c >= b'0' && c <= b'9'
+}
+
+// Determine if the subslice contains at least one decimal digit.
+//
+// Signs and special values, like `-` or `-inf`, are not numbers
+// when scraping text, only as complete inputs to `parse`.
+#[inline]
+fn has_digit(bytes: &[u8]) -> bool {
+    bytes.iter().any(|&c| is_digit(c))
+}
+
+// Get the index of the exponent character, if present.
+#[inline]
+fn exponent_index(bytes: &[u8]) -> Option<usize> {
+    let exponent = get_exponent_default_char().to_ascii_lowercase();
+    bytes.iter().position(|&c| c.to_ascii_lowercase() == exponent)
+}
+
+// Attempt to parse a number at the start of the buffer.
+//
+// A dangling exponent, such as `1e` in `1em`, belongs to the surrounding
+// text, so the mantissa is re-parsed without it.
+#[inline]
+fn parse_candidate<N: FromLexical>(bytes: &[u8])
+    -> Option<(N, usize)>
+{
+    let (value, count) = match N::from_lexical_partial(bytes) {
+        Ok(result) => result,
+        Err(error) if error.code == ErrorCode::EmptyExponent => {
+            let index = exponent_index(bytes)?;
+            N::from_lexical_partial(&index!(bytes[..index])).ok()?
+        },
+        Err(_) => return None,
+    };
+
+    match has_digit(&index!(bytes[..count])) {
+        true  => Some((value, count)),
+        false => None,
+    }
+}
+
+// API
+
+/// Find and parse the first number embedded in a buffer.
+///
+/// Scans forward to the first position at which a valid decimal number
+/// begins, a digit, sign, or `.`, and parses as much of the number as
+/// possible from that position. A number never starts in the middle
+/// of a run of digits, so an invalid number, such as one that overflows,
+/// is skipped as a whole. Returns the parsed value and the range
+/// of bytes it was parsed from, or `None` if the buffer contains no
+/// number. Candidates without any digits, such as a lone `-` or
+/// `-inf`, are skipped.
+///
+/// * `bytes`   - Byte slice to scan for a number.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # pub fn main() {
+/// assert_eq!(lexical_core::find_number::<f64>(b"abc-12.5xyz"), Some((-12.5, 3..8)));
+/// assert_eq!(lexical_core::find_number::<i32>(b"id: 42;"), Some((42, 4..6)));
+/// assert_eq!(lexical_core::find_number::<f64>(b"no numbers"), None);
+/// # }
+/// ```
+pub fn find_number<N: FromLexical>(bytes: &[u8])
+    -> Option<(N, Range<usize>)>
+{
+    let mut previous = b'\0';
+    for (start, &c) in bytes.iter().enumerate() {
+        if is_candidate(c) && !is_digit(previous) {
+            if let Some((value, count)) = parse_candidate::<N>(&index!(bytes[start..])) {
+                return Some((value, start..start + count));
+            }
+        }
+        previous = c;
+    }
+    None
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_number_float_test() {
+        assert_eq!(find_number::<f64>(b"abc-12.5xyz"), Some((-12.5, 3..8)));
+        assert_eq!(find_number::<f64>(b"1.5"), Some((1.5, 0..3)));
+        assert_eq!(find_number::<f64>(b"x=+.5;"), Some((0.5, 2..5)));
+        assert_eq!(find_number::<f64>(b"t 1e5s"), Some((1e5, 2..5)));
+        assert_eq!(find_number::<f64>(b"1.5em"), Some((1.5, 0..3)));
+        assert_eq!(find_number::<f64>(b"- . 7"), Some((7.0, 4..5)));
+        assert_eq!(find_number::<f64>(b"-info 3"), Some((3.0, 6..7)));
+        assert_eq!(find_number::<f64>(b""), None);
+        assert_eq!(find_number::<f64>(b"abc-.xyz"), None);
+    }
+
+    #[test]
+    fn find_number_integer_test() {
+        assert_eq!(find_number::<i32>(b"abc-12.5xyz"), Some((-12, 3..6)));
+        assert_eq!(find_number::<u8>(b"rate: 255/s"), Some((255, 6..9)));
+        assert_eq!(find_number::<i32>(b"a - b 7"), Some((7, 6..7)));
+        assert_eq!(find_number::<u8>(b"256 1"), Some((1, 4..5)));
+        assert_eq!(find_number::<i32>(b"abc"), None);
+    }
+}
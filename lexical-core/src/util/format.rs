@@ -785,7 +785,7 @@ if #[cfg(not(feature = "format"))] {
         ///
         ///  32  33  34  35  36  37  38  39  40  41  42  43  44  45  46  47
         /// +---+---+---+---+---+---+---+---+---+---+---+---+---+---+---+---+
-        /// |I/I|F/I|E/I|I/L|F/L|E/L|I/T|F/T|E/T|I/C|F/C|E/C|S/D| RESERVED  |
+        /// |I/I|F/I|E/I|I/L|F/L|E/L|I/T|F/T|E/T|I/C|F/C|E/C|S/D|D/P|RESERVED |
         /// +---+---+---+---+---+---+---+---+---+---+---+---+---+---+---+---+
         ///
         ///  48  49  50  51  52  53  54  55  56  57  58  59  60  62  62  63
@@ -818,6 +818,7 @@ if #[cfg(not(feature = "format"))] {
         ///     F/C = Fraction consecutive digit separator.
         ///     E/C = Exponent consecutive digit separator.
         ///     S/D = Special (non-finite) digit separator.
+        ///     D/P = No digit separator adjacent to the decimal point.
         /// ```
         ///
         /// Note:
@@ -867,6 +868,7 @@ if #[cfg(not(feature = "format"))] {
         /// L: 'In_f'       // Special (non-finite) digit separator.
         /// M: '010'        // No integer leading zeros.
         /// N: '010.0'      // No float leading zeros.
+        /// O: '3_._01'     // Digit separator adjacent to the decimal point.
         /// ```
         ///
         /// Currently Supported Programming and Data Languages:
@@ -939,6 +941,8 @@ if #[cfg(not(feature = "format"))] {
                 | Self::TRAILING_DIGIT_SEPARATOR.bits
                 | Self::CONSECUTIVE_DIGIT_SEPARATOR.bits
                 | Self::SPECIAL_DIGIT_SEPARATOR.bits
+                | Self::NO_DECIMAL_POINT_DIGIT_SEPARATOR.bits
+                | Self::GROUPED_DIGIT_SEPARATOR.bits
             );
 
             /// Mask to extract the flag bits controlling interface parsing.
@@ -988,6 +992,13 @@ if #[cfg(not(feature = "format"))] {
                 | Self::FRACTION_CONSECUTIVE_DIGIT_SEPARATOR.bits
             );
 
+            /// Mask to extract mantissa digit separator flags.
+            #[doc(hidden)]
+            const MANTISSA_DIGIT_SEPARATOR_FLAG_MASK     = (
+                Self::INTEGER_DIGIT_SEPARATOR_FLAG_MASK.bits
+                | Self::FRACTION_DIGIT_SEPARATOR_FLAG_MASK.bits
+            );
+
             /// Mask to extract exponent digit separator flags.
             #[doc(hidden)]
             const EXPONENT_DIGIT_SEPARATOR_FLAG_MASK     = (
@@ -1187,9 +1198,29 @@ if #[cfg(not(feature = "format"))] {
             );
 
             /// Any digit separators are allowed in special (non-finite) values.
+            ///
+            /// Without this flag, special values are matched against the
+            /// raw bytes, so a digit separator within them, like `n_an`,
+            /// is rejected as an invalid digit.
             #[doc(hidden)]
             const SPECIAL_DIGIT_SEPARATOR               = 0b0000000000000000000100000000000000000000000000000000000000000000;
 
+            /// Digit separators are not allowed adjacent to the decimal point.
+            ///
+            /// This disallows trailing integer and leading fraction digit
+            /// separators immediately before or after the decimal point,
+            /// so both `1_.5` and `1._5` are invalid, while trailing and
+            /// leading digit separators are otherwise unaffected.
+            const NO_DECIMAL_POINT_DIGIT_SEPARATOR      = 0b0000000000000000001000000000000000000000000000000000000000000000;
+
+            /// Integer digit separators must separate groups of 3 digits.
+            ///
+            /// Groups are counted from the decimal point outward, and the
+            /// first group may have 1 to 3 digits, so `1,234,567` is
+            /// valid, while `12,34,567` and `1,2` are invalid. Integers
+            /// without digit separators are always valid.
+            const GROUPED_DIGIT_SEPARATOR               = 0b0000000000000000010000000000000000000000000000000000000000000000;
+
             // PRE-DEFINED
             //
             // Sample Format Shorthand:
@@ -2142,6 +2173,8 @@ if #[cfg(not(feature = "format"))] {
     check_subsequent_flags!(EXPONENT_LEADING_DIGIT_SEPARATOR, EXPONENT_TRAILING_DIGIT_SEPARATOR);
     check_subsequent_flags!(EXPONENT_TRAILING_DIGIT_SEPARATOR, EXPONENT_CONSECUTIVE_DIGIT_SEPARATOR);
     check_subsequent_flags!(EXPONENT_CONSECUTIVE_DIGIT_SEPARATOR, SPECIAL_DIGIT_SEPARATOR);
+    check_subsequent_flags!(SPECIAL_DIGIT_SEPARATOR, NO_DECIMAL_POINT_DIGIT_SEPARATOR);
+    check_subsequent_flags!(NO_DECIMAL_POINT_DIGIT_SEPARATOR, GROUPED_DIGIT_SEPARATOR);
 
     /// Add flag to flags
     macro_rules! add_flag {
@@ -2322,6 +2355,84 @@ if #[cfg(not(feature = "format"))] {
             Some(format)
         }
 
+        /// Compile ignore number format, with digit separators limited to the mantissa or exponent.
+        ///
+        /// Like [`ignore`](#method.ignore), digit separators are ignored
+        /// anywhere within the digits, and all other control grammar is
+        /// permissive, however, digit separators are only allowed in the
+        /// scopes that are enabled. The mantissa scope includes the integer
+        /// and fraction digits, as well as special (non-finite) values.
+        ///
+        /// * `digit_separator`                         - Character to separate digits.
+        /// * `separator_in_mantissa`                   - If digit separators are allowed in the mantissa.
+        /// * `separator_in_exponent`                   - If digit separators are allowed in the exponent.
+        ///
+        /// Returns the value if it was able to compile the format,
+        /// otherwise, returns None.
+        pub fn ignore_scoped(digit_separator: u8, separator_in_mantissa: bool, separator_in_exponent: bool)
+            -> Option<NumberFormat>
+        {
+            let mut format = NumberFormat::default();
+            if separator_in_mantissa {
+                format |= NumberFormat::MANTISSA_DIGIT_SEPARATOR_FLAG_MASK;
+                format |= NumberFormat::SPECIAL_DIGIT_SEPARATOR;
+            }
+            if separator_in_exponent {
+                format |= NumberFormat::EXPONENT_DIGIT_SEPARATOR_FLAG_MASK;
+            }
+
+            if !is_valid_separator(digit_separator) {
+                return None
+            } else if format.intersects(NumberFormat::DIGIT_SEPARATOR_FLAG_MASK) {
+                format.bits |= digit_separator_to_flags(digit_separator);
+            }
+
+            Some(format)
+        }
+
+        /// Compile number format for grouped integer digits, like `1 234 567`.
+        ///
+        /// Grouping separators are only allowed in the integer digits,
+        /// and all other control grammar is permissive.
+        ///
+        /// In strict mode, groups must have 3 digits, counted from the
+        /// decimal point outward, so `1 2` is invalid, and a grouping
+        /// separator must be between two integer digits, otherwise, it
+        /// ends the number, so a space after the number is not consumed.
+        /// Other group sizes can be checked with
+        /// [`ParseFloatOptions::grouping_sizes`](struct.ParseFloatOptions.html#method.grouping_sizes).
+        ///
+        /// In lenient mode, the sizes of the groups are not validated,
+        /// so `1 2` is parsed as `12`, in any radix, like `DE AD BE EF`
+        /// in radix 16. To validate hexadecimal groups of 2 digits, use
+        /// [`parse_hex_grouped`](fn.parse_hex_grouped.html). Multiple
+        /// consecutive grouping separators, and grouping separators
+        /// trailing the integer digits, are also skipped. Leading
+        /// grouping separators are never allowed, so whitespace before
+        /// the number is not skipped.
+        ///
+        /// * `grouping_separator`                      - Character to separate groups of digits.
+        /// * `strict`                                  - If separators must be between groups of 3 integer digits.
+        ///
+        /// Returns the value if it was able to compile the format,
+        /// otherwise, returns None.
+        pub fn grouping(grouping_separator: u8, strict: bool) -> Option<NumberFormat> {
+            if !is_valid_separator(grouping_separator) {
+                return None
+            }
+
+            let mut format = NumberFormat::INTEGER_INTERNAL_DIGIT_SEPARATOR;
+            if strict {
+                format |= NumberFormat::GROUPED_DIGIT_SEPARATOR;
+            } else {
+                format |= NumberFormat::INTEGER_TRAILING_DIGIT_SEPARATOR;
+                format |= NumberFormat::INTEGER_CONSECUTIVE_DIGIT_SEPARATOR;
+            }
+            format.bits |= digit_separator_to_flags(grouping_separator);
+
+            Some(format)
+        }
+
         /// Create float format directly from digit separator for unittests.
         #[cfg(test)]
         #[inline]
@@ -2528,10 +2639,50 @@ if #[cfg(not(feature = "format"))] {
         }
 
         /// Get if any digit separators are allowed in special (non-finite) values.
+        ///
+        /// If not allowed, special values are matched against the raw
+        /// bytes, so a digit separator within them, like `n_an`, is
+        /// rejected as an invalid digit.
         #[inline]
         pub fn special_digit_separator(self) -> bool {
             self.intersects(NumberFormat::SPECIAL_DIGIT_SEPARATOR)
         }
+
+        /// Get if a digit separator is allowed adjacent to the decimal point.
+        ///
+        /// Digit separators are allowed before the decimal point with
+        /// integer trailing digit separators, and after the decimal point
+        /// with fraction leading digit separators, unless disallowed
+        /// with [`NO_DECIMAL_POINT_DIGIT_SEPARATOR`]. If disallowed, the
+        /// number ends at the first digit separator, so `1_.5` and `1._5`
+        /// are invalid, while `1_0.5` is valid.
+        ///
+        /// [`NO_DECIMAL_POINT_DIGIT_SEPARATOR`]: #associatedconstant.NO_DECIMAL_POINT_DIGIT_SEPARATOR
+        #[inline]
+        pub fn separator_around_decimal_point(self) -> bool {
+            !self.intersects(NumberFormat::NO_DECIMAL_POINT_DIGIT_SEPARATOR)
+                && self.intersects(NumberFormat::INTEGER_TRAILING_DIGIT_SEPARATOR | NumberFormat::FRACTION_LEADING_DIGIT_SEPARATOR)
+        }
+
+        /// Get if integer digit separators must separate groups of 3 digits.
+        #[inline]
+        pub fn grouped_digit_separator(self) -> bool {
+            self.intersects(NumberFormat::GROUPED_DIGIT_SEPARATOR)
+        }
+
+        /// Get if any digit separators are allowed in the mantissa.
+        ///
+        /// The mantissa includes both the integer and fraction digits.
+        #[inline]
+        pub fn separator_in_mantissa(self) -> bool {
+            self.intersects(NumberFormat::MANTISSA_DIGIT_SEPARATOR_FLAG_MASK)
+        }
+
+        /// Get if any digit separators are allowed in the exponent.
+        #[inline]
+        pub fn separator_in_exponent(self) -> bool {
+            self.intersects(NumberFormat::EXPONENT_DIGIT_SEPARATOR_FLAG_MASK)
+        }
     }
 
     // TESTS
@@ -2563,6 +2714,72 @@ if #[cfg(not(feature = "format"))] {
             assert_eq!(flags.flags(), NumberFormat::DIGIT_SEPARATOR_FLAG_MASK);
         }
 
+        #[test]
+        fn test_ignore_scoped() {
+            let flags = NumberFormat::ignore_scoped(b'_', true, true).unwrap();
+            assert_eq!(flags, NumberFormat::ignore(b'_').unwrap());
+            assert_eq!(flags.separator_in_mantissa(), true);
+            assert_eq!(flags.separator_in_exponent(), true);
+
+            let flags = NumberFormat::ignore_scoped(b'_', true, false).unwrap();
+            assert_eq!(flags.digit_separator(), b'_');
+            assert_eq!(flags.separator_in_mantissa(), true);
+            assert_eq!(flags.separator_in_exponent(), false);
+            assert_eq!(flags.integer_internal_digit_separator(), true);
+            assert_eq!(flags.fraction_consecutive_digit_separator(), true);
+            assert_eq!(flags.exponent_internal_digit_separator(), false);
+            assert_eq!(flags.special_digit_separator(), true);
+
+            let flags = NumberFormat::ignore_scoped(b'_', false, true).unwrap();
+            assert_eq!(flags.digit_separator(), b'_');
+            assert_eq!(flags.separator_in_mantissa(), false);
+            assert_eq!(flags.separator_in_exponent(), true);
+            assert_eq!(flags.integer_internal_digit_separator(), false);
+            assert_eq!(flags.exponent_leading_digit_separator(), true);
+            assert_eq!(flags.special_digit_separator(), false);
+
+            let flags = NumberFormat::ignore_scoped(b'_', false, false).unwrap();
+            assert_eq!(flags, NumberFormat::default());
+            assert_eq!(flags.digit_separator(), 0);
+            assert!(NumberFormat::ignore_scoped(b'0', true, true).is_none());
+        }
+
+        #[test]
+        fn test_separator_around_decimal_point() {
+            let flags = NumberFormat::ignore(b'_').unwrap();
+            assert_eq!(flags.separator_around_decimal_point(), true);
+
+            let flags = flags | NumberFormat::NO_DECIMAL_POINT_DIGIT_SEPARATOR;
+            assert_eq!(flags.separator_around_decimal_point(), false);
+            assert_eq!(flags.integer_trailing_digit_separator(), true);
+            assert_eq!(flags.fraction_leading_digit_separator(), true);
+
+            let flags = NumberFormat::grouping(b' ', true).unwrap();
+            assert_eq!(flags.separator_around_decimal_point(), false);
+            assert_eq!(NumberFormat::default().separator_around_decimal_point(), false);
+        }
+
+        #[test]
+        fn test_grouping() {
+            let flags = NumberFormat::grouping(b' ', true).unwrap();
+            assert_eq!(flags.digit_separator(), b' ');
+            assert_eq!(flags.integer_internal_digit_separator(), true);
+            assert_eq!(flags.integer_leading_digit_separator(), false);
+            assert_eq!(flags.integer_trailing_digit_separator(), false);
+            assert_eq!(flags.integer_consecutive_digit_separator(), false);
+            assert_eq!(flags.fraction_internal_digit_separator(), false);
+            assert_eq!(flags.grouped_digit_separator(), true);
+
+            let flags = NumberFormat::grouping(b' ', false).unwrap();
+            assert_eq!(flags.integer_internal_digit_separator(), true);
+            assert_eq!(flags.integer_leading_digit_separator(), false);
+            assert_eq!(flags.integer_trailing_digit_separator(), true);
+            assert_eq!(flags.integer_consecutive_digit_separator(), true);
+            assert_eq!(flags.exponent_internal_digit_separator(), false);
+            assert_eq!(flags.grouped_digit_separator(), false);
+            assert!(NumberFormat::grouping(b'.', true).is_none());
+        }
+
         #[test]
         fn test_ignore() {
             let flags = NumberFormat::ignore(b'_').unwrap();
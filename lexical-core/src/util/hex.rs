@@ -0,0 +1,231 @@
+//! Parse fixed-width and grouped, hexadecimal integers.
+
+use super::cast::as_cast;
+use super::error::{Error, ErrorCode};
+use super::num::{Integer, UnsignedInteger};
+use super::result::Result;
+
+// HELPERS
+
+// Convert a hexadecimal character to its value.
+perftools_inline!{
+fn hex_digit(c: u8) -> Option<u8> {
+    match c {
+        b'0' ..= b'9'   => Some(c - b'0'),
+        b'a' ..= b'f'   => Some(c - b'a' + 10),
+        b'A' ..= b'F'   => Some(c - b'A' + 10),
+        _               => None,
+    }
+}}
+
+// Shift the hexadecimal character into the value.
+perftools_inline!{
+fn push_hex_digit<T: UnsignedInteger>(value: T, c: u8, index: usize) -> Result<T> {
+    let digit = hex_digit(c).ok_or(Error::from((ErrorCode::InvalidDigit, index, c)))?;
+    // Any set bit in the top nibble overflows on the shift.
+    if value >> (T::BITS - 4) != T::ZERO {
+        return Err(Error::from((ErrorCode::Overflow, index)));
+    }
+    Ok((value << 4) | as_cast(digit))
+}}
+
+// Accumulate hexadecimal digits by shifting, without a sign or prefix.
+perftools_inline!{
+fn parse_hex_digits<T: UnsignedInteger>(bytes: &[u8]) -> Result<T> {
+    if bytes.is_empty() {
+        return Err(Error::from((ErrorCode::Empty, 0)));
+    }
+
+    let mut value = T::ZERO;
+    for (index, &c) in bytes.iter().enumerate() {
+        value = push_hex_digit(value, c, index)?;
+    }
+    Ok(value)
+}}
+
+// API
+
+/// Parse an unsigned, hexadecimal integer.
+///
+/// Digits are case-insensitive, and neither a sign nor a `0x` prefix is
+/// accepted. Digits are accumulated by shifting, rather than through the
+/// generic radix parser, so this is available without the `radix`
+/// feature. Leading zeros are allowed, while values that do not fit
+/// are reported as [`Overflow`](enum.ErrorCode.html#variant.Overflow).
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::{Error, ErrorCode};
+/// # pub fn main() {
+/// assert_eq!(lexical_core::parse_hex::<u32>(b"deadbeef"), Ok(0xdeadbeef));
+/// assert_eq!(lexical_core::parse_hex::<u32>(b"FF"), Ok(0xFF));
+/// assert_eq!(lexical_core::parse_hex::<u32>(b"0xFF"), Err(Error::from((ErrorCode::InvalidDigit, 1, b'x'))));
+/// # }
+/// ```
+pub fn parse_hex<T: UnsignedInteger>(bytes: &[u8]) -> Result<T> {
+    parse_hex_digits(bytes)
+}
+
+/// Parse an unsigned, hexadecimal integer filling the full width of the type.
+///
+/// The bytes must have exactly 2 digits per byte of the type, like 8
+/// digits for `u32`, otherwise, the digits are parsed the same as
+/// [`parse_hex`]. Longer inputs are reported as
+/// [`InvalidDigit`](enum.ErrorCode.html#variant.InvalidDigit) at the
+/// first byte past the width, and shorter inputs are reported as
+/// [`Empty`](enum.ErrorCode.html#variant.Empty) at the end of the bytes.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::{Error, ErrorCode};
+/// # pub fn main() {
+/// assert_eq!(lexical_core::parse_hex_fixed::<u32>(b"0000beef"), Ok(0xbeef));
+/// assert_eq!(lexical_core::parse_hex_fixed::<u32>(b"beef"), Err(Error::from((ErrorCode::Empty, 4))));
+/// assert_eq!(lexical_core::parse_hex_fixed::<u16>(b"beef0"), Err(Error::from((ErrorCode::InvalidDigit, 4, b'0'))));
+/// # }
+/// ```
+///
+/// [`parse_hex`]: fn.parse_hex.html
+pub fn parse_hex_fixed<T: UnsignedInteger>(bytes: &[u8]) -> Result<T> {
+    let width = T::BITS / 4;
+    if bytes.len() < width {
+        Err(Error::from((ErrorCode::Empty, bytes.len())))
+    } else if bytes.len() > width {
+        Err(Error::from((ErrorCode::InvalidDigit, width)).with_byte(bytes))
+    } else {
+        parse_hex_digits(bytes)
+    }
+}
+
+/// Parse an unsigned, hexadecimal integer in groups of 2 digits, like a hex dump.
+///
+/// Every group must have exactly 2 digits, for a single byte, and the
+/// groups must be separated by exactly one separator, like `DE AD BE EF`,
+/// otherwise, the digits are parsed the same as [`parse_hex`]. A
+/// separator where a digit is expected, or any other byte where a
+/// separator is expected, is reported as
+/// [`InvalidDigit`](enum.ErrorCode.html#variant.InvalidDigit), and
+/// a missing digit at the end of the bytes, like in `DE A`, or a
+/// trailing separator, is reported as
+/// [`Empty`](enum.ErrorCode.html#variant.Empty) at the end of the
+/// bytes. For groups of any size, parse with
+/// [`NumberFormat::grouping`] in radix 16.
+///
+/// * `bytes`       - Byte slice containing a numeric string.
+/// * `separator`   - Character separating the groups, which should not be a hexadecimal digit.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::{Error, ErrorCode};
+/// # pub fn main() {
+/// assert_eq!(lexical_core::parse_hex_grouped::<u32>(b"DE AD BE EF", b' '), Ok(0xDEADBEEF));
+/// assert_eq!(lexical_core::parse_hex_grouped::<u16>(b"ca:fe", b':'), Ok(0xCAFE));
+/// assert_eq!(lexical_core::parse_hex_grouped::<u32>(b"DE A", b' '), Err(Error::from((ErrorCode::Empty, 4))));
+/// assert_eq!(lexical_core::parse_hex_grouped::<u32>(b"DEA D", b' '), Err(Error::from((ErrorCode::InvalidDigit, 2, b'A'))));
+/// # }
+/// ```
+///
+/// [`parse_hex`]: fn.parse_hex.html
+/// [`NumberFormat::grouping`]: struct.NumberFormat.html#method.grouping
+pub fn parse_hex_grouped<T: UnsignedInteger>(bytes: &[u8], separator: u8) -> Result<T> {
+    if bytes.is_empty() {
+        return Err(Error::from((ErrorCode::Empty, 0)));
+    }
+
+    let mut value = T::ZERO;
+    for (index, &c) in bytes.iter().enumerate() {
+        // Every third byte separates the groups.
+        match index % 3 {
+            2 if c == separator => continue,
+            2                   => return Err(Error::from((ErrorCode::InvalidDigit, index, c))),
+            _                   => value = push_hex_digit(value, c, index)?,
+        }
+    }
+    match bytes.len() % 3 {
+        2 => Ok(value),
+        _ => Err(Error::from((ErrorCode::Empty, bytes.len()))),
+    }
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_hex_test() {
+        assert_eq!(parse_hex::<u8>(b"0"), Ok(0));
+        assert_eq!(parse_hex::<u8>(b"ff"), Ok(0xff));
+        assert_eq!(parse_hex::<u8>(b"000Ff"), Ok(0xff));
+        assert_eq!(parse_hex::<u16>(b"aBcD"), Ok(0xabcd));
+        assert_eq!(parse_hex::<u32>(b"deadbeef"), Ok(0xdeadbeef));
+        assert_eq!(parse_hex::<u64>(b"0123456789abcdef"), Ok(0x0123456789abcdef));
+        assert_eq!(parse_hex::<u128>(b"ffffffffffffffffffffffffffffffff"), Ok(u128::max_value()));
+
+        // Errors.
+        assert_eq!(parse_hex::<u8>(b""), Err(Error::from((ErrorCode::Empty, 0))));
+        assert_eq!(parse_hex::<u8>(b"100"), Err(Error::from((ErrorCode::Overflow, 2))));
+        assert_eq!(parse_hex::<u32>(b"deadbeef0"), Err(Error::from((ErrorCode::Overflow, 8))));
+        assert_eq!(parse_hex::<u32>(b"fg"), Err(Error::from((ErrorCode::InvalidDigit, 1, b'g'))));
+        assert_eq!(parse_hex::<u32>(b"+f"), Err(Error::from((ErrorCode::InvalidDigit, 0, b'+'))));
+        assert_eq!(parse_hex::<u32>(b"0xf"), Err(Error::from((ErrorCode::InvalidDigit, 1, b'x'))));
+    }
+
+    #[test]
+    fn parse_hex_fixed_test() {
+        assert_eq!(parse_hex_fixed::<u8>(b"0a"), Ok(0x0a));
+        assert_eq!(parse_hex_fixed::<u16>(b"FFFF"), Ok(0xffff));
+        assert_eq!(parse_hex_fixed::<u32>(b"deadbeef"), Ok(0xdeadbeef));
+        assert_eq!(parse_hex_fixed::<u32>(b"00000000"), Ok(0));
+        assert_eq!(parse_hex_fixed::<u64>(b"00000000deadbeef"), Ok(0xdeadbeef));
+
+        // Underlength inputs.
+        assert_eq!(parse_hex_fixed::<u32>(b""), Err(Error::from((ErrorCode::Empty, 0))));
+        assert_eq!(parse_hex_fixed::<u32>(b"beef"), Err(Error::from((ErrorCode::Empty, 4))));
+        assert_eq!(parse_hex_fixed::<u32>(b"deadbee"), Err(Error::from((ErrorCode::Empty, 7))));
+
+        // Overlength inputs.
+        assert_eq!(parse_hex_fixed::<u8>(b"0ff"), Err(Error::from((ErrorCode::InvalidDigit, 2, b'f'))));
+        assert_eq!(parse_hex_fixed::<u32>(b"deadbeef0"), Err(Error::from((ErrorCode::InvalidDigit, 8, b'0'))));
+
+        // Invalid digits within the width.
+        assert_eq!(parse_hex_fixed::<u32>(b"deadbeeg"), Err(Error::from((ErrorCode::InvalidDigit, 7, b'g'))));
+        assert_eq!(parse_hex_fixed::<u16>(b"0x0f"), Err(Error::from((ErrorCode::InvalidDigit, 1, b'x'))));
+    }
+
+    #[test]
+    fn parse_hex_grouped_test() {
+        assert_eq!(parse_hex_grouped::<u8>(b"ff", b' '), Ok(0xff));
+        assert_eq!(parse_hex_grouped::<u32>(b"DE AD BE EF", b' '), Ok(0xdeadbeef));
+        assert_eq!(parse_hex_grouped::<u32>(b"de-ad-be-ef", b'-'), Ok(0xdeadbeef));
+        assert_eq!(parse_hex_grouped::<u32>(b"00 00 BE EF", b' '), Ok(0xbeef));
+        assert_eq!(parse_hex_grouped::<u64>(b"01:23:45:67:89:AB:CD:EF", b':'), Ok(0x0123456789abcdef));
+
+        // Malformed groups.
+        assert_eq!(parse_hex_grouped::<u32>(b"", b' '), Err(Error::from((ErrorCode::Empty, 0))));
+        assert_eq!(parse_hex_grouped::<u32>(b"DE A", b' '), Err(Error::from((ErrorCode::Empty, 4))));
+        assert_eq!(parse_hex_grouped::<u32>(b"D", b' '), Err(Error::from((ErrorCode::Empty, 1))));
+        assert_eq!(parse_hex_grouped::<u32>(b"DE ", b' '), Err(Error::from((ErrorCode::Empty, 3))));
+        assert_eq!(parse_hex_grouped::<u32>(b"DEAD", b' '), Err(Error::from((ErrorCode::InvalidDigit, 2, b'A'))));
+        assert_eq!(parse_hex_grouped::<u32>(b"DE A BE", b' '), Err(Error::from((ErrorCode::InvalidDigit, 4, b' '))));
+        assert_eq!(parse_hex_grouped::<u32>(b"DE  AD", b' '), Err(Error::from((ErrorCode::InvalidDigit, 3, b' '))));
+        assert_eq!(parse_hex_grouped::<u32>(b" DE", b' '), Err(Error::from((ErrorCode::InvalidDigit, 0, b' '))));
+        assert_eq!(parse_hex_grouped::<u32>(b"DE:AD", b' '), Err(Error::from((ErrorCode::InvalidDigit, 2, b':'))));
+
+        // Errors.
+        assert_eq!(parse_hex_grouped::<u32>(b"DE AD BE EG", b' '), Err(Error::from((ErrorCode::InvalidDigit, 10, b'G'))));
+        assert_eq!(parse_hex_grouped::<u32>(b"DE AD BE EF 00", b' '), Err(Error::from((ErrorCode::Overflow, 12))));
+    }
+}
@@ -0,0 +1,267 @@
+//! Parse decimal floats split across chunks of bytes.
+
+use crate::lib::{fmt, marker};
+use super::error::{Error, ErrorCode};
+use super::num::Float;
+use super::result::Result;
+use super::traits::FromLexical;
+
+/// Maximum number of bytes in a float parsed by an [`IncrementalParser`].
+///
+/// [`IncrementalParser`]: struct.IncrementalParser.html
+pub const INCREMENTAL_MAX_LENGTH: usize = 128;
+
+/// Result of feeding bytes to an [`IncrementalParser`].
+///
+/// [`IncrementalParser`]: struct.IncrementalParser.html
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FeedResult<F> {
+    /// Every byte may be part of the float, so more bytes are needed.
+    NeedMore,
+    /// The float ended, with the value and the number of bytes consumed.
+    Done(F, usize),
+    /// The float ended, and was invalid, with the error and the number of bytes consumed.
+    Error(Error, usize),
+}
+
+/// Resumable parser for decimal floats split across chunks of bytes.
+///
+/// Bytes are buffered across calls to [`feed`], without allocating,
+/// until the float ends at a byte that cannot continue it, so a float
+/// split across network reads is parsed without buffering the entire
+/// stream. The float ends at any byte that is not an ASCII letter or
+/// digit, a `.`, or a sign, and at a sign that does not start the
+/// float or follow an `e` or `E`, like the `,` in `1.5,` or the `-`
+/// in `1-2`. The buffered bytes are parsed like [`parse`], so letters
+/// are only valid in the exponent and special values, and the float
+/// ends at the end of the stream with [`finish`].
+///
+/// Floats may span at most [`INCREMENTAL_MAX_LENGTH`] bytes, which
+/// fits the shortest representation of any `f32` or `f64`, with many
+/// extra digits, but not the exact decimal expansion of every float,
+/// like tiny subnormals. The rest of a longer float is skipped, and
+/// once the float ends, it is reported as
+/// [`Overflow`](enum.ErrorCode.html#variant.Overflow), at the first
+/// byte that does not fit. Error indexes are relative to the start of
+/// the float, including bytes from previous chunks. The parser is
+/// reset after every float or error, so it parses the next float in
+/// the stream.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::{FeedResult, IncrementalParser};
+/// # pub fn main() {
+/// let mut parser = IncrementalParser::<f64>::new();
+/// assert_eq!(parser.feed(b"-12.5"), FeedResult::NeedMore);
+/// assert_eq!(parser.feed(b"e-"), FeedResult::NeedMore);
+/// assert_eq!(parser.feed(b"1,2"), FeedResult::Done(-1.25, 1));
+/// assert_eq!(parser.feed(b"2"), FeedResult::NeedMore);
+/// assert_eq!(parser.finish(), Ok(2.0));
+/// # }
+/// ```
+///
+/// [`feed`]: #method.feed
+/// [`finish`]: #method.finish
+/// [`parse`]: fn.parse.html
+/// [`INCREMENTAL_MAX_LENGTH`]: constant.INCREMENTAL_MAX_LENGTH.html
+pub struct IncrementalParser<F> {
+    /// Bytes of the float from previous chunks.
+    buffer: [u8; INCREMENTAL_MAX_LENGTH],
+    /// Number of buffered bytes.
+    length: usize,
+    /// If the float did not fit in the buffer, and the rest is skipped.
+    overflow: bool,
+    float: marker::PhantomData<F>,
+}
+
+impl<F: FromLexical + Float> IncrementalParser<F> {
+    /// Create a parser without any buffered bytes.
+    #[inline]
+    pub fn new() -> IncrementalParser<F> {
+        IncrementalParser {
+            buffer: [0; INCREMENTAL_MAX_LENGTH],
+            length: 0,
+            overflow: false,
+            float: marker::PhantomData,
+        }
+    }
+
+    /// Get the bytes of the float buffered from previous chunks.
+    #[inline]
+    pub fn buffered(&self) -> &[u8] {
+        &index!(self.buffer[..self.length])
+    }
+
+    /// Feed the next chunk of bytes to the parser.
+    ///
+    /// Returns [`NeedMore`] if every byte may be part of the float,
+    /// otherwise, parses the float and returns the value or the error,
+    /// and the number of bytes consumed from the chunk, not including
+    /// the byte ending the float. An empty chunk needs more bytes.
+    ///
+    /// * `bytes`   - Next chunk of bytes in the stream.
+    ///
+    /// [`NeedMore`]: enum.FeedResult.html#variant.NeedMore
+    pub fn feed(&mut self, bytes: &[u8]) -> FeedResult<F> {
+        for (index, &c) in bytes.iter().enumerate() {
+            if !self.is_float_byte(c) {
+                return match self.parse() {
+                    Ok(value)  => FeedResult::Done(value, index),
+                    Err(error) => FeedResult::Error(error, index),
+                };
+            }
+            let buffer = &mut self.buffer;
+            if self.length == INCREMENTAL_MAX_LENGTH {
+                // Skip the rest of the float, only keeping the last byte
+                // to check if a sign continues the float.
+                self.overflow = true;
+                index_mut!(buffer[INCREMENTAL_MAX_LENGTH - 1] = c);
+            } else {
+                index_mut!(buffer[self.length] = c);
+                self.length += 1;
+            }
+        }
+        FeedResult::NeedMore
+    }
+
+    /// Parse the buffered float at the end of the stream.
+    ///
+    /// The buffered bytes are parsed like [`parse`], so the stream
+    /// must end with a complete float.
+    ///
+    /// [`parse`]: fn.parse.html
+    #[inline]
+    pub fn finish(&mut self) -> Result<F> {
+        self.parse()
+    }
+
+    // Check if the byte may continue the buffered float.
+    #[inline]
+    fn is_float_byte(&self, c: u8) -> bool {
+        match c {
+            b'+' | b'-' => match self.buffered().last() {
+                None                      => true,
+                Some(&b'e') | Some(&b'E') => true,
+                Some(_)                   => false,
+            },
+            _ => c.is_ascii_alphanumeric() || c == b'.',
+        }
+    }
+
+    // Parse the buffered bytes, and reset the parser.
+    #[inline]
+    fn parse(&mut self) -> Result<F> {
+        let result = match self.overflow {
+            true  => Err(Error::from((ErrorCode::Overflow, INCREMENTAL_MAX_LENGTH))),
+            false => F::from_lexical(self.buffered()),
+        };
+        self.length = 0;
+        self.overflow = false;
+        result
+    }
+}
+
+impl<F: FromLexical + Float> Default for IncrementalParser<F> {
+    #[inline]
+    fn default() -> IncrementalParser<F> {
+        IncrementalParser::new()
+    }
+}
+
+impl<F> fmt::Debug for IncrementalParser<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("IncrementalParser")
+            .field("buffered", &&index!(self.buffer[..self.length]))
+            .finish()
+    }
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn feed_test() {
+        let mut parser = IncrementalParser::<f64>::new();
+        assert_eq!(parser.feed(b"3.14159,"), FeedResult::Done(3.14159, 7));
+        assert_eq!(parser.feed(b""), FeedResult::NeedMore);
+        assert_eq!(parser.feed(b"1e"), FeedResult::NeedMore);
+        assert_eq!(parser.buffered(), b"1e");
+        assert_eq!(parser.feed(b"-5 "), FeedResult::Done(1e-5, 2));
+        assert_eq!(parser.buffered(), b"");
+
+        // Signs end the float, except at the start and after an exponent.
+        assert_eq!(parser.feed(b"-1-2"), FeedResult::Done(-1.0, 2));
+        assert_eq!(parser.feed(b"+1E+2+"), FeedResult::Done(100.0, 5));
+
+        // Special values.
+        assert_eq!(parser.feed(b"in"), FeedResult::NeedMore);
+        assert_eq!(parser.feed(b"f]"), FeedResult::Done(f64::INFINITY, 1));
+        match parser.feed(b"NaN;") {
+            FeedResult::Done(value, 3) => assert!(value.is_nan()),
+            result                     => panic!("{:?}", result),
+        }
+
+        // Every split of the float.
+        let bytes = b"-1234.5678e-3;";
+        for split in 0..bytes.len() {
+            let mut parser = IncrementalParser::<f32>::new();
+            let (first, second) = bytes.split_at(split);
+            assert_eq!(parser.feed(first), FeedResult::NeedMore);
+            assert_eq!(parser.feed(second), FeedResult::Done(-1.2345678, second.len() - 1));
+        }
+
+        // Errors, relative to the start of the float.
+        assert_eq!(parser.feed(b";"), FeedResult::Error(Error::from((ErrorCode::Empty, 0)), 0));
+        assert_eq!(parser.feed(b"1.2"), FeedResult::NeedMore);
+        assert_eq!(parser.feed(b".3 "), FeedResult::Error(Error::from((ErrorCode::InvalidDigit, 3, b'.')), 2));
+        assert_eq!(parser.feed(b"1x "), FeedResult::Error(Error::from((ErrorCode::InvalidDigit, 1, b'x')), 2));
+        assert_eq!(parser.feed(b"1e,"), FeedResult::Error(Error::from((ErrorCode::EmptyExponent, 2)), 2));
+        assert_eq!(parser.feed(b"2;"), FeedResult::Done(2.0, 1));
+    }
+
+    #[test]
+    fn feed_max_length_test() {
+        #[cfg(feature = "correct")] {
+            let digits = [b'1'; INCREMENTAL_MAX_LENGTH];
+            let mut parser = IncrementalParser::<f64>::new();
+            assert_eq!(parser.feed(&digits), FeedResult::NeedMore);
+            assert_eq!(parser.feed(b","), FeedResult::Done(1.1111111111111112e127, 0));
+        }
+
+        let digits = [b'0'; INCREMENTAL_MAX_LENGTH];
+        let mut parser = IncrementalParser::<f64>::new();
+        assert_eq!(parser.feed(&digits), FeedResult::NeedMore);
+        assert_eq!(parser.feed(b","), FeedResult::Done(0.0, 0));
+
+        // The rest of the float is skipped, even across chunks.
+        let overflow = Error::from((ErrorCode::Overflow, INCREMENTAL_MAX_LENGTH));
+        assert_eq!(parser.feed(&digits), FeedResult::NeedMore);
+        assert_eq!(parser.feed(b"1,"), FeedResult::Error(overflow, 1));
+        assert_eq!(parser.buffered(), b"");
+        assert_eq!(parser.feed(&digits), FeedResult::NeedMore);
+        assert_eq!(parser.feed(b"1e"), FeedResult::NeedMore);
+        assert_eq!(parser.feed(b"-5 2,"), FeedResult::Error(overflow, 2));
+        assert_eq!(parser.feed(b"2,"), FeedResult::Done(2.0, 1));
+        assert_eq!(parser.feed(&digits), FeedResult::NeedMore);
+        assert_eq!(parser.feed(b"12"), FeedResult::NeedMore);
+        assert_eq!(parser.finish(), Err(overflow));
+        assert_eq!(parser.finish(), Err(Error::from((ErrorCode::Empty, 0))));
+    }
+
+    #[test]
+    fn finish_test() {
+        let mut parser = IncrementalParser::<f64>::new();
+        assert_eq!(parser.feed(b"2.5"), FeedResult::NeedMore);
+        assert_eq!(parser.finish(), Ok(2.5));
+        assert_eq!(parser.finish(), Err(Error::from((ErrorCode::Empty, 0))));
+        assert_eq!(parser.feed(b"1e"), FeedResult::NeedMore);
+        assert_eq!(parser.finish(), Err(Error::from((ErrorCode::EmptyExponent, 2))));
+        assert_eq!(parser.buffered(), b"");
+    }
+}
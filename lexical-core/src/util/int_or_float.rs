@@ -0,0 +1,250 @@
+//! Parse numbers that are usually integers, but may be floats.
+
+use super::config::get_exponent_default_char;
+use super::error::{Error, ErrorCode};
+use super::result::Result;
+use super::traits::FromLexical;
+
+// HELPERS
+
+// Determine if the character starts a fraction or exponent.
+#[inline]
+fn is_float_character(c: u8) -> bool {
+    c == b'.' || c.to_ascii_lowercase() == get_exponent_default_char().to_ascii_lowercase()
+}
+
+// Determine if the integer error should fall back to parsing a float.
+//
+// Floats are parsed if the integer overflowed, or if the integer
+// stopped at a decimal point or exponent.
+#[inline]
+fn is_float_fallback(error: &Error) -> bool {
+    match error.code {
+        ErrorCode::Overflow     => true,
+        ErrorCode::Underflow    => true,
+        ErrorCode::InvalidDigit => match error.failing_byte() {
+            Some(c) => is_float_character(c),
+            None    => false,
+        },
+        _                       => false,
+    }
+}
+
+// API
+
+/// Number parsed as an integer when possible, otherwise, as a float.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum IntOrFloat {
+    /// Number without a fraction or exponent, within the range of an `i64`.
+    Int(i64),
+    /// Number with a fraction or exponent, or outside the range of an `i64`.
+    Float(f64),
+}
+
+/// Parse an integer, falling back to a float.
+///
+/// The bytes are first parsed as an `i64`. If the integer overflows,
+/// or the number contains a decimal point or exponent, the same bytes
+/// are parsed as an `f64`. Otherwise, the integer error is returned,
+/// so special values, like `NaN`, are never parsed.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::IntOrFloat;
+/// # pub fn main() {
+/// assert_eq!(lexical_core::parse_int_or_float(b"15"), Ok(IntOrFloat::Int(15)));
+/// assert_eq!(lexical_core::parse_int_or_float(b"1.5"), Ok(IntOrFloat::Float(1.5)));
+/// assert_eq!(lexical_core::parse_int_or_float(b"1e3"), Ok(IntOrFloat::Float(1000.0)));
+/// assert_eq!(lexical_core::parse_int_or_float(b"10000000000000000000"), Ok(IntOrFloat::Float(1e19)));
+/// # }
+/// ```
+pub fn parse_int_or_float(bytes: &[u8])
+    -> Result<IntOrFloat>
+{
+    match i64::from_lexical(bytes) {
+        Ok(value) => Ok(IntOrFloat::Int(value)),
+        Err(ref error) if is_float_fallback(error) => {
+            f64::from_lexical(bytes).map(IntOrFloat::Float)
+        },
+        Err(error) => Err(error),
+    }
+}
+
+/// Parse an integer, falling back to a float, until an invalid digit is found.
+///
+/// The bytes are first parsed as an `i64`. If the integer overflows,
+/// or the integer is followed by a decimal point or exponent, the same
+/// bytes are parsed as an `f64`. Returns the parsed value and the number
+/// of processed bytes by the parser that produced the value.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::IntOrFloat;
+/// # pub fn main() {
+/// assert_eq!(lexical_core::parse_partial_int_or_float(b"15,"), Ok((IntOrFloat::Int(15), 2)));
+/// assert_eq!(lexical_core::parse_partial_int_or_float(b"1.5,"), Ok((IntOrFloat::Float(1.5), 3)));
+/// # }
+/// ```
+pub fn parse_partial_int_or_float(bytes: &[u8])
+    -> Result<(IntOrFloat, usize)>
+{
+    let float = || f64::from_lexical_partial(bytes).map(|(v, n)| (IntOrFloat::Float(v), n));
+    match i64::from_lexical_partial(bytes) {
+        Ok((_, count)) if count < bytes.len() && is_float_character(index!(bytes[count])) => float(),
+        Ok((value, count)) => Ok((IntOrFloat::Int(value), count)),
+        Err(ref error) if is_float_fallback(error) => float(),
+        Err(error) => Err(error),
+    }
+}
+
+/// Parse a number as an integer, or as a float if floats are allowed.
+///
+/// If floats are not allowed, the bytes are parsed as an `i64`, so a
+/// decimal point or exponent is an
+/// [`InvalidDigit`](enum.ErrorCode.html#variant.InvalidDigit) at the
+/// first byte of the fraction or exponent, and integers outside the range
+/// of an `i64` are an [`Overflow`](enum.ErrorCode.html#variant.Overflow),
+/// rather than a float. Otherwise, the bytes are parsed as an `f64`, even
+/// if they are only digits, so the type of the value only depends on
+/// the schema, and never on the bytes.
+///
+/// * `bytes`       - Byte slice containing a numeric string.
+/// * `allow_float` - If the bytes are parsed as a float, rather than an integer.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::{Error, ErrorCode, IntOrFloat};
+/// # pub fn main() {
+/// assert_eq!(lexical_core::parse_strict_number(b"15", false), Ok(IntOrFloat::Int(15)));
+/// assert_eq!(lexical_core::parse_strict_number(b"15", true), Ok(IntOrFloat::Float(15.0)));
+/// assert_eq!(lexical_core::parse_strict_number(b"1.5", true), Ok(IntOrFloat::Float(1.5)));
+/// assert_eq!(lexical_core::parse_strict_number(b"1.5", false), Err(Error::from((ErrorCode::InvalidDigit, 1, b'.'))));
+/// # }
+/// ```
+pub fn parse_strict_number(bytes: &[u8], allow_float: bool)
+    -> Result<IntOrFloat>
+{
+    match allow_float {
+        true  => f64::from_lexical(bytes).map(IntOrFloat::Float),
+        false => i64::from_lexical(bytes).map(IntOrFloat::Int),
+    }
+}
+
+/// Parse a float, and get if it was written as a float, rather than an integer.
+///
+/// The bytes are parsed the same as [`parse`] for an `f64`. The flag is
+/// `false` if the bytes are only decimal digits, after an optional sign,
+/// like `"5"`, and `true` if the bytes contain a decimal point or an
+/// exponent, like `"5.0"` or `"5e0"`. Special values, like `NaN`, are
+/// always written as floats.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # pub fn main() {
+/// assert_eq!(lexical_core::parse_float_typed(b"5"), Ok((5.0, false)));
+/// assert_eq!(lexical_core::parse_float_typed(b"5.0"), Ok((5.0, true)));
+/// assert_eq!(lexical_core::parse_float_typed(b"5e0"), Ok((5.0, true)));
+/// # }
+/// ```
+///
+/// [`parse`]: fn.parse.html
+pub fn parse_float_typed(bytes: &[u8])
+    -> Result<(f64, bool)>
+{
+    let value = f64::from_lexical(bytes)?;
+    let digits = match bytes.first() {
+        Some(&b'+') | Some(&b'-')   => &index!(bytes[1..]),
+        _                           => bytes,
+    };
+    Ok((value, !digits.iter().all(u8::is_ascii_digit)))
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_int_or_float_test() {
+        assert_eq!(parse_int_or_float(b"0"), Ok(IntOrFloat::Int(0)));
+        assert_eq!(parse_int_or_float(b"-15"), Ok(IntOrFloat::Int(-15)));
+        assert_eq!(parse_int_or_float(b"9223372036854775807"), Ok(IntOrFloat::Int(9223372036854775807)));
+        assert_eq!(parse_int_or_float(b"-9223372036854775808"), Ok(IntOrFloat::Int(-9223372036854775808)));
+        assert_eq!(parse_int_or_float(b"10000000000000000000"), Ok(IntOrFloat::Float(1e19)));
+        assert_eq!(parse_int_or_float(b"-10000000000000000000"), Ok(IntOrFloat::Float(-1e19)));
+        assert_eq!(parse_int_or_float(b"1.0"), Ok(IntOrFloat::Float(1.0)));
+        assert_eq!(parse_int_or_float(b"1."), Ok(IntOrFloat::Float(1.0)));
+        assert_eq!(parse_int_or_float(b".5"), Ok(IntOrFloat::Float(0.5)));
+        assert_eq!(parse_int_or_float(b"1E3"), Ok(IntOrFloat::Float(1000.0)));
+        assert_eq!(parse_int_or_float(b"1e"), Err(Error::from((ErrorCode::EmptyExponent, 2))));
+        assert_eq!(parse_int_or_float(b"1a"), Err(Error::from((ErrorCode::InvalidDigit, 1, b'a'))));
+        assert_eq!(parse_int_or_float(b"NaN"), Err(Error::from((ErrorCode::InvalidDigit, 0, b'N'))));
+        assert_eq!(parse_int_or_float(b""), Err(Error::from((ErrorCode::Empty, 0))));
+    }
+
+    #[test]
+    fn parse_partial_int_or_float_test() {
+        assert_eq!(parse_partial_int_or_float(b"15"), Ok((IntOrFloat::Int(15), 2)));
+        assert_eq!(parse_partial_int_or_float(b"15]"), Ok((IntOrFloat::Int(15), 2)));
+        assert_eq!(parse_partial_int_or_float(b"1.5]"), Ok((IntOrFloat::Float(1.5), 3)));
+        assert_eq!(parse_partial_int_or_float(b"1e5]"), Ok((IntOrFloat::Float(1e5), 3)));
+        assert_eq!(parse_partial_int_or_float(b"100000000000000000000]"), Ok((IntOrFloat::Float(1e20), 21)));
+        assert_eq!(parse_partial_int_or_float(b"1e]"), Err(Error::from((ErrorCode::EmptyExponent, 2))));
+    }
+
+    #[test]
+    fn parse_strict_number_test() {
+        assert_eq!(parse_strict_number(b"15", false), Ok(IntOrFloat::Int(15)));
+        assert_eq!(parse_strict_number(b"-15", false), Ok(IntOrFloat::Int(-15)));
+        assert_eq!(parse_strict_number(b"15", true), Ok(IntOrFloat::Float(15.0)));
+        assert_eq!(parse_strict_number(b"1.5", true), Ok(IntOrFloat::Float(1.5)));
+        assert_eq!(parse_strict_number(b"-1e3", true), Ok(IntOrFloat::Float(-1000.0)));
+        assert_eq!(parse_strict_number(b"10000000000000000000", true), Ok(IntOrFloat::Float(1e19)));
+
+        // Floats are errors at the decimal point or exponent.
+        assert_eq!(parse_strict_number(b"1.5", false), Err(Error::from((ErrorCode::InvalidDigit, 1, b'.'))));
+        assert_eq!(parse_strict_number(b"-15.0", false), Err(Error::from((ErrorCode::InvalidDigit, 3, b'.'))));
+        assert_eq!(parse_strict_number(b"1e3", false), Err(Error::from((ErrorCode::InvalidDigit, 1, b'e'))));
+        assert_eq!(parse_strict_number(b"15E3", false), Err(Error::from((ErrorCode::InvalidDigit, 2, b'E'))));
+        assert_eq!(parse_strict_number(b".5", false), Err(Error::from((ErrorCode::InvalidDigit, 0, b'.'))));
+        assert_eq!(parse_strict_number(b"NaN", false), Err(Error::from((ErrorCode::InvalidDigit, 0, b'N'))));
+        assert_eq!(parse_strict_number(b"10000000000000000000", false), Err(Error::from((ErrorCode::Overflow, 19))));
+
+        // Errors.
+        assert_eq!(parse_strict_number(b"", true), Err(Error::from((ErrorCode::Empty, 0))));
+        assert_eq!(parse_strict_number(b"", false), Err(Error::from((ErrorCode::Empty, 0))));
+        assert_eq!(parse_strict_number(b"1.5a", true), Err(Error::from((ErrorCode::InvalidDigit, 3, b'a'))));
+    }
+
+    #[test]
+    fn parse_float_typed_test() {
+        assert_eq!(parse_float_typed(b"5"), Ok((5.0, false)));
+        assert_eq!(parse_float_typed(b"-5"), Ok((-5.0, false)));
+        assert_eq!(parse_float_typed(b"+005"), Ok((5.0, false)));
+        assert_eq!(parse_float_typed(b"100000000000000000000"), Ok((1e20, false)));
+        assert_eq!(parse_float_typed(b"5.0"), Ok((5.0, true)));
+        assert_eq!(parse_float_typed(b"5."), Ok((5.0, true)));
+        assert_eq!(parse_float_typed(b".5"), Ok((0.5, true)));
+        assert_eq!(parse_float_typed(b"5e0"), Ok((5.0, true)));
+        assert_eq!(parse_float_typed(b"-5E+1"), Ok((-50.0, true)));
+        assert_eq!(parse_float_typed(b"inf"), Ok((f64::INFINITY, true)));
+        assert_eq!(parse_float_typed(b""), Err(Error::from((ErrorCode::Empty, 0))));
+        assert_eq!(parse_float_typed(b"5a"), Err(Error::from((ErrorCode::InvalidDigit, 1, b'a'))));
+    }
+}
@@ -0,0 +1,121 @@
+//! Parse decimal floats with their base-10 order of magnitude.
+
+use super::result::Result;
+use super::traits::FromLexical;
+
+// HELPERS
+
+// Get the order of magnitude of the significant digits of a valid, decimal float.
+//
+// Returns None if the digits are all zero, or if there are no digits.
+#[inline]
+fn decimal_magnitude(bytes: &[u8]) -> Option<i32> {
+    let bytes = match bytes.first() {
+        Some(&b'+') | Some(&b'-') => &index!(bytes[1..]),
+        _                         => bytes,
+    };
+    let integer_len = bytes.iter().take_while(|c| c.is_ascii_digit()).count();
+    let (integer, bytes) = bytes.split_at(integer_len);
+    let (fraction, bytes) = match bytes.first() {
+        Some(&b'.') => {
+            let bytes = &index!(bytes[1..]);
+            let fraction_len = bytes.iter().take_while(|c| c.is_ascii_digit()).count();
+            bytes.split_at(fraction_len)
+        },
+        _           => (&index!(bytes[..0]), bytes),
+    };
+
+    // Get the magnitude of the first non-zero digit.
+    let mut magnitude: i64 = match integer.iter().position(|&c| c != b'0') {
+        Some(index) => (integer_len - index - 1) as i64,
+        None        => -(fraction.iter().position(|&c| c != b'0')? as i64) - 1,
+    };
+
+    // Skip the exponent character, and add the exponent, saturating
+    // far beyond the range of the magnitude.
+    if !bytes.is_empty() {
+        let bytes = &index!(bytes[1..]);
+        let (is_negative, digits) = match bytes.first() {
+            Some(&b'-') => (true, &index!(bytes[1..])),
+            Some(&b'+') => (false, &index!(bytes[1..])),
+            _           => (false, bytes),
+        };
+        let mut exponent: i64 = 0;
+        for &c in digits {
+            exponent = (exponent * 10 + (c - b'0') as i64).min(1 << 40);
+        }
+        magnitude += if is_negative { -exponent } else { exponent };
+    }
+
+    Some(magnitude.max(i32::min_value() as i64).min(i32::max_value() as i64) as i32)
+}
+
+// API
+
+/// Parse a decimal float, and get its base-10 order of magnitude.
+///
+/// Returns the value and `floor(log10(|value|))`, computed exactly from
+/// the significant digits of the string, rather than from the rounded
+/// float. Therefore, the magnitude is correct near powers of ten, and
+/// for values beyond the range of the float, like `1e400`. Rarely, the
+/// float rounds up to the next power of ten, and the magnitude is one
+/// less than the magnitude of the float, like `9.99999999999999999`,
+/// with a magnitude of `0` and a value of `10.0`. Zero and special
+/// values, without any non-zero digits, have a magnitude of `i32::MIN`.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # pub fn main() {
+/// assert_eq!(lexical_core::parse_with_magnitude(b"1234.5"), Ok((1234.5, 3)));
+/// assert_eq!(lexical_core::parse_with_magnitude(b"0.001"), Ok((0.001, -3)));
+/// assert_eq!(lexical_core::parse_with_magnitude(b"0"), Ok((0.0, i32::min_value())));
+/// # }
+/// ```
+pub fn parse_with_magnitude(bytes: &[u8]) -> Result<(f64, i32)> {
+    let value = f64::from_lexical(bytes)?;
+    let magnitude = decimal_magnitude(bytes).unwrap_or(i32::min_value());
+    Ok((value, magnitude))
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use crate::util::*;
+    use super::*;
+
+    #[test]
+    fn parse_with_magnitude_test() {
+        assert_eq!(parse_with_magnitude(b"1"), Ok((1.0, 0)));
+        assert_eq!(parse_with_magnitude(b"9.99"), Ok((9.99, 0)));
+        assert_eq!(parse_with_magnitude(b"10"), Ok((10.0, 1)));
+        assert_eq!(parse_with_magnitude(b"-1000"), Ok((-1000.0, 3)));
+        assert_eq!(parse_with_magnitude(b"0.1"), Ok((0.1, -1)));
+        assert_eq!(parse_with_magnitude(b"0.099"), Ok((0.099, -2)));
+        assert_eq!(parse_with_magnitude(b"00012.5"), Ok((12.5, 1)));
+        assert_eq!(parse_with_magnitude(b"1.5e3"), Ok((1500.0, 3)));
+        assert_eq!(parse_with_magnitude(b"0.15E-3"), Ok((0.00015, -4)));
+        assert_eq!(parse_with_magnitude(b"1000e-3"), Ok((1.0, 0)));
+        assert_eq!(parse_with_magnitude(b"5e-324"), Ok((5e-324, -324)));
+
+        // The magnitude is exact, even beyond the range of the float.
+        assert_eq!(parse_with_magnitude(b"1e400"), Ok((f64::INFINITY, 400)));
+        assert_eq!(parse_with_magnitude(b"1e-400"), Ok((0.0, -400)));
+        assert_eq!(parse_with_magnitude(b"1e99999999999999"), Ok((f64::INFINITY, i32::max_value())));
+
+        // Values without non-zero digits.
+        assert_eq!(parse_with_magnitude(b"0"), Ok((0.0, i32::min_value())));
+        assert_eq!(parse_with_magnitude(b"-0.000e5"), Ok((-0.0, i32::min_value())));
+        assert_eq!(parse_with_magnitude(b"inf"), Ok((f64::INFINITY, i32::min_value())));
+        assert!(parse_with_magnitude(b"NaN").unwrap().0.is_nan());
+
+        // Errors.
+        assert_eq!(parse_with_magnitude(b""), Err(Error::from((ErrorCode::Empty, 0))));
+        assert_eq!(parse_with_magnitude(b"1.5a"), Err(Error::from((ErrorCode::InvalidDigit, 3, b'a'))));
+    }
+}
@@ -0,0 +1,39 @@
+//! Metrics describing how a float was parsed.
+
+/// Metrics describing how a float string was parsed.
+///
+/// Metrics are collected by [`parse_with_metrics`], and describe
+/// the significant digits of the mantissa, the parsed exponent, and
+/// if the value could not be parsed with the fast path. Special
+/// values, like `NaN`, report the default metrics.
+///
+/// [`parse_with_metrics`]: fn.parse_with_metrics.html
+#[cfg(feature = "metrics")]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ParseMetrics {
+    /// Number of mantissa digits consumed by the parser.
+    ///
+    /// Leading zeros of the integer and trailing zeros of the
+    /// fraction are not significant, and are not counted.
+    pub digits_consumed: usize,
+    /// If the value required the moderate or slow path.
+    ///
+    /// The fast path is only used if the mantissa digits fit in the
+    /// float without truncation, and the scaled exponent, which is
+    /// the parsed exponent minus the number of fraction digits, is
+    /// within the range of exact powers of the radix, `[-10, 10]`
+    /// for a decimal `f32`, and `[-22, 22]` for a decimal `f64`,
+    /// or above the range if the extra powers still fit in the
+    /// mantissa, like `"1e12"` as an `f32`.
+    /// Leading zeros of the fraction count towards the scaled
+    /// exponent, so `"0.00000000001"` as an `f32` uses the slow
+    /// path, despite having a single significant digit.
+    pub used_slow_path: bool,
+    /// Parsed exponent, without adjusting for the fraction digits.
+    pub exponent: i32,
+}
+
+/// Placeholder for the metrics, which are not collected without the `metrics` feature.
+#[cfg(not(feature = "metrics"))]
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct ParseMetrics;
@@ -21,22 +21,56 @@ pub(crate) mod test;
 
 // Hide implementation details.
 mod algorithm;
+mod backend;
+mod batch;
+mod bits;
+mod bounded;
+mod canonical;
 mod cast;
+mod clamped;
 mod config;
 mod consume;
+mod decompose;
 mod div128;
+mod duration;
 mod error;
 mod format;
+mod hex;
+mod incremental;
+mod int_or_float;
 mod iterator;
+mod magnitude;
 mod mask;
+mod metrics;
+mod nanos;
+mod nonzero;
 mod num;
+mod options;
 mod primitive;
 mod pow;
+mod qfixed;
+mod quantity;
+mod quantize;
+mod ratio;
 mod result;
 mod rounding;
 mod sign;
+mod signed_parts;
+mod spans;
 mod table;
 
+#[cfg(feature = "currency")]
+mod currency;
+
+#[cfg(feature = "find")]
+mod find;
+
+#[cfg(feature = "optional")]
+mod optional;
+
+#[cfg(feature = "radix")]
+mod prefix;
+
 #[cfg(feature = "format")]
 mod skip_value;
 
@@ -64,6 +98,9 @@ pub(crate) use self::table::*;
 #[cfg(feature = "format")]
 pub(crate) use self::skip_value::*;
 
+#[cfg(not(feature = "metrics"))]
+pub(crate) use self::metrics::*;
+
 cfg_if! {
 if #[cfg(feature = "correct")] {
     pub(crate) use self::sequence::*;
@@ -72,12 +109,49 @@ if #[cfg(feature = "correct")] {
 }}  // cfg_if
 
 // Publicly export config globally.
+pub use self::backend::*;
+pub use self::batch::*;
+pub use self::bits::*;
+pub use self::bounded::*;
+pub use self::canonical::*;
+pub use self::clamped::*;
 pub use self::config::*;
+pub use self::decompose::*;
+pub use self::duration::*;
 pub use self::error::*;
 pub use self::format::*;
+pub use self::hex::*;
+pub use self::incremental::*;
+pub use self::int_or_float::*;
+pub use self::magnitude::*;
+pub use self::nanos::*;
+pub use self::nonzero::*;
 pub use self::num::*;
+pub use self::options::*;
+pub use self::qfixed::*;
+pub use self::quantity::*;
+pub use self::quantize::*;
+pub use self::ratio::*;
 pub use self::result::*;
+pub use self::rounding::RoundingMode;
+pub use self::signed_parts::*;
+pub use self::spans::*;
 pub use self::traits::*;
 
+#[cfg(feature = "currency")]
+pub use self::currency::*;
+
+#[cfg(feature = "find")]
+pub use self::find::*;
+
+#[cfg(feature = "metrics")]
+pub use self::metrics::*;
+
+#[cfg(feature = "optional")]
+pub use self::optional::*;
+
+#[cfg(feature = "radix")]
+pub use self::prefix::*;
+
 #[cfg(feature = "rounding")]
 pub use self::rounding::RoundingKind;
@@ -0,0 +1,91 @@
+//! Parse fractional seconds into nanoseconds.
+
+use super::error::{Error, ErrorCode};
+use super::result::Result;
+
+// Number of fractional digits in nanoseconds.
+pub(crate) const NANOS_DIGITS: usize = 9;
+
+/// Parse fractional seconds, like in RFC 3339 timestamps, into nanoseconds.
+///
+/// The bytes must start with a `.`, followed by at least 1 digit. Up
+/// to 9 digits are scaled to nanoseconds, padding with zeros, and any
+/// further digits are truncated. Returns the nanoseconds and the
+/// number of processed bytes, including the `.` and every digit, so
+/// parsing stops at the first byte that is not a digit.
+///
+/// If the bytes are empty, the error is
+/// [`Empty`](enum.ErrorCode.html#variant.Empty), if the bytes do not
+/// start with a `.`, the error is
+/// [`InvalidDigit`](enum.ErrorCode.html#variant.InvalidDigit), and if
+/// the `.` is not followed by a digit, the error is
+/// [`EmptyFraction`](enum.ErrorCode.html#variant.EmptyFraction).
+///
+/// * `bytes`   - Byte slice containing fractional seconds.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # pub fn main() {
+/// assert_eq!(lexical_core::parse_fractional_nanos(b".1"), Ok((100000000, 2)));
+/// assert_eq!(lexical_core::parse_fractional_nanos(b".123456Z"), Ok((123456000, 7)));
+/// assert_eq!(lexical_core::parse_fractional_nanos(b".1234567891"), Ok((123456789, 11)));
+/// # }
+/// ```
+pub fn parse_fractional_nanos(bytes: &[u8]) -> Result<(u32, usize)> {
+    match bytes.first() {
+        None        => return Err(Error::from((ErrorCode::Empty, 0))),
+        Some(&b'.') => (),
+        Some(_)     => return Err(Error::from((ErrorCode::InvalidDigit, 0)).with_byte(bytes)),
+    }
+
+    let digits = &index!(bytes[1..]);
+    let count = digits.iter().take_while(|c| c.is_ascii_digit()).count();
+    if count == 0 {
+        return Err(Error::from((ErrorCode::EmptyFraction, 1)));
+    }
+
+    // Scale the significant digits, and pad with zeros.
+    let mut nanos: u32 = 0;
+    for index in 0..NANOS_DIGITS {
+        let digit = match index < count {
+            true  => (index!(digits[index]) - b'0') as u32,
+            false => 0,
+        };
+        nanos = nanos * 10 + digit;
+    }
+
+    Ok((nanos, count + 1))
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_fractional_nanos_test() {
+        assert_eq!(parse_fractional_nanos(b".1"), Ok((100000000, 2)));
+        assert_eq!(parse_fractional_nanos(b".0"), Ok((0, 2)));
+        assert_eq!(parse_fractional_nanos(b".000000001"), Ok((1, 10)));
+        assert_eq!(parse_fractional_nanos(b".999999999"), Ok((999999999, 10)));
+        assert_eq!(parse_fractional_nanos(b".123456789"), Ok((123456789, 10)));
+        assert_eq!(parse_fractional_nanos(b".1234567891"), Ok((123456789, 11)));
+        assert_eq!(parse_fractional_nanos(b".9999999999999999999999"), Ok((999999999, 23)));
+        assert_eq!(parse_fractional_nanos(b".5+01:00"), Ok((500000000, 2)));
+        assert_eq!(parse_fractional_nanos(b".25Z"), Ok((250000000, 3)));
+    }
+
+    #[test]
+    fn parse_fractional_nanos_error_test() {
+        assert_eq!(parse_fractional_nanos(b""), Err(Error::from((ErrorCode::Empty, 0))));
+        assert_eq!(parse_fractional_nanos(b"1"), Err(Error::from((ErrorCode::InvalidDigit, 0, b'1'))));
+        assert_eq!(parse_fractional_nanos(b",5"), Err(Error::from((ErrorCode::InvalidDigit, 0, b','))));
+        assert_eq!(parse_fractional_nanos(b"."), Err(Error::from((ErrorCode::EmptyFraction, 1))));
+        assert_eq!(parse_fractional_nanos(b".Z"), Err(Error::from((ErrorCode::EmptyFraction, 1))));
+        assert_eq!(parse_fractional_nanos(b".+5"), Err(Error::from((ErrorCode::EmptyFraction, 1))));
+    }
+}
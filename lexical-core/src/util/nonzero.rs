@@ -0,0 +1,109 @@
+//! Parse non-zero integers.
+
+use crate::lib::num;
+use super::error::{Error, ErrorCode};
+use super::result::Result;
+use super::traits::FromLexical;
+
+// TRAITS
+
+/// Non-zero integer type, parsed from its primitive integer type.
+pub trait NonZeroInteger: Sized {
+    /// Primitive integer type of the non-zero integer.
+    type Integer: FromLexical;
+
+    /// Create the non-zero integer, if the value is not zero.
+    fn from_integer(value: Self::Integer) -> Option<Self>;
+}
+
+macro_rules! non_zero_integer_impl {
+    ($($t:ty, $i:ty ;)*) => ($(
+        impl NonZeroInteger for $t {
+            type Integer = $i;
+
+            #[inline]
+            fn from_integer(value: $i) -> Option<$t> {
+                <$t>::new(value)
+            }
+        }
+    )*)
+}
+
+non_zero_integer_impl! {
+    num::NonZeroU8, u8 ;
+    num::NonZeroU16, u16 ;
+    num::NonZeroU32, u32 ;
+    num::NonZeroU64, u64 ;
+    num::NonZeroU128, u128 ;
+    num::NonZeroUsize, usize ;
+    num::NonZeroI8, i8 ;
+    num::NonZeroI16, i16 ;
+    num::NonZeroI32, i32 ;
+    num::NonZeroI64, i64 ;
+    num::NonZeroI128, i128 ;
+    num::NonZeroIsize, isize ;
+}
+
+// API
+
+/// Parse a non-zero integer.
+///
+/// The bytes are parsed the same as [`parse`] for the primitive integer,
+/// so invalid digits and overflow are reported as usual. Zero, including
+/// `-0` and leading zeros, is reported as
+/// [`Zero`](enum.ErrorCode.html#variant.Zero) at the start of the bytes.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::{Error, ErrorCode};
+/// # use std::num::NonZeroU32;
+/// # pub fn main() {
+/// assert_eq!(lexical_core::parse_nonzero::<NonZeroU32>(b"42"), Ok(NonZeroU32::new(42).unwrap()));
+/// assert_eq!(lexical_core::parse_nonzero::<NonZeroU32>(b"0"), Err(Error::from((ErrorCode::Zero, 0))));
+/// # }
+/// ```
+///
+/// [`parse`]: fn.parse.html
+pub fn parse_nonzero<N: NonZeroInteger>(bytes: &[u8]) -> Result<N> {
+    let value = N::Integer::from_lexical(bytes)?;
+    N::from_integer(value).ok_or(Error::from((ErrorCode::Zero, 0)))
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    macro_rules! check_nonzero {
+        ($($t:ident)*) => ($(
+            assert_eq!(parse_nonzero::<num::$t>(b"1"), Ok(num::$t::new(1).unwrap()));
+            assert_eq!(parse_nonzero::<num::$t>(b"0"), Err(Error::from((ErrorCode::Zero, 0))));
+            assert_eq!(parse_nonzero::<num::$t>(b"00"), Err(Error::from((ErrorCode::Zero, 0))));
+            assert_eq!(parse_nonzero::<num::$t>(b""), Err(Error::from((ErrorCode::Empty, 0))));
+            assert_eq!(parse_nonzero::<num::$t>(b"1a"), Err(Error::from((ErrorCode::InvalidDigit, 1, b'a'))));
+        )*)
+    }
+
+    #[test]
+    fn parse_nonzero_test() {
+        check_nonzero! {
+            NonZeroU8 NonZeroU16 NonZeroU32 NonZeroU64 NonZeroU128 NonZeroUsize
+            NonZeroI8 NonZeroI16 NonZeroI32 NonZeroI64 NonZeroI128 NonZeroIsize
+        }
+
+        // Limits and signs.
+        assert_eq!(parse_nonzero::<num::NonZeroU8>(b"255"), Ok(num::NonZeroU8::new(255).unwrap()));
+        assert_eq!(parse_nonzero::<num::NonZeroU8>(b"256"), Err(Error::from((ErrorCode::Overflow, 2))));
+        assert_eq!(parse_nonzero::<num::NonZeroU8>(b"-1"), Err(Error::from((ErrorCode::InvalidDigit, 0, b'-'))));
+        assert_eq!(parse_nonzero::<num::NonZeroI8>(b"-128"), Ok(num::NonZeroI8::new(-128).unwrap()));
+        assert_eq!(parse_nonzero::<num::NonZeroI8>(b"-0"), Err(Error::from((ErrorCode::Zero, 0))));
+        assert_eq!(parse_nonzero::<num::NonZeroI64>(b"-9223372036854775808"), Ok(num::NonZeroI64::new(i64::min_value()).unwrap()));
+        assert_eq!(parse_nonzero::<num::NonZeroU128>(b"340282366920938463463374607431768211455"), Ok(num::NonZeroU128::new(u128::max_value()).unwrap()));
+    }
+}
@@ -0,0 +1,169 @@
+//! Parse and write optional numbers, with tokens for missing values.
+
+use super::num::Number;
+use super::options::WriteOptionalOptions;
+use super::result::Result;
+use super::traits::{FromLexical, ToLexical};
+
+/// Maximum number of bytes required to write an optional number.
+///
+/// This is the larger of the decimal formatted size of the number
+/// and the length of the null token.
+///
+/// * `options` - Options to customize the written null token.
+#[inline]
+pub fn formatted_size_optional<N: ToLexical>(options: &WriteOptionalOptions) -> usize {
+    let null_length = options.null_token().len();
+    if N::FORMATTED_SIZE_DECIMAL > null_length {
+        N::FORMATTED_SIZE_DECIMAL
+    } else {
+        null_length
+    }
+}
+
+/// Write optional number to string.
+///
+/// Present values are written the same as [`write`], and missing
+/// values are written as the null token. Returns a subslice of the
+/// input buffer containing the written bytes, starting from the same
+/// address in memory as the input slice.
+///
+/// * `value`   - Optional number to serialize.
+/// * `bytes`   - Slice containing a numeric string.
+/// * `options` - Options to customize the written null token.
+///
+/// # Panics
+///
+/// Panics if the buffer may not be large enough to hold the serialized
+/// value. In order to ensure the function will not panic, provide a
+/// buffer with at least [`formatted_size_optional`] elements.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::WriteOptionalOptions;
+/// # pub fn main() {
+/// let options = WriteOptionalOptions::new();
+/// let mut buffer = [b'0'; lexical_core::BUFFER_SIZE];
+/// assert_eq!(lexical_core::write_optional(None::<f64>, &mut buffer, &options), b"null");
+/// assert_eq!(lexical_core::write_optional(Some(5u32), &mut buffer, &options), b"5");
+/// # }
+/// ```
+///
+/// [`write`]: fn.write.html
+/// [`formatted_size_optional`]: fn.formatted_size_optional.html
+#[inline]
+pub fn write_optional<'a, N: ToLexical>(n: Option<N>, bytes: &'a mut [u8], options: &WriteOptionalOptions)
+    -> &'a mut [u8]
+{
+    assert!(bytes.len() >= formatted_size_optional::<N>(options));
+    match n {
+        Some(n) => n.to_lexical(bytes),
+        None    => {
+            let null_token = options.null_token();
+            let bytes = &mut bytes[..null_token.len()];
+            bytes.copy_from_slice(null_token);
+            bytes
+        },
+    }
+}
+
+/// Parse optional number from string.
+///
+/// Returns `None` if the bytes match any of the missing tokens,
+/// ignoring ASCII case, otherwise, parses the bytes the same as
+/// [`parse`]. Missing values are distinct from NaN: a `"NaN"` token
+/// is only missing if it is one of the missing tokens. An empty
+/// token treats empty bytes as missing.
+///
+/// * `bytes`           - Byte slice containing a numeric string.
+/// * `missing_tokens`  - Tokens for missing values.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # pub fn main() {
+/// let missing: &[&[u8]] = &[b"NA", b"-", b""];
+/// assert_eq!(lexical_core::parse_or_missing::<f64>(b"1.5", missing), Ok(Some(1.5)));
+/// assert_eq!(lexical_core::parse_or_missing::<f64>(b"na", missing), Ok(None));
+/// assert_eq!(lexical_core::parse_or_missing::<f64>(b"", missing), Ok(None));
+/// # }
+/// ```
+///
+/// [`parse`]: fn.parse.html
+#[inline]
+pub fn parse_or_missing<N: FromLexical>(bytes: &[u8], missing_tokens: &[&[u8]])
+    -> Result<Option<N>>
+{
+    if missing_tokens.iter().any(|token| token.eq_ignore_ascii_case(bytes)) {
+        Ok(None)
+    } else {
+        N::from_lexical(bytes).map(Some)
+    }
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use crate::util::*;
+    use super::*;
+
+    #[test]
+    fn formatted_size_optional_test() {
+        let options = WriteOptionalOptions::new();
+        assert_eq!(formatted_size_optional::<u8>(&options), 4);
+        assert_eq!(formatted_size_optional::<u64>(&options), u64::FORMATTED_SIZE_DECIMAL);
+
+        let options = WriteOptionalOptions::builder().null_token(b"missing value").build().unwrap();
+        assert_eq!(formatted_size_optional::<u8>(&options), 13);
+    }
+
+    #[test]
+    fn write_optional_test() {
+        let mut buffer = [b'0'; 64];
+        let options = WriteOptionalOptions::new();
+        assert_eq!(write_optional(None::<f64>, &mut buffer, &options), b"null");
+        assert_eq!(write_optional(Some(-12i32), &mut buffer, &options), b"-12");
+        assert_eq!(write_optional(Some(1.5f64), &mut buffer, &options), b"1.5");
+
+        let options = WriteOptionalOptions::builder().null_token(b"NA").build().unwrap();
+        assert_eq!(write_optional(None::<u8>, &mut buffer, &options).len(), 2);
+        assert_eq!(write_optional(None::<u8>, &mut buffer, &options), b"NA");
+    }
+
+    #[test]
+    fn parse_or_missing_test() {
+        let missing: &[&[u8]] = &[b"NA", b"NaN", b"-"];
+        assert_eq!(parse_or_missing::<f64>(b"1.5", missing), Ok(Some(1.5)));
+        assert_eq!(parse_or_missing::<f64>(b"-1.5", missing), Ok(Some(-1.5)));
+        assert_eq!(parse_or_missing::<f64>(b"NA", missing), Ok(None));
+        assert_eq!(parse_or_missing::<f64>(b"nA", missing), Ok(None));
+        assert_eq!(parse_or_missing::<f64>(b"nan", missing), Ok(None));
+        assert_eq!(parse_or_missing::<f64>(b"-", missing), Ok(None));
+        assert_eq!(parse_or_missing::<u8>(b"NA", missing), Ok(None));
+        assert_eq!(parse_or_missing::<u8>(b"255", missing), Ok(Some(255)));
+
+        // Tokens must match the entire bytes.
+        assert_eq!(parse_or_missing::<f64>(b"NAN1", missing), Err(Error::from((ErrorCode::InvalidDigit, 3, b'1'))));
+        assert_eq!(parse_or_missing::<f64>(b"--", missing), Err(Error::from((ErrorCode::EmptyMantissa, 1))));
+
+        // Empty bytes are only missing with an empty token.
+        assert_eq!(parse_or_missing::<f64>(b"", missing), Err(Error::from((ErrorCode::Empty, 0))));
+        assert_eq!(parse_or_missing::<f64>(b"", &[b""]), Ok(None));
+
+        // NaN is a value unless it is a missing token.
+        assert!(parse_or_missing::<f64>(b"NaN", &[b"NA"]).unwrap().unwrap().is_nan());
+    }
+
+    #[test]
+    #[should_panic]
+    fn write_optional_small_buffer_test() {
+        let mut buffer = [b'0'; 3];
+        let options = WriteOptionalOptions::new();
+        write_optional(None::<u8>, &mut buffer, &options);
+    }
+}
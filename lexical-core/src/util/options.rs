@@ -0,0 +1,2322 @@
+//! Options to customize number-to-string and string-to-number conversions.
+
+use super::algorithm::{ltrim_chars_slice, rtrim_chars_slice};
+use super::error::Error;
+use super::result::Result;
+
+#[cfg(feature = "format")]
+use super::format::NumberFormat;
+
+// PARSE FLOAT
+
+/// Options to customize parsing floats.
+///
+/// Options are created from a [`ParseFloatOptionsBuilder`], which
+/// validates the options when built. The default options parse the
+/// same strings as [`parse`](fn.parse.html).
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::ParseFloatOptions;
+/// # pub fn main() {
+/// let options = ParseFloatOptions::builder()
+///     .exponent_string(b"x")
+///     .build()
+///     .unwrap();
+/// assert_eq!(options.exponent_string(), b"x");
+/// # }
+/// ```
+///
+/// [`ParseFloatOptionsBuilder`]: struct.ParseFloatOptionsBuilder.html
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ParseFloatOptions {
+    /// Delimiter between the mantissa and the exponent.
+    exponent_string: &'static [u8],
+    /// Preserve the sign of negative zero.
+    parse_negative_zero: bool,
+    /// Error on a sign following the leading sign.
+    strict_sign: bool,
+    /// End the number at an exponent without digits.
+    lenient_exponent: bool,
+    /// Error on redundant leading zeros in the exponent.
+    reject_exponent_leading_zeros: bool,
+    /// Parse a decimal point without digits as zero.
+    lenient_bare_point: bool,
+    /// Parse a mantissa without digits before the exponent as one.
+    implicit_mantissa_one: bool,
+    /// Apply a sign directly preceding the decimal point.
+    sign_before_point: bool,
+    /// Bytes to trim from the start and end before parsing.
+    trim_chars: &'static [u8],
+    /// Exact number of significant digits required.
+    require_significant_digits: Option<usize>,
+    /// Require an exponent, in scientific notation.
+    require_exponent: bool,
+    /// Match the case of the exponent string.
+    case_sensitive_exponent: bool,
+    /// Number format of the float grammar.
+    #[cfg(feature = "format")]
+    format: NumberFormat,
+    /// Sizes of the groups of integer digits, from the least-significant group.
+    #[cfg(feature = "format")]
+    grouping_sizes: &'static [u8],
+    /// Bits of the value parsed from the sentinel string, and the sentinel string.
+    #[cfg(feature = "optional")]
+    sentinel: Option<(u64, &'static [u8])>,
+}
+
+impl ParseFloatOptions {
+    /// Create options with default values.
+    #[inline]
+    pub fn new() -> ParseFloatOptions {
+        ParseFloatOptions {
+            exponent_string: DEFAULT_EXPONENT_STRING,
+            parse_negative_zero: true,
+            strict_sign: false,
+            lenient_exponent: false,
+            reject_exponent_leading_zeros: false,
+            lenient_bare_point: false,
+            implicit_mantissa_one: false,
+            sign_before_point: true,
+            trim_chars: b"",
+            require_significant_digits: None,
+            require_exponent: false,
+            case_sensitive_exponent: false,
+            #[cfg(feature = "format")]
+            format: NumberFormat::STANDARD,
+            #[cfg(feature = "format")]
+            grouping_sizes: b"",
+            #[cfg(feature = "optional")]
+            sentinel: None,
+        }
+    }
+
+    /// Create a builder to customize the options.
+    #[inline]
+    pub fn builder() -> ParseFloatOptionsBuilder {
+        ParseFloatOptionsBuilder::new()
+    }
+
+    /// Get the string delimiting the mantissa and the exponent.
+    ///
+    /// The exponent string is matched case-insensitively. If the
+    /// string starts with a valid digit for the radix, like `e` in
+    /// radix 16, the default exponent character for the radix is
+    /// used instead.
+    #[inline]
+    pub fn exponent_string(&self) -> &'static [u8] {
+        self.exponent_string
+    }
+
+    /// Get if negative zero is parsed with the sign bit set.
+    ///
+    /// If set, `"-0"` is parsed as `-0.0`, otherwise, it is parsed
+    /// as `0.0`. This includes negative values that underflow to
+    /// zero, like `"-1e-400"`.
+    #[inline]
+    pub fn parse_negative_zero(&self) -> bool {
+        self.parse_negative_zero
+    }
+
+    /// Get if a sign following the leading sign is an error.
+    ///
+    /// At most one leading sign is parsed. By default, a second sign,
+    /// like in `"--5"` or `"+-5"`, ends the number, so it is reported
+    /// as an empty mantissa. If set, the second sign is reported as
+    /// [`InvalidSign`](enum.ErrorCode.html#variant.InvalidSign).
+    #[inline]
+    pub fn strict_sign(&self) -> bool {
+        self.strict_sign
+    }
+
+    /// Get if an exponent without digits ends the number.
+    ///
+    /// By default, an exponent string without any exponent digits,
+    /// like in `"1e"`, `"1e+"`, or `"1efoo"`, is reported as an
+    /// empty exponent. If set, the number ends before the exponent
+    /// string, so partial parsers return `1.0` and consume 1 byte,
+    /// while complete parsers report the exponent string as an
+    /// invalid digit.
+    #[inline]
+    pub fn lenient_exponent(&self) -> bool {
+        self.lenient_exponent
+    }
+
+    /// Get if redundant leading zeros in the exponent are an error.
+    ///
+    /// If set, exponent digits starting with a zero and followed by
+    /// another digit, like in `"1e00"` or `"1e-07"`, are reported as
+    /// [`InvalidLeadingZeros`](enum.ErrorCode.html#variant.InvalidLeadingZeros)
+    /// at the digit following the zero. A single zero, like in
+    /// `"1e0"`, is accepted.
+    #[inline]
+    pub fn reject_exponent_leading_zeros(&self) -> bool {
+        self.reject_exponent_leading_zeros
+    }
+
+    /// Get if a decimal point without digits is parsed as zero.
+    ///
+    /// By default, a decimal point without any integer or fraction
+    /// digits, like in `"."`, `"+."`, or `".e5"`, is reported as an
+    /// empty mantissa. If set, it is parsed as zero, with the sign
+    /// and any exponent. This is strictly a leniency for importing
+    /// messy data, like CSV exports that write a lone decimal point
+    /// for an empty number, and should not be used otherwise.
+    #[inline]
+    pub fn lenient_bare_point(&self) -> bool {
+        self.lenient_bare_point
+    }
+
+    /// Get if a mantissa without digits before the exponent is one.
+    ///
+    /// By default, a float starting with the exponent string, like
+    /// `"e3"` or `"-e3"`, is reported as an empty mantissa. If set,
+    /// the missing mantissa is parsed as one, with the sign, so `"e3"`
+    /// is parsed as `1000.0`, and `"-E-2"` as `-0.01`, like the
+    /// shorthand for powers of ten in some engineering formats. The
+    /// exponent must still have digits, so a lone `"e"` is an error.
+    #[inline]
+    pub fn implicit_mantissa_one(&self) -> bool {
+        self.implicit_mantissa_one
+    }
+
+    /// Get if a sign may directly precede the decimal point.
+    ///
+    /// By default, a sign followed by a decimal point, like in `"-.5"`
+    /// or `"+.25"`, is applied to the fraction, like for `"-0.5"`. If
+    /// not set, the decimal point is reported as
+    /// [`EmptyInteger`](enum.ErrorCode.html#variant.EmptyInteger),
+    /// while unsigned fractions, like `".5"`, are still accepted. This
+    /// also applies to a sign before a decimal point without digits,
+    /// like `"-."`, with [`lenient_bare_point`](#method.lenient_bare_point).
+    #[inline]
+    pub fn sign_before_point(&self) -> bool {
+        self.sign_before_point
+    }
+
+    /// Get the bytes to trim from the start and end before parsing.
+    ///
+    /// Any of the bytes are trimmed from the start and the end of the
+    /// string, like brackets or quotes around the number, so `"[5]"`
+    /// is parsed as `5` with the bytes `[]`. This only trims, and does
+    /// not check the bytes are balanced, so `"[[5"` and `"5]"` are also
+    /// parsed as `5`. Error indexes and the processed bytes are relative
+    /// to the start of the untrimmed string, and the trailing bytes are
+    /// processed only if the entire number is. The bytes must not
+    /// contain ASCII alphanumeric characters, signs, decimal points,
+    /// or bytes in the exponent string.
+    #[inline]
+    pub fn trim_chars(&self) -> &'static [u8] {
+        self.trim_chars
+    }
+
+    /// Get the exact number of significant digits required.
+    ///
+    /// If set, floats without exactly this many significant digits in
+    /// the mantissa are reported as
+    /// [`InvalidSignificantDigits`](enum.ErrorCode.html#variant.InvalidSignificantDigits)
+    /// at the start of the bytes. Leading zeros are not significant,
+    /// but every digit after the first non-zero digit is, including
+    /// trailing zeros in the integer or the fraction, so `"0.0314"` has
+    /// 3 significant digits, and `"3.140"` and `"3140"` have 4. Zero
+    /// has 1 significant digit, and special values, like `NaN` and
+    /// `inf`, are not checked.
+    #[inline]
+    pub fn require_significant_digits(&self) -> Option<usize> {
+        self.require_significant_digits
+    }
+
+    /// Get if an exponent is required, in scientific notation.
+    ///
+    /// If set, floats without an exponent, like `"3.14"`, are reported as
+    /// [`EmptyExponent`](enum.ErrorCode.html#variant.EmptyExponent)
+    /// at the end of the mantissa, while `"3.14e0"` and `"3e5"` are
+    /// accepted. Special values, like `NaN` and `inf`, are not checked.
+    #[inline]
+    pub fn require_exponent(&self) -> bool {
+        self.require_exponent
+    }
+
+    /// Get if the exponent string must match the case of the exponent.
+    ///
+    /// By default, the exponent string is matched ignoring ASCII case,
+    /// so `"1E5"` and `"1e5"` are both accepted. If set, an exponent
+    /// with a different case, like `"1E5"` for the exponent string `"e"`,
+    /// is not an exponent, and is reported as
+    /// [`InvalidDigit`](enum.ErrorCode.html#variant.InvalidDigit).
+    #[inline]
+    pub fn case_sensitive_exponent(&self) -> bool {
+        self.case_sensitive_exponent
+    }
+
+    /// Get the number format of the float grammar.
+    ///
+    /// The format controls the digit separators and the control grammar,
+    /// like for [`parse_format`](fn.parse_format.html), and defaults to
+    /// the standard format. The other options check the bytes as they
+    /// are written, so a digit separator cannot be combined with
+    /// [`require_significant_digits`](#method.require_significant_digits)
+    /// or [`reject_exponent_leading_zeros`](#method.reject_exponent_leading_zeros).
+    #[inline]
+    #[cfg(feature = "format")]
+    pub fn format(&self) -> NumberFormat {
+        self.format
+    }
+
+    /// Get the sizes of the groups of integer digits.
+    ///
+    /// If set, the digit separators of the format must separate the
+    /// integer digits into groups of these sizes, starting from the
+    /// least-significant group, and the last size repeats for the
+    /// remaining groups, so `[3]` checks Western grouping, like
+    /// `"1,234,567"`, and `[3, 2]` checks Indian grouping, like
+    /// `"12,34,567"`. The most-significant group may have fewer digits,
+    /// and integers without digit separators are always accepted. The
+    /// first misplaced separator, checking from the least-significant
+    /// group outward, is reported as
+    /// [`InvalidDigit`](enum.ErrorCode.html#variant.InvalidDigit).
+    /// By default, the sizes are empty, and the groups are only checked
+    /// as groups of 3 digits for formats with
+    /// [`GROUPED_DIGIT_SEPARATOR`](struct.NumberFormat.html#associatedconstant.GROUPED_DIGIT_SEPARATOR),
+    /// like a strict [`NumberFormat::grouping`](struct.NumberFormat.html#method.grouping),
+    /// otherwise, the separators are only checked by the format. The
+    /// sizes require a digit separator, and must not be zero.
+    #[inline]
+    #[cfg(feature = "format")]
+    pub fn grouping_sizes(&self) -> &'static [u8] {
+        self.grouping_sizes
+    }
+
+    /// Get the value parsed from the sentinel string, and the sentinel string.
+    ///
+    /// If set, bytes starting with the sentinel string, matched exactly,
+    /// are parsed as the value, like `"--"` for a missing float, so a
+    /// chosen value, like a NaN with a payload, roundtrips with
+    /// [`WriteFloatOptions::sentinel`]. The sentinel is matched after
+    /// trimming, and takes precedence over NaN, infinity, and every
+    /// other option, unless the bytes also start with a valid float
+    /// longer than the sentinel, so a sentinel of `"-"` parses `"-5"`
+    /// as `-5.0`. For `f32`, the value is converted to an `f32`.
+    ///
+    /// [`WriteFloatOptions::sentinel`]: struct.WriteFloatOptions.html#method.sentinel
+    #[inline]
+    #[cfg(feature = "optional")]
+    pub fn sentinel(&self) -> Option<(f64, &'static [u8])> {
+        self.sentinel.map(|(bits, string)| (f64::from_bits(bits), string))
+    }
+}
+
+impl Default for ParseFloatOptions {
+    #[inline]
+    fn default() -> ParseFloatOptions {
+        ParseFloatOptions::new()
+    }
+}
+
+/// Builder for [`ParseFloatOptions`].
+///
+/// [`ParseFloatOptions`]: struct.ParseFloatOptions.html
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ParseFloatOptionsBuilder {
+    exponent_string: &'static [u8],
+    parse_negative_zero: bool,
+    strict_sign: bool,
+    lenient_exponent: bool,
+    reject_exponent_leading_zeros: bool,
+    lenient_bare_point: bool,
+    implicit_mantissa_one: bool,
+    sign_before_point: bool,
+    trim_chars: &'static [u8],
+    require_significant_digits: Option<usize>,
+    require_exponent: bool,
+    case_sensitive_exponent: bool,
+    #[cfg(feature = "format")]
+    format: NumberFormat,
+    #[cfg(feature = "format")]
+    grouping_sizes: &'static [u8],
+    #[cfg(feature = "optional")]
+    sentinel: Option<(u64, &'static [u8])>,
+}
+
+impl ParseFloatOptionsBuilder {
+    /// Create a builder with default values.
+    #[inline]
+    pub fn new() -> ParseFloatOptionsBuilder {
+        ParseFloatOptionsBuilder {
+            exponent_string: DEFAULT_EXPONENT_STRING,
+            parse_negative_zero: true,
+            strict_sign: false,
+            lenient_exponent: false,
+            reject_exponent_leading_zeros: false,
+            lenient_bare_point: false,
+            implicit_mantissa_one: false,
+            sign_before_point: true,
+            trim_chars: b"",
+            require_significant_digits: None,
+            require_exponent: false,
+            case_sensitive_exponent: false,
+            #[cfg(feature = "format")]
+            format: NumberFormat::STANDARD,
+            #[cfg(feature = "format")]
+            grouping_sizes: b"",
+            #[cfg(feature = "optional")]
+            sentinel: None,
+        }
+    }
+
+    /// Set the string delimiting the mantissa and the exponent.
+    #[inline]
+    pub fn exponent_string(mut self, exponent_string: &'static [u8]) -> ParseFloatOptionsBuilder {
+        self.exponent_string = exponent_string;
+        self
+    }
+
+    /// Set if negative zero is parsed with the sign bit set.
+    #[inline]
+    pub fn parse_negative_zero(mut self, parse_negative_zero: bool) -> ParseFloatOptionsBuilder {
+        self.parse_negative_zero = parse_negative_zero;
+        self
+    }
+
+    /// Set if a sign following the leading sign is an error.
+    #[inline]
+    pub fn strict_sign(mut self, strict_sign: bool) -> ParseFloatOptionsBuilder {
+        self.strict_sign = strict_sign;
+        self
+    }
+
+    /// Set if an exponent without digits ends the number.
+    #[inline]
+    pub fn lenient_exponent(mut self, lenient_exponent: bool) -> ParseFloatOptionsBuilder {
+        self.lenient_exponent = lenient_exponent;
+        self
+    }
+
+    /// Set if redundant leading zeros in the exponent are an error.
+    #[inline]
+    pub fn reject_exponent_leading_zeros(mut self, reject_exponent_leading_zeros: bool) -> ParseFloatOptionsBuilder {
+        self.reject_exponent_leading_zeros = reject_exponent_leading_zeros;
+        self
+    }
+
+    /// Set if a decimal point without digits is parsed as zero.
+    #[inline]
+    pub fn lenient_bare_point(mut self, lenient_bare_point: bool) -> ParseFloatOptionsBuilder {
+        self.lenient_bare_point = lenient_bare_point;
+        self
+    }
+
+    /// Set if a mantissa without digits before the exponent is one.
+    #[inline]
+    pub fn implicit_mantissa_one(mut self, implicit_mantissa_one: bool) -> ParseFloatOptionsBuilder {
+        self.implicit_mantissa_one = implicit_mantissa_one;
+        self
+    }
+
+    /// Set if a sign may directly precede the decimal point.
+    #[inline]
+    pub fn sign_before_point(mut self, sign_before_point: bool) -> ParseFloatOptionsBuilder {
+        self.sign_before_point = sign_before_point;
+        self
+    }
+
+    /// Set the bytes to trim from the start and end before parsing.
+    #[inline]
+    pub fn trim_chars(mut self, trim_chars: &'static [u8]) -> ParseFloatOptionsBuilder {
+        self.trim_chars = trim_chars;
+        self
+    }
+
+    /// Set the exact number of significant digits required.
+    ///
+    /// Requiring 0 significant digits is invalid.
+    #[inline]
+    pub fn require_significant_digits(mut self, require_significant_digits: Option<usize>) -> ParseFloatOptionsBuilder {
+        self.require_significant_digits = require_significant_digits;
+        self
+    }
+
+    /// Set if an exponent is required, in scientific notation.
+    #[inline]
+    pub fn require_exponent(mut self, require_exponent: bool) -> ParseFloatOptionsBuilder {
+        self.require_exponent = require_exponent;
+        self
+    }
+
+    /// Set if the exponent string must match the case of the exponent.
+    #[inline]
+    pub fn case_sensitive_exponent(mut self, case_sensitive_exponent: bool) -> ParseFloatOptionsBuilder {
+        self.case_sensitive_exponent = case_sensitive_exponent;
+        self
+    }
+
+    /// Set the number format of the float grammar.
+    #[inline]
+    #[cfg(feature = "format")]
+    pub fn format(mut self, format: NumberFormat) -> ParseFloatOptionsBuilder {
+        self.format = format;
+        self
+    }
+
+    /// Set the sizes of the groups of integer digits.
+    #[inline]
+    #[cfg(feature = "format")]
+    pub fn grouping_sizes(mut self, grouping_sizes: &'static [u8]) -> ParseFloatOptionsBuilder {
+        self.grouping_sizes = grouping_sizes;
+        self
+    }
+
+    /// Set the value parsed from the sentinel string, and the sentinel string.
+    #[inline]
+    #[cfg(feature = "optional")]
+    pub fn sentinel_for(mut self, value: f64, string: &'static [u8]) -> ParseFloatOptionsBuilder {
+        self.sentinel = Some((value.to_bits(), string));
+        self
+    }
+
+    /// Build the options.
+    ///
+    /// Returns the options if they are valid, otherwise, returns None.
+    /// The exponent string must not be empty or start with a digit,
+    /// and the trim bytes must be valid. The digit separator must not
+    /// be in the exponent string or the trim bytes, the grouping sizes
+    /// must be valid, and the sentinel string must not be empty.
+    #[inline]
+    pub fn build(self) -> Option<ParseFloatOptions> {
+        if !is_valid_exponent_string(self.exponent_string) {
+            return None;
+        }
+        if !is_valid_trim_chars(self.trim_chars, self.exponent_string) {
+            return None;
+        }
+        if self.require_significant_digits == Some(0) {
+            // Every float has at least 1 significant digit.
+            return None;
+        }
+        #[cfg(feature = "format")] {
+            let digit_separator = self.format.digit_separator();
+            if digit_separator != 0 && !is_valid_digit_separator_options(&self, digit_separator) {
+                return None;
+            }
+            if !is_valid_grouping_sizes(self.grouping_sizes, digit_separator) {
+                return None;
+            }
+        }
+        #[cfg(feature = "optional")] {
+            if self.sentinel.map_or(false, |(_, string)| string.is_empty()) {
+                return None;
+            }
+        }
+        Some(ParseFloatOptions {
+            exponent_string: self.exponent_string,
+            parse_negative_zero: self.parse_negative_zero,
+            strict_sign: self.strict_sign,
+            lenient_exponent: self.lenient_exponent,
+            reject_exponent_leading_zeros: self.reject_exponent_leading_zeros,
+            lenient_bare_point: self.lenient_bare_point,
+            implicit_mantissa_one: self.implicit_mantissa_one,
+            sign_before_point: self.sign_before_point,
+            trim_chars: self.trim_chars,
+            require_significant_digits: self.require_significant_digits,
+            require_exponent: self.require_exponent,
+            case_sensitive_exponent: self.case_sensitive_exponent,
+            #[cfg(feature = "format")]
+            format: self.format,
+            #[cfg(feature = "format")]
+            grouping_sizes: self.grouping_sizes,
+            #[cfg(feature = "optional")]
+            sentinel: self.sentinel,
+        })
+    }
+}
+
+impl Default for ParseFloatOptionsBuilder {
+    #[inline]
+    fn default() -> ParseFloatOptionsBuilder {
+        ParseFloatOptionsBuilder::new()
+    }
+}
+
+// PARSE INTEGER
+
+/// Options to customize parsing integers.
+///
+/// Options are created from a [`ParseIntegerOptionsBuilder`], which
+/// validates the options when built. The default options parse the
+/// same strings as [`parse`](fn.parse.html).
+///
+/// [`ParseIntegerOptionsBuilder`]: struct.ParseIntegerOptionsBuilder.html
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ParseIntegerOptions {
+    /// Error on a sign following the leading sign.
+    strict_sign: bool,
+    /// Prefix for hexadecimal numbers.
+    hex_prefix: &'static [u8],
+    /// Prefix for octal numbers.
+    oct_prefix: &'static [u8],
+    /// Prefix for binary numbers.
+    bin_prefix: &'static [u8],
+    /// Parse numbers with a leading zero as octal.
+    octal_leading_zero: bool,
+    /// Bytes to trim from the start and end before parsing.
+    trim_chars: &'static [u8],
+    /// Number format of the integer grammar.
+    #[cfg(feature = "format")]
+    format: NumberFormat,
+    /// Sizes of the groups of digits, from the least-significant group.
+    #[cfg(feature = "format")]
+    grouping_sizes: &'static [u8],
+}
+
+impl ParseIntegerOptions {
+    /// Create options with default values.
+    #[inline]
+    pub fn new() -> ParseIntegerOptions {
+        ParseIntegerOptions {
+            strict_sign: false,
+            hex_prefix: DEFAULT_HEX_PREFIX,
+            oct_prefix: DEFAULT_OCT_PREFIX,
+            bin_prefix: DEFAULT_BIN_PREFIX,
+            octal_leading_zero: false,
+            trim_chars: b"",
+            #[cfg(feature = "format")]
+            format: NumberFormat::STANDARD,
+            #[cfg(feature = "format")]
+            grouping_sizes: b"",
+        }
+    }
+
+    /// Create a builder to customize the options.
+    #[inline]
+    pub fn builder() -> ParseIntegerOptionsBuilder {
+        ParseIntegerOptionsBuilder::new()
+    }
+
+    /// Get if a sign following the leading sign is an error.
+    ///
+    /// At most one leading sign is parsed. By default, a second sign,
+    /// like in `"--5"` or `"+-5"`, ends the number, so it is reported
+    /// as an invalid digit. If set, the second sign is reported as
+    /// [`InvalidSign`](enum.ErrorCode.html#variant.InvalidSign).
+    /// Unsigned integers never parse a leading `-`, so `"-+5"` is
+    /// always an invalid digit for unsigned integers.
+    #[inline]
+    pub fn strict_sign(&self) -> bool {
+        self.strict_sign
+    }
+
+    /// Get the prefix for hexadecimal numbers.
+    ///
+    /// Radix prefixes are detected by [`parse_flexible_with_options`],
+    /// ignoring ASCII case, and default to `0x`, `0o`, and `0b`.
+    ///
+    /// [`parse_flexible_with_options`]: fn.parse_flexible_with_options.html
+    #[inline]
+    pub fn hex_prefix(&self) -> &'static [u8] {
+        self.hex_prefix
+    }
+
+    /// Get the prefix for octal numbers.
+    #[inline]
+    pub fn oct_prefix(&self) -> &'static [u8] {
+        self.oct_prefix
+    }
+
+    /// Get the prefix for binary numbers.
+    #[inline]
+    pub fn bin_prefix(&self) -> &'static [u8] {
+        self.bin_prefix
+    }
+
+    /// Get if numbers with a leading zero are parsed as octal.
+    ///
+    /// If set, [`parse_flexible_with_options`] parses a leading `0`
+    /// followed by a digit as octal, like C, so `"017"` is parsed as
+    /// `15` in radix 8, and `"08"` is reported as an invalid digit at
+    /// the `8`. The radix prefixes take precedence, so `"0x1F"` is
+    /// still hexadecimal, and a lone `"0"` is parsed in the default
+    /// radix. Otherwise, a leading zero is a redundant zero digit, so
+    /// `"017"` is parsed as `17` in radix 10.
+    ///
+    /// [`parse_flexible_with_options`]: fn.parse_flexible_with_options.html
+    #[inline]
+    pub fn octal_leading_zero(&self) -> bool {
+        self.octal_leading_zero
+    }
+
+    /// Get the bytes to trim from the start and end before parsing.
+    ///
+    /// Any of the bytes are trimmed from the start and the end of the
+    /// string, like brackets or quotes around the number, so `"[5]"`
+    /// is parsed as `5` with the bytes `[]`. This only trims, and does
+    /// not check the bytes are balanced. See [`ParseFloatOptions::trim_chars`]
+    /// for details. The bytes must not contain ASCII alphanumeric
+    /// characters, signs, or decimal points.
+    ///
+    /// [`ParseFloatOptions::trim_chars`]: struct.ParseFloatOptions.html#method.trim_chars
+    #[inline]
+    pub fn trim_chars(&self) -> &'static [u8] {
+        self.trim_chars
+    }
+
+    /// Get the number format of the integer grammar.
+    ///
+    /// The format controls the digit separators, like for
+    /// [`parse_format`](fn.parse_format.html), and defaults to the
+    /// standard format.
+    #[inline]
+    #[cfg(feature = "format")]
+    pub fn format(&self) -> NumberFormat {
+        self.format
+    }
+
+    /// Get the sizes of the groups of digits.
+    ///
+    /// If set, the digit separators of the format must separate the
+    /// digits into groups of these sizes, like `[3, 2]` for `"12,34,567"`.
+    /// See [`ParseFloatOptions::grouping_sizes`] for details.
+    ///
+    /// [`ParseFloatOptions::grouping_sizes`]: struct.ParseFloatOptions.html#method.grouping_sizes
+    #[inline]
+    #[cfg(feature = "format")]
+    pub fn grouping_sizes(&self) -> &'static [u8] {
+        self.grouping_sizes
+    }
+}
+
+impl Default for ParseIntegerOptions {
+    #[inline]
+    fn default() -> ParseIntegerOptions {
+        ParseIntegerOptions::new()
+    }
+}
+
+/// Builder for [`ParseIntegerOptions`].
+///
+/// [`ParseIntegerOptions`]: struct.ParseIntegerOptions.html
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ParseIntegerOptionsBuilder {
+    strict_sign: bool,
+    hex_prefix: &'static [u8],
+    oct_prefix: &'static [u8],
+    bin_prefix: &'static [u8],
+    octal_leading_zero: bool,
+    trim_chars: &'static [u8],
+    #[cfg(feature = "format")]
+    format: NumberFormat,
+    #[cfg(feature = "format")]
+    grouping_sizes: &'static [u8],
+}
+
+impl ParseIntegerOptionsBuilder {
+    /// Create a builder with default values.
+    #[inline]
+    pub fn new() -> ParseIntegerOptionsBuilder {
+        ParseIntegerOptionsBuilder {
+            strict_sign: false,
+            hex_prefix: DEFAULT_HEX_PREFIX,
+            oct_prefix: DEFAULT_OCT_PREFIX,
+            bin_prefix: DEFAULT_BIN_PREFIX,
+            octal_leading_zero: false,
+            trim_chars: b"",
+            #[cfg(feature = "format")]
+            format: NumberFormat::STANDARD,
+            #[cfg(feature = "format")]
+            grouping_sizes: b"",
+        }
+    }
+
+    /// Set if a sign following the leading sign is an error.
+    #[inline]
+    pub fn strict_sign(mut self, strict_sign: bool) -> ParseIntegerOptionsBuilder {
+        self.strict_sign = strict_sign;
+        self
+    }
+
+    /// Set the prefix for hexadecimal numbers.
+    #[inline]
+    pub fn hex_prefix(mut self, hex_prefix: &'static [u8]) -> ParseIntegerOptionsBuilder {
+        self.hex_prefix = hex_prefix;
+        self
+    }
+
+    /// Set the prefix for octal numbers.
+    #[inline]
+    pub fn oct_prefix(mut self, oct_prefix: &'static [u8]) -> ParseIntegerOptionsBuilder {
+        self.oct_prefix = oct_prefix;
+        self
+    }
+
+    /// Set the prefix for binary numbers.
+    #[inline]
+    pub fn bin_prefix(mut self, bin_prefix: &'static [u8]) -> ParseIntegerOptionsBuilder {
+        self.bin_prefix = bin_prefix;
+        self
+    }
+
+    /// Set if numbers with a leading zero are parsed as octal.
+    #[inline]
+    pub fn octal_leading_zero(mut self, octal_leading_zero: bool) -> ParseIntegerOptionsBuilder {
+        self.octal_leading_zero = octal_leading_zero;
+        self
+    }
+
+    /// Set the bytes to trim from the start and end before parsing.
+    #[inline]
+    pub fn trim_chars(mut self, trim_chars: &'static [u8]) -> ParseIntegerOptionsBuilder {
+        self.trim_chars = trim_chars;
+        self
+    }
+
+    /// Set the number format of the integer grammar.
+    #[inline]
+    #[cfg(feature = "format")]
+    pub fn format(mut self, format: NumberFormat) -> ParseIntegerOptionsBuilder {
+        self.format = format;
+        self
+    }
+
+    /// Set the sizes of the groups of digits.
+    #[inline]
+    #[cfg(feature = "format")]
+    pub fn grouping_sizes(mut self, grouping_sizes: &'static [u8]) -> ParseIntegerOptionsBuilder {
+        self.grouping_sizes = grouping_sizes;
+        self
+    }
+
+    /// Build the options.
+    ///
+    /// Returns the options if they are valid, otherwise, returns None.
+    /// The radix prefixes must not be empty, consist only of digits,
+    /// or contain a sign or decimal point, and no prefix may start
+    /// with another prefix, ignoring ASCII case. The trim bytes must
+    /// also be valid, and must not contain the digit separator, and
+    /// the grouping sizes must be valid.
+    #[inline]
+    pub fn build(self) -> Option<ParseIntegerOptions> {
+        if !is_valid_trim_chars(self.trim_chars, b"") {
+            return None;
+        }
+        #[cfg(feature = "format")] {
+            let digit_separator = self.format.digit_separator();
+            if digit_separator != 0 && self.trim_chars.contains(&digit_separator) {
+                return None;
+            }
+            if !is_valid_grouping_sizes(self.grouping_sizes, digit_separator) {
+                return None;
+            }
+        }
+        let prefixes = [self.hex_prefix, self.oct_prefix, self.bin_prefix];
+        if !prefixes.iter().all(|prefix| is_valid_radix_prefix(prefix)) {
+            return None;
+        }
+        for (i, x) in prefixes.iter().enumerate() {
+            for (j, y) in prefixes.iter().enumerate() {
+                if i != j && x.len() <= y.len() && x.eq_ignore_ascii_case(&y[..x.len()]) {
+                    return None;
+                }
+            }
+        }
+        Some(ParseIntegerOptions {
+            strict_sign: self.strict_sign,
+            hex_prefix: self.hex_prefix,
+            oct_prefix: self.oct_prefix,
+            bin_prefix: self.bin_prefix,
+            octal_leading_zero: self.octal_leading_zero,
+            trim_chars: self.trim_chars,
+            #[cfg(feature = "format")]
+            format: self.format,
+            #[cfg(feature = "format")]
+            grouping_sizes: self.grouping_sizes,
+        })
+    }
+}
+
+impl Default for ParseIntegerOptionsBuilder {
+    #[inline]
+    fn default() -> ParseIntegerOptionsBuilder {
+        ParseIntegerOptionsBuilder::new()
+    }
+}
+
+// WRITE FLOAT
+
+/// Layout of the digits in written floats.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::{WriteFloatLayout, WriteFloatOptions};
+/// # pub fn main() {
+/// let options = WriteFloatOptions::builder()
+///     .layout(WriteFloatLayout::ScaledInteger)
+///     .build()
+///     .unwrap();
+/// let mut buffer = [b'0'; lexical_core::BUFFER_SIZE];
+/// assert_eq!(lexical_core::write_with_options(123.45f64, &mut buffer, &options), b"12345e-2");
+/// assert_eq!(lexical_core::write_with_options(12300.0f64, &mut buffer, &options), b"12300e0");
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum WriteFloatLayout {
+    /// Write the digits with a decimal point, and an exponent for large or small floats.
+    Standard,
+    /// Write the significant digits as an integer, followed by an exponent.
+    ///
+    /// The float is never written with a decimal point. The shortest
+    /// digits are written without the trailing zeros of the fraction,
+    /// and the exponent is always written, so `123.45` is written as
+    /// `12345e-2`, `12300.0` as `12300e0`, and `0.0` as `0e0`.
+    ScaledInteger,
+    /// Write the digits in scientific notation, with a single integer digit.
+    ///
+    /// The exponent is always written, so `1200.0` is written as `1.2e3`,
+    /// and `0.0` as `0e0`. The minimum fraction digits pad the mantissa,
+    /// so `1200.0` is written as `1.200000e3` with 6 minimum fraction
+    /// digits, rather than the digits after the decimal point of the
+    /// value in the standard layout.
+    Scientific,
+    /// Write the digits in fixed or scientific notation, whichever is shorter.
+    ///
+    /// The lengths are compared after padding the fraction and formatting
+    /// the exponent, and fixed notation is written if both are equally
+    /// long, so `123456.0` is written as `123456.0`, and `100000.0` as
+    /// `1.0e5`. This matches the choice of R.
+    Compact,
+    /// Write the digits in fixed notation for exponents within the inclusive range.
+    ///
+    /// Floats with a scientific exponent from the first to the second
+    /// value are written in fixed notation, and the remaining floats are
+    /// written in scientific notation, so `100000.0` is written as
+    /// `100000.0`, and `1000000.0` as `1.0e6`, with a range of `-4` to
+    /// `5`. The minimum fraction digits pad the mantissa in scientific
+    /// notation. Large ranges require large buffers, since every digit
+    /// is written in fixed notation.
+    FixedRange(i32, i32),
+}
+
+impl Default for WriteFloatLayout {
+    #[inline]
+    fn default() -> WriteFloatLayout {
+        WriteFloatLayout::Standard
+    }
+}
+
+/// Choice between equally-short digits that parse to the same float.
+///
+/// The shortest digits that parse to a float may not be unique, like
+/// for the smallest denormal `f64`, where `3e-324` to `7e-324` all
+/// parse to the same float. The last significant digit of the
+/// shortest digits is chosen from these digits, like `1.3e-323` to
+/// `1.7e-323` for the third-smallest denormal `f64`.
+///
+/// Choices other than [`Nearest`] parse the digits again, up to 8
+/// times, to find the digits that still parse to the float, so they
+/// require the `correct` feature to parse the digits without error.
+///
+/// [`Nearest`]: enum.ShortestTie.html#variant.Nearest
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::{ShortestTie, WriteFloatOptions};
+/// # pub fn main() {
+/// let options = WriteFloatOptions::builder()
+///     .shortest_tie(ShortestTie::TowardZero)
+///     .build()
+///     .unwrap();
+/// let mut buffer = [b'0'; lexical_core::BUFFER_SIZE + 1];
+/// assert_eq!(lexical_core::write_with_options(1.5e-323f64, &mut buffer, &options), b"1.3e-323");
+/// # }
+/// ```
+#[cfg(feature = "correct")]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ShortestTie {
+    /// Choose the digits nearest to the exact value of the float.
+    ///
+    /// This matches the choice of Ryu, and of the other algorithms
+    /// used to write the shortest digits.
+    Nearest,
+    /// Choose the digits nearest to zero.
+    TowardZero,
+    /// Choose the digits farthest from zero.
+    AwayZero,
+}
+
+#[cfg(feature = "correct")]
+impl Default for ShortestTie {
+    #[inline]
+    fn default() -> ShortestTie {
+        ShortestTie::Nearest
+    }
+}
+
+/// Options to customize writing floats.
+///
+/// Options are created from a [`WriteFloatOptionsBuilder`], which
+/// validates the options when built. The default options produce
+/// the same output as [`write`](fn.write.html).
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::WriteFloatOptions;
+/// # pub fn main() {
+/// let options = WriteFloatOptions::builder()
+///     .min_fraction_digits(2)
+///     .build()
+///     .unwrap();
+/// assert_eq!(options.min_fraction_digits(), 2);
+/// # }
+/// ```
+///
+/// [`WriteFloatOptionsBuilder`]: struct.WriteFloatOptionsBuilder.html
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct WriteFloatOptions {
+    /// Minimum number of digits to write after the decimal point.
+    min_fraction_digits: usize,
+    /// Delimiter between the mantissa and the exponent.
+    exponent_string: &'static [u8],
+    /// Write integral floats without a decimal point.
+    integer_as_integer: bool,
+    /// Layout of the written digits.
+    layout: WriteFloatLayout,
+    /// Choice between equally-short digits.
+    #[cfg(feature = "correct")]
+    shortest_tie: ShortestTie,
+    /// String written for positive zero, overriding the computed string.
+    zero_string: Option<&'static [u8]>,
+    /// String written for negative zero, overriding the computed string.
+    negative_zero_string: Option<&'static [u8]>,
+    /// Separator and size of the groups of fraction digits.
+    fraction_grouping: Option<(u8, u8)>,
+    /// Write a `+` sign before positive exponents.
+    positive_exponent_sign: bool,
+    /// Minimum number of digits to write in the exponent.
+    min_exponent_digits: usize,
+    /// String written for NaN, overriding the global string.
+    nan_string: Option<&'static [u8]>,
+    /// String written for infinity, overriding the global string.
+    inf_string: Option<&'static [u8]>,
+    /// Maximum number of zeros to write in fixed notation.
+    fixed_zero_cap: Option<usize>,
+    /// String written before negative floats.
+    minus_sign: &'static [u8],
+    /// String written before positive floats, if set.
+    plus_sign: Option<&'static [u8]>,
+    /// Bits of the value written as the sentinel string, and the sentinel string.
+    #[cfg(feature = "optional")]
+    sentinel: Option<(u64, &'static [u8])>,
+}
+
+impl WriteFloatOptions {
+    /// Create options with default values.
+    #[inline]
+    pub fn new() -> WriteFloatOptions {
+        WriteFloatOptions {
+            min_fraction_digits: DEFAULT_MIN_FRACTION_DIGITS,
+            exponent_string: DEFAULT_EXPONENT_STRING,
+            integer_as_integer: false,
+            layout: WriteFloatLayout::Standard,
+            #[cfg(feature = "correct")]
+            shortest_tie: ShortestTie::Nearest,
+            zero_string: None,
+            negative_zero_string: None,
+            fraction_grouping: None,
+            positive_exponent_sign: false,
+            min_exponent_digits: 1,
+            nan_string: None,
+            inf_string: None,
+            fixed_zero_cap: None,
+            minus_sign: DEFAULT_MINUS_SIGN,
+            plus_sign: None,
+            #[cfg(feature = "optional")]
+            sentinel: None,
+        }
+    }
+
+    /// Create a builder to customize the options.
+    #[inline]
+    pub fn builder() -> WriteFloatOptionsBuilder {
+        WriteFloatOptionsBuilder::new()
+    }
+
+    /// Create options writing floats like R.
+    ///
+    /// Floats are written in the shorter of fixed or scientific
+    /// notation, with a signed exponent of at least 2 digits, and
+    /// without a trailing `.0`, like `123456`, `1e+05`, and `1e-04`.
+    /// Infinity is written as `Inf`, NaN as `NaN`, and negative zero
+    /// as `0`. R writes at most 15 significant digits, while the
+    /// shortest digits are always written, so values like `0.1 + 0.2`
+    /// are written with more digits than R.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate lexical_core;
+    /// # use lexical_core::WriteFloatOptions;
+    /// # pub fn main() {
+    /// let options = WriteFloatOptions::r_compatible();
+    /// let mut buffer = [b'0'; lexical_core::BUFFER_SIZE];
+    /// assert_eq!(lexical_core::write_with_options(1e5f64, &mut buffer, &options), b"1e+05");
+    /// assert_eq!(lexical_core::write_with_options(-1.0f64 / 0.0, &mut buffer, &options), b"-Inf");
+    /// # }
+    /// ```
+    #[inline]
+    pub fn r_compatible() -> WriteFloatOptions {
+        WriteFloatOptions {
+            min_fraction_digits: 0,
+            layout: WriteFloatLayout::Compact,
+            zero_string: Some(b"0"),
+            negative_zero_string: Some(b"0"),
+            positive_exponent_sign: true,
+            min_exponent_digits: 2,
+            nan_string: Some(b"NaN"),
+            inf_string: Some(b"Inf"),
+            ..WriteFloatOptions::new()
+        }
+    }
+
+    /// Create options writing floats like Julia.
+    ///
+    /// Floats with a scientific exponent from `-4` to `5` are written in
+    /// fixed notation, and the remaining floats in scientific notation,
+    /// always with a fraction, like `100000.0`, `1.0e6`, and `1.0e-5`.
+    /// Infinity is written as `Inf`, and NaN as `NaN`. This matches
+    /// the output of `Float64` values, while Julia writes `Float32`
+    /// values with an `f` exponent.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate lexical_core;
+    /// # use lexical_core::WriteFloatOptions;
+    /// # pub fn main() {
+    /// let options = WriteFloatOptions::julia_compatible();
+    /// let mut buffer = [b'0'; lexical_core::BUFFER_SIZE + 1];
+    /// assert_eq!(lexical_core::write_with_options(1e6f64, &mut buffer, &options), b"1.0e6");
+    /// assert_eq!(lexical_core::write_with_options(0.0001f64, &mut buffer, &options), b"0.0001");
+    /// # }
+    /// ```
+    #[inline]
+    pub fn julia_compatible() -> WriteFloatOptions {
+        WriteFloatOptions {
+            min_fraction_digits: 1,
+            layout: WriteFloatLayout::FixedRange(-4, 5),
+            zero_string: Some(b"0.0"),
+            negative_zero_string: Some(b"-0.0"),
+            nan_string: Some(b"NaN"),
+            inf_string: Some(b"Inf"),
+            ..WriteFloatOptions::new()
+        }
+    }
+
+    /// Get the minimum number of digits to write after the decimal point.
+    ///
+    /// The shortest representation of the float is padded with trailing
+    /// zeros until it has at least this many fraction digits. For
+    /// floats written in scientific notation, including every float
+    /// with the [`Scientific`] layout, the mantissa is padded.
+    /// A minimum of `0` removes the trailing `.0` from integral floats.
+    ///
+    /// [`Scientific`]: enum.WriteFloatLayout.html#variant.Scientific
+    #[inline]
+    pub fn min_fraction_digits(&self) -> usize {
+        self.min_fraction_digits
+    }
+
+    /// Get the string delimiting the mantissa and the exponent.
+    ///
+    /// If the string starts with a valid digit for the radix, like
+    /// `e` in radix 16, the default exponent character for the radix
+    /// is written instead.
+    #[inline]
+    pub fn exponent_string(&self) -> &'static [u8] {
+        self.exponent_string
+    }
+
+    /// Get if integral floats are written without a decimal point.
+    ///
+    /// Floats written without an exponent and with a fraction of
+    /// zero, like `42.0`, are written as integers, like `42`, and
+    /// the minimum fraction digits only apply to the remaining
+    /// floats. This is independent of the `trim_floats` feature,
+    /// and floats written in scientific notation are unchanged.
+    #[inline]
+    pub fn integer_as_integer(&self) -> bool {
+        self.integer_as_integer
+    }
+
+    /// Get the layout of the written digits.
+    ///
+    /// With the [`ScaledInteger`] layout, the minimum fraction digits
+    /// and integer as integer options are ignored, since floats are
+    /// never written with a decimal point. The exponent string is
+    /// still used to delimit the mantissa and the exponent. With the
+    /// [`Scientific`] layout, the integer as integer option is ignored,
+    /// and the minimum fraction digits pad the mantissa. The remaining
+    /// layouts choose between fixed and scientific notation per float.
+    ///
+    /// [`ScaledInteger`]: enum.WriteFloatLayout.html#variant.ScaledInteger
+    /// [`Scientific`]: enum.WriteFloatLayout.html#variant.Scientific
+    #[inline]
+    pub fn layout(&self) -> WriteFloatLayout {
+        self.layout
+    }
+
+    /// Get the choice between equally-short digits.
+    ///
+    /// The default choice, [`Nearest`], matches the digits written by
+    /// Ryu. Other choices step the last significant digit toward or
+    /// away from zero, while the digits still parse to the same float,
+    /// so the output is deterministic for every algorithm. This only
+    /// applies to decimal strings, and other choices parse the digits
+    /// again, up to 8 times, which is slower than writing the float.
+    ///
+    /// [`Nearest`]: enum.ShortestTie.html#variant.Nearest
+    #[cfg(feature = "correct")]
+    #[inline]
+    pub fn shortest_tie(&self) -> ShortestTie {
+        self.shortest_tie
+    }
+
+    /// Get the string written for positive zero.
+    ///
+    /// If set, positive zero is written as exactly this string, ignoring
+    /// every other option, otherwise, the string is computed like any
+    /// other float. This matches formats that write zero differently,
+    /// like `0`, `0.0`, or `0E0`, regardless of the layout.
+    #[inline]
+    pub fn zero_string(&self) -> Option<&'static [u8]> {
+        self.zero_string
+    }
+
+    /// Get the string written for negative zero.
+    ///
+    /// If set, negative zero is written as exactly this string, ignoring
+    /// every other option, otherwise, the string is computed like any
+    /// other float. This is independent of the string for positive zero
+    /// and the sentinel, so with the `trim_floats` feature, negative
+    /// zero is written as `0`, like [`write`](fn.write.html), rather
+    /// than as either string for positive zero.
+    #[inline]
+    pub fn negative_zero_string(&self) -> Option<&'static [u8]> {
+        self.negative_zero_string
+    }
+
+    /// Get the separator and size of the groups of fraction digits.
+    ///
+    /// If set, the separator is written between every group of the
+    /// given number of digits after the decimal point, starting from
+    /// the decimal point, like `0.123 456 789` with a separator of
+    /// `b' '` and a size of `3`. This includes any padding digits, and
+    /// applies to the mantissa in scientific notation, but the integer
+    /// digits and the exponent are never grouped. The separator must
+    /// not be an ASCII alphanumeric character, a sign, or a decimal
+    /// point, and the size must not be `0`. The grouped digits are
+    /// not parsed by the default parsers.
+    #[inline]
+    pub fn fraction_grouping(&self) -> Option<(u8, u8)> {
+        self.fraction_grouping
+    }
+
+    /// Get if a `+` sign is written before positive exponents.
+    ///
+    /// Negative exponents always have a `-` sign, and the sign is
+    /// written after the exponent string, like `1e+16`.
+    #[inline]
+    pub fn positive_exponent_sign(&self) -> bool {
+        self.positive_exponent_sign
+    }
+
+    /// Get the minimum number of digits to write in the exponent.
+    ///
+    /// Exponents with fewer digits are padded with leading zeros,
+    /// after the sign, like `1e-07` with a minimum of `2`. This
+    /// applies to every float written with an exponent.
+    #[inline]
+    pub fn min_exponent_digits(&self) -> usize {
+        self.min_exponent_digits
+    }
+
+    /// Get the string written for NaN.
+    ///
+    /// If set, NaN is written as exactly this string, otherwise, the
+    /// global string from [`get_nan_string`] is written. The string
+    /// must not be empty, or start with a digit, a sign, or a decimal
+    /// point.
+    ///
+    /// [`get_nan_string`]: fn.get_nan_string.html
+    #[inline]
+    pub fn nan_string(&self) -> Option<&'static [u8]> {
+        self.nan_string
+    }
+
+    /// Get the string written for infinity.
+    ///
+    /// If set, infinity is written as this string, after the sign
+    /// of negative infinity, otherwise, the global string from
+    /// [`get_inf_string`] is written. The string must not be empty,
+    /// or start with a digit, a sign, or a decimal point.
+    ///
+    /// [`get_inf_string`]: fn.get_inf_string.html
+    #[inline]
+    pub fn inf_string(&self) -> Option<&'static [u8]> {
+        self.inf_string
+    }
+
+    /// Get the maximum number of zeros to write in fixed notation.
+    ///
+    /// If set, floats that would be written in fixed notation with more
+    /// zeros than the cap, to position the significant digits around
+    /// the decimal point, are written in scientific notation instead,
+    /// like `1e-20` rather than `0.00000000000000000001`. The zeros
+    /// are the leading zeros of small floats, including the integer
+    /// zero, like the 3 zeros in `0.001`, and the trailing zeros of the
+    /// integer digits of large floats, like the 3 zeros in `1000.0`.
+    /// This applies to every layout written in fixed notation, and
+    /// never writes a float in fixed notation that would otherwise be
+    /// written in scientific notation.
+    #[inline]
+    pub fn fixed_zero_cap(&self) -> Option<usize> {
+        self.fixed_zero_cap
+    }
+
+    /// Get the string written before negative floats.
+    ///
+    /// The string replaces the `-` sign of negative floats, including
+    /// negative zero and negative infinity, like the Unicode minus
+    /// sign, `U+2212`, encoded as the bytes `b"\xE2\x88\x92"`. The
+    /// sign of the exponent is always `-`, and the strings for zero,
+    /// NaN, and the sentinel are written exactly. The string must not
+    /// be empty, or contain an ASCII alphanumeric character or a
+    /// decimal point.
+    #[inline]
+    pub fn minus_sign(&self) -> &'static [u8] {
+        self.minus_sign
+    }
+
+    /// Get the string written before positive floats.
+    ///
+    /// If set, the string is written before every positive float,
+    /// including positive zero and positive infinity, like `+`, so
+    /// the sign is always written. NaN and the strings for zero and
+    /// the sentinel are written without a sign. The string has the
+    /// same requirements as the [`minus_sign`].
+    ///
+    /// [`minus_sign`]: #method.minus_sign
+    #[inline]
+    pub fn plus_sign(&self) -> Option<&'static [u8]> {
+        self.plus_sign
+    }
+
+    /// Get the value written as the sentinel string, and the sentinel string.
+    ///
+    /// If set, a float with the same bits as the value is written as
+    /// exactly the sentinel string, like `"--"` for a missing float.
+    /// The sentinel takes precedence over the NaN, infinity, and zero
+    /// strings, and every other option, but only matches the exact
+    /// bits, so a chosen NaN payload is written as the sentinel, while
+    /// any other NaN is still written as NaN, and `-0.0` does not match
+    /// `0.0`. For `f32`, the value is converted to an `f32`. The string
+    /// must not be empty.
+    #[inline]
+    #[cfg(feature = "optional")]
+    pub fn sentinel(&self) -> Option<(f64, &'static [u8])> {
+        self.sentinel.map(|(bits, string)| (f64::from_bits(bits), string))
+    }
+}
+
+impl Default for WriteFloatOptions {
+    #[inline]
+    fn default() -> WriteFloatOptions {
+        WriteFloatOptions::new()
+    }
+}
+
+/// Builder for [`WriteFloatOptions`].
+///
+/// [`WriteFloatOptions`]: struct.WriteFloatOptions.html
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct WriteFloatOptionsBuilder {
+    min_fraction_digits: usize,
+    exponent_string: &'static [u8],
+    integer_as_integer: bool,
+    layout: WriteFloatLayout,
+    #[cfg(feature = "correct")]
+    shortest_tie: ShortestTie,
+    zero_string: Option<&'static [u8]>,
+    negative_zero_string: Option<&'static [u8]>,
+    fraction_grouping: Option<(u8, u8)>,
+    positive_exponent_sign: bool,
+    min_exponent_digits: usize,
+    nan_string: Option<&'static [u8]>,
+    inf_string: Option<&'static [u8]>,
+    fixed_zero_cap: Option<usize>,
+    minus_sign: &'static [u8],
+    plus_sign: Option<&'static [u8]>,
+    #[cfg(feature = "optional")]
+    sentinel: Option<(u64, &'static [u8])>,
+}
+
+impl WriteFloatOptionsBuilder {
+    /// Create a builder with default values.
+    #[inline]
+    pub fn new() -> WriteFloatOptionsBuilder {
+        WriteFloatOptionsBuilder {
+            min_fraction_digits: DEFAULT_MIN_FRACTION_DIGITS,
+            exponent_string: DEFAULT_EXPONENT_STRING,
+            integer_as_integer: false,
+            layout: WriteFloatLayout::Standard,
+            #[cfg(feature = "correct")]
+            shortest_tie: ShortestTie::Nearest,
+            zero_string: None,
+            negative_zero_string: None,
+            fraction_grouping: None,
+            positive_exponent_sign: false,
+            min_exponent_digits: 1,
+            nan_string: None,
+            inf_string: None,
+            fixed_zero_cap: None,
+            minus_sign: DEFAULT_MINUS_SIGN,
+            plus_sign: None,
+            #[cfg(feature = "optional")]
+            sentinel: None,
+        }
+    }
+
+    /// Set the minimum number of digits to write after the decimal point.
+    #[inline]
+    pub fn min_fraction_digits(mut self, min_fraction_digits: usize) -> WriteFloatOptionsBuilder {
+        self.min_fraction_digits = min_fraction_digits;
+        self
+    }
+
+    /// Set the string delimiting the mantissa and the exponent.
+    #[inline]
+    pub fn exponent_string(mut self, exponent_string: &'static [u8]) -> WriteFloatOptionsBuilder {
+        self.exponent_string = exponent_string;
+        self
+    }
+
+    /// Set if integral floats are written without a decimal point.
+    #[inline]
+    pub fn integer_as_integer(mut self, integer_as_integer: bool) -> WriteFloatOptionsBuilder {
+        self.integer_as_integer = integer_as_integer;
+        self
+    }
+
+    /// Set the layout of the written digits.
+    #[inline]
+    pub fn layout(mut self, layout: WriteFloatLayout) -> WriteFloatOptionsBuilder {
+        self.layout = layout;
+        self
+    }
+
+    /// Set the choice between equally-short digits.
+    #[cfg(feature = "correct")]
+    #[inline]
+    pub fn shortest_tie(mut self, shortest_tie: ShortestTie) -> WriteFloatOptionsBuilder {
+        self.shortest_tie = shortest_tie;
+        self
+    }
+
+    /// Set the string written for positive zero.
+    #[inline]
+    pub fn zero_string(mut self, zero_string: Option<&'static [u8]>) -> WriteFloatOptionsBuilder {
+        self.zero_string = zero_string;
+        self
+    }
+
+    /// Set the string written for negative zero.
+    #[inline]
+    pub fn negative_zero_string(mut self, negative_zero_string: Option<&'static [u8]>) -> WriteFloatOptionsBuilder {
+        self.negative_zero_string = negative_zero_string;
+        self
+    }
+
+    /// Set the separator and size of the groups of fraction digits.
+    #[inline]
+    pub fn fraction_grouping(mut self, fraction_grouping: Option<(u8, u8)>) -> WriteFloatOptionsBuilder {
+        self.fraction_grouping = fraction_grouping;
+        self
+    }
+
+    /// Set if a `+` sign is written before positive exponents.
+    #[inline]
+    pub fn positive_exponent_sign(mut self, positive_exponent_sign: bool) -> WriteFloatOptionsBuilder {
+        self.positive_exponent_sign = positive_exponent_sign;
+        self
+    }
+
+    /// Set the minimum number of digits to write in the exponent.
+    #[inline]
+    pub fn min_exponent_digits(mut self, min_exponent_digits: usize) -> WriteFloatOptionsBuilder {
+        self.min_exponent_digits = min_exponent_digits;
+        self
+    }
+
+    /// Set the string written for NaN.
+    #[inline]
+    pub fn nan_string(mut self, nan_string: Option<&'static [u8]>) -> WriteFloatOptionsBuilder {
+        self.nan_string = nan_string;
+        self
+    }
+
+    /// Set the string written for infinity.
+    #[inline]
+    pub fn inf_string(mut self, inf_string: Option<&'static [u8]>) -> WriteFloatOptionsBuilder {
+        self.inf_string = inf_string;
+        self
+    }
+
+    /// Set the maximum number of zeros to write in fixed notation.
+    #[inline]
+    pub fn fixed_zero_cap(mut self, fixed_zero_cap: Option<usize>) -> WriteFloatOptionsBuilder {
+        self.fixed_zero_cap = fixed_zero_cap;
+        self
+    }
+
+    /// Set the string written before negative floats.
+    #[inline]
+    pub fn minus_sign(mut self, minus_sign: &'static [u8]) -> WriteFloatOptionsBuilder {
+        self.minus_sign = minus_sign;
+        self
+    }
+
+    /// Set the string written before positive floats, if set.
+    #[inline]
+    pub fn plus_sign(mut self, plus_sign: Option<&'static [u8]>) -> WriteFloatOptionsBuilder {
+        self.plus_sign = plus_sign;
+        self
+    }
+
+    /// Set the value written as the sentinel string, and the sentinel string.
+    #[inline]
+    #[cfg(feature = "optional")]
+    pub fn sentinel_for(mut self, value: f64, string: &'static [u8]) -> WriteFloatOptionsBuilder {
+        self.sentinel = Some((value.to_bits(), string));
+        self
+    }
+
+    /// Build the options.
+    ///
+    /// Returns the options if they are valid, otherwise, returns None.
+    /// The exponent string must not be empty or start with a digit,
+    /// the fraction grouping and the special strings must be valid,
+    /// the fixed range must not start after it ends, the signs must be
+    /// valid, and the sentinel string must not be empty.
+    #[inline]
+    pub fn build(self) -> Option<WriteFloatOptions> {
+        if !is_valid_exponent_string(self.exponent_string) {
+            return None;
+        }
+        if let Some((separator, size)) = self.fraction_grouping {
+            if !is_valid_separator(separator) || size == 0 {
+                return None;
+            }
+        }
+        if let WriteFloatLayout::FixedRange(min, max) = self.layout {
+            if min > max {
+                return None;
+            }
+        }
+        if !self.nan_string.map_or(true, is_valid_special_string) {
+            return None;
+        }
+        if !self.inf_string.map_or(true, is_valid_special_string) {
+            return None;
+        }
+        if !is_valid_sign(self.minus_sign) || !self.plus_sign.map_or(true, is_valid_sign) {
+            return None;
+        }
+        #[cfg(feature = "optional")] {
+            if self.sentinel.map_or(false, |(_, string)| string.is_empty()) {
+                return None;
+            }
+        }
+        Some(WriteFloatOptions {
+            min_fraction_digits: self.min_fraction_digits,
+            exponent_string: self.exponent_string,
+            integer_as_integer: self.integer_as_integer,
+            layout: self.layout,
+            #[cfg(feature = "correct")]
+            shortest_tie: self.shortest_tie,
+            zero_string: self.zero_string,
+            negative_zero_string: self.negative_zero_string,
+            fraction_grouping: self.fraction_grouping,
+            positive_exponent_sign: self.positive_exponent_sign,
+            min_exponent_digits: self.min_exponent_digits,
+            nan_string: self.nan_string,
+            inf_string: self.inf_string,
+            fixed_zero_cap: self.fixed_zero_cap,
+            minus_sign: self.minus_sign,
+            plus_sign: self.plus_sign,
+            #[cfg(feature = "optional")]
+            sentinel: self.sentinel,
+        })
+    }
+}
+
+impl Default for WriteFloatOptionsBuilder {
+    #[inline]
+    fn default() -> WriteFloatOptionsBuilder {
+        WriteFloatOptionsBuilder::new()
+    }
+}
+
+// WRITE INTEGER
+
+/// Options to customize writing integers.
+///
+/// Options are created from a [`WriteIntegerOptionsBuilder`], which
+/// validates the options when built. The default options produce
+/// the same output as [`write`](fn.write.html).
+///
+/// [`WriteIntegerOptionsBuilder`]: struct.WriteIntegerOptionsBuilder.html
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct WriteIntegerOptions {
+    /// Write negative values in non-decimal radixes as two's complement.
+    #[cfg(feature = "radix")]
+    two_complement: bool,
+    /// String written before negative values.
+    minus_sign: &'static [u8],
+    /// String written before positive values, if set.
+    plus_sign: Option<&'static [u8]>,
+}
+
+impl WriteIntegerOptions {
+    /// Create options with default values.
+    #[inline]
+    pub fn new() -> WriteIntegerOptions {
+        WriteIntegerOptions {
+            #[cfg(feature = "radix")]
+            two_complement: false,
+            minus_sign: DEFAULT_MINUS_SIGN,
+            plus_sign: None,
+        }
+    }
+
+    /// Create a builder to customize the options.
+    #[inline]
+    pub fn builder() -> WriteIntegerOptionsBuilder {
+        WriteIntegerOptionsBuilder::new()
+    }
+
+    /// Get if negative values in non-decimal radixes are written as two's complement.
+    ///
+    /// If set, negative values written with a radix other than 10
+    /// are written as the bit pattern of the signed type, without
+    /// a sign, rather than as a sign and the magnitude. The width of
+    /// the bit pattern is the width of the integer type, so `-1i8`
+    /// in radix 2 is written as `11111111`. Decimal values always
+    /// use a sign and the magnitude.
+    #[inline]
+    #[cfg(feature = "radix")]
+    pub fn two_complement(&self) -> bool {
+        self.two_complement
+    }
+
+    /// Get the string written before negative values.
+    ///
+    /// The string replaces the `-` sign of negative values, like the
+    /// Unicode minus sign, `U+2212`, encoded as the bytes
+    /// `b"\xE2\x88\x92"`. Values written as two's complement have no
+    /// sign. The string must not be empty, or contain an ASCII
+    /// alphanumeric character or a decimal point.
+    #[inline]
+    pub fn minus_sign(&self) -> &'static [u8] {
+        self.minus_sign
+    }
+
+    /// Get the string written before positive values.
+    ///
+    /// If set, the string is written before every positive value and
+    /// zero, like `+`, so the sign is always written, including for
+    /// unsigned values. The string has the same requirements as the
+    /// [`minus_sign`].
+    ///
+    /// [`minus_sign`]: #method.minus_sign
+    #[inline]
+    pub fn plus_sign(&self) -> Option<&'static [u8]> {
+        self.plus_sign
+    }
+}
+
+impl Default for WriteIntegerOptions {
+    #[inline]
+    fn default() -> WriteIntegerOptions {
+        WriteIntegerOptions::new()
+    }
+}
+
+/// Builder for [`WriteIntegerOptions`].
+///
+/// [`WriteIntegerOptions`]: struct.WriteIntegerOptions.html
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct WriteIntegerOptionsBuilder {
+    #[cfg(feature = "radix")]
+    two_complement: bool,
+    minus_sign: &'static [u8],
+    plus_sign: Option<&'static [u8]>,
+}
+
+impl WriteIntegerOptionsBuilder {
+    /// Create a builder with default values.
+    #[inline]
+    pub fn new() -> WriteIntegerOptionsBuilder {
+        WriteIntegerOptionsBuilder {
+            #[cfg(feature = "radix")]
+            two_complement: false,
+            minus_sign: DEFAULT_MINUS_SIGN,
+            plus_sign: None,
+        }
+    }
+
+    /// Set if negative values in non-decimal radixes are written as two's complement.
+    #[inline]
+    #[cfg(feature = "radix")]
+    pub fn two_complement(mut self, two_complement: bool) -> WriteIntegerOptionsBuilder {
+        self.two_complement = two_complement;
+        self
+    }
+
+    /// Set the string written before negative values.
+    #[inline]
+    pub fn minus_sign(mut self, minus_sign: &'static [u8]) -> WriteIntegerOptionsBuilder {
+        self.minus_sign = minus_sign;
+        self
+    }
+
+    /// Set the string written before positive values, if set.
+    #[inline]
+    pub fn plus_sign(mut self, plus_sign: Option<&'static [u8]>) -> WriteIntegerOptionsBuilder {
+        self.plus_sign = plus_sign;
+        self
+    }
+
+    /// Build the options.
+    ///
+    /// Returns the options if they are valid, otherwise, returns None.
+    /// The signs must not be empty, or contain an ASCII alphanumeric
+    /// character or a decimal point.
+    #[inline]
+    pub fn build(self) -> Option<WriteIntegerOptions> {
+        if !is_valid_sign(self.minus_sign) || !self.plus_sign.map_or(true, is_valid_sign) {
+            return None;
+        }
+        Some(WriteIntegerOptions {
+            #[cfg(feature = "radix")]
+            two_complement: self.two_complement,
+            minus_sign: self.minus_sign,
+            plus_sign: self.plus_sign,
+        })
+    }
+}
+
+impl Default for WriteIntegerOptionsBuilder {
+    #[inline]
+    fn default() -> WriteIntegerOptionsBuilder {
+        WriteIntegerOptionsBuilder::new()
+    }
+}
+
+// WRITE OPTIONAL
+
+/// Options to customize writing optional numbers.
+///
+/// Options are created from a [`WriteOptionalOptionsBuilder`], which
+/// validates the options when built. By default, missing values are
+/// written as `null`.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::WriteOptionalOptions;
+/// # pub fn main() {
+/// let options = WriteOptionalOptions::builder()
+///     .null_token(b"NA")
+///     .build()
+///     .unwrap();
+/// assert_eq!(options.null_token(), b"NA");
+/// # }
+/// ```
+///
+/// [`WriteOptionalOptionsBuilder`]: struct.WriteOptionalOptionsBuilder.html
+#[cfg(feature = "optional")]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct WriteOptionalOptions {
+    /// Token written for missing values.
+    null_token: &'static [u8],
+}
+
+#[cfg(feature = "optional")]
+impl WriteOptionalOptions {
+    /// Create options with default values.
+    #[inline]
+    pub fn new() -> WriteOptionalOptions {
+        WriteOptionalOptions {
+            null_token: DEFAULT_NULL_TOKEN,
+        }
+    }
+
+    /// Create a builder to customize the options.
+    #[inline]
+    pub fn builder() -> WriteOptionalOptionsBuilder {
+        WriteOptionalOptionsBuilder::new()
+    }
+
+    /// Get the token written for missing values.
+    ///
+    /// The token is written verbatim in place of the number, and
+    /// present values are written the same as [`write`](fn.write.html).
+    #[inline]
+    pub fn null_token(&self) -> &'static [u8] {
+        self.null_token
+    }
+}
+
+#[cfg(feature = "optional")]
+impl Default for WriteOptionalOptions {
+    #[inline]
+    fn default() -> WriteOptionalOptions {
+        WriteOptionalOptions::new()
+    }
+}
+
+/// Builder for [`WriteOptionalOptions`].
+///
+/// [`WriteOptionalOptions`]: struct.WriteOptionalOptions.html
+#[cfg(feature = "optional")]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct WriteOptionalOptionsBuilder {
+    null_token: &'static [u8],
+}
+
+#[cfg(feature = "optional")]
+impl WriteOptionalOptionsBuilder {
+    /// Create a builder with default values.
+    #[inline]
+    pub fn new() -> WriteOptionalOptionsBuilder {
+        WriteOptionalOptionsBuilder {
+            null_token: DEFAULT_NULL_TOKEN,
+        }
+    }
+
+    /// Set the token written for missing values.
+    #[inline]
+    pub fn null_token(mut self, null_token: &'static [u8]) -> WriteOptionalOptionsBuilder {
+        self.null_token = null_token;
+        self
+    }
+
+    /// Build the options.
+    ///
+    /// Returns the options if they are valid, otherwise, returns None.
+    /// The null token must not be empty or start with a digit, so it
+    /// cannot be confused with a number.
+    #[inline]
+    pub fn build(self) -> Option<WriteOptionalOptions> {
+        if !is_valid_null_token(self.null_token) {
+            return None;
+        }
+        Some(WriteOptionalOptions {
+            null_token: self.null_token,
+        })
+    }
+}
+
+#[cfg(feature = "optional")]
+impl Default for WriteOptionalOptionsBuilder {
+    #[inline]
+    fn default() -> WriteOptionalOptionsBuilder {
+        WriteOptionalOptionsBuilder::new()
+    }
+}
+
+// Floats are written with a trailing ".0" unless `trim_floats` is enabled.
+#[cfg(not(feature = "trim_floats"))]
+const DEFAULT_MIN_FRACTION_DIGITS: usize = 1;
+
+#[cfg(feature = "trim_floats")]
+const DEFAULT_MIN_FRACTION_DIGITS: usize = 0;
+
+// Exponents are delimited by `e` by default.
+const DEFAULT_EXPONENT_STRING: &'static [u8] = b"e";
+
+// Negative numbers are written with an ASCII `-` by default.
+const DEFAULT_MINUS_SIGN: &'static [u8] = b"-";
+
+// Missing values are written as `null` by default.
+#[cfg(feature = "optional")]
+const DEFAULT_NULL_TOKEN: &'static [u8] = b"null";
+
+// Radix prefixes are the C and Rust prefixes by default.
+const DEFAULT_HEX_PREFIX: &'static [u8] = b"0x";
+const DEFAULT_OCT_PREFIX: &'static [u8] = b"0o";
+const DEFAULT_BIN_PREFIX: &'static [u8] = b"0b";
+
+// Check the radix prefix is not empty or only digits, and has no sign or decimal point.
+#[inline]
+fn is_valid_radix_prefix(prefix: &[u8]) -> bool {
+    let is_control = |c: &u8| *c == b'+' || *c == b'-' || *c == b'.';
+    !prefix.iter().all(u8::is_ascii_digit) && !prefix.iter().any(is_control)
+}
+
+// Check the byte is not alphanumeric, a sign, or a decimal point.
+#[inline]
+fn is_valid_separator(c: u8) -> bool {
+    !c.is_ascii_alphanumeric() && c != b'+' && c != b'-' && c != b'.'
+}
+
+// Check the trim bytes are valid separators, and are not in the exponent string.
+#[inline]
+fn is_valid_trim_chars(trim_chars: &[u8], exponent_string: &[u8]) -> bool {
+    trim_chars.iter().all(|&c| is_valid_separator(c) && !exponent_string.contains(&c))
+}
+
+// Check the digit separator is not in the exponent string or the trim
+// bytes, and the options do not check the digits as they are written.
+#[inline]
+#[cfg(feature = "format")]
+fn is_valid_digit_separator_options(builder: &ParseFloatOptionsBuilder, digit_separator: u8) -> bool {
+    !builder.exponent_string.contains(&digit_separator)
+        && !builder.trim_chars.contains(&digit_separator)
+        && builder.require_significant_digits.is_none()
+        && !builder.reject_exponent_leading_zeros
+}
+
+// Check the grouping sizes are not zero, and have a digit separator to check.
+#[inline]
+#[cfg(feature = "format")]
+fn is_valid_grouping_sizes(grouping_sizes: &[u8], digit_separator: u8) -> bool {
+    grouping_sizes.is_empty() || (digit_separator != 0 && !grouping_sizes.contains(&0))
+}
+
+// Get the sizes of the groups of integer digits to check.
+//
+// Formats with grouped digit separators check groups of 3 digits,
+// unless other sizes are set.
+#[inline]
+#[cfg(feature = "format")]
+pub(crate) fn grouping_sizes_or_default(format: NumberFormat, grouping_sizes: &'static [u8]) -> &'static [u8] {
+    match grouping_sizes.is_empty() && format.grouped_digit_separator() {
+        true  => &[3],
+        false => grouping_sizes,
+    }
+}
+
+// Find the first digit separator that does not end a group of the expected size.
+//
+// The integer digits follow an optional sign, and are checked from
+// the least-significant group outward, where the last size repeats
+// for the remaining groups. The most-significant group may be shorter,
+// but not empty, and integers without separators are not checked.
+#[inline]
+#[cfg(feature = "format")]
+pub(crate) fn find_misplaced_separator(bytes: &[u8], radix: u32, digit_separator: u8, grouping_sizes: &[u8])
+    -> Option<usize>
+{
+    if grouping_sizes.is_empty() {
+        return None;
+    }
+    let start = match bytes.first() {
+        Some(&b'+') | Some(&b'-')   => 1,
+        _                           => 0,
+    };
+    let end = start + index!(bytes[start..]).iter()
+        .take_while(|&&c| c == digit_separator || (c as char).is_digit(radix))
+        .count();
+    let size = | group: usize | index!(grouping_sizes[group.min(grouping_sizes.len() - 1)]) as usize;
+
+    let mut group_end = end;
+    let mut group = 0;
+    for index in (start..end).rev() {
+        if index!(bytes[index]) == digit_separator {
+            if group_end - index - 1 != size(group) {
+                return Some(index);
+            }
+            group_end = index;
+            group += 1;
+        }
+    }
+    match group != 0 && (group_end == start || group_end - start > size(group)) {
+        true  => Some(group_end),
+        false => None,
+    }
+}
+
+// Parse the bytes without the leading and trailing trim bytes.
+//
+// Error indexes and the processed bytes are relative to the untrimmed
+// bytes, and the trailing trim bytes are only processed if the parser
+// processes every byte between the trim bytes.
+#[inline]
+pub(crate) fn parse_trimmed<T, Cb>(bytes: &[u8], trim_chars: &[u8], cb: Cb)
+    -> Result<(T, usize)>
+    where Cb: FnOnce(&[u8]) -> Result<(T, usize)>
+{
+    let (trimmed, leading) = ltrim_chars_slice(bytes, trim_chars);
+    let (trimmed, _) = rtrim_chars_slice(trimmed, trim_chars);
+    match cb(trimmed) {
+        Ok((value, processed)) if processed == trimmed.len() => Ok((value, bytes.len())),
+        Ok((value, processed)) => Ok((value, processed + leading)),
+        Err(error)             => Err(error.shift(leading)),
+    }
+}
+
+// Check the exponent string is not empty, and does not start with a digit.
+#[inline]
+fn is_valid_exponent_string(exponent_string: &[u8]) -> bool {
+    match exponent_string.first() {
+        Some(c) => !c.is_ascii_digit(),
+        None    => false,
+    }
+}
+
+// Check the special string is not empty, and does not start with a digit,
+// a sign, or a decimal point.
+#[inline]
+fn is_valid_special_string(string: &[u8]) -> bool {
+    match string.first() {
+        Some(&c) => !c.is_ascii_digit() && c != b'+' && c != b'-' && c != b'.',
+        None     => false,
+    }
+}
+
+// Check the sign is not empty, and has no digit or decimal point, in any radix.
+#[inline]
+fn is_valid_sign(sign: &[u8]) -> bool {
+    !sign.is_empty() && sign.iter().all(|&c| !c.is_ascii_alphanumeric() && c != b'.')
+}
+
+// Check the null token is not empty, and does not start with a digit.
+#[inline]
+#[cfg(feature = "optional")]
+fn is_valid_null_token(null_token: &[u8]) -> bool {
+    is_valid_exponent_string(null_token)
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_float_options_test() {
+        let options = ParseFloatOptions::new();
+        assert_eq!(options, ParseFloatOptions::default());
+        assert_eq!(options, ParseFloatOptions::builder().build().unwrap());
+        assert_eq!(options.exponent_string(), b"e");
+        assert!(options.parse_negative_zero());
+
+        let options = ParseFloatOptions::builder().parse_negative_zero(false).build().unwrap();
+        assert!(!options.parse_negative_zero());
+        assert!(!options.strict_sign());
+
+        let options = ParseFloatOptions::builder().strict_sign(true).build().unwrap();
+        assert!(options.strict_sign());
+        assert!(!options.reject_exponent_leading_zeros());
+
+        let options = ParseFloatOptions::builder().reject_exponent_leading_zeros(true).build().unwrap();
+        assert!(options.reject_exponent_leading_zeros());
+        assert!(!options.lenient_bare_point());
+
+        let options = ParseFloatOptions::builder().lenient_bare_point(true).build().unwrap();
+        assert!(options.lenient_bare_point());
+
+        assert!(!options.implicit_mantissa_one());
+        let options = ParseFloatOptions::builder().implicit_mantissa_one(true).build().unwrap();
+        assert!(options.implicit_mantissa_one());
+        assert!(options.sign_before_point());
+
+        let options = ParseFloatOptions::builder().sign_before_point(false).build().unwrap();
+        assert!(!options.sign_before_point());
+
+        let options = ParseFloatOptions::builder().exponent_string(b"x10^").build().unwrap();
+        assert_eq!(options.exponent_string(), b"x10^");
+        assert!(ParseFloatOptions::builder().exponent_string(b"").build().is_none());
+        assert!(ParseFloatOptions::builder().exponent_string(b"1e").build().is_none());
+
+        assert_eq!(ParseFloatOptions::new().trim_chars(), b"");
+        let options = ParseFloatOptions::builder().trim_chars(b"[]\"").build().unwrap();
+        assert_eq!(options.trim_chars(), b"[]\"");
+        assert!(ParseFloatOptions::builder().trim_chars(b"0").build().is_none());
+        assert!(ParseFloatOptions::builder().trim_chars(b"e").build().is_none());
+        assert!(ParseFloatOptions::builder().trim_chars(b"-").build().is_none());
+        assert!(ParseFloatOptions::builder().trim_chars(b".").build().is_none());
+        assert!(ParseFloatOptions::builder().trim_chars(b"^").exponent_string(b"^").build().is_none());
+
+        assert_eq!(ParseFloatOptions::new().require_significant_digits(), None);
+        let options = ParseFloatOptions::builder().require_significant_digits(Some(7)).build().unwrap();
+        assert_eq!(options.require_significant_digits(), Some(7));
+        assert!(ParseFloatOptions::builder().require_significant_digits(Some(0)).build().is_none());
+
+        assert!(!ParseFloatOptions::new().require_exponent());
+        let options = ParseFloatOptions::builder().require_exponent(true).build().unwrap();
+        assert!(options.require_exponent());
+        assert!(!options.case_sensitive_exponent());
+
+        let options = ParseFloatOptions::builder().case_sensitive_exponent(true).build().unwrap();
+        assert!(options.case_sensitive_exponent());
+
+        #[cfg(feature = "format")] {
+            assert_eq!(ParseFloatOptions::new().format(), NumberFormat::standard().unwrap());
+            let format = NumberFormat::ignore(b'_').unwrap();
+            let options = ParseFloatOptions::builder().format(format).build().unwrap();
+            assert_eq!(options.format(), format);
+            assert!(ParseFloatOptions::builder().format(format).exponent_string(b"_").build().is_none());
+            assert!(ParseFloatOptions::builder().format(format).trim_chars(b"_").build().is_none());
+            assert!(ParseFloatOptions::builder().format(format).require_significant_digits(Some(3)).build().is_none());
+            assert!(ParseFloatOptions::builder().format(format).reject_exponent_leading_zeros(true).build().is_none());
+
+            assert_eq!(ParseFloatOptions::new().grouping_sizes(), b"");
+            let options = ParseFloatOptions::builder().format(format).grouping_sizes(&[3, 2]).build().unwrap();
+            assert_eq!(options.grouping_sizes(), &[3, 2]);
+            assert!(ParseFloatOptions::builder().grouping_sizes(&[3]).build().is_none());
+            assert!(ParseFloatOptions::builder().format(format).grouping_sizes(&[3, 0]).build().is_none());
+        }
+
+        #[cfg(feature = "optional")] {
+            assert_eq!(ParseFloatOptions::new().sentinel(), None);
+            let options = ParseFloatOptions::builder().sentinel_for(-999.0, b"--").build().unwrap();
+            assert_eq!(options.sentinel(), Some((-999.0, &b"--"[..])));
+            assert!(ParseFloatOptions::builder().sentinel_for(-999.0, b"").build().is_none());
+        }
+    }
+
+    #[test]
+    fn parse_integer_options_test() {
+        let options = ParseIntegerOptions::new();
+        assert_eq!(options, ParseIntegerOptions::default());
+        assert_eq!(options, ParseIntegerOptions::builder().build().unwrap());
+        assert!(!options.strict_sign());
+
+        let options = ParseIntegerOptions::builder().strict_sign(true).build().unwrap();
+        assert!(options.strict_sign());
+
+        assert_eq!(ParseIntegerOptions::new().hex_prefix(), b"0x");
+        assert_eq!(ParseIntegerOptions::new().oct_prefix(), b"0o");
+        assert_eq!(ParseIntegerOptions::new().bin_prefix(), b"0b");
+        let options = ParseIntegerOptions::builder()
+            .hex_prefix(b"$")
+            .oct_prefix(b"@")
+            .bin_prefix(b"%")
+            .build()
+            .unwrap();
+        assert_eq!(options.hex_prefix(), b"$");
+        assert_eq!(options.oct_prefix(), b"@");
+        assert_eq!(options.bin_prefix(), b"%");
+
+        // Invalid prefixes.
+        assert!(ParseIntegerOptions::builder().hex_prefix(b"").build().is_none());
+        assert!(ParseIntegerOptions::builder().hex_prefix(b"0").build().is_none());
+        assert!(ParseIntegerOptions::builder().hex_prefix(b"-x").build().is_none());
+        assert!(ParseIntegerOptions::builder().hex_prefix(b"0.").build().is_none());
+        assert!(ParseIntegerOptions::builder().hex_prefix(b"0b").build().is_none());
+        assert!(ParseIntegerOptions::builder().hex_prefix(b"0B1").build().is_none());
+        assert!(ParseIntegerOptions::builder().hex_prefix(b"$").bin_prefix(b"$b").build().is_none());
+
+        assert!(!ParseIntegerOptions::new().octal_leading_zero());
+        let options = ParseIntegerOptions::builder().octal_leading_zero(true).build().unwrap();
+        assert!(options.octal_leading_zero());
+
+        assert_eq!(ParseIntegerOptions::new().trim_chars(), b"");
+        let options = ParseIntegerOptions::builder().trim_chars(b"()").build().unwrap();
+        assert_eq!(options.trim_chars(), b"()");
+        assert!(ParseIntegerOptions::builder().trim_chars(b"a").build().is_none());
+        assert!(ParseIntegerOptions::builder().trim_chars(b"+").build().is_none());
+
+        #[cfg(feature = "format")] {
+            assert_eq!(ParseIntegerOptions::new().format(), NumberFormat::standard().unwrap());
+            assert_eq!(ParseIntegerOptions::new().grouping_sizes(), b"");
+            let format = NumberFormat::grouping(b',', true).unwrap();
+            let options = ParseIntegerOptions::builder().format(format).grouping_sizes(&[3]).build().unwrap();
+            assert_eq!(options.format(), format);
+            assert_eq!(options.grouping_sizes(), &[3]);
+            assert!(ParseIntegerOptions::builder().format(format).trim_chars(b",").build().is_none());
+            assert!(ParseIntegerOptions::builder().grouping_sizes(&[3]).build().is_none());
+            assert!(ParseIntegerOptions::builder().format(format).grouping_sizes(&[0]).build().is_none());
+        }
+    }
+
+    #[test]
+    fn write_float_options_test() {
+        let options = WriteFloatOptions::new();
+        assert_eq!(options, WriteFloatOptions::default());
+        assert_eq!(options, WriteFloatOptions::builder().build().unwrap());
+        assert_eq!(options.min_fraction_digits(), DEFAULT_MIN_FRACTION_DIGITS);
+
+        assert_eq!(options.exponent_string(), b"e");
+
+        let options = WriteFloatOptions::builder().min_fraction_digits(3).build().unwrap();
+        assert_eq!(options.min_fraction_digits(), 3);
+
+        let options = WriteFloatOptions::builder().exponent_string(b"E").build().unwrap();
+        assert_eq!(options.exponent_string(), b"E");
+        assert!(WriteFloatOptions::builder().exponent_string(b"").build().is_none());
+        assert!(WriteFloatOptions::builder().exponent_string(b"0").build().is_none());
+
+        assert!(!options.integer_as_integer());
+        let options = WriteFloatOptions::builder().integer_as_integer(true).build().unwrap();
+        assert!(options.integer_as_integer());
+
+        assert_eq!(options.layout(), WriteFloatLayout::Standard);
+        let options = WriteFloatOptions::builder().layout(WriteFloatLayout::ScaledInteger).build().unwrap();
+        assert_eq!(options.layout(), WriteFloatLayout::ScaledInteger);
+        #[cfg(feature = "correct")] {
+            assert_eq!(WriteFloatOptions::new().shortest_tie(), ShortestTie::Nearest);
+            let options = WriteFloatOptions::builder().shortest_tie(ShortestTie::AwayZero).build().unwrap();
+            assert_eq!(options.shortest_tie(), ShortestTie::AwayZero);
+        }
+
+        assert_eq!(WriteFloatOptions::new().zero_string(), None);
+        assert_eq!(WriteFloatOptions::new().negative_zero_string(), None);
+        let options = WriteFloatOptions::builder()
+            .zero_string(Some(b"0E0"))
+            .negative_zero_string(Some(b"-0E0"))
+            .build()
+            .unwrap();
+        assert_eq!(options.zero_string(), Some(&b"0E0"[..]));
+        assert_eq!(options.negative_zero_string(), Some(&b"-0E0"[..]));
+
+        assert_eq!(WriteFloatOptions::new().fraction_grouping(), None);
+        let options = WriteFloatOptions::builder().fraction_grouping(Some((b' ', 3))).build().unwrap();
+        assert_eq!(options.fraction_grouping(), Some((b' ', 3)));
+        assert!(WriteFloatOptions::builder().fraction_grouping(Some((b' ', 0))).build().is_none());
+        assert!(WriteFloatOptions::builder().fraction_grouping(Some((b'0', 3))).build().is_none());
+        assert!(WriteFloatOptions::builder().fraction_grouping(Some((b'.', 3))).build().is_none());
+        assert!(WriteFloatOptions::builder().fraction_grouping(Some((b'-', 3))).build().is_none());
+
+        let options = WriteFloatOptions::builder().layout(WriteFloatLayout::FixedRange(-4, 5)).build().unwrap();
+        assert_eq!(options.layout(), WriteFloatLayout::FixedRange(-4, 5));
+        assert!(WriteFloatOptions::builder().layout(WriteFloatLayout::FixedRange(0, 0)).build().is_some());
+        assert!(WriteFloatOptions::builder().layout(WriteFloatLayout::FixedRange(1, 0)).build().is_none());
+
+        assert!(!WriteFloatOptions::new().positive_exponent_sign());
+        assert_eq!(WriteFloatOptions::new().min_exponent_digits(), 1);
+        let options = WriteFloatOptions::builder()
+            .positive_exponent_sign(true)
+            .min_exponent_digits(2)
+            .build()
+            .unwrap();
+        assert!(options.positive_exponent_sign());
+        assert_eq!(options.min_exponent_digits(), 2);
+
+        assert_eq!(WriteFloatOptions::new().nan_string(), None);
+        assert_eq!(WriteFloatOptions::new().inf_string(), None);
+        let options = WriteFloatOptions::builder()
+            .nan_string(Some(b"NA"))
+            .inf_string(Some(b"Inf"))
+            .build()
+            .unwrap();
+        assert_eq!(options.nan_string(), Some(&b"NA"[..]));
+        assert_eq!(options.inf_string(), Some(&b"Inf"[..]));
+        assert!(WriteFloatOptions::builder().nan_string(Some(b"")).build().is_none());
+        assert!(WriteFloatOptions::builder().nan_string(Some(b"1")).build().is_none());
+        assert!(WriteFloatOptions::builder().inf_string(Some(b"-Inf")).build().is_none());
+        assert!(WriteFloatOptions::builder().inf_string(Some(b".Inf")).build().is_none());
+
+        assert_eq!(WriteFloatOptions::new().fixed_zero_cap(), None);
+        let options = WriteFloatOptions::builder().fixed_zero_cap(Some(5)).build().unwrap();
+        assert_eq!(options.fixed_zero_cap(), Some(5));
+
+        #[cfg(feature = "optional")] {
+            assert_eq!(WriteFloatOptions::new().sentinel(), None);
+            let options = WriteFloatOptions::builder().sentinel_for(-999.0, b"--").build().unwrap();
+            assert_eq!(options.sentinel(), Some((-999.0, &b"--"[..])));
+            assert!(WriteFloatOptions::builder().sentinel_for(-999.0, b"").build().is_none());
+        }
+
+        assert_eq!(WriteFloatOptions::new().minus_sign(), b"-");
+        assert_eq!(WriteFloatOptions::new().plus_sign(), None);
+        let options = WriteFloatOptions::builder()
+            .minus_sign(b"\xE2\x88\x92")
+            .plus_sign(Some(b"+"))
+            .build()
+            .unwrap();
+        assert_eq!(options.minus_sign(), b"\xE2\x88\x92");
+        assert_eq!(options.plus_sign(), Some(&b"+"[..]));
+        assert!(WriteFloatOptions::builder().minus_sign(b"").build().is_none());
+        assert!(WriteFloatOptions::builder().minus_sign(b"-1").build().is_none());
+        assert!(WriteFloatOptions::builder().plus_sign(Some(b"e")).build().is_none());
+        assert!(WriteFloatOptions::builder().plus_sign(Some(b".")).build().is_none());
+
+        let options = WriteFloatOptions::r_compatible();
+        assert_eq!(options.layout(), WriteFloatLayout::Compact);
+        assert!(options.positive_exponent_sign());
+        let options = WriteFloatOptions::julia_compatible();
+        assert_eq!(options.layout(), WriteFloatLayout::FixedRange(-4, 5));
+        assert!(!options.positive_exponent_sign());
+    }
+
+    #[test]
+    fn write_integer_options_test() {
+        let options = WriteIntegerOptions::new();
+        assert_eq!(options, WriteIntegerOptions::default());
+        assert_eq!(options, WriteIntegerOptions::builder().build().unwrap());
+
+        #[cfg(feature = "radix")] {
+            assert!(!options.two_complement());
+            let options = WriteIntegerOptions::builder().two_complement(true).build().unwrap();
+            assert!(options.two_complement());
+        }
+
+        assert_eq!(options.minus_sign(), b"-");
+        assert_eq!(options.plus_sign(), None);
+        let options = WriteIntegerOptions::builder()
+            .minus_sign(b"\xE2\x88\x92")
+            .plus_sign(Some(b"+"))
+            .build()
+            .unwrap();
+        assert_eq!(options.minus_sign(), b"\xE2\x88\x92");
+        assert_eq!(options.plus_sign(), Some(&b"+"[..]));
+        assert!(WriteIntegerOptions::builder().minus_sign(b"").build().is_none());
+        assert!(WriteIntegerOptions::builder().plus_sign(Some(b"0")).build().is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "optional")]
+    fn write_optional_options_test() {
+        let options = WriteOptionalOptions::new();
+        assert_eq!(options, WriteOptionalOptions::default());
+        assert_eq!(options, WriteOptionalOptions::builder().build().unwrap());
+        assert_eq!(options.null_token(), b"null");
+
+        let options = WriteOptionalOptions::builder().null_token(b"NA").build().unwrap();
+        assert_eq!(options.null_token(), b"NA");
+        assert!(WriteOptionalOptions::builder().null_token(b"").build().is_none());
+        assert!(WriteOptionalOptions::builder().null_token(b"0").build().is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "format")]
+    fn find_misplaced_separator_test() {
+        // Western grouping.
+        assert_eq!(find_misplaced_separator(b"1,234,567", 10, b',', &[3]), None);
+        assert_eq!(find_misplaced_separator(b"-12,345.67", 10, b',', &[3]), None);
+        assert_eq!(find_misplaced_separator(b"1234567", 10, b',', &[3]), None);
+        assert_eq!(find_misplaced_separator(b"12,34,567", 10, b',', &[3]), Some(2));
+        assert_eq!(find_misplaced_separator(b"1234,567", 10, b',', &[3]), Some(4));
+        assert_eq!(find_misplaced_separator(b"1,234,56", 10, b',', &[3]), Some(5));
+        assert_eq!(find_misplaced_separator(b"1,,234", 10, b',', &[3]), Some(1));
+
+        // Indian grouping.
+        assert_eq!(find_misplaced_separator(b"12,34,567", 10, b',', &[3, 2]), None);
+        assert_eq!(find_misplaced_separator(b"1,23,45,678.9", 10, b',', &[3, 2]), None);
+        assert_eq!(find_misplaced_separator(b"1,234,567", 10, b',', &[3, 2]), Some(1));
+        assert_eq!(find_misplaced_separator(b"123,45,678", 10, b',', &[3, 2]), Some(3));
+
+        // Unchecked groups.
+        assert_eq!(find_misplaced_separator(b"1,2,3", 10, b',', &[]), None);
+    }
+}
@@ -0,0 +1,303 @@
+//! Parse numbers with an optional radix prefix.
+
+use super::algorithm::copy_to_dst;
+use super::config::BUFFER_SIZE;
+use super::error::{Error, ErrorCode};
+use super::options::ParseIntegerOptions;
+use super::result::Result;
+use super::traits::{FromLexical, FromLexicalWithOptions};
+
+// HELPERS
+
+// Get the radix and length of the first matching prefix, ignoring case.
+#[inline]
+fn match_prefix(bytes: &[u8], prefixes: &[(&[u8], u8)]) -> Option<(u8, usize)> {
+    prefixes.iter()
+        .find(|(prefix, _)| bytes.len() >= prefix.len() && prefix.eq_ignore_ascii_case(&bytes[..prefix.len()]))
+        .map(|&(prefix, radix)| (radix, prefix.len()))
+}
+
+// Get the radix and length of a `0x`, `0o`, or `0b` prefix.
+#[inline]
+fn radix_prefix(bytes: &[u8]) -> Option<(u8, usize)> {
+    match_prefix(bytes, &[(b"0x", 16), (b"0o", 8), (b"0b", 2)])
+}
+
+// Get the radix and length of the prefix from the options.
+#[inline]
+fn options_prefix(bytes: &[u8], options: &ParseIntegerOptions) -> Option<(u8, usize)> {
+    let prefixes = [
+        (options.hex_prefix(), 16),
+        (options.oct_prefix(), 8),
+        (options.bin_prefix(), 2),
+    ];
+    match match_prefix(bytes, &prefixes) {
+        // A leading zero followed by a digit is an octal digit, like in C.
+        None if options.octal_leading_zero() && is_leading_zero(bytes) => Some((8, 0)),
+        prefix => prefix,
+    }
+}
+
+// Check if the bytes start with a zero followed by a digit.
+#[inline]
+fn is_leading_zero(bytes: &[u8]) -> bool {
+    bytes.len() >= 2 && bytes[0] == b'0' && bytes[1].is_ascii_digit()
+}
+
+// Get the length of the sign at the start of the bytes.
+#[inline]
+fn sign_len(bytes: &[u8]) -> usize {
+    match bytes.first() {
+        Some(&b'+') | Some(&b'-') => 1,
+        _                         => 0,
+    }
+}
+
+// Parse the digits after the prefix, with any sign before the prefix.
+//
+// The sign is not adjacent to the digits, so the sign and the digits
+// are copied to a buffer to parse them together. Leading zeros are
+// skipped if the digits do not fit in the buffer, and digits that still
+// do not fit are an overflow. Errors are relative to the start of the
+// bytes, and errors in the sign are at the sign.
+fn parse_prefixed<N, Cb>(bytes: &[u8], sign_len: usize, shift: usize, cb: Cb)
+    -> Result<N>
+    where Cb: FnOnce(&[u8]) -> Result<N>
+{
+    let start = sign_len + shift;
+    let digits = &bytes[start..];
+    if self::sign_len(digits) != 0 {
+        // The sign must come before the prefix, like `-0x5`, not `0x-5`.
+        return Err(Error::from((ErrorCode::InvalidDigit, start)).with_byte(bytes));
+    } else if sign_len == 0 || shift == 0 {
+        // The sign, if any, is adjacent to the digits.
+        return cb(&bytes[start - sign_len..]).map_err(|error| error.shift(shift));
+    }
+
+    let mut buffer = [b'\0'; BUFFER_SIZE];
+    let max_digits = buffer.len() - sign_len;
+    let zeros = match digits.len() > max_digits {
+        true  => digits.iter().take_while(|&&c| c == b'0').count().min(digits.len() - max_digits),
+        false => 0,
+    };
+    let digits = &digits[zeros..];
+    if digits.len() > max_digits {
+        return Err(Error::from((ErrorCode::Overflow, start + zeros + max_digits)));
+    }
+    copy_to_dst(&mut buffer, &bytes[..sign_len]);
+    let len = sign_len + copy_to_dst(&mut buffer[sign_len..], digits);
+    cb(&buffer[..len]).map_err(|error| {
+        let index = match error.index < sign_len {
+            true  => error.index,
+            false => error.index + shift + zeros,
+        };
+        Error { index, ..error }
+    })
+}
+
+// API
+
+/// Parse number from bytes, using the radix prefix if present.
+///
+/// A leading `0x`, `0o`, or `0b` prefix, case-insensitive, parses the
+/// remaining bytes in radix 16, 8, or 2, respectively, otherwise, the
+/// bytes are parsed in the default radix. Returns the value and the
+/// radix used to parse it. The prefix takes precedence over the default
+/// radix, so `"0b1"` is parsed in radix 2 even if the default radix is
+/// 16. The bytes are the sign, if any, then the prefix, then the
+/// digits, like `"-0x1F"`, so a sign after the prefix, like in
+/// `"0x-1F"`, is reported as
+/// [`InvalidDigit`](enum.ErrorCode.html#variant.InvalidDigit) at the
+/// sign. The index of any error is relative to the start of the bytes,
+/// including the sign and the prefix.
+///
+/// * `bytes`           - Byte slice containing a numeric string.
+/// * `default_radix`   - Radix for the number parsing without a prefix.
+///
+/// # Panics
+///
+/// Panics if the default radix is not in the range `[2, 36]`.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # pub fn main() {
+/// assert_eq!(lexical_core::parse_flexible::<i64>(b"0x1F", 10), Ok((31, 16)));
+/// assert_eq!(lexical_core::parse_flexible::<i64>(b"-0x1F", 10), Ok((-31, 16)));
+/// assert_eq!(lexical_core::parse_flexible::<i64>(b"31", 10), Ok((31, 10)));
+/// assert!(lexical_core::parse_flexible::<i64>(b"0x-1F", 10).is_err());
+/// # }
+/// ```
+pub fn parse_flexible<N: FromLexical>(bytes: &[u8], default_radix: u8)
+    -> Result<(N, u8)>
+{
+    let sign_len = sign_len(bytes);
+    let (radix, shift) = radix_prefix(&bytes[sign_len..]).unwrap_or((default_radix, 0));
+    let value = parse_prefixed(bytes, sign_len, shift, |digits| N::from_lexical_radix(digits, radix))?;
+    Ok((value, radix))
+}
+
+/// Parse integer from bytes, using the radix prefixes from the options.
+///
+/// Identical to [`parse_flexible`], except the radix prefixes are
+/// taken from the [`ParseIntegerOptions`], ignoring ASCII case, and
+/// the sign and the digits are parsed with the options. The sign
+/// still comes before the prefix.
+///
+/// * `bytes`           - Byte slice containing a numeric string.
+/// * `default_radix`   - Radix for the number parsing without a prefix.
+/// * `options`         - Options to specify the radix prefixes and parsing.
+///
+/// # Panics
+///
+/// Panics if the default radix is not in the range `[2, 36]`.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::ParseIntegerOptions;
+/// # pub fn main() {
+/// let options = ParseIntegerOptions::builder()
+///     .hex_prefix(b"$")
+///     .build()
+///     .unwrap();
+/// assert_eq!(lexical_core::parse_flexible_with_options::<i64>(b"$FF", 10, &options), Ok((255, 16)));
+/// assert_eq!(lexical_core::parse_flexible_with_options::<i64>(b"0x", 10, &options), Err((lexical_core::ErrorCode::InvalidDigit, 1, b'x').into()));
+/// # }
+/// ```
+///
+/// [`parse_flexible`]: fn.parse_flexible.html
+/// [`ParseIntegerOptions`]: struct.ParseIntegerOptions.html
+pub fn parse_flexible_with_options<N>(bytes: &[u8], default_radix: u8, options: &ParseIntegerOptions)
+    -> Result<(N, u8)>
+    where N: FromLexicalWithOptions<Options=ParseIntegerOptions>
+{
+    let sign_len = sign_len(bytes);
+    let (radix, shift) = options_prefix(&bytes[sign_len..], options).unwrap_or((default_radix, 0));
+    let value = parse_prefixed(bytes, sign_len, shift, |digits| N::from_lexical_radix_with_options(digits, radix, options))?;
+    Ok((value, radix))
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::*;
+
+    #[test]
+    fn radix_prefix_test() {
+        assert_eq!(radix_prefix(b"0x1F"), Some((16, 2)));
+        assert_eq!(radix_prefix(b"0X"), Some((16, 2)));
+        assert_eq!(radix_prefix(b"0o17"), Some((8, 2)));
+        assert_eq!(radix_prefix(b"0B1"), Some((2, 2)));
+        assert_eq!(radix_prefix(b"017"), None);
+        assert_eq!(radix_prefix(b"-0x1F"), None);
+        assert_eq!(radix_prefix(b"0"), None);
+        assert_eq!(radix_prefix(b""), None);
+    }
+
+    #[test]
+    fn parse_flexible_test() {
+        assert_eq!(parse_flexible::<i64>(b"0b101", 10), Ok((5, 2)));
+        assert_eq!(parse_flexible::<i64>(b"0o17", 10), Ok((15, 8)));
+        assert_eq!(parse_flexible::<i64>(b"0x1f", 10), Ok((31, 16)));
+        assert_eq!(parse_flexible::<i64>(b"17", 10), Ok((17, 10)));
+        assert_eq!(parse_flexible::<i64>(b"17", 8), Ok((15, 8)));
+        assert_eq!(parse_flexible::<i64>(b"0", 10), Ok((0, 10)));
+        assert_eq!(parse_flexible::<i64>(b"0b1", 16), Ok((1, 2)));
+        assert_eq!(parse_flexible::<f64>(b"0x1.8", 10), Ok((1.5, 16)));
+
+        // Errors are relative to the start of the bytes.
+        assert_eq!(parse_flexible::<i64>(b"0x", 10), Err(Error::from((ErrorCode::Empty, 2))));
+        assert_eq!(parse_flexible::<i64>(b"0b102", 10), Err(Error::from((ErrorCode::InvalidDigit, 4, b'2'))));
+        assert_eq!(parse_flexible::<i64>(b"0b102", 10).unwrap_err().failing_byte(), Some(b'2'));
+        assert_eq!(parse_flexible::<i64>(b"18", 8), Err(Error::from((ErrorCode::InvalidDigit, 1, b'8'))));
+        assert_eq!(parse_flexible::<u8>(b"0x100", 10), Err(Error::from((ErrorCode::Overflow, 4))));
+    }
+
+    #[test]
+    fn parse_flexible_sign_test() {
+        // The sign comes before the prefix.
+        assert_eq!(parse_flexible::<i64>(b"-0x5", 10), Ok((-5, 16)));
+        assert_eq!(parse_flexible::<i64>(b"+0x5", 10), Ok((5, 16)));
+        assert_eq!(parse_flexible::<i64>(b"-0b101", 10), Ok((-5, 2)));
+        assert_eq!(parse_flexible::<i64>(b"-17", 8), Ok((-15, 8)));
+        assert_eq!(parse_flexible::<i8>(b"-0x80", 10), Ok((-128, 16)));
+        assert_eq!(parse_flexible::<f64>(b"-0x1.8", 10), Ok((-1.5, 16)));
+
+        // A sign after the prefix is an error at the sign.
+        assert_eq!(parse_flexible::<i64>(b"0x-5", 10), Err(Error::from((ErrorCode::InvalidDigit, 2, b'-'))));
+        assert_eq!(parse_flexible::<i64>(b"0x+5", 10), Err(Error::from((ErrorCode::InvalidDigit, 2, b'+'))));
+        assert_eq!(parse_flexible::<i64>(b"-0x-5", 10), Err(Error::from((ErrorCode::InvalidDigit, 3, b'-'))));
+        assert_eq!(parse_flexible::<i64>(b"--0x5", 10), Err(Error::from((ErrorCode::InvalidDigit, 1, b'-'))));
+
+        // Errors are relative to the start of the bytes, including the sign.
+        assert_eq!(parse_flexible::<i64>(b"-0x", 10), Err(Error::from((ErrorCode::Empty, 3))));
+        assert_eq!(parse_flexible::<i64>(b"-0x5G", 10), Err(Error::from((ErrorCode::InvalidDigit, 4, b'G'))));
+        assert_eq!(parse_flexible::<i8>(b"-0x81", 10), Err(Error::from((ErrorCode::Underflow, 4))));
+        assert_eq!(parse_flexible::<u8>(b"-0x5", 10), Err(Error::from((ErrorCode::InvalidDigit, 0, b'-'))));
+
+        // Leading zeros are skipped if the digits are long.
+        let mut bytes = [b'0'; 1000];
+        bytes[0] = b'-';
+        bytes[2] = b'x';
+        bytes[999] = b'5';
+        assert_eq!(parse_flexible::<i64>(&bytes[..], 10), Ok((-5, 16)));
+        bytes[500] = b'1';
+        assert_eq!(parse_flexible::<i64>(&bytes[..], 10), Err(Error::from((ErrorCode::Overflow, 755))));
+    }
+
+    #[test]
+    fn parse_flexible_with_options_test() {
+        let options = ParseIntegerOptions::new();
+        assert_eq!(parse_flexible_with_options::<i64>(b"0x1F", 10, &options), Ok((31, 16)));
+        assert_eq!(parse_flexible_with_options::<i64>(b"0B101", 10, &options), Ok((5, 2)));
+        assert_eq!(parse_flexible_with_options::<i64>(b"17", 8, &options), Ok((15, 8)));
+
+        let options = ParseIntegerOptions::builder()
+            .hex_prefix(b"$")
+            .oct_prefix(b"&o")
+            .bin_prefix(b"%")
+            .build()
+            .unwrap();
+        assert_eq!(parse_flexible_with_options::<i64>(b"$FF", 10, &options), Ok((255, 16)));
+        assert_eq!(parse_flexible_with_options::<u8>(b"$ff", 10, &options), Ok((255, 16)));
+        assert_eq!(parse_flexible_with_options::<i64>(b"&O17", 10, &options), Ok((15, 8)));
+        assert_eq!(parse_flexible_with_options::<i64>(b"%101", 10, &options), Ok((5, 2)));
+        assert_eq!(parse_flexible_with_options::<i64>(b"-$FF", 10, &options), Ok((-255, 16)));
+        assert_eq!(parse_flexible_with_options::<i64>(b"$-FF", 10, &options), Err(Error::from((ErrorCode::InvalidDigit, 1, b'-'))));
+        assert_eq!(parse_flexible_with_options::<i64>(b"17", 10, &options), Ok((17, 10)));
+
+        // The default prefixes are no longer recognized.
+        assert_eq!(parse_flexible_with_options::<i64>(b"0x1F", 10, &options), Err(Error::from((ErrorCode::InvalidDigit, 1, b'x'))));
+        assert_eq!(parse_flexible_with_options::<i64>(b"$", 10, &options), Err(Error::from((ErrorCode::Empty, 1))));
+        assert_eq!(parse_flexible_with_options::<i64>(b"$FG", 10, &options), Err(Error::from((ErrorCode::InvalidDigit, 2, b'G'))));
+    }
+
+    #[test]
+    fn octal_leading_zero_test() {
+        let options = ParseIntegerOptions::new();
+        assert_eq!(parse_flexible_with_options::<i64>(b"017", 10, &options), Ok((17, 10)));
+        assert_eq!(parse_flexible_with_options::<i64>(b"08", 10, &options), Ok((8, 10)));
+
+        let options = ParseIntegerOptions::builder().octal_leading_zero(true).build().unwrap();
+        assert_eq!(parse_flexible_with_options::<i64>(b"017", 10, &options), Ok((15, 8)));
+        assert_eq!(parse_flexible_with_options::<i64>(b"0017", 10, &options), Ok((15, 8)));
+        assert_eq!(parse_flexible_with_options::<u8>(b"0377", 10, &options), Ok((255, 8)));
+        assert_eq!(parse_flexible_with_options::<i64>(b"17", 10, &options), Ok((17, 10)));
+        assert_eq!(parse_flexible_with_options::<i64>(b"0", 10, &options), Ok((0, 10)));
+        assert_eq!(parse_flexible_with_options::<i64>(b"0x1F", 10, &options), Ok((31, 16)));
+        assert_eq!(parse_flexible_with_options::<i64>(b"0o17", 10, &options), Ok((15, 8)));
+        assert_eq!(parse_flexible_with_options::<i64>(b"0b11", 10, &options), Ok((3, 2)));
+        assert_eq!(parse_flexible_with_options::<i64>(b"-017", 10, &options), Ok((-15, 8)));
+
+        // Invalid octal digits are reported at the digit.
+        assert_eq!(parse_flexible_with_options::<i64>(b"08", 10, &options), Err(Error::from((ErrorCode::InvalidDigit, 1, b'8'))));
+        assert_eq!(parse_flexible_with_options::<i64>(b"0179", 10, &options), Err(Error::from((ErrorCode::InvalidDigit, 3, b'9'))));
+        assert_eq!(parse_flexible_with_options::<u8>(b"0400", 10, &options), Err(Error::from((ErrorCode::Overflow, 3))));
+    }
+}
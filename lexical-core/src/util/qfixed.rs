@@ -0,0 +1,237 @@
+//! Write fixed-point integers as decimal strings.
+//!
+//! The fixed-point integer is scaled exactly using integer arithmetic,
+//! rather than converting to a float, so no precision is lost.
+
+use super::num::Number;
+use super::traits::ToLexical;
+
+// HELPERS
+
+// Get the next fractional digit, and the new fractional remainder.
+#[inline]
+fn next_digit(fraction: u64, frac_bits: u32, mask: u64) -> (u64, u64) {
+    let scaled = fraction * 10;
+    (scaled >> frac_bits, scaled & mask)
+}
+
+// Determine if the written fraction must be rounded up.
+//
+// Rounds to the nearest digit, with ties to even, using the exact
+// remainder after the last written digit.
+#[inline]
+fn is_round_up(integer: u64, fraction: u64, frac_bits: u32, mask: u64, decimal_places: usize)
+    -> bool
+{
+    if frac_bits == 0 {
+        return false;
+    }
+    let mut last = integer;
+    let mut fraction = fraction;
+    for _ in 0..decimal_places {
+        let (digit, remainder) = next_digit(fraction, frac_bits, mask);
+        last = digit;
+        fraction = remainder;
+        if fraction == 0 {
+            // Any remaining digits are all zero, so it is exact.
+            return false;
+        }
+    }
+    let half = 1 << (frac_bits - 1);
+    fraction > half || (fraction == half && last % 2 == 1)
+}
+
+// Determine if the first fractional digits are all the same digit.
+#[inline]
+fn is_all_digit(fraction: u64, frac_bits: u32, mask: u64, decimal_places: usize, expected: u64)
+    -> bool
+{
+    let mut fraction = fraction;
+    for _ in 0..decimal_places {
+        let (digit, remainder) = next_digit(fraction, frac_bits, mask);
+        if digit != expected {
+            return false;
+        }
+        fraction = remainder;
+    }
+    true
+}
+
+// Propagate a carry through the written fractional digits.
+//
+// Returns if the carry propagated into the integer.
+#[inline]
+fn carry_fraction(digits: &mut [u8]) -> bool {
+    for digit in digits.iter_mut().rev() {
+        if *digit == b'9' {
+            *digit = b'0';
+        } else {
+            *digit += 1;
+            return false;
+        }
+    }
+    true
+}
+
+// API
+
+/// Write fixed-point integer to string, with an implied binary point.
+///
+/// The value is `value / 2^frac_bits`, written with exactly
+/// `decimal_places` fractional digits, and rounded to the nearest
+/// digit with ties to even. If `decimal_places` is 0, no decimal
+/// point is written. Values that round to zero are written without
+/// a sign. Returns the number of bytes written.
+///
+/// * `value`           - Raw fixed-point integer, such as a Q16.16 value.
+/// * `frac_bits`       - Number of fractional bits in the value.
+/// * `decimal_places`  - Number of fractional digits to write.
+/// * `bytes`           - Buffer to write the decimal string to.
+///
+/// # Panics
+///
+/// Panics if `frac_bits` is greater than 32, or if the buffer may not
+/// be large enough to hold the serialized value. In order to ensure
+/// the function will not panic, provide a buffer with at least
+/// `i32::FORMATTED_SIZE_DECIMAL + 1 + decimal_places` elements.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # pub fn main() {
+/// let mut buffer = [b'0'; lexical_core::BUFFER_SIZE];
+/// let count = lexical_core::format_qfixed(0x0003_2000, 16, 4, &mut buffer);
+/// assert_eq!(&buffer[..count], b"3.1250");
+/// let count = lexical_core::format_qfixed(-0x0000_8000, 16, 0, &mut buffer);
+/// assert_eq!(&buffer[..count], b"0");
+/// # }
+/// ```
+pub fn format_qfixed(value: i32, frac_bits: u32, decimal_places: usize, bytes: &mut [u8])
+    -> usize
+{
+    assert!(frac_bits <= 32, "frac_bits must be at most 32.");
+    assert!(bytes.len() >= i32::FORMATTED_SIZE_DECIMAL + 1 + decimal_places);
+
+    // Split the magnitude into the integral and fractional parts.
+    // The magnitude is at most 2^31, so the integral part fits in a
+    // `u32`, even after rounding.
+    let magnitude = (value as i64).abs() as u64;
+    let mask = (1u64 << frac_bits) - 1;
+    let fraction = magnitude & mask;
+    let mut integer = (magnitude >> frac_bits) as u32;
+
+    // Round before writing, so any carry into the integer is known.
+    // Rounding up only carries into the integer if all written digits
+    // are 9, and the value is only zero if it is not rounded up and all
+    // written digits are 0.
+    let round_up = is_round_up(integer as u64, fraction, frac_bits, mask, decimal_places);
+    if round_up && is_all_digit(fraction, frac_bits, mask, decimal_places, 9) {
+        integer += 1;
+    }
+    let is_zero = !round_up && integer == 0 && is_all_digit(fraction, frac_bits, mask, decimal_places, 0);
+
+    // Write the sign, skipping it for values that round to zero.
+    let mut index = 0;
+    if value < 0 && !is_zero {
+        index_mut!(bytes[index] = b'-');
+        index += 1;
+    }
+
+    // Write the integral and fractional digits.
+    index += integer.to_lexical(&mut index_mut!(bytes[index..])).len();
+    if decimal_places != 0 {
+        index_mut!(bytes[index] = b'.');
+        index += 1;
+        let start = index;
+        let mut remainder = fraction;
+        for _ in 0..decimal_places {
+            let (digit, next) = next_digit(remainder, frac_bits, mask);
+            remainder = next;
+            index_mut!(bytes[index] = b'0' + digit as u8);
+            index += 1;
+        }
+        if round_up {
+            carry_fraction(&mut index_mut!(bytes[start..index]));
+        }
+    }
+
+    index
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "std")]
+    fn reference(value: i32, frac_bits: u32, decimal_places: usize) -> String {
+        // Q16.16 values are exact as an `f64`, and Rust formats the
+        // exact value with ties to even.
+        let float = value as f64 / (1u64 << frac_bits) as f64;
+        let string = format!("{:.*}", decimal_places, float);
+        match string.trim_start_matches('-').bytes().all(|c| c == b'0' || c == b'.') {
+            true  => string.trim_start_matches('-').to_string(),
+            false => string,
+        }
+    }
+
+    fn format(value: i32, frac_bits: u32, decimal_places: usize) -> Vec<u8> {
+        let mut buffer = vec![b'\x00'; i32::FORMATTED_SIZE_DECIMAL + 1 + decimal_places];
+        let count = format_qfixed(value, frac_bits, decimal_places, &mut buffer);
+        buffer[..count].to_vec()
+    }
+
+    #[test]
+    fn format_qfixed_test() {
+        assert_eq!(format(0, 16, 2), b"0.00".to_vec());
+        assert_eq!(format(0x0001_0000, 16, 0), b"1".to_vec());
+        assert_eq!(format(0x0001_8000, 16, 1), b"1.5".to_vec());
+        assert_eq!(format(-0x0001_8000, 16, 3), b"-1.500".to_vec());
+        assert_eq!(format(0x0000_0001, 16, 16), b"0.0000152587890625".to_vec());
+        assert_eq!(format(0x0000_0001, 16, 18), b"0.000015258789062500".to_vec());
+        assert_eq!(format(i32::max_value(), 16, 2), b"32768.00".to_vec());
+        assert_eq!(format(i32::min_value(), 16, 2), b"-32768.00".to_vec());
+        assert_eq!(format(i32::min_value(), 0, 1), b"-2147483648.0".to_vec());
+        assert_eq!(format(i32::min_value(), 32, 3), b"-0.500".to_vec());
+        assert_eq!(format(i32::max_value(), 32, 3), b"0.500".to_vec());
+        assert_eq!(format(7, 0, 0), b"7".to_vec());
+    }
+
+    #[test]
+    fn format_qfixed_rounding_test() {
+        // Ties round to even.
+        assert_eq!(format(0x0000_2000, 16, 2), b"0.12".to_vec());
+        assert_eq!(format(0x0000_6000, 16, 2), b"0.38".to_vec());
+        assert_eq!(format(0x0000_8000, 16, 0), b"0".to_vec());
+        assert_eq!(format(0x0001_8000, 16, 0), b"2".to_vec());
+        assert_eq!(format(0x0002_8000, 16, 0), b"2".to_vec());
+
+        // Carry into the integer.
+        assert_eq!(format(0x0009_FFFF, 16, 2), b"10.00".to_vec());
+        assert_eq!(format(-0x0009_FFFF, 16, 0), b"-10".to_vec());
+
+        // Values that round to zero have no sign.
+        assert_eq!(format(-1, 16, 2), b"0.00".to_vec());
+        assert_eq!(format(-0x0000_8000, 16, 0), b"0".to_vec());
+        assert_eq!(format(-0x0000_8001, 16, 0), b"-1".to_vec());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn format_qfixed_reference_test() {
+        let values = [
+            0, 1, -1, 0x0000_8000, 0x0001_0000, 0x0003_243F, -0x0003_243F,
+            0x0002_B7E1, 0x7FFF_FFFF, -0x7FFF_FFFF, -0x8000_0000, 0x0000_1999,
+            0x0000_CCCD, 0x1234_5678, -0x0000_0A3D, 0x0009_FFFF,
+        ];
+        for &value in values.iter() {
+            for decimal_places in 0..20 {
+                let expected = reference(value, 16, decimal_places);
+                assert_eq!(format(value, 16, decimal_places), expected.into_bytes());
+            }
+        }
+    }
+}
@@ -0,0 +1,121 @@
+//! Parse decimal floats followed by a unit.
+
+use super::options::ParseFloatOptions;
+use super::result::Result;
+use super::traits::FromLexicalWithOptions;
+
+// HELPERS
+
+// Parse the float, and the unit up to the first delimiter.
+perftools_inline!{
+fn parse_quantity_impl<'a, Callback>(bytes: &'a [u8], is_delimiter: Callback)
+    -> Result<(f64, &'a [u8], usize)>
+    where Callback: Fn(u8) -> bool
+{
+    // Units starting with the exponent character, like `eV`, end the
+    // number, rather than being an exponent without digits.
+    let options = ParseFloatOptions::builder().lenient_exponent(true).build().unwrap();
+    let (value, count) = f64::from_lexical_partial_with_options(bytes, &options)?;
+    let rest = &index!(bytes[count..]);
+    let unit_len = rest.iter().position(|&c| is_delimiter(c)).unwrap_or(rest.len());
+    Ok((value, &index!(rest[..unit_len]), count + unit_len))
+}}
+
+// API
+
+/// Parse a decimal float followed by a unit, like `3.5kg`.
+///
+/// The float is parsed like [`parse_partial`](fn.parse_partial.html)
+/// for an `f64`, and the unit is every byte after the float, up to
+/// the first ASCII whitespace or the end of the bytes. Returns the
+/// value, the unit, and the number of bytes consumed, including the
+/// unit. The unit directly follows the float, so the unit is empty if
+/// there is whitespace between them, like in `3.5 kg`, and the float
+/// ends before an exponent character without exponent digits, so
+/// `1.5eV` is parsed as `1.5` with the unit `eV`.
+///
+/// * `bytes`   - Byte slice containing a numeric string and a unit.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # pub fn main() {
+/// assert_eq!(lexical_core::parse_quantity(b"3.5kg "), Ok((3.5, &b"kg"[..], 5)));
+/// assert_eq!(lexical_core::parse_quantity(b"-40"), Ok((-40.0, &b""[..], 3)));
+/// assert_eq!(lexical_core::parse_quantity(b"1.5eV"), Ok((1.5, &b"eV"[..], 5)));
+/// # }
+/// ```
+pub fn parse_quantity(bytes: &[u8]) -> Result<(f64, &[u8], usize)> {
+    parse_quantity_impl(bytes, |c| c.is_ascii_whitespace())
+}
+
+/// Parse a decimal float followed by a unit, ending the unit at any of the delimiters.
+///
+/// The float and unit are parsed the same as [`parse_quantity`], except
+/// the unit ends at the first of the delimiters, rather than at ASCII
+/// whitespace, so the unit may contain whitespace. The delimiter is
+/// not consumed.
+///
+/// * `bytes`       - Byte slice containing a numeric string and a unit.
+/// * `delimiters`  - Bytes ending the unit.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # pub fn main() {
+/// assert_eq!(lexical_core::parse_quantity_delimited(b"9.81m/s^2,1kg", b","), Ok((9.81, &b"m/s^2"[..], 9)));
+/// assert_eq!(lexical_core::parse_quantity_delimited(b"5 km;", b";"), Ok((5.0, &b" km"[..], 4)));
+/// # }
+/// ```
+///
+/// [`parse_quantity`]: fn.parse_quantity.html
+pub fn parse_quantity_delimited<'a>(bytes: &'a [u8], delimiters: &[u8])
+    -> Result<(f64, &'a [u8], usize)>
+{
+    parse_quantity_impl(bytes, |c| delimiters.contains(&c))
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use crate::util::*;
+    use super::*;
+
+    #[test]
+    fn parse_quantity_test() {
+        assert_eq!(parse_quantity(b"3.5kg"), Ok((3.5, &b"kg"[..], 5)));
+        assert_eq!(parse_quantity(b"3.5kg 2m"), Ok((3.5, &b"kg"[..], 5)));
+        assert_eq!(parse_quantity(b"3.5kg\tx"), Ok((3.5, &b"kg"[..], 5)));
+        assert_eq!(parse_quantity(b"1e3Hz"), Ok((1000.0, &b"Hz"[..], 5)));
+        assert_eq!(parse_quantity(b"-273.15\xc2\xb0C"), Ok((-273.15, &b"\xc2\xb0C"[..], 10)));
+        assert_eq!(parse_quantity(b"12"), Ok((12.0, &b""[..], 2)));
+        assert_eq!(parse_quantity(b"12 kg"), Ok((12.0, &b""[..], 2)));
+        assert_eq!(parse_quantity(b"12%"), Ok((12.0, &b"%"[..], 3)));
+
+        // Units starting with the exponent character.
+        assert_eq!(parse_quantity(b"1.5eV"), Ok((1.5, &b"eV"[..], 5)));
+        assert_eq!(parse_quantity(b"2em"), Ok((2.0, &b"em"[..], 3)));
+        assert_eq!(parse_quantity(b"2e"), Ok((2.0, &b"e"[..], 2)));
+        assert_eq!(parse_quantity(b"2e1em"), Ok((20.0, &b"em"[..], 5)));
+
+        // Errors.
+        assert_eq!(parse_quantity(b""), Err(Error::from((ErrorCode::Empty, 0))));
+        assert_eq!(parse_quantity(b"kg"), Err(Error::from((ErrorCode::EmptyMantissa, 0))));
+        assert_eq!(parse_quantity(b" 3kg"), Err(Error::from((ErrorCode::EmptyMantissa, 0))));
+    }
+
+    #[test]
+    fn parse_quantity_delimited_test() {
+        assert_eq!(parse_quantity_delimited(b"3.5kg,2m", b","), Ok((3.5, &b"kg"[..], 5)));
+        assert_eq!(parse_quantity_delimited(b"3.5 kg,2m", b","), Ok((3.5, &b" kg"[..], 6)));
+        assert_eq!(parse_quantity_delimited(b"3.5kg;2m", b",;"), Ok((3.5, &b"kg"[..], 5)));
+        assert_eq!(parse_quantity_delimited(b"3.5kg", b","), Ok((3.5, &b"kg"[..], 5)));
+        assert_eq!(parse_quantity_delimited(b"3.5,kg", b","), Ok((3.5, &b""[..], 3)));
+        assert_eq!(parse_quantity_delimited(b"3.5 kg", b""), Ok((3.5, &b" kg"[..], 6)));
+        assert_eq!(parse_quantity_delimited(b",", b","), Err(Error::from((ErrorCode::EmptyMantissa, 0))));
+    }
+}
@@ -0,0 +1,101 @@
+//! Round floats to the nearest multiple of a step.
+
+use super::clamped::round_integral;
+use super::num::Float;
+use super::rounding::RoundingMode;
+
+/// Round a float to a multiple of a step, with a rounding scheme.
+///
+/// The float is divided by the step, rounded to an integral value
+/// with the rounding scheme, and then multiplied by the step. If the
+/// step is the reciprocal of an integer, like `0.01`, the float is
+/// multiplied by the integer instead, so decimal steps round to the
+/// closest float to the decimal multiple. `NaN` and infinite values,
+/// and values whose multiple would overflow, are returned unchanged.
+///
+/// Returns `None` if the step is not positive and finite.
+///
+/// * `value`       - Float to round.
+/// * `step`        - Positive, finite step to round to a multiple of.
+/// * `rounding`    - Rounding scheme for the quotient.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::RoundingMode;
+/// # pub fn main() {
+/// let kind = RoundingMode::NearestTieEven;
+/// assert_eq!(lexical_core::quantize(3.14159, 0.01, kind), Some(3.14));
+/// assert_eq!(lexical_core::quantize(0.3, 0.0009765625, kind), Some(0.2998046875));
+/// assert_eq!(lexical_core::quantize(17.0, 5.0, RoundingMode::TowardZero), Some(15.0));
+/// assert_eq!(lexical_core::quantize(1.0, 0.0, kind), None);
+/// # }
+/// ```
+pub fn quantize(value: f64, step: f64, rounding: RoundingMode) -> Option<f64> {
+    if !(step > 0.0) || step.is_special() {
+        return None;
+    } else if value.is_special() {
+        return Some(value);
+    }
+
+    let inverse = 1.0 / step;
+    let is_reciprocal = inverse > 1.0 && round_integral(inverse, RoundingMode::TowardZero) == inverse;
+    let quantized = match is_reciprocal {
+        true  => round_integral(value * inverse, rounding) / inverse,
+        false => round_integral(value / step, rounding) * step,
+    };
+    match quantized.is_special() {
+        true  => Some(value),
+        false => Some(quantized),
+    }
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quantize_test() {
+        let even = RoundingMode::NearestTieEven;
+        assert_eq!(quantize(3.14159, 0.01, even), Some(3.14));
+        assert_eq!(quantize(0.3, 0.1, even), Some(0.3));
+        assert_eq!(quantize(0.25, 0.5, even), Some(0.0));
+        assert_eq!(quantize(0.75, 0.5, even), Some(1.0));
+        assert_eq!(quantize(-0.2, 1.0, even), Some(0.0));
+        assert_eq!(quantize(1234.5, 1000.0, even), Some(1000.0));
+        assert_eq!(quantize(1.0 / 3.0, 0.0009765625, even), Some(0.3330078125));
+        assert_eq!(quantize(1e300, 1e-300, even), Some(1e300));
+        assert_eq!(quantize(1.7e308, 1e308, even), Some(1.7e308));
+    }
+
+    #[test]
+    fn quantize_rounding_test() {
+        let values = [2.5, 3.5, 2.2, -2.5, -2.2];
+        let check = |kind, expected: [f64; 5]| {
+            for (value, expected) in values.iter().zip(expected.iter()) {
+                assert_eq!(quantize(*value, 1.0, kind), Some(*expected));
+            }
+        };
+        check(RoundingMode::NearestTieEven, [2.0, 4.0, 2.0, -2.0, -2.0]);
+        check(RoundingMode::NearestTieAwayZero, [3.0, 4.0, 2.0, -3.0, -2.0]);
+        check(RoundingMode::TowardPositiveInfinity, [3.0, 4.0, 3.0, -2.0, -2.0]);
+        check(RoundingMode::TowardNegativeInfinity, [2.0, 3.0, 2.0, -3.0, -3.0]);
+        check(RoundingMode::TowardZero, [2.0, 3.0, 2.0, -2.0, -2.0]);
+    }
+
+    #[test]
+    fn quantize_special_test() {
+        let even = RoundingMode::NearestTieEven;
+        assert!(quantize(f64::NAN, 0.5, even).unwrap().is_nan());
+        assert_eq!(quantize(f64::INFINITY, 0.5, even), Some(f64::INFINITY));
+        assert_eq!(quantize(f64::NEG_INFINITY, 0.5, even), Some(f64::NEG_INFINITY));
+        assert_eq!(quantize(1.0, 0.0, even), None);
+        assert_eq!(quantize(1.0, -0.5, even), None);
+        assert_eq!(quantize(1.0, f64::NAN, even), None);
+        assert_eq!(quantize(1.0, f64::INFINITY, even), None);
+    }
+}
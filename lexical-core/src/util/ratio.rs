@@ -0,0 +1,233 @@
+//! Parse ratios of unsigned integers, like aspect ratios, and decimals as exact ratios.
+
+use super::decompose::parse_exponent;
+use super::error::{Error, ErrorCode};
+use super::num::Float;
+use super::result::Result;
+use super::spans::parse_spans;
+use super::traits::FromLexical;
+
+// HELPERS
+
+// Largest powers of 2 and 5 that fit in a `u128`.
+const MAX_POW2: i64 = 127;
+const MAX_POW5: i64 = 55;
+
+// API
+
+/// Parse a ratio of two unsigned integers separated by a `:`, like `16:9`.
+///
+/// Both integers are parsed like [`parse`](fn.parse.html) for a `u64`,
+/// and the ratio is the first integer divided by the second. Returns
+/// the float ratio, and the two integers, so the exact ratio is kept,
+/// like for aspect ratios. Every byte must be part of the ratio.
+///
+/// If the `:` is missing, the error is
+/// [`Empty`](enum.ErrorCode.html#variant.Empty) at the end of the
+/// bytes, or [`InvalidDigit`](enum.ErrorCode.html#variant.InvalidDigit)
+/// at the first byte after the first integer, and if the second
+/// integer is zero, the error is
+/// [`Zero`](enum.ErrorCode.html#variant.Zero) at the second integer.
+/// Errors parsing either integer are at their index in the bytes.
+///
+/// * `bytes`   - Byte slice containing a ratio.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::ErrorCode;
+/// # pub fn main() {
+/// assert_eq!(lexical_core::parse_colon_ratio::<f64>(b"3:2"), Ok((1.5, 3, 2)));
+/// assert_eq!(lexical_core::parse_colon_ratio::<f32>(b"16:10"), Ok((1.6, 16, 10)));
+/// assert_eq!(lexical_core::parse_colon_ratio::<f64>(b"16:0").err().unwrap().code, ErrorCode::Zero);
+/// # }
+/// ```
+pub fn parse_colon_ratio<N: Float>(bytes: &[u8]) -> Result<(N, u64, u64)> {
+    let (numerator, count) = u64::from_lexical_partial(bytes)?;
+    match bytes.get(count) {
+        None        => return Err(Error::from((ErrorCode::Empty, count))),
+        Some(&b':') => (),
+        Some(_)     => return Err(Error::from((ErrorCode::InvalidDigit, count)).with_byte(bytes)),
+    }
+
+    let shift = count + 1;
+    let denominator = match u64::from_lexical(&index!(bytes[shift..])) {
+        Ok(0)       => return Err(Error::from((ErrorCode::Zero, shift))),
+        Ok(value)   => value,
+        Err(error)  => return Err(error.shift(shift)),
+    };
+
+    let ratio = N::as_cast(numerator) / N::as_cast(denominator);
+    Ok((ratio, numerator, denominator))
+}
+
+/// Parse a decimal number into the exact ratio of two integers, like `"0.25"` to `(1, 4)`.
+///
+/// The number is decomposed like [`decompose_decimal`] into its
+/// significant digits and power-of-ten exponent, and the ratio is
+/// the digits over the power of ten, reduced to lowest terms, so
+/// `"1.5"` is `(3, 2)`, and `"1.5e3"` is `(1500, 1)`. The numerator
+/// has the sign, and the denominator is always positive, so zero,
+/// including `"-0.0"`, is `(0, 1)`. Every byte must be part of the
+/// number.
+///
+/// A reduced numerator or denominator that does not fit in 128 bits
+/// is reported as [`Overflow`](enum.ErrorCode.html#variant.Overflow)
+/// at the end of the significant digits, and special values, like
+/// `"NaN"`, are reported as
+/// [`InvalidDigit`](enum.ErrorCode.html#variant.InvalidDigit).
+///
+/// * `bytes`   - Byte slice containing a decimal number.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::ErrorCode;
+/// # pub fn main() {
+/// assert_eq!(lexical_core::parse_rational(b"0.25"), Ok((1, 4)));
+/// assert_eq!(lexical_core::parse_rational(b"-1.5"), Ok((-3, 2)));
+/// assert_eq!(lexical_core::parse_rational(b"1.5e3"), Ok((1500, 1)));
+/// assert_eq!(lexical_core::parse_rational(b"1e-40").err().unwrap().code, ErrorCode::Overflow);
+/// # }
+/// ```
+///
+/// [`decompose_decimal`]: fn.decompose_decimal.html
+pub fn parse_rational(bytes: &[u8]) -> Result<(i128, u128)> {
+    let (spans, consumed) = parse_spans(bytes)?;
+    if consumed != bytes.len() {
+        return Err(Error::from((ErrorCode::InvalidDigit, consumed)).with_byte(bytes));
+    }
+    let is_negative = bytes.first() == Some(&b'-');
+    let integer = &index!(bytes[spans.integer]);
+    let fraction = &index!(bytes[spans.fraction.clone()]);
+    let overflow = Error::from((ErrorCode::Overflow, spans.fraction.end));
+
+    // Accumulate the significant digits, moving trailing zeros to the
+    // exponent, so they never overflow the numerator.
+    let digits = integer.iter().chain(fraction.iter()).skip_while(|&&c| c == b'0');
+    let length = digits.clone().count();
+    let zeros = integer.iter().chain(fraction.iter()).rev().take_while(|&&c| c == b'0').count().min(length);
+    let count = length - zeros;
+    if count == 0 {
+        return Ok((0, 1));
+    }
+    let mut numerator: u128 = 0;
+    for &c in digits.take(count) {
+        numerator = numerator.checked_mul(10)
+            .and_then(|n| n.checked_add((c - b'0') as u128))
+            .ok_or(overflow)?;
+    }
+    let exponent = parse_exponent(&index!(bytes[spans.exponent])) - fraction.len() as i64 + zeros as i64;
+
+    // Scale the numerator by a positive power of ten, or cancel the
+    // factors of 2 and 5 from the denominator.
+    let (numerator, denominator) = match exponent >= 0 {
+        true  => {
+            let scale = match exponent <= MAX_POW5 {
+                true  => 10u128.checked_pow(exponent as u32),
+                false => None,
+            };
+            (scale.and_then(|s| numerator.checked_mul(s)).ok_or(overflow)?, 1)
+        },
+        false => {
+            let mut twos = -exponent;
+            let mut fives = -exponent;
+            while twos > 0 && numerator % 2 == 0 {
+                numerator /= 2;
+                twos -= 1;
+            }
+            while fives > 0 && numerator % 5 == 0 {
+                numerator /= 5;
+                fives -= 1;
+            }
+            if twos > MAX_POW2 || fives > MAX_POW5 {
+                return Err(overflow);
+            }
+            let denominator = 2u128.pow(twos as u32).checked_mul(5u128.pow(fives as u32));
+            (numerator, denominator.ok_or(overflow)?)
+        },
+    };
+
+    // The magnitude of a negative numerator may be one larger than `i128::MAX`.
+    let max = i128::max_value() as u128 + is_negative as u128;
+    if numerator > max {
+        return Err(overflow);
+    }
+    match is_negative {
+        true  => Ok(((numerator as i128).wrapping_neg(), denominator)),
+        false => Ok((numerator as i128, denominator)),
+    }
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_colon_ratio_test() {
+        assert_eq!(parse_colon_ratio::<f64>(b"16:9"), Ok((16.0 / 9.0, 16, 9)));
+        assert_eq!(parse_colon_ratio::<f64>(b"4:3"), Ok((4.0 / 3.0, 4, 3)));
+        assert_eq!(parse_colon_ratio::<f64>(b"1:1"), Ok((1.0, 1, 1)));
+        assert_eq!(parse_colon_ratio::<f64>(b"0:1"), Ok((0.0, 0, 1)));
+        assert_eq!(parse_colon_ratio::<f32>(b"21:9"), Ok((21.0 / 9.0, 21, 9)));
+        assert_eq!(parse_colon_ratio::<f64>(b"18446744073709551615:1"), Ok((18446744073709551615.0, u64::max_value(), 1)));
+
+        // Divide by zero.
+        assert_eq!(parse_colon_ratio::<f64>(b"16:0"), Err(Error::from((ErrorCode::Zero, 3))));
+        assert_eq!(parse_colon_ratio::<f64>(b"0:0"), Err(Error::from((ErrorCode::Zero, 2))));
+
+        // Missing parts.
+        assert_eq!(parse_colon_ratio::<f64>(b""), Err(Error::from((ErrorCode::Empty, 0))));
+        assert_eq!(parse_colon_ratio::<f64>(b"16"), Err(Error::from((ErrorCode::Empty, 2))));
+        assert_eq!(parse_colon_ratio::<f64>(b"16:"), Err(Error::from((ErrorCode::Empty, 3))));
+        assert_eq!(parse_colon_ratio::<f64>(b":9"), Err(Error::from((ErrorCode::InvalidDigit, 0, b':'))));
+
+        // Errors.
+        assert_eq!(parse_colon_ratio::<f64>(b"16/9"), Err(Error::from((ErrorCode::InvalidDigit, 2, b'/'))));
+        assert_eq!(parse_colon_ratio::<f64>(b"16:9:1"), Err(Error::from((ErrorCode::InvalidDigit, 4, b':'))));
+        assert_eq!(parse_colon_ratio::<f64>(b"16:-9"), Err(Error::from((ErrorCode::InvalidDigit, 3, b'-'))));
+        assert_eq!(parse_colon_ratio::<f64>(b"16: 9"), Err(Error::from((ErrorCode::InvalidDigit, 3, b' '))));
+        assert_eq!(parse_colon_ratio::<f64>(b"18446744073709551616:1"), Err(Error::from((ErrorCode::Overflow, 19))));
+    }
+
+    #[test]
+    fn parse_rational_test() {
+        assert_eq!(parse_rational(b"0.25"), Ok((1, 4)));
+        assert_eq!(parse_rational(b"1.5"), Ok((3, 2)));
+        assert_eq!(parse_rational(b"-1.5"), Ok((-3, 2)));
+        assert_eq!(parse_rational(b"+0.125"), Ok((1, 8)));
+        assert_eq!(parse_rational(b"0.1"), Ok((1, 10)));
+        assert_eq!(parse_rational(b"0.6"), Ok((3, 5)));
+        assert_eq!(parse_rational(b"12"), Ok((12, 1)));
+        assert_eq!(parse_rational(b"1.50"), Ok((3, 2)));
+        assert_eq!(parse_rational(b"1200"), Ok((1200, 1)));
+        assert_eq!(parse_rational(b"1.5e3"), Ok((1500, 1)));
+        assert_eq!(parse_rational(b"15e-3"), Ok((3, 200)));
+        assert_eq!(parse_rational(b".5"), Ok((1, 2)));
+        assert_eq!(parse_rational(b"0"), Ok((0, 1)));
+        assert_eq!(parse_rational(b"-0.00e5"), Ok((0, 1)));
+        assert_eq!(parse_rational(b"5e-39"), Ok((1, 200000000000000000000000000000000000000)));
+        assert_eq!(parse_rational(b"0.50000000000000000000000000000000000000000000000000"), Ok((1, 2)));
+        assert_eq!(parse_rational(b"170141183460469231731687303715884105727"), Ok((i128::max_value(), 1)));
+        assert_eq!(parse_rational(b"-170141183460469231731687303715884105728"), Ok((i128::min_value(), 1)));
+        assert_eq!(parse_rational(b"1e38"), Ok((100000000000000000000000000000000000000, 1)));
+
+        // Overflow.
+        assert_eq!(parse_rational(b"170141183460469231731687303715884105728"), Err(Error::from((ErrorCode::Overflow, 39))));
+        assert_eq!(parse_rational(b"1e39"), Err(Error::from((ErrorCode::Overflow, 1))));
+        assert_eq!(parse_rational(b"1e-40"), Err(Error::from((ErrorCode::Overflow, 1))));
+        assert_eq!(parse_rational(b"3e-39"), Err(Error::from((ErrorCode::Overflow, 1))));
+        assert_eq!(parse_rational(b"1e100000000000"), Err(Error::from((ErrorCode::Overflow, 1))));
+        assert_eq!(parse_rational(b"1e-100000000000"), Err(Error::from((ErrorCode::Overflow, 1))));
+
+        // Errors.
+        assert_eq!(parse_rational(b""), Err(Error::from((ErrorCode::Empty, 0))));
+        assert_eq!(parse_rational(b"1.5x"), Err(Error::from((ErrorCode::InvalidDigit, 3, b'x'))));
+        assert_eq!(parse_rational(b"NaN"), Err(Error::from((ErrorCode::InvalidDigit, 0, b'N'))));
+    }
+}
@@ -53,6 +53,29 @@ pub enum RoundingKind {
     Downward = -2,
 }
 
+/// Rounding scheme for values rounded outside of float parsing.
+///
+/// Used by [`parse_int_clamped`], [`quantize`], and [`reround`], and
+/// available without the `rounding` feature, unlike `RoundingKind`,
+/// which sets the rounding scheme for float parsing.
+///
+/// [`parse_int_clamped`]: fn.parse_int_clamped.html
+/// [`quantize`]: fn.quantize.html
+/// [`reround`]: fn.reround.html
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum RoundingMode {
+    /// Round to the nearest, tie to even.
+    NearestTieEven,
+    /// Round to the nearest, tie away from zero.
+    NearestTieAwayZero,
+    /// Round toward positive infinity.
+    TowardPositiveInfinity,
+    /// Round toward negative infinity.
+    TowardNegativeInfinity,
+    /// Round toward zero.
+    TowardZero,
+}
+
 /// Determine if we are rounding to the nearest value, then tying away.
 #[inline]
 pub(crate) fn is_nearest(kind: RoundingKind) -> bool {
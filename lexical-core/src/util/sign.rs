@@ -130,6 +130,40 @@ pub(crate) fn parse_sign<'a, T>(bytes: &'a [u8], format: NumberFormat)
     return parse_sign_separator::<T>(bytes, format);
 }
 
+// VALIDATE
+
+/// Find a sign following the leading sign, without any digit separators.
+///
+/// Returns the index of the second sign, if the leading sign was
+/// parsed, and it is followed by another sign.
+#[inline]
+pub(crate) fn find_second_sign<T>(bytes: &[u8])
+    -> Option<usize>
+    where T: Number
+{
+    let (_, digits) = parse_sign_no_separator::<T>(bytes, b'\x00');
+    match digits.get(0) {
+        Some(&b'+') | Some(&b'-') if digits.len() < bytes.len() => Some(1),
+        _                                                       => None,
+    }
+}
+
+/// Find a decimal point directly following the leading sign.
+///
+/// Returns the index of the decimal point, if the leading sign was
+/// parsed, and it is followed by a decimal point.
+#[inline]
+pub(crate) fn find_sign_before_point<T>(bytes: &[u8])
+    -> Option<usize>
+    where T: Number
+{
+    let (_, digits) = parse_sign_no_separator::<T>(bytes, b'\x00');
+    match digits.get(0) {
+        Some(&b'.') if digits.len() < bytes.len() => Some(1),
+        _                                         => None,
+    }
+}
+
 // TESTS
 // -----
 
@@ -218,4 +252,28 @@ mod tests {
         assert_eq!(parse_sign::<u32>(b"+5", format), (Sign::Positive, b!("5")));
         assert_eq!(parse_sign::<u32>(b"-5", format), (Sign::Positive, b!("-5")));
     }
+
+    #[test]
+    fn find_second_sign_test() {
+        assert_eq!(find_second_sign::<i32>(b"5"), None);
+        assert_eq!(find_second_sign::<i32>(b"-5"), None);
+        assert_eq!(find_second_sign::<i32>(b"-"), None);
+        assert_eq!(find_second_sign::<i32>(b"--5"), Some(1));
+        assert_eq!(find_second_sign::<i32>(b"+-5"), Some(1));
+        assert_eq!(find_second_sign::<i32>(b"-+5"), Some(1));
+        assert_eq!(find_second_sign::<f64>(b"++5"), Some(1));
+        assert_eq!(find_second_sign::<u32>(b"+-5"), Some(1));
+        assert_eq!(find_second_sign::<u32>(b"-+5"), None);
+    }
+
+    #[test]
+    fn find_sign_before_point_test() {
+        assert_eq!(find_sign_before_point::<f64>(b".5"), None);
+        assert_eq!(find_sign_before_point::<f64>(b"-0.5"), None);
+        assert_eq!(find_sign_before_point::<f64>(b"-"), None);
+        assert_eq!(find_sign_before_point::<f64>(b"-.5"), Some(1));
+        assert_eq!(find_sign_before_point::<f64>(b"+.25"), Some(1));
+        assert_eq!(find_sign_before_point::<f64>(b"-."), Some(1));
+        assert_eq!(find_sign_before_point::<f64>(b"--.5"), None);
+    }
 }
@@ -0,0 +1,75 @@
+//! Parse the sign and magnitude of an integer separately.
+
+use super::error::{Error, ErrorCode};
+use super::num::UnsignedInteger;
+use super::result::Result;
+use super::traits::FromLexical;
+
+// API
+
+/// Parse the sign and unsigned magnitude of a decimal integer from bytes.
+///
+/// At most one leading `+` or `-` sign is parsed, followed by the
+/// magnitude, so the sign may be applied to a custom type, including
+/// negative values with a magnitude too large for a signed integer.
+/// Returns if the sign is negative, the magnitude, and the number of
+/// processed bytes, like [`parse_partial`]. A sign following the
+/// leading sign, like in `"-+5"`, is an invalid digit.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # pub fn main() {
+/// assert_eq!(lexical_core::parse_signed_parts::<u64>(b"-18446744073709551615"), Ok((true, 18446744073709551615, 21)));
+/// assert_eq!(lexical_core::parse_signed_parts::<u8>(b"+5,"), Ok((false, 5, 2)));
+/// assert_eq!(lexical_core::parse_signed_parts::<u8>(b"5"), Ok((false, 5, 1)));
+/// # }
+/// ```
+///
+/// [`parse_partial`]: fn.parse_partial.html
+pub fn parse_signed_parts<N: FromLexical + UnsignedInteger>(bytes: &[u8])
+    -> Result<(bool, N, usize)>
+{
+    let (negative, shift) = match bytes.first() {
+        Some(&b'+') => (false, 1),
+        Some(&b'-') => (true, 1),
+        _           => (false, 0),
+    };
+    let digits = &index!(bytes[shift..]);
+    if shift == 1 && (digits.first() == Some(&b'+') || digits.first() == Some(&b'-')) {
+        return Err(Error::from((ErrorCode::InvalidDigit, shift)).with_byte(bytes));
+    }
+    match N::from_lexical_partial(digits) {
+        Ok((value, count))  => Ok((negative, value, count + shift)),
+        Err(error)          => Err(error.shift(shift)),
+    }
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_signed_parts_test() {
+        assert_eq!(parse_signed_parts::<u8>(b"0"), Ok((false, 0, 1)));
+        assert_eq!(parse_signed_parts::<u8>(b"-0"), Ok((true, 0, 2)));
+        assert_eq!(parse_signed_parts::<u8>(b"+255"), Ok((false, 255, 4)));
+        assert_eq!(parse_signed_parts::<u8>(b"-255"), Ok((true, 255, 4)));
+        assert_eq!(parse_signed_parts::<u32>(b"-12a"), Ok((true, 12, 3)));
+        assert_eq!(parse_signed_parts::<u8>(b"-a"), Err(Error::from((ErrorCode::InvalidDigit, 1, b'a'))));
+        assert_eq!(parse_signed_parts::<u128>(b"-340282366920938463463374607431768211455"), Ok((true, u128::max_value(), 40)));
+
+        // Errors are relative to the start of the bytes.
+        assert_eq!(parse_signed_parts::<u8>(b""), Err(Error::from((ErrorCode::Empty, 0))));
+        assert_eq!(parse_signed_parts::<u8>(b"-"), Err(Error::from((ErrorCode::Empty, 1))));
+        assert_eq!(parse_signed_parts::<u8>(b"-256"), Err(Error::from((ErrorCode::Overflow, 3))));
+        assert_eq!(parse_signed_parts::<u8>(b"-+5"), Err(Error::from((ErrorCode::InvalidDigit, 1, b'+'))));
+        assert_eq!(parse_signed_parts::<u8>(b"--5"), Err(Error::from((ErrorCode::InvalidDigit, 1, b'-'))));
+    }
+}
@@ -0,0 +1,117 @@
+//! Find the sub-spans of decimal float strings.
+
+use crate::lib::ops::Range;
+use super::error::{Error, ErrorCode};
+use super::result::Result;
+use super::traits::FromLexical;
+
+/// Byte ranges of the components of a decimal float string.
+///
+/// Empty components have empty ranges, at the index the component
+/// would start, so the ranges are always in order and never overlap.
+/// The decimal point and the exponent character are between the
+/// ranges, and are not part of any component.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NumberSpans {
+    /// Leading sign of the mantissa, either empty or a single byte.
+    pub sign: Range<usize>,
+    /// Digits before the decimal point.
+    pub integer: Range<usize>,
+    /// Digits after the decimal point.
+    pub fraction: Range<usize>,
+    /// Exponent after the exponent character, with its sign.
+    pub exponent: Range<usize>,
+}
+
+// API
+
+/// Parse a decimal float, and get the byte ranges of its components.
+///
+/// Parses the longest valid float at the start of the bytes, like
+/// [`parse_partial`](fn.parse_partial.html) for an `f64`, without
+/// converting the digits to a float. Returns the ranges of the sign,
+/// the integer digits, the fraction digits, and the exponent, and the
+/// number of bytes consumed. The fraction is empty without a decimal
+/// point, and the exponent is empty without an exponent character.
+/// Special values, like `"NaN"`, have no digits and are reported as
+/// [`InvalidDigit`](enum.ErrorCode.html#variant.InvalidDigit).
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::NumberSpans;
+/// # pub fn main() {
+/// let spans = NumberSpans { sign: 0..1, integer: 1..3, fraction: 4..6, exponent: 7..9 };
+/// assert_eq!(lexical_core::parse_spans(b"-12.34e-5,"), Ok((spans, 9)));
+///
+/// let spans = NumberSpans { sign: 0..0, integer: 0..0, fraction: 1..2, exponent: 2..2 };
+/// assert_eq!(lexical_core::parse_spans(b".5"), Ok((spans, 2)));
+/// # }
+/// ```
+pub fn parse_spans(bytes: &[u8]) -> Result<(NumberSpans, usize)> {
+    let (_, consumed) = f64::from_lexical_partial(bytes)?;
+    let bytes = &index!(bytes[..consumed]);
+    let digits = | start: usize | {
+        start + index!(bytes[start..]).iter().take_while(|c| c.is_ascii_digit()).count()
+    };
+
+    // The float is valid, so the components are only found in order.
+    let sign = match bytes.first() {
+        Some(&b'+') | Some(&b'-') => 0..1,
+        _                         => 0..0,
+    };
+    let integer = sign.end..digits(sign.end);
+    let fraction = match bytes.get(integer.end) {
+        Some(&b'.') => integer.end+1..digits(integer.end+1),
+        _           => integer.end..integer.end,
+    };
+    if integer.start == integer.end && fraction.start == fraction.end {
+        return Err(Error::from((ErrorCode::InvalidDigit, integer.start)).with_byte(bytes));
+    }
+    let exponent = match fraction.end < consumed {
+        true  => fraction.end+1..consumed,
+        false => consumed..consumed,
+    };
+
+    Ok((NumberSpans { sign, integer, fraction, exponent }, consumed))
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spans(sign: Range<usize>, integer: Range<usize>, fraction: Range<usize>, exponent: Range<usize>)
+        -> NumberSpans
+    {
+        NumberSpans { sign, integer, fraction, exponent }
+    }
+
+    #[test]
+    fn parse_spans_test() {
+        assert_eq!(parse_spans(b"1"), Ok((spans(0..0, 0..1, 1..1, 1..1), 1)));
+        assert_eq!(parse_spans(b"+123"), Ok((spans(0..1, 1..4, 4..4, 4..4), 4)));
+        assert_eq!(parse_spans(b"-1.5"), Ok((spans(0..1, 1..2, 3..4, 4..4), 4)));
+        assert_eq!(parse_spans(b"1."), Ok((spans(0..0, 0..1, 2..2, 2..2), 2)));
+        assert_eq!(parse_spans(b"-.25"), Ok((spans(0..1, 1..1, 2..4, 4..4), 4)));
+        assert_eq!(parse_spans(b"1e5"), Ok((spans(0..0, 0..1, 1..1, 2..3), 3)));
+        assert_eq!(parse_spans(b"1.e+10"), Ok((spans(0..0, 0..1, 2..2, 3..6), 6)));
+        assert_eq!(parse_spans(b"00.00120E-07"), Ok((spans(0..0, 0..2, 3..8, 9..12), 12)));
+
+        // Partial parsing.
+        assert_eq!(parse_spans(b"1.5,2"), Ok((spans(0..0, 0..1, 2..3, 3..3), 3)));
+        assert_eq!(parse_spans(b"12 e5"), Ok((spans(0..0, 0..2, 2..2, 2..2), 2)));
+
+        // Errors.
+        assert_eq!(parse_spans(b""), Err(Error::from((ErrorCode::Empty, 0))));
+        assert_eq!(parse_spans(b"."), Err(Error::from((ErrorCode::EmptyMantissa, 0))));
+        assert_eq!(parse_spans(b"1e"), Err(Error::from((ErrorCode::EmptyExponent, 2))));
+        assert_eq!(parse_spans(b"inf"), Err(Error::from((ErrorCode::InvalidDigit, 0, b'i'))));
+        assert_eq!(parse_spans(b"-NaN"), Err(Error::from((ErrorCode::InvalidDigit, 1, b'N'))));
+    }
+}
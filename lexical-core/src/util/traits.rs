@@ -1,20 +1,32 @@
 //! Wrap the low-level API into idiomatic serializers.
 
 use super::format::NumberFormat;
+#[cfg(feature = "metrics")]
+use super::metrics::ParseMetrics;
 use super::num::Number;
+#[cfg(feature = "std")]
+use super::options::WriteFloatOptions;
 use super::result::Result;
 
 // HELPERS
 
+/// Store the invalid byte in the error of a partial result.
+macro_rules! to_partial {
+    ($cb:expr, $bytes:expr $(,$args:expr)*) => {
+        $cb($bytes $(,$args)*).map_err(|e: $crate::Error| e.with_byte($bytes))
+    };
+}
+
 /// Map partial result to complete result.
 macro_rules! to_complete {
     ($cb:expr, $bytes:expr $(,$args:expr)*) => {
-        match $cb($bytes $(,$args)*) {
+        match to_partial!($cb, $bytes $(,$args)*) {
             Err(e)                  => Err(e),
             Ok((value, processed))  => if processed == $bytes.len() {
                 Ok(value)
             } else{
-                Err((ErrorCode::InvalidDigit, processed).into())
+                let error = $crate::Error::from(($crate::ErrorCode::InvalidDigit, processed));
+                Err(error.with_byte($bytes))
             }
         }
     };
@@ -42,7 +54,8 @@ pub trait FromLexical: Number {
     ///
     /// This method parses until an invalid digit is found (or the end
     /// of the string), returning the number of processed digits
-    /// and the parsed value until that point.
+    /// and the parsed value until that point. If no digits are found
+    /// after the sign, the first invalid digit is reported as an error.
     ///
     /// Returns a `Result` containing either the parsed value
     /// and the number of processed digits, or an error containing
@@ -104,7 +117,7 @@ macro_rules! from_lexical {
             #[inline]
             fn from_lexical_partial(bytes: &[u8]) -> Result<($t, usize)>
             {
-                $cb(bytes, 10)
+                to_partial!($cb, bytes, 10)
             }
 
             #[cfg(feature = "radix")]
@@ -118,7 +131,7 @@ macro_rules! from_lexical {
             #[inline]
             fn from_lexical_partial_radix(bytes: &[u8], radix: u8) -> Result<($t, usize)>
             {
-                $cb(bytes, radix.as_u32())
+                to_partial!($cb, bytes, radix.as_u32())
             }
         }
     )
@@ -206,7 +219,7 @@ macro_rules! from_lexical_lossy {
             #[inline]
             fn from_lexical_partial_lossy(bytes: &[u8]) -> Result<($t, usize)>
             {
-                $cb(bytes, 10)
+                to_partial!($cb, bytes, 10)
             }
 
             #[cfg(feature = "radix")]
@@ -220,7 +233,7 @@ macro_rules! from_lexical_lossy {
             #[inline]
             fn from_lexical_partial_lossy_radix(bytes: &[u8], radix: u8) -> Result<($t, usize)>
             {
-                $cb(bytes, radix.as_u32())
+                to_partial!($cb, bytes, radix.as_u32())
             }
         }
     )
@@ -318,7 +331,7 @@ macro_rules! from_lexical_format {
             #[inline]
             fn from_lexical_partial_format(bytes: &[u8], format: NumberFormat) -> Result<($t, usize)>
             {
-                $cb(bytes, 10, format)
+                to_partial!($cb, bytes, 10, format)
             }
 
             #[cfg(feature = "radix")]
@@ -332,7 +345,7 @@ macro_rules! from_lexical_format {
             #[inline]
             fn from_lexical_partial_format_radix(bytes: &[u8], radix: u8, format: NumberFormat) -> Result<($t, usize)>
             {
-                $cb(bytes, radix.as_u32(), format)
+                to_partial!($cb, bytes, radix.as_u32(), format)
             }
         }
     )
@@ -440,7 +453,7 @@ macro_rules! from_lexical_lossy_format {
             fn from_lexical_partial_lossy_format(bytes: &[u8], format: NumberFormat)
                 -> Result<($t, usize)>
             {
-                $cb(bytes, 10, format)
+                to_partial!($cb, bytes, 10, format)
             }
 
             #[cfg(feature = "radix")]
@@ -456,7 +469,178 @@ macro_rules! from_lexical_lossy_format {
             fn from_lexical_partial_lossy_format_radix(bytes: &[u8], radix: u8, format: NumberFormat)
                 -> Result<($t, usize)>
             {
-                $cb(bytes, radix.as_u32(), format)
+                to_partial!($cb, bytes, radix.as_u32(), format)
+            }
+        }
+    )
+}
+
+// FROM LEXICAL METRICS
+
+/// Trait for floating-point types that can report how they were parsed.
+#[cfg(feature = "metrics")]
+pub trait FromLexicalMetrics: FromLexical {
+    /// Checked parser for a string-to-number conversion, with metrics.
+    ///
+    /// This method parses the entire string, returning an error if
+    /// any invalid digits are found during parsing.
+    ///
+    /// Returns a `Result` containing either the parsed value and the
+    /// metrics collected while parsing it, or an error containing any
+    /// errors that occurred during parsing.
+    ///
+    /// * `bytes`   - Slice containing a numeric string.
+    fn from_lexical_metrics(bytes: &[u8]) -> Result<(Self, ParseMetrics)>;
+
+    /// Checked parser for a string-to-number conversion, with metrics.
+    ///
+    /// This method parses the entire string, returning an error if
+    /// any invalid digits are found during parsing.
+    ///
+    /// Returns a `Result` containing either the parsed value and the
+    /// metrics collected while parsing it, or an error containing any
+    /// errors that occurred during parsing.
+    ///
+    /// * `bytes`   - Slice containing a numeric string.
+    /// * `radix`   - Radix for the number parsing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the radix is not in the range `[2, 36]`.
+    #[cfg(feature = "radix")]
+    fn from_lexical_metrics_radix(bytes: &[u8], radix: u8) -> Result<(Self, ParseMetrics)>;
+}
+
+// Implement FromLexicalMetrics for numeric type.
+#[cfg(feature = "metrics")]
+macro_rules! from_lexical_metrics {
+    ($cb:expr, $t:ty) => (
+        impl FromLexicalMetrics for $t {
+            #[inline]
+            fn from_lexical_metrics(bytes: &[u8]) -> Result<($t, ParseMetrics)>
+            {
+                to_partial!($cb, bytes, 10)
+            }
+
+            #[cfg(feature = "radix")]
+            #[inline]
+            fn from_lexical_metrics_radix(bytes: &[u8], radix: u8) -> Result<($t, ParseMetrics)>
+            {
+                to_partial!($cb, bytes, radix.as_u32())
+            }
+        }
+    )
+}
+
+// FROM LEXICAL WITH OPTIONS
+
+/// Trait for numerical types that can be parsed from bytes with custom options.
+///
+/// The options type is specific to the number, for example,
+/// [`ParseFloatOptions`] for floats, and [`ParseIntegerOptions`]
+/// for integers.
+///
+/// [`ParseFloatOptions`]: struct.ParseFloatOptions.html
+/// [`ParseIntegerOptions`]: struct.ParseIntegerOptions.html
+pub trait FromLexicalWithOptions: FromLexical {
+    /// Custom options to parse the number.
+    type Options;
+
+    /// Checked parser for a string-to-number conversion with custom options.
+    ///
+    /// This method parses the entire string, returning an error if
+    /// any invalid digits are found during parsing.
+    ///
+    /// Returns a `Result` containing either the parsed value,
+    /// or an error containing any errors that occurred during parsing.
+    ///
+    /// * `bytes`   - Slice containing a numeric string.
+    /// * `options` - Options to customize the number parsing.
+    fn from_lexical_with_options(bytes: &[u8], options: &Self::Options) -> Result<Self>;
+
+    /// Checked parser for a string-to-number conversion with custom options.
+    ///
+    /// This method parses until an invalid digit is found (or the end
+    /// of the string), returning the number of processed digits
+    /// and the parsed value until that point.
+    ///
+    /// Returns a `Result` containing either the parsed value
+    /// and the number of processed digits, or an error containing
+    /// any errors that occurred during parsing.
+    ///
+    /// * `bytes`   - Slice containing a numeric string.
+    /// * `options` - Options to customize the number parsing.
+    fn from_lexical_partial_with_options(bytes: &[u8], options: &Self::Options) -> Result<(Self, usize)>;
+
+    /// Checked parser for a string-to-number conversion with custom options.
+    ///
+    /// This method parses the entire string, returning an error if
+    /// any invalid digits are found during parsing.
+    ///
+    /// Returns a `Result` containing either the parsed value,
+    /// or an error containing any errors that occurred during parsing.
+    ///
+    /// * `bytes`   - Slice containing a numeric string.
+    /// * `radix`   - Radix for the number parsing.
+    /// * `options` - Options to customize the number parsing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the radix is not in the range `[2, 36]`.
+    #[cfg(feature = "radix")]
+    fn from_lexical_radix_with_options(bytes: &[u8], radix: u8, options: &Self::Options) -> Result<Self>;
+
+    /// Checked parser for a string-to-number conversion with custom options.
+    ///
+    /// This method parses until an invalid digit is found (or the end
+    /// of the string), returning the number of processed digits
+    /// and the parsed value until that point.
+    ///
+    /// Returns a `Result` containing either the parsed value
+    /// and the number of processed digits, or an error containing
+    /// any errors that occurred during parsing.
+    ///
+    /// * `bytes`   - Slice containing a numeric string.
+    /// * `radix`   - Radix for the number parsing.
+    /// * `options` - Options to customize the number parsing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the radix is not in the range `[2, 36]`.
+    #[cfg(feature = "radix")]
+    fn from_lexical_partial_radix_with_options(bytes: &[u8], radix: u8, options: &Self::Options) -> Result<(Self, usize)>;
+}
+
+// Implement FromLexicalWithOptions for numeric type.
+macro_rules! from_lexical_with_options {
+    ($cb:expr, $t:ty, $options:ty) => (
+        impl FromLexicalWithOptions for $t {
+            type Options = $options;
+
+            #[inline]
+            fn from_lexical_with_options(bytes: &[u8], options: &$options) -> Result<$t>
+            {
+                to_complete!($cb, bytes, 10, options)
+            }
+
+            #[inline]
+            fn from_lexical_partial_with_options(bytes: &[u8], options: &$options) -> Result<($t, usize)>
+            {
+                to_partial!($cb, bytes, 10, options)
+            }
+
+            #[cfg(feature = "radix")]
+            #[inline]
+            fn from_lexical_radix_with_options(bytes: &[u8], radix: u8, options: &$options) -> Result<$t>
+            {
+                to_complete!($cb, bytes, radix.as_u32(), options)
+            }
+
+            #[cfg(feature = "radix")]
+            #[inline]
+            fn from_lexical_partial_radix_with_options(bytes: &[u8], radix: u8, options: &$options) -> Result<($t, usize)>
+            {
+                to_partial!($cb, bytes, radix.as_u32(), options)
             }
         }
     )
@@ -513,11 +697,31 @@ pub trait ToLexical: Number {
     /// [`FORMATTED_SIZE`]: trait.Number.html#associatedconstant.FORMATTED_SIZE
     #[cfg(feature = "radix")]
     fn to_lexical_radix<'a>(self, radix: u8, bytes: &'a mut [u8]) -> &'a mut [u8];
+
+    // Size of the buffer for `to_lexical_default` with the default write options.
+    #[doc(hidden)]
+    #[inline]
+    #[cfg(feature = "std")]
+    fn formatted_size_default(_: &Option<WriteFloatOptions>) -> usize {
+        Self::FORMATTED_SIZE_DECIMAL
+    }
+
+    // Serializer for `lexical::to_string`, with the default write options.
+    //
+    // Floats are written with the options from `get_default_write_options`,
+    // if set, so the buffer must have at least `formatted_size_default`
+    // elements for the same options.
+    #[doc(hidden)]
+    #[inline]
+    #[cfg(feature = "std")]
+    fn to_lexical_default<'a>(self, bytes: &'a mut [u8], _: &Option<WriteFloatOptions>) -> &'a mut [u8] {
+        self.to_lexical(bytes)
+    }
 }
 
 // Implement ToLexical for numeric type.
 macro_rules! to_lexical {
-    ($cb:expr, $t:ty) => (
+    ($cb:expr, $t:ty $(, $size:expr, $default:expr)?) => (
         impl ToLexical for $t {
             #[inline]
             fn to_lexical<'a>(self, bytes: &'a mut [u8])
@@ -538,6 +742,119 @@ macro_rules! to_lexical {
                 let len = $cb(self, radix.as_u32(), bytes);
                 &mut index_mut!(bytes[..len])
             }
+
+            $(
+                #[cfg(feature = "std")]
+                #[inline]
+                fn formatted_size_default(options: &Option<WriteFloatOptions>) -> usize {
+                    $size(options)
+                }
+
+                #[cfg(feature = "std")]
+                #[inline]
+                fn to_lexical_default<'a>(self, bytes: &'a mut [u8], options: &Option<WriteFloatOptions>)
+                    -> &'a mut [u8]
+                {
+                    $default(self, bytes, options)
+                }
+            )?
+        }
+    )
+}
+
+// TO LEXICAL WITH OPTIONS
+
+/// Trait for numerical types that can be serialized to bytes with custom options.
+///
+/// The options type is specific to the number, for example,
+/// [`WriteFloatOptions`] for floats.
+///
+/// [`WriteFloatOptions`]: struct.WriteFloatOptions.html
+pub trait ToLexicalWithOptions: ToLexical {
+    /// Custom options to serialize the number.
+    type Options;
+
+    /// Maximum number of bytes required to serialize the number with options.
+    ///
+    /// * `radix`   - Radix for number encoding.
+    /// * `options` - Options to customize the serialized number.
+    fn formatted_size_with_options(radix: u8, options: &Self::Options) -> usize;
+
+    /// Serializer for a number-to-string conversion with custom options.
+    ///
+    /// Returns a subslice of the input buffer containing the written bytes,
+    /// starting from the same address in memory as the input slice.
+    ///
+    /// * `value`   - Number to serialize.
+    /// * `bytes`   - Slice containing a numeric string.
+    /// * `options` - Options to customize the serialized number.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is not of sufficient size. The caller
+    /// must provide a slice of sufficient size. In order to ensure
+    /// the function will not panic, ensure the buffer has at least
+    /// [`formatted_size_with_options`] elements.
+    ///
+    /// [`formatted_size_with_options`]: #tymethod.formatted_size_with_options
+    fn to_lexical_with_options<'a>(self, bytes: &'a mut [u8], options: &Self::Options) -> &'a mut [u8];
+
+    /// Serializer for a number-to-string conversion with custom options.
+    ///
+    /// Returns a subslice of the input buffer containing the written bytes,
+    /// starting from the same address in memory as the input slice.
+    ///
+    /// * `value`   - Number to serialize.
+    /// * `radix`   - Radix for number encoding.
+    /// * `bytes`   - Slice containing a numeric string.
+    /// * `options` - Options to customize the serialized number.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the radix is not in the range `[2, 36]`.
+    ///
+    /// Also panics if the buffer is not of sufficient size. The caller
+    /// must provide a slice of sufficient size. In order to ensure
+    /// the function will not panic, ensure the buffer has at least
+    /// [`formatted_size_with_options`] elements.
+    ///
+    /// [`formatted_size_with_options`]: #tymethod.formatted_size_with_options
+    #[cfg(feature = "radix")]
+    fn to_lexical_radix_with_options<'a>(self, radix: u8, bytes: &'a mut [u8], options: &Self::Options) -> &'a mut [u8];
+}
+
+// Implement ToLexicalWithOptions for numeric type.
+macro_rules! to_lexical_with_options {
+    ($cb:expr, $size:expr, $t:ty, $options:ty) => (
+        impl ToLexicalWithOptions for $t {
+            type Options = $options;
+
+            #[inline]
+            fn formatted_size_with_options(radix: u8, options: &$options)
+                -> usize
+            {
+                $size(radix.as_u32(), options)
+            }
+
+            #[inline]
+            fn to_lexical_with_options<'a>(self, bytes: &'a mut [u8], options: &$options)
+                -> &'a mut [u8]
+            {
+                assert!(bytes.len() >= $size(10, options));
+                let len = $cb(self, 10, bytes, options);
+                &mut index_mut!(bytes[..len])
+            }
+
+            #[cfg(feature = "radix")]
+            #[inline]
+            fn to_lexical_radix_with_options<'a>(self, radix: u8, bytes: &'a mut [u8], options: &$options)
+                -> &'a mut [u8]
+            {
+                assert_radix!(radix);
+                assert!(bytes.len() >= $size(radix.as_u32(), options));
+                let len = $cb(self, radix.as_u32(), bytes, options);
+                &mut index_mut!(bytes[..len])
+            }
         }
     )
 }
@@ -0,0 +1,106 @@
+//! Shorten float strings to fit within a character budget.
+
+use crate::lib::String;
+use crate::lib::iter;
+use lexical_core::get_exponent_default_char;
+
+/// Style to shorten a float string that does not fit within a budget.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BudgetStyle {
+    /// Round away significant digits after the decimal point until the string fits.
+    ///
+    /// If the string does not fit even without any digits after the
+    /// decimal point, an empty string is returned.
+    RoundToFit,
+    /// Truncate the string and append the marker character.
+    ///
+    /// The marker counts as a single character towards the budget.
+    /// If the budget is 0, an empty string is returned.
+    TruncateMark(char),
+}
+
+// HELPERS
+
+// Round the ASCII digits up to the first `count` digits, half away from zero.
+//
+// Returns if the rounding carried out of the most significant digit.
+pub(crate) fn round_digits(digits: &mut [u8], count: usize) -> bool {
+    let round_up = digits.get(count).map_or(false, |&c| c >= b'5');
+    for digit in digits[count..].iter_mut() {
+        *digit = b'0';
+    }
+    if !round_up {
+        return false;
+    }
+    for digit in digits[..count].iter_mut().rev() {
+        if *digit == b'9' {
+            *digit = b'0';
+        } else {
+            *digit += 1;
+            return false;
+        }
+    }
+    true
+}
+
+// Write the sign, mantissa, and optional exponent to string.
+pub(crate) fn compose(sign: &str, integer: &[u8], fraction: &[u8], exponent: Option<i32>) -> String {
+    let mut string = String::from(sign);
+    string.extend(integer.iter().map(|&c| c as char));
+    if !fraction.is_empty() {
+        string.push('.');
+        string.extend(fraction.iter().map(|&c| c as char));
+    }
+    if let Some(exponent) = exponent {
+        string.push(get_exponent_default_char() as char);
+        string.push_str(&crate::to_string(exponent));
+    }
+    string
+}
+
+// Round the fraction digits of a decimal float string until it fits.
+pub(crate) fn round_to_fit(string: &str, max_chars: usize) -> Option<String> {
+    // The sign may be a custom, multi-byte minus sign.
+    let start = string.find(|c: char| c.is_ascii_digit()).unwrap_or(0);
+    let (sign, rest) = string.split_at(start);
+    let exponent_char = get_exponent_default_char() as char;
+    let (mantissa, mut exponent) = match rest.find(exponent_char) {
+        Some(index) => (&rest[..index], Some(rest[index+1..].parse::<i32>().ok()?)),
+        None        => (rest, None),
+    };
+    let (integer, fraction) = match mantissa.find('.') {
+        Some(index) => (&mantissa[..index], &mantissa[index+1..]),
+        None        => (mantissa, ""),
+    };
+    if integer.is_empty() || !mantissa.bytes().all(|c| c.is_ascii_digit() || c == b'.') {
+        // Special values do not have any digits to round.
+        return None;
+    }
+
+    let digits: String = integer.chars().chain(fraction.chars()).collect();
+    for count in (0..fraction.len()).rev() {
+        let mut rounded = digits.clone().into_bytes();
+        let mut integer_len = integer.len();
+        if round_digits(&mut rounded, integer_len + count) {
+            // Carried into a new digit, like `9.9` to `10`.
+            rounded.insert(0, b'1');
+            match exponent.as_mut() {
+                Some(exponent)  => *exponent += 1,
+                None            => integer_len += 1,
+            }
+        }
+        let result = compose(sign, &rounded[..integer_len], &rounded[integer_len..integer_len+count], exponent);
+        if result.chars().count() <= max_chars {
+            return Some(result);
+        }
+    }
+    None
+}
+
+// Truncate the string and append a marker to fit.
+pub(crate) fn truncate_mark(string: &str, max_chars: usize, mark: char) -> String {
+    match max_chars {
+        0 => String::new(),
+        _ => string.chars().take(max_chars - 1).chain(iter::once(mark)).collect(),
+    }
+}
@@ -0,0 +1,86 @@
+//! Compatibility with the standard library parsing traits.
+
+use crate::lib::{ops, str};
+use lexical_core::{Error, FromLexical, Result};
+
+/// Newtype to parse numbers with lexical from `FromStr`.
+///
+/// [`str::parse`] dispatches on `FromStr`, so wrapping the number
+/// type in `Lexical` allows generic code requiring `FromStr` to use
+/// lexical's parsers. `FromStr` always parses decimal strings.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # use lexical::Lexical;
+/// # pub fn main() {
+/// let value = "3.14".parse::<Lexical<f64>>().unwrap();
+/// assert_eq!(*value, 3.14);
+/// assert_eq!(value.into_inner(), 3.14);
+/// assert!("3.14a".parse::<Lexical<f64>>().is_err());
+/// # }
+/// ```
+///
+/// [`str::parse`]: https://doc.rust-lang.org/std/primitive.str.html#method.parse
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Lexical<T>(pub T);
+
+impl<T> Lexical<T> {
+    /// Get the wrapped number.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: FromLexical> Lexical<T> {
+    /// Parse the wrapped number from a string with a custom radix.
+    ///
+    /// * `string`  - String to convert to number.
+    /// * `radix`   - Number of unique digits for the number (base).
+    ///
+    /// # Panics
+    ///
+    /// Panics if radix is not in range `[2, 36]`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate lexical;
+    /// # use lexical::Lexical;
+    /// # pub fn main() {
+    /// assert_eq!(Lexical::<u8>::from_str_radix("ff", 16), Ok(Lexical(255)));
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg(feature = "radix")]
+    pub fn from_str_radix(string: &str, radix: u8) -> Result<Lexical<T>> {
+        T::from_lexical_radix(string.as_bytes(), radix).map(Lexical)
+    }
+}
+
+impl<T: FromLexical> str::FromStr for Lexical<T> {
+    type Err = Error;
+
+    #[inline]
+    fn from_str(string: &str) -> Result<Lexical<T>> {
+        T::from_lexical(string.as_bytes()).map(Lexical)
+    }
+}
+
+impl<T> ops::Deref for Lexical<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> ops::DerefMut for Lexical<T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
@@ -0,0 +1,171 @@
+//! Generate the digits of a float once, to write with different precisions.
+
+use crate::lib::{fmt, String, Vec};
+use crate::budget::{compose, round_digits};
+use crate::notation::{decompose, scientific};
+
+// HELPERS
+
+// Round the significant digits to exactly `decimal_places` fraction digits.
+//
+// Returns the digits, with at least one integer digit, and the number
+// of integer digits. Missing fraction digits are padded with zeros,
+// and extra digits are rounded half away from zero.
+pub(crate) fn round_fixed(digits: &[u8], exponent: i32, decimal_places: usize) -> (Vec<u8>, usize) {
+    // Align the digits so the integer digits come first, with at least
+    // one integer digit, and pad the fraction to the decimal places.
+    let mut integer_len = exponent.max(0) as usize + 1;
+    let mut fixed = Vec::with_capacity(digits.len() + decimal_places);
+    fixed.resize((-exponent).max(0) as usize, b'0');
+    fixed.extend_from_slice(digits);
+    let count = integer_len + decimal_places;
+    if fixed.len() < count {
+        fixed.resize(count, b'0');
+    }
+    if round_digits(&mut fixed, count) {
+        // Carried into a new digit, like `9.96` to `10.0`.
+        fixed.insert(0, b'1');
+        integer_len += 1;
+    }
+    fixed.truncate(integer_len + decimal_places);
+    (fixed, integer_len)
+}
+
+// Get the sign to write for the digits, without the sign of zero.
+fn nonzero_sign(negative: bool, digits: &[u8]) -> &'static str {
+    match negative && digits.iter().any(|&c| c != b'0') {
+        true  => "-",
+        false => "",
+    }
+}
+
+// API
+
+/// Digits of a float, generated once, to write with different precisions.
+///
+/// Created by [`float_digits`], which generates the shortest digits
+/// that round-trip to the float a single time, so a float may be
+/// written with many precisions, like a rounded value for display and
+/// the full value for storage, without generating the digits again.
+///
+/// Every view lays out the same digits, so views with more digits
+/// than the shortest digits are padded with zeros, rather than
+/// writing the exact value of the float, which needs the exact
+/// decimal expansion from [`to_exact_decimal`]. Rounded views round
+/// the shortest digits half away from zero, and special (non-finite)
+/// values are written like [`to_string`] in every view.
+///
+/// [`float_digits`]: fn.float_digits.html
+/// [`to_exact_decimal`]: fn.to_exact_decimal.html
+/// [`to_string`]: fn.to_string.html
+#[derive(Clone, Debug, PartialEq)]
+pub struct FloatDigits {
+    /// Shortest string that round-trips to the float.
+    string: String,
+    /// If the float is negative.
+    negative: bool,
+    /// Significant digits, without leading or trailing zeros.
+    digits: Vec<u8>,
+    /// Power of 10 of the first significant digit.
+    exponent: i32,
+}
+
+impl FloatDigits {
+    // Generate the shortest digits of the float.
+    pub(crate) fn new(n: f64) -> FloatDigits {
+        let string = crate::to_string(n);
+        let (negative, digits, exponent) = match decompose(&string) {
+            Some((sign, digits, exponent)) => (!sign.is_empty(), digits, exponent),
+            None                           => (false, Vec::new(), 0),
+        };
+        FloatDigits { string, negative, digits, exponent }
+    }
+
+    /// Get the significant decimal digits, as ASCII.
+    ///
+    /// The digits have no leading or trailing zeros, except for zero,
+    /// which is a single `0` digit. Special values have no digits.
+    #[inline]
+    pub fn digits(&self) -> &[u8] {
+        &self.digits
+    }
+
+    /// Get the power of 10 of the first significant digit.
+    ///
+    /// Special values have an exponent of `0`.
+    #[inline]
+    pub fn exponent(&self) -> i32 {
+        self.exponent
+    }
+
+    /// Write the digits with exactly `decimal_places` fraction digits.
+    ///
+    /// The digits are written in fixed notation, rounded half away
+    /// from zero, and padded with zeros past the shortest digits. No
+    /// decimal point is written for `0` decimal places, and values
+    /// that round to zero are written without a sign.
+    ///
+    /// * `decimal_places`  - Number of fraction digits to write.
+    pub fn to_fixed(&self, decimal_places: usize) -> String {
+        if self.digits.is_empty() {
+            return self.string.clone();
+        }
+        let (fixed, integer_len) = round_fixed(&self.digits, self.exponent, decimal_places);
+        let (integer, fraction) = fixed.split_at(integer_len);
+        compose(nonzero_sign(self.negative, &fixed), integer, fraction, None)
+    }
+
+    /// Write the digits with exactly `count` significant digits.
+    ///
+    /// The digits are rounded half away from zero, padded with zeros
+    /// past the shortest digits, and at least 1 significant digit is
+    /// written. Like `%g` in C, the digits are written in scientific
+    /// notation if the exponent is less than `-4`, or at least the
+    /// number of significant digits, otherwise, in fixed notation, but
+    /// trailing zeros are always kept. Zero is written without a sign.
+    ///
+    /// * `count`   - Number of significant digits to write.
+    pub fn to_significant(&self, count: usize) -> String {
+        if self.digits.is_empty() {
+            return self.string.clone();
+        }
+        let count = count.max(1);
+        let mut digits = self.digits.clone();
+        let mut exponent = self.exponent;
+        if digits.len() < count {
+            digits.resize(count, b'0');
+        }
+        if round_digits(&mut digits, count) {
+            // Carried into a new digit, like `9.96` to `10.0`.
+            digits.insert(0, b'1');
+            exponent += 1;
+        }
+        digits.truncate(count);
+
+        let sign = nonzero_sign(self.negative, &digits);
+        if exponent < -4 || exponent >= count as i32 {
+            return scientific(sign, &digits, exponent);
+        }
+        match exponent < 0 {
+            true  => {
+                let mut fraction = Vec::new();
+                fraction.resize((-exponent - 1) as usize, b'0');
+                fraction.extend_from_slice(&digits);
+                compose(sign, b"0", &fraction, None)
+            },
+            false => {
+                let (integer, fraction) = digits.split_at(exponent as usize + 1);
+                compose(sign, integer, fraction, None)
+            },
+        }
+    }
+}
+
+impl fmt::Display for FloatDigits {
+    /// Write the shortest string that round-trips, like [`to_string`].
+    ///
+    /// [`to_string`]: fn.to_string.html
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.string)
+    }
+}
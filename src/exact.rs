@@ -0,0 +1,128 @@
+//! Write the exact decimal expansion of floats.
+
+use crate::lib::{String, Vec};
+
+// Base of the limbs of the big integer, the largest power of 10 in a `u32`.
+const LIMB_BASE: u64 = 1_000_000_000;
+const LIMB_DIGITS: usize = 9;
+
+// Largest powers of 2 and 5 that keep the product of a limb and the
+// power, plus the carry, within a `u64`.
+const MAX_SHIFT: u32 = 30;
+const MAX_POW5: (u64, u32) = (1_220_703_125, 13);
+
+// HELPERS
+
+// Multiply the little-endian big integer by a small factor.
+fn mul_small(limbs: &mut Vec<u32>, factor: u64) {
+    let mut carry = 0;
+    for limb in limbs.iter_mut() {
+        let product = *limb as u64 * factor + carry;
+        *limb = (product % LIMB_BASE) as u32;
+        carry = product / LIMB_BASE;
+    }
+    while carry != 0 {
+        limbs.push((carry % LIMB_BASE) as u32);
+        carry /= LIMB_BASE;
+    }
+}
+
+// Create a little-endian big integer from a `u64`.
+fn vec_from_u64(mut value: u64) -> Vec<u32> {
+    let mut limbs = Vec::new();
+    while value != 0 {
+        limbs.push((value % LIMB_BASE) as u32);
+        value /= LIMB_BASE;
+    }
+    limbs
+}
+
+// Write the decimal digits of the big integer, without leading zeros.
+fn to_digits(limbs: &[u32]) -> Vec<u8> {
+    let mut digits = Vec::with_capacity(limbs.len() * LIMB_DIGITS);
+    for &limb in limbs.iter().rev() {
+        let mut buffer = [b'0'; LIMB_DIGITS];
+        let mut value = limb;
+        for digit in buffer.iter_mut().rev() {
+            *digit = b'0' + (value % 10) as u8;
+            value /= 10;
+        }
+        digits.extend_from_slice(&buffer);
+    }
+    let leading = digits.iter().take_while(|&&c| c == b'0').count();
+    digits.drain(..leading);
+    digits
+}
+
+// API
+
+// Write the exact value of the float, in fixed notation.
+//
+// The float is `mantissa * 2^exponent`, so for negative exponents, the
+// digits are `mantissa * 5^-exponent`, with `-exponent` fraction digits.
+pub(crate) fn to_exact(n: f64) -> String {
+    if !n.is_finite() {
+        return crate::to_string(n);
+    }
+
+    let bits = n.to_bits();
+    let biased = ((bits >> 52) & 0x7FF) as i32;
+    let fraction = bits & 0x000F_FFFF_FFFF_FFFF;
+    let (mut mantissa, mut exponent) = match biased {
+        0 => (fraction, -1074),
+        _ => (fraction | (1 << 52), biased - 1075),
+    };
+    // Remove trailing zero bits, so the fraction has no trailing zeros.
+    if mantissa == 0 {
+        exponent = 0;
+    }
+    while mantissa != 0 && mantissa & 1 == 0 && exponent < 0 {
+        mantissa >>= 1;
+        exponent += 1;
+    }
+
+    // Scale the mantissa by the power of 2, or the power of 5 for the
+    // fraction digits.
+    let mut limbs = vec_from_u64(mantissa);
+    let mut remaining = exponent.max(-exponent) as u32;
+    while remaining != 0 {
+        match exponent > 0 {
+            true  => {
+                let shift = remaining.min(MAX_SHIFT);
+                mul_small(&mut limbs, 1 << shift);
+                remaining -= shift;
+            },
+            false => {
+                let (pow5, count) = match remaining >= MAX_POW5.1 {
+                    true  => MAX_POW5,
+                    false => (5u64.pow(remaining), remaining),
+                };
+                mul_small(&mut limbs, pow5);
+                remaining -= count;
+            },
+        }
+    }
+
+    // Split the digits at the decimal point, with at least 1 digit on
+    // either side.
+    let fraction_len = (-exponent).max(0) as usize;
+    let mut digits = to_digits(&limbs);
+    if digits.len() <= fraction_len {
+        let padding = fraction_len + 1 - digits.len();
+        digits.resize(fraction_len + 1, b'0');
+        digits.rotate_right(padding);
+    }
+    let (integer, fraction) = digits.split_at(digits.len() - fraction_len);
+
+    let mut string = String::with_capacity(digits.len() + 3);
+    if n.is_sign_negative() {
+        string.push('-');
+    }
+    string.extend(integer.iter().map(|&c| c as char));
+    string.push('.');
+    match fraction.is_empty() {
+        true  => string.push('0'),
+        false => string.extend(fraction.iter().map(|&c| c as char)),
+    }
+    string
+}
@@ -27,6 +27,35 @@
 //! **To String**
 //! - [`to_string`]
 #![cfg_attr(feature = "radix", doc = " - [`to_string_radix`]")]
+//! - [`to_string_with_options`]
+#![cfg_attr(feature = "radix", doc = " - [`to_string_radix_with_options`]")]
+//! - [`to_string_budget`]
+//! - [`to_string_precision`]
+//! - [`to_string_lossy`]
+//! - [`to_exact_decimal`]
+//! - [`to_string_fixed`]
+//! - [`to_string_scientific`]
+//! - [`to_string_percent`]
+//! - [`float_digits`]
+//! - [`to_string_quantized`]
+//! - [`to_string_qfixed`]
+//! - [`format_from_bits`]
+//! - [`format_duration_secs`]
+//! - [`to_string_cached`]
+//! - [`to_string_cow`]
+//! - [`to_utf16le_string`]
+//! - [`to_string_padded`]
+#![cfg_attr(feature = "smallstr", doc = " - [`to_small_string`]")]
+#![cfg_attr(all(feature = "smallstr", feature = "radix"), doc = " - [`to_small_string_radix`]")]
+//! - [`quantize`]
+#![cfg_attr(feature = "optional", doc = " - [`to_string_optional`]")]
+//! - [`write_fmt`]
+#![cfg_attr(feature = "radix", doc = " - [`write_fmt_radix`]")]
+#![cfg_attr(feature = "std", doc = " - [`writeln_to`]")]
+#![cfg_attr(all(feature = "std", feature = "radix"), doc = " - [`writeln_to_radix`]")]
+//! - [`write_wrapping`]
+#![cfg_attr(feature = "radix", doc = " - [`write_wrapping_radix`]")]
+//! - [`write_utf16le`]
 //!
 //! **From String**
 //! - [`parse`]
@@ -45,6 +74,53 @@
 #![cfg_attr(feature = "radix", doc = " - [`parse_partial_lossy_radix`]")]
 #![cfg_attr(feature = "format", doc = " - [`parse_partial_lossy_format`]")]
 #![cfg_attr(all(feature = "format", feature = "radix"), doc = " - [`parse_partial_lossy_format_radix`]")]
+//! - [`parse_with_options`]
+#![cfg_attr(feature = "radix", doc = " - [`parse_radix_with_options`]")]
+//! - [`parse_partial_with_options`]
+#![cfg_attr(feature = "radix", doc = " - [`parse_partial_radix_with_options`]")]
+//! - [`parse_int_or_float`]
+//! - [`parse_partial_int_or_float`]
+//! - [`parse_strict_number`]
+//! - [`parse_float_typed`]
+//! - [`parse_int_clamped`]
+//! - [`parse_partial_int_clamped`]
+//! - [`parse_saturating_float`]
+//! - [`parse_bounded`]
+//! - [`parse_partial_bounded`]
+//! - [`parse_nonzero`]
+//! - [`parse_hex`]
+//! - [`parse_hex_fixed`]
+//! - [`parse_hex_grouped`]
+#![cfg_attr(feature = "correct", doc = " - [`parse_bracket`]")]
+#![cfg_attr(feature = "correct", doc = " - [`parse_bracket_halfway`]")]
+#![cfg_attr(feature = "correct", doc = " - [`reround`]")]
+#![cfg_attr(feature = "optional", doc = " - [`parse_or_missing`]")]
+//! - [`parse_to_bits`]
+//! - [`parse_canonical`]
+//! - [`parse_fractional_nanos`]
+//! - [`parse_duration_secs`]
+//! - [`parse_with_magnitude`]
+//! - [`significant_digits`]
+//! - [`decompose_decimal`]
+//! - [`parse_spans`]
+//! - [`parse_quantity`]
+//! - [`parse_quantity_delimited`]
+//! - [`parse_colon_ratio`]
+//! - [`parse_rational`]
+//! - [`parse_all`]
+#![cfg_attr(feature = "radix", doc = " - [`parse_all_radix`]")]
+#![cfg_attr(feature = "rayon", doc = " - [`par_parse_column`]")]
+//! - [`parse_into_histogram`]
+//! - [`parse_fixed_width_column`]
+#![cfg_attr(feature = "radix", doc = " - [`parse_flexible`]")]
+#![cfg_attr(feature = "radix", doc = " - [`parse_flexible_with_options`]")]
+//! - [`parse_signed_parts`]
+#![cfg_attr(feature = "currency", doc = " - [`parse_currency`]")]
+#![cfg_attr(all(feature = "currency", feature = "format"), doc = " - [`parse_currency_format`]")]
+#![cfg_attr(feature = "find", doc = " - [`find_number`]")]
+#![cfg_attr(feature = "metrics", doc = " - [`parse_with_metrics`]")]
+#![cfg_attr(all(feature = "metrics", feature = "radix"), doc = " - [`parse_with_metrics_radix`]")]
+#![cfg_attr(feature = "testing", doc = " - [`audit_options`]")]
 //!
 //! # Configuration Settings
 //!
@@ -55,6 +131,9 @@
 //! - [`get_nan_string`]
 //! - [`get_inf_string`]
 //! - [`get_infinity_string`]
+#![cfg_attr(feature = "std", doc = " - [`get_default_write_options`]")]
+//! - [`float_backend`]
+//! - [`parse_backend`]
 //!
 //! **Set Configuration**
 //! - [`set_exponent_default_char`]
@@ -63,9 +142,39 @@
 //! - [`set_nan_string`]
 //! - [`set_inf_string`]
 //! - [`set_infinity_string`]
+#![cfg_attr(feature = "std", doc = " - [`set_default_write_options`]")]
 //!
 //! [`to_string`]: fn.to_string.html
 #![cfg_attr(feature = "radix", doc = " [`to_string_radix`]: fn.to_string_radix.html")]
+//! [`to_string_with_options`]: fn.to_string_with_options.html
+#![cfg_attr(feature = "radix", doc = " [`to_string_radix_with_options`]: fn.to_string_radix_with_options.html")]
+//! [`to_string_budget`]: fn.to_string_budget.html
+//! [`to_string_precision`]: fn.to_string_precision.html
+//! [`to_string_lossy`]: fn.to_string_lossy.html
+//! [`to_exact_decimal`]: fn.to_exact_decimal.html
+//! [`to_string_fixed`]: fn.to_string_fixed.html
+//! [`to_string_scientific`]: fn.to_string_scientific.html
+//! [`to_string_percent`]: fn.to_string_percent.html
+//! [`float_digits`]: fn.float_digits.html
+//! [`to_string_quantized`]: fn.to_string_quantized.html
+//! [`to_string_qfixed`]: fn.to_string_qfixed.html
+//! [`format_from_bits`]: fn.format_from_bits.html
+//! [`format_duration_secs`]: fn.format_duration_secs.html
+//! [`to_string_cached`]: fn.to_string_cached.html
+//! [`to_string_cow`]: fn.to_string_cow.html
+//! [`to_utf16le_string`]: fn.to_utf16le_string.html
+//! [`to_string_padded`]: fn.to_string_padded.html
+#![cfg_attr(feature = "smallstr", doc = " [`to_small_string`]: fn.to_small_string.html")]
+#![cfg_attr(all(feature = "smallstr", feature = "radix"), doc = " [`to_small_string_radix`]: fn.to_small_string_radix.html")]
+//! [`quantize`]: fn.quantize.html
+#![cfg_attr(feature = "optional", doc = " [`to_string_optional`]: fn.to_string_optional.html")]
+//! [`write_fmt`]: fn.write_fmt.html
+#![cfg_attr(feature = "radix", doc = " [`write_fmt_radix`]: fn.write_fmt_radix.html")]
+#![cfg_attr(feature = "std", doc = " [`writeln_to`]: fn.writeln_to.html")]
+#![cfg_attr(all(feature = "std", feature = "radix"), doc = " [`writeln_to_radix`]: fn.writeln_to_radix.html")]
+//! [`write_wrapping`]: fn.write_wrapping.html
+#![cfg_attr(feature = "radix", doc = " [`write_wrapping_radix`]: fn.write_wrapping_radix.html")]
+//! [`write_utf16le`]: fn.write_utf16le.html
 //! [`parse`]: fn.parse.html
 #![cfg_attr(feature = "radix", doc = " [`parse_radix`]: fn.parse_radix.html")]
 #![cfg_attr(feature = "format", doc = " [`parse_format`]: fn.parse_format.html")]
@@ -82,6 +191,53 @@
 #![cfg_attr(feature = "radix", doc = " [`parse_partial_lossy_radix`]: fn.parse_partial_lossy_radix.html")]
 #![cfg_attr(feature = "format", doc = " [`parse_partial_lossy_format`]: fn.parse_partial_lossy_format.html")]
 #![cfg_attr(all(feature = "format", feature = "radix"), doc = " [`parse_partial_lossy_format_radix`]: fn.parse_partial_lossy_format_radix.html")]
+//! [`parse_with_options`]: fn.parse_with_options.html
+#![cfg_attr(feature = "radix", doc = " [`parse_radix_with_options`]: fn.parse_radix_with_options.html")]
+//! [`parse_partial_with_options`]: fn.parse_partial_with_options.html
+#![cfg_attr(feature = "radix", doc = " [`parse_partial_radix_with_options`]: fn.parse_partial_radix_with_options.html")]
+//! [`parse_int_or_float`]: fn.parse_int_or_float.html
+//! [`parse_partial_int_or_float`]: fn.parse_partial_int_or_float.html
+//! [`parse_strict_number`]: fn.parse_strict_number.html
+//! [`parse_float_typed`]: fn.parse_float_typed.html
+//! [`parse_int_clamped`]: fn.parse_int_clamped.html
+//! [`parse_partial_int_clamped`]: fn.parse_partial_int_clamped.html
+//! [`parse_saturating_float`]: fn.parse_saturating_float.html
+//! [`parse_bounded`]: fn.parse_bounded.html
+//! [`parse_partial_bounded`]: fn.parse_partial_bounded.html
+//! [`parse_nonzero`]: fn.parse_nonzero.html
+//! [`parse_hex`]: fn.parse_hex.html
+//! [`parse_hex_fixed`]: fn.parse_hex_fixed.html
+//! [`parse_hex_grouped`]: fn.parse_hex_grouped.html
+#![cfg_attr(feature = "correct", doc = " [`parse_bracket`]: fn.parse_bracket.html")]
+#![cfg_attr(feature = "correct", doc = " [`parse_bracket_halfway`]: fn.parse_bracket_halfway.html")]
+#![cfg_attr(feature = "correct", doc = " [`reround`]: fn.reround.html")]
+#![cfg_attr(feature = "optional", doc = " [`parse_or_missing`]: fn.parse_or_missing.html")]
+//! [`parse_to_bits`]: fn.parse_to_bits.html
+//! [`parse_canonical`]: fn.parse_canonical.html
+//! [`parse_fractional_nanos`]: fn.parse_fractional_nanos.html
+//! [`parse_duration_secs`]: fn.parse_duration_secs.html
+//! [`parse_with_magnitude`]: fn.parse_with_magnitude.html
+//! [`significant_digits`]: fn.significant_digits.html
+//! [`decompose_decimal`]: fn.decompose_decimal.html
+//! [`parse_spans`]: fn.parse_spans.html
+//! [`parse_quantity`]: fn.parse_quantity.html
+//! [`parse_quantity_delimited`]: fn.parse_quantity_delimited.html
+//! [`parse_colon_ratio`]: fn.parse_colon_ratio.html
+//! [`parse_rational`]: fn.parse_rational.html
+//! [`parse_all`]: fn.parse_all.html
+#![cfg_attr(feature = "radix", doc = " [`parse_all_radix`]: fn.parse_all_radix.html")]
+#![cfg_attr(feature = "rayon", doc = " [`par_parse_column`]: fn.par_parse_column.html")]
+//! [`parse_into_histogram`]: fn.parse_into_histogram.html
+//! [`parse_fixed_width_column`]: fn.parse_fixed_width_column.html
+#![cfg_attr(feature = "radix", doc = " [`parse_flexible`]: fn.parse_flexible.html")]
+#![cfg_attr(feature = "radix", doc = " [`parse_flexible_with_options`]: fn.parse_flexible_with_options.html")]
+//! [`parse_signed_parts`]: fn.parse_signed_parts.html
+#![cfg_attr(feature = "currency", doc = " [`parse_currency`]: fn.parse_currency.html")]
+#![cfg_attr(all(feature = "currency", feature = "format"), doc = " [`parse_currency_format`]: fn.parse_currency_format.html")]
+#![cfg_attr(feature = "find", doc = " [`find_number`]: fn.find_number.html")]
+#![cfg_attr(feature = "metrics", doc = " [`parse_with_metrics`]: fn.parse_with_metrics.html")]
+#![cfg_attr(all(feature = "metrics", feature = "radix"), doc = " [`parse_with_metrics_radix`]: fn.parse_with_metrics_radix.html")]
+#![cfg_attr(feature = "testing", doc = " [`audit_options`]: fn.audit_options.html")]
 //!
 //! [`get_exponent_default_char`]: fn.get_exponent_default_char.html
 #![cfg_attr(feature = "radix", doc = " [`get_exponent_backup_char`]: fn.get_exponent_backup_char.html")]
@@ -89,6 +245,9 @@
 //! [`get_nan_string`]: fn.get_nan_string.html
 //! [`get_inf_string`]: fn.get_inf_string.html
 //! [`get_infinity_string`]: fn.get_infinity_string.html
+#![cfg_attr(feature = "std", doc = " [`get_default_write_options`]: fn.get_default_write_options.html")]
+//! [`float_backend`]: fn.float_backend.html
+//! [`parse_backend`]: fn.parse_backend.html
 //!
 //! [`set_exponent_default_char`]: fn.set_exponent_default_char.html
 #![cfg_attr(feature = "radix", doc = " [`set_exponent_backup_char`]: fn.set_exponent_backup_char.html")]
@@ -96,6 +255,7 @@
 //! [`set_nan_string`]: fn.set_nan_string.html
 //! [`set_inf_string`]: fn.set_inf_string.html
 //! [`set_infinity_string`]: fn.set_infinity_string.html
+#![cfg_attr(feature = "std", doc = " [`set_default_write_options`]: fn.set_default_write_options.html")]
 
 // FEATURES
 
@@ -109,6 +269,12 @@ extern crate cfg_if;
 
 extern crate lexical_core;
 
+#[cfg(feature = "rayon")]
+extern crate rayon_impl as rayon;
+
+#[cfg(feature = "smallstr")]
+extern crate smallstr_impl as smallstr;
+
 // CONFIG
 
 // Need an allocator for String/Vec.
@@ -126,14 +292,26 @@ if #[cfg(feature = "std")] {
 
 cfg_if! {
 if #[cfg(feature = "std")] {
+    pub(crate) use std::borrow::Cow;
     pub(crate) use std::string::String;
     pub(crate) use std::vec::Vec;
 } else {
+    pub(crate) use ::alloc::borrow::Cow;
     pub(crate) use ::alloc::string::String;
     pub(crate) use ::alloc::vec::Vec;
 }
 }}  // cfg_if
 
+// Hide implementation details.
+mod budget;
+mod compat;
+mod digits;
+mod exact;
+mod notation;
+mod padded;
+mod percent;
+mod precision;
+
 // API
 
 // Re-export exponent character getters and setters.
@@ -146,6 +324,16 @@ pub use lexical_core::{get_exponent_backup_char, set_exponent_backup_char};
 pub use lexical_core::{get_inf_string, get_infinity_string, get_nan_string};
 pub use lexical_core::{set_inf_string, set_infinity_string, set_nan_string};
 
+// Re-export the default options to write floats.
+#[cfg(feature = "std")]
+pub use lexical_core::{get_default_write_options, set_default_write_options};
+
+// Re-export the algorithms selected to parse and write floats.
+pub use lexical_core::{float_backend, parse_backend, FloatBackend, ParseBackend};
+
+// Re-export the rounding scheme for rounded values.
+pub use lexical_core::RoundingMode;
+
 // Re-export the float rounding scheme used.
 #[cfg(all(feature = "correct", feature = "rounding"))]
 pub use lexical_core::{get_float_rounding, set_float_rounding, RoundingKind};
@@ -154,14 +342,94 @@ pub use lexical_core::{get_float_rounding, set_float_rounding, RoundingKind};
 #[cfg(feature = "format")]
 pub use lexical_core::NumberFormat;
 
+// Re-export the parse and write options.
+pub use lexical_core::{ParseFloatOptions, ParseFloatOptionsBuilder};
+pub use lexical_core::{ParseIntegerOptions, ParseIntegerOptionsBuilder};
+pub use lexical_core::{WriteFloatLayout, WriteFloatOptions, WriteFloatOptionsBuilder};
+pub use lexical_core::{WriteIntegerOptions, WriteIntegerOptionsBuilder};
+#[cfg(feature = "optional")]
+pub use lexical_core::{WriteOptionalOptions, WriteOptionalOptionsBuilder};
+#[cfg(feature = "correct")]
+pub use lexical_core::ShortestTie;
+
+// Re-export the non-zero integers to parse.
+pub use lexical_core::NonZeroInteger;
+
+// Re-export the integer or float parse result.
+pub use lexical_core::IntOrFloat;
+
+// Re-export the byte ranges of the components of a float.
+pub use lexical_core::NumberSpans;
+
+// Re-export the resumable parser for floats split across chunks.
+pub use lexical_core::{FeedResult, IncrementalParser, INCREMENTAL_MAX_LENGTH};
+
+// Re-export the cached strings for small integers.
+pub use lexical_core::to_string_cached;
+
+// Re-export the float quantization.
+pub use lexical_core::quantize;
+
+// Re-export the rounding of bracketed floats.
+#[cfg(feature = "correct")]
+pub use lexical_core::reround;
+
+// Re-export the writers into `fmt::Write` sinks.
+pub use lexical_core::write_fmt;
+#[cfg(feature = "radix")]
+pub use lexical_core::write_fmt_radix;
+
+// Re-export the writers of lines into `io::Write` sinks.
+#[cfg(feature = "std")]
+pub use lexical_core::writeln_to;
+#[cfg(all(feature = "std", feature = "radix"))]
+pub use lexical_core::writeln_to_radix;
+
+// Re-export the writers into ring buffers.
+pub use lexical_core::write_wrapping;
+#[cfg(feature = "radix")]
+pub use lexical_core::write_wrapping_radix;
+
+// Re-export the writer for UTF-16LE strings.
+pub use lexical_core::write_utf16le;
+
+// Re-export the newtype to parse numbers from `FromStr`.
+pub use compat::Lexical;
+
+// Re-export the style to shorten floats to a character budget.
+pub use budget::BudgetStyle;
+
+// Re-export the alignment to pad numbers to a field width.
+pub use padded::Align;
+
+// Re-export the digits of a float to write with different precisions.
+pub use digits::FloatDigits;
+
+// Re-export the inline string type.
+#[cfg(feature = "smallstr")]
+pub use smallstr::SmallString;
+
+// Re-export the parse metrics.
+#[cfg(feature = "metrics")]
+pub use lexical_core::{FromLexicalMetrics, ParseMetrics};
+
 // Re-export the Result, Error and ErrorCode globally.
 pub use lexical_core::{Error, ErrorCode, Result};
 
 // Publicly expose traits so they may be used for generic programming.
-pub use lexical_core::{FromLexical, FromLexicalLossy, ToLexical};
+pub use lexical_core::{FromLexical, FromLexicalLossy, FromLexicalWithOptions, ToLexical, ToLexicalWithOptions};
 #[cfg(feature = "format")]
 pub use lexical_core::{FromLexicalFormat,FromLexicalLossyFormat};
 
+// Bound the integers for the clamped and fixed-width parsers.
+use lexical_core::Integer;
+
+// Bound the floats for the character budget and the bracketing parser.
+use lexical_core::Float;
+
+// Bound the magnitudes for the signed parts and the hexadecimal parsers.
+use lexical_core::UnsignedInteger;
+
 // HELPERS
 
 /// Get a vector as a slice, including the capacity.
@@ -179,6 +447,9 @@ use lib::convert::AsRef;
 
 /// High-level conversion of a number to a decimal-encoded string.
 ///
+/// With the `std` feature, floats are written with the default options
+/// from [`set_default_write_options`], if set.
+///
 /// * `n`       - Number to convert to string.
 ///
 /// # Examples
@@ -191,248 +462,257 @@ use lib::convert::AsRef;
 /// assert_eq!(lexical::to_string(0.0), "0.0");
 /// # }
 /// ```
+///
+/// [`set_default_write_options`]: fn.set_default_write_options.html
 #[inline]
 pub fn to_string<N: ToLexical>(n: N) -> lib::String {
     unsafe {
-        let mut buf = lib::Vec::<u8>::with_capacity(N::FORMATTED_SIZE_DECIMAL);
+        // The default options are read once, to size the buffer for them.
+        #[cfg(feature = "std")]
+        let options = lexical_core::get_default_write_options();
+        #[cfg(feature = "std")]
+        let size = N::formatted_size_default(&options);
+        #[cfg(not(feature = "std"))]
+        let size = N::FORMATTED_SIZE_DECIMAL;
+
+        let mut buf = lib::Vec::<u8>::with_capacity(size);
+        #[cfg(feature = "std")]
+        let len = n.to_lexical_default(vector_as_slice(&mut buf), &options).len();
+        #[cfg(not(feature = "std"))]
         let len = lexical_core::write(n, vector_as_slice(&mut buf)).len();
         buf.set_len(len);
         lib::String::from_utf8_unchecked(buf)
     }
 }
 
-/// High-level conversion of a number to string with a custom radix.
+/// High-level conversion of an integer to a decimal-encoded string, using cached strings.
+///
+/// Integers in the range `[0, 255]` borrow a static string from
+/// [`to_string_cached`], without an allocation, and other integers
+/// are converted to an owned string with [`to_string`].
 ///
 /// * `n`       - Number to convert to string.
-/// * `base`    - Number of unique digits for the number (radix).
 ///
 /// # Examples
 ///
 /// ```rust
 /// # extern crate lexical;
+/// # use std::borrow::Cow;
 /// # pub fn main() {
-/// assert_eq!(lexical::to_string_radix(5, 10), "5");
-/// ##[cfg(not(feature = "trim_floats"))]
-/// assert_eq!(lexical::to_string_radix(0.0, 10), "0.0");
+/// assert_eq!(lexical::to_string_cow(42u16), Cow::Borrowed("42"));
+/// assert_eq!(lexical::to_string_cow(-1i32), Cow::<str>::Owned("-1".to_string()));
+/// assert_eq!(lexical::to_string_cow(256i32), "256");
 /// # }
 /// ```
 ///
-/// # Panics
-///
-/// Panics if radix is not in the range `[2, 36]`
-#[cfg(feature = "radix")]
+/// [`to_string_cached`]: fn.to_string_cached.html
+/// [`to_string`]: fn.to_string.html
 #[inline]
-pub fn to_string_radix<N: ToLexical>(n: N, radix: u8) -> lib::String {
-    unsafe {
-        let mut buf = lib::Vec::<u8>::with_capacity(N::FORMATTED_SIZE);
-        let len = lexical_core::write_radix(n, radix, vector_as_slice(&mut buf)).len();
-        buf.set_len(len);
-        lib::String::from_utf8_unchecked(buf)
+pub fn to_string_cow<N>(n: N) -> lib::Cow<'static, str>
+    where N: ToLexical + Integer + lib::convert::TryInto<u8>
+{
+    match n.try_into() {
+        Ok(small) => lib::Cow::Borrowed(to_string_cached(small)),
+        Err(_)    => lib::Cow::Owned(to_string(n)),
     }
 }
 
-/// High-level conversion of decimal-encoded bytes to a number.
+/// High-level conversion of an integer to a decimal-encoded string, padded to a field width.
 ///
-/// This function only returns a value if the entire string is
-/// successfully parsed.
+/// The string is padded with the fill byte to `width` characters,
+/// aligned within the field by `align`. The sign counts towards the
+/// width, and integers wider than the field are returned unpadded.
+/// The fill byte is written as the character with the same code point,
+/// so each fill byte is a single character.
 ///
-/// * `bytes`   - Byte slice to convert to number.
+/// * `n`       - Integer to convert to string.
+/// * `width`   - Minimum number of characters in the string.
+/// * `fill`    - Byte to pad the string with.
+/// * `align`   - Alignment of the integer within the field.
 ///
 /// # Examples
 ///
 /// ```rust
 /// # extern crate lexical;
-/// # use lexical::ErrorCode;
+/// # use lexical::Align;
 /// # pub fn main() {
-/// // Create our error.
-/// fn err_code<T>(r: lexical::Result<T>) -> ErrorCode {
-///     r.err().unwrap().code
-/// }
-///
-/// // String overloads
-/// assert_eq!(lexical::parse::<i32, _>("5"), Ok(5));
-/// assert_eq!(err_code(lexical::parse::<i32, _>("1a")), ErrorCode::InvalidDigit);
-/// assert_eq!(lexical::parse::<f32, _>("0"), Ok(0.0));
-/// assert_eq!(lexical::parse::<f32, _>("1.0"), Ok(1.0));
-/// assert_eq!(lexical::parse::<f32, _>("1."), Ok(1.0));
-///
-/// // Bytes overloads
-/// assert_eq!(lexical::parse::<i32, _>(b"5"), Ok(5));
-/// assert_eq!(err_code(lexical::parse::<i32, _>(b"1a")), ErrorCode::InvalidDigit);
-/// assert_eq!(lexical::parse::<f32, _>(b"0"), Ok(0.0));
-/// assert_eq!(lexical::parse::<f32, _>(b"1.0"), Ok(1.0));
-/// assert_eq!(lexical::parse::<f32, _>(b"1."), Ok(1.0));
-/// # assert_eq!(lexical::parse::<f32, _>(b"5.002868148396374"), Ok(5.002868148396374));
-/// # assert_eq!(lexical::parse::<f64, _>(b"5.002868148396374"), Ok(5.002868148396374));
+/// assert_eq!(lexical::to_string_padded(42, 5, b'*', Align::Right), "***42");
+/// assert_eq!(lexical::to_string_padded(42, 5, b' ', Align::Left), "42   ");
+/// assert_eq!(lexical::to_string_padded(42, 5, b'-', Align::Center), "-42--");
+/// assert_eq!(lexical::to_string_padded(-42, 5, b' ', Align::Right), "  -42");
+/// assert_eq!(lexical::to_string_padded(123456, 5, b' ', Align::Right), "123456");
+/// assert_eq!(lexical::to_string_padded(7u8, 3, 0xB7, Align::Right), "\u{B7}\u{B7}7");
 /// # }
 /// ```
 #[inline]
-pub fn parse<N: FromLexical, Bytes: AsRef<[u8]>>(bytes: Bytes)
-    -> Result<N>
-{
-    N::from_lexical(bytes.as_ref())
+pub fn to_string_padded<N: ToLexical + Integer>(n: N, width: usize, fill: u8, align: Align) -> lib::String {
+    padded::pad(to_string(n), width, fill as char, align)
 }
 
-/// High-level, partial conversion of decimal-encoded bytes to a number.
+/// High-level conversion of a number to a decimal-encoded, UTF-16LE string.
 ///
-/// This functions parses as many digits as possible, returning the parsed
-/// value and the number of digits processed if at least one character
-/// is processed. If another error, such as numerical overflow or underflow
-/// occurs, this function returns the error code and the index at which
-/// the error occurred.
+/// Each ASCII byte of the number is widened to a UTF-16 code unit,
+/// stored in little-endian byte order, like for the wide strings of
+/// Windows APIs. No byte-order mark or null terminator is written.
 ///
-/// * `bytes`   - Byte slice to convert to number.
+/// * `n`       - Number to convert to string.
 ///
 /// # Examples
 ///
 /// ```rust
 /// # extern crate lexical;
-/// # use lexical::ErrorCode;
 /// # pub fn main() {
+/// let wide = lexical::to_utf16le_string(-1.5f64);
+/// assert_eq!(String::from_utf16(&wide.iter().map(|&w| u16::from_le(w)).collect::<Vec<_>>()).unwrap(), "-1.5");
+/// # }
+/// ```
+#[inline]
+pub fn to_utf16le_string<N: ToLexical>(n: N) -> lib::Vec<u16> {
+    let mut words = lib::Vec::<u16>::new();
+    words.resize(N::FORMATTED_SIZE_DECIMAL, 0);
+    let len = lexical_core::write_utf16le(n, &mut words).len();
+    words.truncate(len);
+    words
+}
+
+/// High-level conversion of a number to a decimal-encoded, inline string.
 ///
-/// // String overloads
-/// assert_eq!(lexical::parse_partial::<i32, _>("5"), Ok((5, 1)));
-/// assert_eq!(lexical::parse_partial::<i32, _>("1a"), Ok((1, 1)));
-/// assert_eq!(lexical::parse_partial::<f32, _>("0"), Ok((0.0, 1)));
-/// assert_eq!(lexical::parse_partial::<f32, _>("1.0"), Ok((1.0, 3)));
-/// assert_eq!(lexical::parse_partial::<f32, _>("1."), Ok((1.0, 2)));
+/// The number is written to a buffer on the stack, and copied to a
+/// string stored inline, without an allocation, if it fits within
+/// 24 bytes. Longer strings spill to the heap.
 ///
-/// // Bytes overloads
-/// assert_eq!(lexical::parse_partial::<i32, _>(b"5"), Ok((5, 1)));
-/// assert_eq!(lexical::parse_partial::<i32, _>(b"1a"), Ok((1, 1)));
-/// assert_eq!(lexical::parse_partial::<f32, _>(b"0"), Ok((0.0, 1)));
-/// assert_eq!(lexical::parse_partial::<f32, _>(b"1.0"), Ok((1.0, 3)));
-/// assert_eq!(lexical::parse_partial::<f32, _>(b"1."), Ok((1.0, 2)));
-/// # assert_eq!(lexical::parse_partial::<f32, _>(b"5.002868148396374"), Ok((5.002868148396374, 17)));
-/// # assert_eq!(lexical::parse_partial::<f64, _>(b"5.002868148396374"), Ok((5.002868148396374, 17)));
+/// * `n`       - Number to convert to string.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// let string = lexical::to_small_string(-12345i32);
+/// assert_eq!(string, "-12345");
+/// assert!(!string.spilled());
+/// assert_eq!(lexical::to_small_string(1.5e-7f64), "1.5e-7");
 /// # }
 /// ```
+#[cfg(feature = "smallstr")]
 #[inline]
-pub fn parse_partial<N: FromLexical, Bytes: AsRef<[u8]>>(bytes: Bytes)
-    -> Result<(N, usize)>
-{
-    N::from_lexical_partial(bytes.as_ref())
+pub fn to_small_string<N: ToLexical>(n: N) -> SmallString<[u8; 24]> {
+    let mut buffer = [b'0'; lexical_core::BUFFER_SIZE];
+    let bytes = lexical_core::write(n, &mut buffer);
+    // The written bytes are always valid ASCII.
+    SmallString::from_str(unsafe { lib::str::from_utf8_unchecked(bytes) })
 }
 
-/// High-level lossy conversion of decimal-encoded bytes to a number.
+/// High-level conversion of a number to an inline string with a custom radix.
 ///
-/// This function uses aggressive optimizations to avoid worst-case
-/// scenarios, and can return inaccurate results. For guaranteed accurate
-/// floats, use [`parse`].
+/// The number is written to a buffer on the stack, and copied to a
+/// string stored inline, without an allocation, if it fits within
+/// 24 bytes. Longer strings, like most 128-bit integers in binary,
+/// spill to the heap.
 ///
-/// * `bytes`   - Byte slice to convert to number.
+/// * `n`       - Number to convert to string.
+/// * `radix`   - Number of unique digits for the number (base).
 ///
 /// # Examples
 ///
 /// ```rust
 /// # extern crate lexical;
-/// # use lexical::ErrorCode;
 /// # pub fn main() {
-/// // Get our error code.
-/// fn err_code<T>(r: lexical::Result<T>) -> ErrorCode {
-///     r.err().unwrap().code
-/// }
-///
-/// // String overloads
-/// assert_eq!(lexical::parse_lossy::<f32, _>("0"), Ok(0.0));
-/// assert_eq!(lexical::parse_lossy::<f32, _>("1.0"), Ok(1.0));
-/// assert_eq!(lexical::parse_lossy::<f32, _>("1."), Ok(1.0));
-/// assert_eq!(err_code(lexical::parse_lossy::<f32, _>("1a")), ErrorCode::InvalidDigit);
+/// assert_eq!(lexical::to_small_string_radix(255u8, 2), "11111111");
 ///
-/// // Bytes overloads
-/// assert_eq!(lexical::parse_lossy::<f32, _>(b"0"), Ok(0.0));
-/// assert_eq!(lexical::parse_lossy::<f32, _>(b"1.0"), Ok(1.0));
-/// assert_eq!(lexical::parse_lossy::<f32, _>(b"1."), Ok(1.0));
-/// assert_eq!(err_code(lexical::parse_lossy::<f32, _>(b"1a")), ErrorCode::InvalidDigit);
+/// let string = lexical::to_small_string_radix(u128::max_value(), 2);
+/// assert_eq!(string.len(), 128);
+/// assert!(string.spilled());
 /// # }
 /// ```
 ///
-/// [`parse`]: fn.parse.html
+/// # Panics
+///
+/// Panics if radix is not in the range `[2, 36]`
+#[cfg(all(feature = "smallstr", feature = "radix"))]
 #[inline]
-pub fn parse_lossy<N: FromLexicalLossy, Bytes: AsRef<[u8]>>(bytes: Bytes)
-    -> Result<N>
-{
-    N::from_lexical_lossy(bytes.as_ref())
+pub fn to_small_string_radix<N: ToLexical>(n: N, radix: u8) -> SmallString<[u8; 24]> {
+    let mut buffer = [b'0'; lexical_core::BUFFER_SIZE];
+    let bytes = lexical_core::write_radix(n, radix, &mut buffer);
+    // The written bytes are always valid ASCII.
+    SmallString::from_str(unsafe { lib::str::from_utf8_unchecked(bytes) })
 }
 
-/// High-level, partial, lossy conversion of decimal-encoded bytes to a number.
-///
-/// This function uses aggressive optimizations to avoid worst-case
-/// scenarios, and can return inaccurate results. For guaranteed accurate
-/// floats, use [`parse_partial`].
-///
-/// This functions parses as many digits as possible, returning the parsed
-/// value and the number of digits processed if at least one character
-/// is processed. If another error, such as numerical overflow or underflow
-/// occurs, this function returns the error code and the index at which
-/// the error occurred.
+/// High-level conversion of a number to string with a custom radix.
 ///
-/// * `bytes`   - Byte slice to convert to number.
+/// * `n`       - Number to convert to string.
+/// * `base`    - Number of unique digits for the number (radix).
 ///
 /// # Examples
 ///
 /// ```rust
 /// # extern crate lexical;
-/// # use lexical::ErrorCode;
 /// # pub fn main() {
-///
-/// // String overloads
-/// assert_eq!(lexical::parse_partial_lossy::<f32, _>("0"), Ok((0.0, 1)));
-/// assert_eq!(lexical::parse_partial_lossy::<f32, _>("1.0"), Ok((1.0, 3)));
-/// assert_eq!(lexical::parse_partial_lossy::<f32, _>("1."), Ok((1.0, 2)));
-///
-/// // Bytes overloads
-/// assert_eq!(lexical::parse_partial_lossy::<f32, _>(b"0"), Ok((0.0, 1)));
-/// assert_eq!(lexical::parse_partial_lossy::<f32, _>(b"1.0"), Ok((1.0, 3)));
-/// assert_eq!(lexical::parse_partial_lossy::<f32, _>(b"1."), Ok((1.0, 2)));
+/// assert_eq!(lexical::to_string_radix(5, 10), "5");
+/// ##[cfg(not(feature = "trim_floats"))]
+/// assert_eq!(lexical::to_string_radix(0.0, 10), "0.0");
 /// # }
 /// ```
 ///
-/// [`parse_partial`]: fn.parse_partial.html
+/// # Panics
+///
+/// Panics if radix is not in the range `[2, 36]`
+#[cfg(feature = "radix")]
 #[inline]
-pub fn parse_partial_lossy<N: FromLexicalLossy, Bytes: AsRef<[u8]>>(bytes: Bytes)
-    -> Result<(N, usize)>
-{
-    N::from_lexical_partial_lossy(bytes.as_ref())
+pub fn to_string_radix<N: ToLexical>(n: N, radix: u8) -> lib::String {
+    unsafe {
+        let mut buf = lib::Vec::<u8>::with_capacity(N::FORMATTED_SIZE);
+        let len = lexical_core::write_radix(n, radix, vector_as_slice(&mut buf)).len();
+        buf.set_len(len);
+        lib::String::from_utf8_unchecked(buf)
+    }
 }
 
-/// High-level conversion of bytes to a number with a custom radix.
-///
-/// This function only returns a value if the entire string is
-/// successfully parsed.
+/// High-level conversion of a number to a decimal-encoded string with custom options.
 ///
-/// * `bytes`   - Byte slice to convert to number.
-/// * `radix`   - Number of unique digits for the number (base).
+/// * `n`       - Number to convert to string.
+/// * `options` - Options to customize the string.
 ///
 /// # Examples
 ///
 /// ```rust
 /// # extern crate lexical;
-/// # use lexical::ErrorCode;
+/// # use lexical::WriteFloatOptions;
 /// # pub fn main() {
-/// // Get our error code wrapper.
-/// fn err_code<T>(r: lexical::Result<T>) -> ErrorCode {
-///     r.err().unwrap().code
-/// }
+/// let options = WriteFloatOptions::builder().min_fraction_digits(2).build().unwrap();
+/// assert_eq!(lexical::to_string_with_options(1.0, &options), "1.00");
+/// assert_eq!(lexical::to_string_with_options(1.5, &options), "1.50");
+/// assert_eq!(lexical::to_string_with_options(1.125, &options), "1.125");
+/// # }
+/// ```
+#[inline]
+pub fn to_string_with_options<N: ToLexicalWithOptions>(n: N, options: &N::Options) -> lib::String {
+    unsafe {
+        let size = N::formatted_size_with_options(10, options);
+        let mut buf = lib::Vec::<u8>::with_capacity(size);
+        let len = lexical_core::write_with_options(n, vector_as_slice(&mut buf), options).len();
+        buf.set_len(len);
+        lib::String::from_utf8_unchecked(buf)
+    }
+}
+
+/// High-level conversion of a number to string with a custom radix and custom options.
 ///
-/// // String overloads
-/// assert_eq!(lexical::parse_radix::<i32, _>("5", 10), Ok(5));
-/// assert_eq!(err_code(lexical::parse_radix::<i32, _>("1a", 10)), ErrorCode::InvalidDigit);
-/// assert_eq!(err_code(lexical::parse_radix::<i32, _>("1.", 10)), ErrorCode::InvalidDigit);
-/// assert_eq!(lexical::parse_radix::<f32, _>("0", 10), Ok(0.0));
-/// assert_eq!(lexical::parse_radix::<f32, _>("1.0", 10), Ok(1.0));
-/// assert_eq!(lexical::parse_radix::<f32, _>("1.", 10), Ok(1.0));
-/// assert_eq!(err_code(lexical::parse_radix::<f32, _>("1a", 10)), ErrorCode::InvalidDigit);
-/// assert_eq!(err_code(lexical::parse_radix::<f32, _>("1.0.", 10)), ErrorCode::InvalidDigit);
+/// * `n`       - Number to convert to string.
+/// * `base`    - Number of unique digits for the number (radix).
+/// * `options` - Options to customize the string.
 ///
-/// // Bytes overloads
-/// assert_eq!(lexical::parse_radix::<i32, _>(b"5", 10), Ok(5));
-/// assert_eq!(err_code(lexical::parse_radix::<i32, _>(b"1a", 10)), ErrorCode::InvalidDigit);
-/// assert_eq!(lexical::parse_radix::<f32, _>(b"0", 10), Ok(0.0));
-/// assert_eq!(lexical::parse_radix::<f32, _>(b"1.0", 10), Ok(1.0));
-/// assert_eq!(lexical::parse_radix::<f32, _>(b"1.", 10), Ok(1.0));
-/// assert_eq!(err_code(lexical::parse_radix::<f32, _>(b"1a", 10)), ErrorCode::InvalidDigit);
-/// assert_eq!(err_code(lexical::parse_radix::<f32, _>(b"1.0.", 10)), ErrorCode::InvalidDigit);
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # use lexical::{WriteFloatOptions, WriteIntegerOptions};
+/// # pub fn main() {
+/// let options = WriteFloatOptions::builder().min_fraction_digits(2).build().unwrap();
+/// assert_eq!(lexical::to_string_radix_with_options(2.0, 2, &options), "10.00");
+///
+/// let options = WriteIntegerOptions::builder().two_complement(true).build().unwrap();
+/// assert_eq!(lexical::to_string_radix_with_options(-1i8, 2, &options), "11111111");
 /// # }
 /// ```
 ///
@@ -441,126 +721,2077 @@ pub fn parse_partial_lossy<N: FromLexicalLossy, Bytes: AsRef<[u8]>>(bytes: Bytes
 /// Panics if radix is not in the range `[2, 36]`
 #[cfg(feature = "radix")]
 #[inline]
-pub fn parse_radix<N: FromLexical, Bytes: AsRef<[u8]>>(bytes: Bytes, radix: u8)
-    -> Result<N>
-{
-    N::from_lexical_radix(bytes.as_ref(), radix)
+pub fn to_string_radix_with_options<N: ToLexicalWithOptions>(n: N, radix: u8, options: &N::Options) -> lib::String {
+    unsafe {
+        let size = N::formatted_size_with_options(radix, options);
+        let mut buf = lib::Vec::<u8>::with_capacity(size);
+        let len = lexical_core::write_radix_with_options(n, radix, vector_as_slice(&mut buf), options).len();
+        buf.set_len(len);
+        lib::String::from_utf8_unchecked(buf)
+    }
 }
 
-/// High-level, partial conversion of bytes to a number with a custom radix.
+/// High-level conversion of an optional number to a decimal-encoded string.
 ///
-/// This functions parses as many digits as possible, returning the parsed
-/// value and the number of digits processed if at least one character
-/// is processed. If another error, such as numerical overflow or underflow
-/// occurs, this function returns the error code and the index at which
-/// the error occurred.
+/// Present values are converted the same as [`to_string`], and
+/// missing values are converted to the null token of the options.
 ///
-/// * `bytes`   - Byte slice to convert to number.
-/// * `radix`   - Number of unique digits for the number (base).
+/// * `n`       - Optional number to convert to string.
+/// * `options` - Options to customize the null token.
 ///
 /// # Examples
 ///
 /// ```rust
 /// # extern crate lexical;
-/// # use lexical::ErrorCode;
+/// # use lexical::WriteOptionalOptions;
 /// # pub fn main() {
+/// let options = WriteOptionalOptions::new();
+/// assert_eq!(lexical::to_string_optional(None::<f64>, &options), "null");
+/// assert_eq!(lexical::to_string_optional(Some(5), &options), "5");
+///
+/// let options = WriteOptionalOptions::builder().null_token(b"NA").build().unwrap();
+/// assert_eq!(lexical::to_string_optional(None::<f64>, &options), "NA");
+/// # }
+/// ```
+///
+/// [`to_string`]: fn.to_string.html
+#[inline]
+#[cfg(feature = "optional")]
+pub fn to_string_optional<N: ToLexical>(n: Option<N>, options: &WriteOptionalOptions) -> lib::String {
+    unsafe {
+        let size = lexical_core::formatted_size_optional::<N>(options);
+        let mut buf = lib::Vec::<u8>::with_capacity(size);
+        let len = lexical_core::write_optional(n, vector_as_slice(&mut buf), options).len();
+        buf.set_len(len);
+        lib::String::from_utf8_unchecked(buf)
+    }
+}
+
+/// High-level conversion of a float to a decimal-encoded string of at most `max_chars` characters.
+///
+/// If the string does not fit within the budget, it is shortened
+/// with the budget style. Rounding is applied to the shortest string
+/// that round-trips to the float, rather than to the exact value of
+/// the float.
+///
+/// * `n`           - Float to convert to string.
+/// * `max_chars`   - Maximum number of characters in the string.
+/// * `style`       - Style to shorten the string if it does not fit.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # use lexical::BudgetStyle;
+/// # pub fn main() {
+/// assert_eq!(lexical::to_string_budget(3.14159, 6, BudgetStyle::RoundToFit), "3.1416");
+/// assert_eq!(lexical::to_string_budget(3.14159, 5, BudgetStyle::TruncateMark('…')), "3.14…");
+/// assert_eq!(lexical::to_string_budget(1.5e-10, 6, BudgetStyle::RoundToFit), "2e-10");
+/// assert_eq!(lexical::to_string_budget(9.96, 3, BudgetStyle::RoundToFit), "10");
+/// assert_eq!(lexical::to_string_budget(123.5, 2, BudgetStyle::RoundToFit), "");
+/// assert_eq!(lexical::to_string_budget(123.5, 0, BudgetStyle::TruncateMark('…')), "");
+/// # }
+/// ```
+#[inline]
+pub fn to_string_budget<N: Float + ToLexical>(n: N, max_chars: usize, style: BudgetStyle) -> lib::String {
+    // The default write options may use a multi-byte minus sign,
+    // so the budget counts characters rather than bytes.
+    let string = to_string(n);
+    if string.chars().count() <= max_chars {
+        return string;
+    }
+    match style {
+        BudgetStyle::RoundToFit         => budget::round_to_fit(&string, max_chars).unwrap_or_default(),
+        BudgetStyle::TruncateMark(mark) => budget::truncate_mark(&string, max_chars, mark),
+    }
+}
+
+/// High-level conversion of a float to a decimal-encoded string with the fewest digits within a tolerance.
+///
+/// The shortest string that round-trips to the float is rounded to
+/// the fewest significant digits, half away from zero, such that the
+/// string parses to a float within `rel_tol` of the float, relative
+/// to its absolute value. At least 1 significant digit is always
+/// written, so very large tolerances round to 1 significant digit.
+/// A tolerance of `0`, a negative tolerance, or a `NaN` tolerance
+/// writes the shortest string that round-trips, like [`to_string`].
+/// Zero and special (non-finite) values are written like [`to_string`].
+///
+/// * `n`           - Float to convert to string.
+/// * `rel_tol`     - Maximum relative error of the parsed string.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// assert_eq!(lexical::to_string_precision(3.14159265, 1e-3), "3.14");
+/// assert_eq!(lexical::to_string_precision(3.14159265, 1e-4), "3.1416");
+/// assert_eq!(lexical::to_string_precision(-0.0012345678, 1e-2), "-0.00123");
+/// assert_eq!(lexical::to_string_precision(3.14159265, 0.0), "3.14159265");
+/// ##[cfg(not(feature = "trim_floats"))]
+/// assert_eq!(lexical::to_string_precision(987.0, 1.0), "1000.0");
+/// # }
+/// ```
+///
+/// [`to_string`]: fn.to_string.html
+#[inline]
+pub fn to_string_precision(n: f64, rel_tol: f64) -> lib::String {
+    precision::to_precision(n, rel_tol)
+}
+
+/// High-level conversion of a float to a decimal-encoded string with a bounded error for lossy parsing.
+///
+/// Writes the fewest significant digits such that the string parses,
+/// with [`parse_lossy`], to a float within `max_rel_error` of the float,
+/// relative to its absolute value. The output is not round-trippable
+/// by design: digits are dropped as long as the error stays within the
+/// bound, so the parsed float is usually not the original float. This
+/// pairs with [`parse_lossy`] for fast, low-precision pipelines.
+///
+/// The bound is checked against the string that is returned, so it
+/// holds for every tolerance at least as large as the error of the
+/// lossy parser, a few units in the last place. Otherwise, or for a
+/// tolerance of `0`, a negative tolerance, or a `NaN` tolerance, the
+/// shortest string that round-trips is written, like [`to_string`].
+/// Zero and special (non-finite) values are written like [`to_string`].
+///
+/// * `n`               - Float to convert to string.
+/// * `max_rel_error`   - Maximum relative error of the lossy-parsed string.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// assert_eq!(lexical::to_string_lossy(3.14159265, 1e-3), "3.14");
+/// assert_eq!(lexical::to_string_lossy(-2.718281828, 1e-6), "-2.71828");
+/// assert_eq!(lexical::to_string_lossy(3.14159265, 0.0), "3.14159265");
+///
+/// // The bound holds across a sweep of values and tolerances.
+/// let mut x = 1.234567890123e-30;
+/// while x < 1e30 {
+///     for &max_rel_error in &[1e-1, 1e-3, 1e-6, 1e-9, 1e-12] {
+///         for &value in &[x, -x] {
+///             let string = lexical::to_string_lossy(value, max_rel_error);
+///             let parsed: f64 = lexical::parse_lossy(&string).unwrap();
+///             assert!((parsed - value).abs() <= max_rel_error * value.abs());
+///         }
+///     }
+///     x *= 7.919;
+/// }
+/// # }
+/// ```
+///
+/// [`parse_lossy`]: fn.parse_lossy.html
+/// [`to_string`]: fn.to_string.html
+#[inline]
+pub fn to_string_lossy(n: f64, max_rel_error: f64) -> lib::String {
+    precision::to_lossy(n, max_rel_error)
+}
+
+/// High-level conversion of a float to its exact decimal expansion.
+///
+/// Every finite float is a binary fraction, so it has a finite decimal
+/// expansion, which is written with every digit in fixed notation,
+/// rather than the shortest string that round-trips, like [`to_string`].
+/// This shows the rounding of the float, like `0.1` being slightly
+/// more than one tenth. Integral floats are written with a trailing
+/// `.0`, negative zero keeps its sign, and special values are written
+/// like [`to_string`].
+///
+/// The digits are computed with a big integer, and both the big integer
+/// and the string are allocated on the heap. The string has up to 767
+/// significant digits, and up to 1077 bytes for the smallest
+/// subnormal floats, with quadratic time in the number of digits.
+///
+/// * `n`   - Float to convert to string.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// assert_eq!(lexical::to_exact_decimal(0.1), "0.1000000000000000055511151231257827021181583404541015625");
+/// assert_eq!(lexical::to_exact_decimal(-2.5), "-2.5");
+/// assert_eq!(lexical::to_exact_decimal(1e23), "99999999999999991611392.0");
+/// assert_eq!(lexical::to_exact_decimal(-0.0), "-0.0");
+///
+/// // The smallest subnormal float has 751 significant digits.
+/// let string = lexical::to_exact_decimal(5e-324);
+/// assert!(string.starts_with("0.000"));
+/// assert!(string.ends_with("533447265625"));
+/// assert_eq!(string.len(), 1076);
+///
+/// // The exact decimal expansion always parses to the same float.
+/// let mut x = 1.234567890123e-300f64;
+/// while x < 1e300 {
+///     for &value in &[x, -x, f64::from_bits(x.to_bits() + 1)] {
+///         assert_eq!(lexical::parse::<f64, _>(lexical::to_exact_decimal(value)), Ok(value));
+///     }
+///     x *= 1.1e7;
+/// }
+/// assert_eq!(lexical::parse::<f64, _>(lexical::to_exact_decimal(f64::MAX)), Ok(f64::MAX));
+/// # }
+/// ```
+///
+/// [`to_string`]: fn.to_string.html
+#[inline]
+pub fn to_exact_decimal(n: f64) -> lib::String {
+    exact::to_exact(n)
+}
+
+/// High-level conversion of a float to a decimal-encoded string in fixed notation.
+///
+/// The shortest string that round-trips to the float is written
+/// without an exponent, so large and small floats are written with
+/// all the leading or trailing zeros. Special (non-finite) values
+/// are written like [`to_string`].
+///
+/// * `n`           - Float to convert to string.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// assert_eq!(lexical::to_string_fixed(1.5e-7), "0.00000015");
+/// ##[cfg(not(feature = "trim_floats"))]
+/// assert_eq!(lexical::to_string_fixed(1e21), "1000000000000000000000.0");
+/// # }
+/// ```
+///
+/// [`to_string`]: fn.to_string.html
+#[inline]
+pub fn to_string_fixed<N: Float + ToLexical>(n: N) -> lib::String {
+    notation::to_fixed(to_string(n))
+}
+
+/// High-level conversion of a float to a decimal-encoded string in scientific notation.
+///
+/// The shortest string that round-trips to the float is written
+/// in scientific notation if the absolute value of the decimal
+/// exponent is at least `threshold`, otherwise, it is written in
+/// fixed notation, like [`to_string_fixed`]. A threshold of `0`
+/// always writes scientific notation. Special (non-finite) values
+/// are written like [`to_string`].
+///
+/// * `n`           - Float to convert to string.
+/// * `threshold`   - Minimum absolute exponent to write scientific notation.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// assert_eq!(lexical::to_string_scientific(100.0, 0), "1e2");
+/// assert_eq!(lexical::to_string_scientific(-0.0125, 0), "-1.25e-2");
+/// assert_eq!(lexical::to_string_scientific(1e21, 3), "1e21");
+/// ##[cfg(not(feature = "trim_floats"))]
+/// assert_eq!(lexical::to_string_scientific(100.0, 3), "100.0");
+/// # }
+/// ```
+///
+/// [`to_string_fixed`]: fn.to_string_fixed.html
+/// [`to_string`]: fn.to_string.html
+#[inline]
+pub fn to_string_scientific<N: Float + ToLexical>(n: N, threshold: u32) -> lib::String {
+    notation::to_scientific(to_string(n), threshold)
+}
+
+/// High-level conversion of a float to a decimal-encoded percentage string.
+///
+/// The float is multiplied by 100 and written in fixed notation with
+/// exactly `decimal_places` fractional digits, followed by `%`. The
+/// float is scaled exactly, from the shortest string that round-trips
+/// to the float, and then rounded half away from zero, so `0.125` at
+/// 0 decimal places is written as `"13%"`. Values that round to zero
+/// are written without a sign, and special (non-finite) values are
+/// written like [`to_string`], followed by `%`.
+///
+/// * `n`               - Float to convert to string.
+/// * `decimal_places`  - Number of fractional digits to write.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// assert_eq!(lexical::to_string_percent(0.1234, 1), "12.3%");
+/// assert_eq!(lexical::to_string_percent(-0.5, 2), "-50.00%");
+/// assert_eq!(lexical::to_string_percent(1.5, 0), "150%");
+/// assert_eq!(lexical::to_string_percent(0.99999, 2), "100.00%");
+/// assert_eq!(lexical::to_string_percent(-0.00001, 1), "0.0%");
+/// assert_eq!(lexical::to_string_percent(1234.5678, 3), "123456.780%");
+/// assert_eq!(lexical::to_string_percent(0.07, 0), "7%");
+/// assert_eq!(lexical::to_string_percent(1e-5, 4), "0.0010%");
+/// # }
+/// ```
+///
+/// [`to_string`]: fn.to_string.html
+#[inline]
+pub fn to_string_percent(n: f64, decimal_places: usize) -> lib::String {
+    percent::to_percent(n, decimal_places)
+}
+
+/// High-level generation of the digits of a float, to write with different precisions.
+///
+/// The shortest digits that round-trip to the float are generated
+/// once, and each view of the [`FloatDigits`] lays out the same
+/// digits, so a float may be written with many precisions without
+/// generating the digits again. Views with more digits than the
+/// shortest digits are padded with zeros, and do not write the exact
+/// value of the float, like [`to_exact_decimal`].
+///
+/// * `n`   - Float to generate the digits of.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// let digits = lexical::float_digits(3.14159);
+/// assert_eq!(digits.to_string(), "3.14159");
+/// assert_eq!(digits.to_fixed(2), "3.14");
+/// assert_eq!(digits.to_fixed(8), "3.14159000");
+/// assert_eq!(digits.to_significant(3), "3.14");
+///
+/// let digits = lexical::float_digits(-1234.5);
+/// assert_eq!(digits.digits(), b"12345");
+/// assert_eq!(digits.exponent(), 3);
+/// assert_eq!(digits.to_fixed(0), "-1235");
+/// assert_eq!(digits.to_significant(2), "-1.2e3");
+/// assert_eq!(digits.to_significant(6), "-1234.50");
+///
+/// // Padding writes zeros, not the exact value of the float.
+/// assert_eq!(lexical::float_digits(0.1).to_fixed(20), "0.10000000000000000000");
+/// assert_eq!(lexical::float_digits(0.000123).to_significant(2), "0.00012");
+/// assert_eq!(lexical::float_digits(9.96).to_significant(2), "10");
+/// assert_eq!(lexical::float_digits(-0.001).to_fixed(2), "0.00");
+/// assert_eq!(lexical::float_digits(f64::NAN).to_fixed(2), "NaN");
+/// # }
+/// ```
+///
+/// [`FloatDigits`]: struct.FloatDigits.html
+/// [`to_exact_decimal`]: fn.to_exact_decimal.html
+#[inline]
+pub fn float_digits(n: f64) -> FloatDigits {
+    FloatDigits::new(n)
+}
+
+/// High-level conversion of a float to a decimal-encoded string, after rounding to a multiple of a step.
+///
+/// The float is rounded to a multiple of the step with [`quantize`],
+/// and the rounded float is converted with [`to_string`]. `NaN` and
+/// infinite values are converted unchanged. Returns `None` if the
+/// step is not positive and finite.
+///
+/// * `n`           - Float to convert to string.
+/// * `step`        - Positive, finite step to round to a multiple of.
+/// * `rounding`    - Rounding scheme for the quotient.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # use lexical::RoundingMode;
+/// # pub fn main() {
+/// let kind = RoundingMode::NearestTieEven;
+/// assert_eq!(lexical::to_string_quantized(3.14159, 0.01, kind), Some("3.14".to_string()));
+/// assert_eq!(lexical::to_string_quantized(0.3, 0.0009765625, kind), Some("0.2998046875".to_string()));
+/// assert_eq!(lexical::to_string_quantized(1.0, 0.0, kind), None);
+/// # }
+/// ```
+///
+/// [`quantize`]: fn.quantize.html
+/// [`to_string`]: fn.to_string.html
+#[inline]
+pub fn to_string_quantized(n: f64, step: f64, rounding: RoundingMode) -> Option<lib::String> {
+    lexical_core::quantize(n, step, rounding).map(to_string)
+}
+
+/// High-level conversion of a fixed-point integer to a decimal-encoded string.
+///
+/// The value is `value / 2^frac_bits`, written with exactly
+/// `decimal_places` fractional digits, and rounded to the nearest
+/// digit with ties to even. The value is scaled with integer
+/// arithmetic, so no precision is lost converting to a float.
+///
+/// * `value`           - Raw fixed-point integer, such as a Q16.16 value.
+/// * `frac_bits`       - Number of fractional bits in the value.
+/// * `decimal_places`  - Number of fractional digits to write.
+///
+/// # Panics
+///
+/// Panics if `frac_bits` is greater than 32.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// assert_eq!(lexical::to_string_qfixed(0x0003_2000, 16, 4), "3.1250");
+/// assert_eq!(lexical::to_string_qfixed(-0x0001_8000, 16, 1), "-1.5");
+/// # }
+/// ```
+#[inline]
+pub fn to_string_qfixed(value: i32, frac_bits: u32, decimal_places: usize) -> lib::String {
+    unsafe {
+        let size = <i32 as lexical_core::Number>::FORMATTED_SIZE_DECIMAL + 1 + decimal_places;
+        let mut buf = lib::Vec::<u8>::with_capacity(size);
+        let len = lexical_core::format_qfixed(value, frac_bits, decimal_places, vector_as_slice(&mut buf));
+        buf.set_len(len);
+        lib::String::from_utf8_unchecked(buf)
+    }
+}
+
+/// High-level conversion of the bit pattern of an `f64` to a decimal-encoded string.
+///
+/// The float is written the same as [`to_string`], so the string
+/// round-trips with [`parse_to_bits`] for all finite floats and
+/// infinities. All `NaN` bit patterns are written as the `NaN`
+/// string, without the sign or payload.
+///
+/// * `bits`    - Bit pattern of the float to convert to string.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// assert_eq!(lexical::format_from_bits(0x3FF8000000000000), "1.5");
+/// assert_eq!(lexical::parse_to_bits(lexical::format_from_bits(0x3FB999999999999A)), Ok(0x3FB999999999999A));
+/// # }
+/// ```
+///
+/// [`to_string`]: fn.to_string.html
+/// [`parse_to_bits`]: fn.parse_to_bits.html
+#[inline]
+pub fn format_from_bits(bits: u64) -> lib::String {
+    to_string(f64::from_bits(bits))
+}
+
+/// High-level conversion of a duration to a string of decimal seconds.
+///
+/// The whole seconds are followed by a `.` and the nanoseconds as
+/// fractional seconds, without trailing zeros, so the string
+/// round-trips with [`parse_duration_secs`]. Whole seconds are written
+/// without a decimal point.
+///
+/// * `duration`    - Duration to convert to string.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # use std::time::Duration;
+/// # pub fn main() {
+/// assert_eq!(lexical::format_duration_secs(Duration::new(1, 500_000_000)), "1.5");
+/// assert_eq!(lexical::format_duration_secs(Duration::from_micros(250)), "0.00025");
+/// assert_eq!(lexical::format_duration_secs(Duration::from_secs(3600)), "3600");
+/// # }
+/// ```
+///
+/// [`parse_duration_secs`]: fn.parse_duration_secs.html
+#[inline]
+pub fn format_duration_secs(duration: lib::time::Duration) -> lib::String {
+    let mut buffer = [b'0'; lexical_core::DURATION_SECS_FORMATTED_SIZE];
+    let bytes = lexical_core::write_duration_secs(duration, &mut buffer);
+    // The written bytes are always valid ASCII.
+    unsafe { lib::str::from_utf8_unchecked(bytes) }.into()
+}
+
+/// High-level conversion of decimal-encoded bytes to a number.
+///
+/// This function only returns a value if the entire string is
+/// successfully parsed.
+///
+/// * `bytes`   - Byte slice to convert to number.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # use lexical::ErrorCode;
+/// # pub fn main() {
+/// // Create our error.
+/// fn err_code<T>(r: lexical::Result<T>) -> ErrorCode {
+///     r.err().unwrap().code
+/// }
 ///
 /// // String overloads
-/// assert_eq!(lexical::parse_partial_radix::<i32, _>("5", 10), Ok((5, 1)));
-/// assert_eq!(lexical::parse_partial_radix::<i32, _>("1a", 10), Ok((1, 1)));
-/// assert_eq!(lexical::parse_partial_radix::<i32, _>("1.", 10), Ok((1, 1)));
-/// assert_eq!(lexical::parse_partial_radix::<f32, _>("0", 10), Ok((0.0, 1)));
-/// assert_eq!(lexical::parse_partial_radix::<f32, _>("1.0", 10), Ok((1.0, 3)));
-/// assert_eq!(lexical::parse_partial_radix::<f32, _>("1.", 10), Ok((1.0, 2)));
-/// assert_eq!(lexical::parse_partial_radix::<f32, _>("1a", 10), Ok((1.0, 1)));
-/// assert_eq!(lexical::parse_partial_radix::<f32, _>("1.0.", 10), Ok((1.0, 3)));
+/// assert_eq!(lexical::parse::<i32, _>("5"), Ok(5));
+/// assert_eq!(err_code(lexical::parse::<i32, _>("1a")), ErrorCode::InvalidDigit);
+/// assert_eq!(lexical::parse::<f32, _>("0"), Ok(0.0));
+/// assert_eq!(lexical::parse::<f32, _>("1.0"), Ok(1.0));
+/// assert_eq!(lexical::parse::<f32, _>("1."), Ok(1.0));
+///
+/// // Bytes overloads
+/// assert_eq!(lexical::parse::<i32, _>(b"5"), Ok(5));
+/// assert_eq!(err_code(lexical::parse::<i32, _>(b"1a")), ErrorCode::InvalidDigit);
+/// assert_eq!(lexical::parse::<f32, _>(b"0"), Ok(0.0));
+/// assert_eq!(lexical::parse::<f32, _>(b"1.0"), Ok(1.0));
+/// assert_eq!(lexical::parse::<f32, _>(b"1."), Ok(1.0));
+/// # assert_eq!(lexical::parse::<f32, _>(b"5.002868148396374"), Ok(5.002868148396374));
+/// # assert_eq!(lexical::parse::<f64, _>(b"5.002868148396374"), Ok(5.002868148396374));
+/// # }
+/// ```
+#[inline]
+pub fn parse<N: FromLexical, Bytes: AsRef<[u8]>>(bytes: Bytes)
+    -> Result<N>
+{
+    N::from_lexical(bytes.as_ref())
+}
+
+/// High-level, partial conversion of decimal-encoded bytes to a number.
+///
+/// This functions parses as many digits as possible, returning the parsed
+/// value and the number of digits processed if at least one character
+/// is processed. If another error, such as numerical overflow or underflow
+/// occurs, this function returns the error code and the index at which
+/// the error occurred.
+///
+/// * `bytes`   - Byte slice to convert to number.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # use lexical::ErrorCode;
+/// # pub fn main() {
+///
+/// // String overloads
+/// assert_eq!(lexical::parse_partial::<i32, _>("5"), Ok((5, 1)));
+/// assert_eq!(lexical::parse_partial::<i32, _>("1a"), Ok((1, 1)));
+/// assert_eq!(lexical::parse_partial::<f32, _>("0"), Ok((0.0, 1)));
+/// assert_eq!(lexical::parse_partial::<f32, _>("1.0"), Ok((1.0, 3)));
+/// assert_eq!(lexical::parse_partial::<f32, _>("1."), Ok((1.0, 2)));
+///
+/// // Bytes overloads
+/// assert_eq!(lexical::parse_partial::<i32, _>(b"5"), Ok((5, 1)));
+/// assert_eq!(lexical::parse_partial::<i32, _>(b"1a"), Ok((1, 1)));
+/// assert_eq!(lexical::parse_partial::<f32, _>(b"0"), Ok((0.0, 1)));
+/// assert_eq!(lexical::parse_partial::<f32, _>(b"1.0"), Ok((1.0, 3)));
+/// assert_eq!(lexical::parse_partial::<f32, _>(b"1."), Ok((1.0, 2)));
+/// # assert_eq!(lexical::parse_partial::<f32, _>(b"5.002868148396374"), Ok((5.002868148396374, 17)));
+/// # assert_eq!(lexical::parse_partial::<f64, _>(b"5.002868148396374"), Ok((5.002868148396374, 17)));
+/// # }
+/// ```
+#[inline]
+pub fn parse_partial<N: FromLexical, Bytes: AsRef<[u8]>>(bytes: Bytes)
+    -> Result<(N, usize)>
+{
+    N::from_lexical_partial(bytes.as_ref())
+}
+
+/// High-level lossy conversion of decimal-encoded bytes to a number.
+///
+/// This function uses aggressive optimizations to avoid worst-case
+/// scenarios, and can return inaccurate results. For guaranteed accurate
+/// floats, use [`parse`].
+///
+/// * `bytes`   - Byte slice to convert to number.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # use lexical::ErrorCode;
+/// # pub fn main() {
+/// // Get our error code.
+/// fn err_code<T>(r: lexical::Result<T>) -> ErrorCode {
+///     r.err().unwrap().code
+/// }
+///
+/// // String overloads
+/// assert_eq!(lexical::parse_lossy::<f32, _>("0"), Ok(0.0));
+/// assert_eq!(lexical::parse_lossy::<f32, _>("1.0"), Ok(1.0));
+/// assert_eq!(lexical::parse_lossy::<f32, _>("1."), Ok(1.0));
+/// assert_eq!(err_code(lexical::parse_lossy::<f32, _>("1a")), ErrorCode::InvalidDigit);
+///
+/// // Bytes overloads
+/// assert_eq!(lexical::parse_lossy::<f32, _>(b"0"), Ok(0.0));
+/// assert_eq!(lexical::parse_lossy::<f32, _>(b"1.0"), Ok(1.0));
+/// assert_eq!(lexical::parse_lossy::<f32, _>(b"1."), Ok(1.0));
+/// assert_eq!(err_code(lexical::parse_lossy::<f32, _>(b"1a")), ErrorCode::InvalidDigit);
+/// # }
+/// ```
+///
+/// [`parse`]: fn.parse.html
+#[inline]
+pub fn parse_lossy<N: FromLexicalLossy, Bytes: AsRef<[u8]>>(bytes: Bytes)
+    -> Result<N>
+{
+    N::from_lexical_lossy(bytes.as_ref())
+}
+
+/// High-level, partial, lossy conversion of decimal-encoded bytes to a number.
+///
+/// This function uses aggressive optimizations to avoid worst-case
+/// scenarios, and can return inaccurate results. For guaranteed accurate
+/// floats, use [`parse_partial`].
+///
+/// This functions parses as many digits as possible, returning the parsed
+/// value and the number of digits processed if at least one character
+/// is processed. If another error, such as numerical overflow or underflow
+/// occurs, this function returns the error code and the index at which
+/// the error occurred.
+///
+/// * `bytes`   - Byte slice to convert to number.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # use lexical::ErrorCode;
+/// # pub fn main() {
+///
+/// // String overloads
+/// assert_eq!(lexical::parse_partial_lossy::<f32, _>("0"), Ok((0.0, 1)));
+/// assert_eq!(lexical::parse_partial_lossy::<f32, _>("1.0"), Ok((1.0, 3)));
+/// assert_eq!(lexical::parse_partial_lossy::<f32, _>("1."), Ok((1.0, 2)));
+///
+/// // Bytes overloads
+/// assert_eq!(lexical::parse_partial_lossy::<f32, _>(b"0"), Ok((0.0, 1)));
+/// assert_eq!(lexical::parse_partial_lossy::<f32, _>(b"1.0"), Ok((1.0, 3)));
+/// assert_eq!(lexical::parse_partial_lossy::<f32, _>(b"1."), Ok((1.0, 2)));
+/// # }
+/// ```
+///
+/// [`parse_partial`]: fn.parse_partial.html
+#[inline]
+pub fn parse_partial_lossy<N: FromLexicalLossy, Bytes: AsRef<[u8]>>(bytes: Bytes)
+    -> Result<(N, usize)>
+{
+    N::from_lexical_partial_lossy(bytes.as_ref())
+}
+
+/// High-level conversion of decimal-encoded bytes to a number with custom options.
+///
+/// This function only returns a value if the entire string is
+/// successfully parsed.
+///
+/// * `bytes`   - Byte slice to convert to number.
+/// * `options` - Options to customize the number parsing.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # use lexical::ParseFloatOptions;
+/// # pub fn main() {
+/// let options = ParseFloatOptions::builder()
+///     .exponent_string(b"*10^")
+///     .build()
+///     .unwrap();
+/// assert_eq!(lexical::parse_with_options::<f64, _>("1.5*10^3", &options), Ok(1500.0));
+/// assert!(lexical::parse_with_options::<f64, _>("1.5e3", &options).is_err());
+/// # }
+/// ```
+#[inline]
+pub fn parse_with_options<N: FromLexicalWithOptions, Bytes: AsRef<[u8]>>(bytes: Bytes, options: &N::Options)
+    -> Result<N>
+{
+    N::from_lexical_with_options(bytes.as_ref(), options)
+}
+
+/// High-level conversion of decimal-encoded bytes to a number with custom options.
+///
+/// This functions parses as many digits as possible, returning the parsed
+/// value and the number of digits processed if at least one character
+/// is processed.
+///
+/// * `bytes`   - Byte slice to convert to number.
+/// * `options` - Options to customize the number parsing.
+#[inline]
+pub fn parse_partial_with_options<N: FromLexicalWithOptions, Bytes: AsRef<[u8]>>(bytes: Bytes, options: &N::Options)
+    -> Result<(N, usize)>
+{
+    N::from_lexical_partial_with_options(bytes.as_ref(), options)
+}
+
+/// High-level conversion of decimal-encoded bytes to a float, with parse metrics.
+///
+/// This function parses the entire string, returning an error if any
+/// invalid digits are found. Along with the value, returns metrics
+/// on the number of significant digits consumed, the parsed exponent,
+/// and if the value required the slower, arbitrary-precision paths.
+///
+/// * `bytes`   - Byte slice to convert to number.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// let (value, metrics) = lexical::parse_with_metrics::<f64, _>("1.5").unwrap();
+/// assert_eq!(value, 1.5);
+/// assert_eq!(metrics.digits_consumed, 2);
+/// assert!(!metrics.used_slow_path);
+///
+/// let (_, metrics) = lexical::parse_with_metrics::<f64, _>("9007199254740993").unwrap();
+/// assert!(metrics.used_slow_path);
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "metrics")]
+pub fn parse_with_metrics<N: FromLexicalMetrics, Bytes: AsRef<[u8]>>(bytes: Bytes)
+    -> Result<(N, ParseMetrics)>
+{
+    N::from_lexical_metrics(bytes.as_ref())
+}
+
+/// High-level conversion of bytes to a number with a custom radix.
+///
+/// This function only returns a value if the entire string is
+/// successfully parsed.
+///
+/// * `bytes`   - Byte slice to convert to number.
+/// * `radix`   - Number of unique digits for the number (base).
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # use lexical::ErrorCode;
+/// # pub fn main() {
+/// // Get our error code wrapper.
+/// fn err_code<T>(r: lexical::Result<T>) -> ErrorCode {
+///     r.err().unwrap().code
+/// }
+///
+/// // String overloads
+/// assert_eq!(lexical::parse_radix::<i32, _>("5", 10), Ok(5));
+/// assert_eq!(err_code(lexical::parse_radix::<i32, _>("1a", 10)), ErrorCode::InvalidDigit);
+/// assert_eq!(err_code(lexical::parse_radix::<i32, _>("1.", 10)), ErrorCode::InvalidDigit);
+/// assert_eq!(lexical::parse_radix::<f32, _>("0", 10), Ok(0.0));
+/// assert_eq!(lexical::parse_radix::<f32, _>("1.0", 10), Ok(1.0));
+/// assert_eq!(lexical::parse_radix::<f32, _>("1.", 10), Ok(1.0));
+/// assert_eq!(err_code(lexical::parse_radix::<f32, _>("1a", 10)), ErrorCode::InvalidDigit);
+/// assert_eq!(err_code(lexical::parse_radix::<f32, _>("1.0.", 10)), ErrorCode::InvalidDigit);
+///
+/// // Bytes overloads
+/// assert_eq!(lexical::parse_radix::<i32, _>(b"5", 10), Ok(5));
+/// assert_eq!(err_code(lexical::parse_radix::<i32, _>(b"1a", 10)), ErrorCode::InvalidDigit);
+/// assert_eq!(lexical::parse_radix::<f32, _>(b"0", 10), Ok(0.0));
+/// assert_eq!(lexical::parse_radix::<f32, _>(b"1.0", 10), Ok(1.0));
+/// assert_eq!(lexical::parse_radix::<f32, _>(b"1.", 10), Ok(1.0));
+/// assert_eq!(err_code(lexical::parse_radix::<f32, _>(b"1a", 10)), ErrorCode::InvalidDigit);
+/// assert_eq!(err_code(lexical::parse_radix::<f32, _>(b"1.0.", 10)), ErrorCode::InvalidDigit);
+/// # }
+/// ```
+///
+/// # Panics
+///
+/// Panics if radix is not in the range `[2, 36]`
+#[cfg(feature = "radix")]
+#[inline]
+pub fn parse_radix<N: FromLexical, Bytes: AsRef<[u8]>>(bytes: Bytes, radix: u8)
+    -> Result<N>
+{
+    N::from_lexical_radix(bytes.as_ref(), radix)
+}
+
+/// High-level, partial conversion of bytes to a number with a custom radix.
+///
+/// This functions parses as many digits as possible, returning the parsed
+/// value and the number of digits processed if at least one character
+/// is processed. If another error, such as numerical overflow or underflow
+/// occurs, this function returns the error code and the index at which
+/// the error occurred.
+///
+/// * `bytes`   - Byte slice to convert to number.
+/// * `radix`   - Number of unique digits for the number (base).
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # use lexical::ErrorCode;
+/// # pub fn main() {
+///
+/// // String overloads
+/// assert_eq!(lexical::parse_partial_radix::<i32, _>("5", 10), Ok((5, 1)));
+/// assert_eq!(lexical::parse_partial_radix::<i32, _>("1a", 10), Ok((1, 1)));
+/// assert_eq!(lexical::parse_partial_radix::<i32, _>("1.", 10), Ok((1, 1)));
+/// assert_eq!(lexical::parse_partial_radix::<f32, _>("0", 10), Ok((0.0, 1)));
+/// assert_eq!(lexical::parse_partial_radix::<f32, _>("1.0", 10), Ok((1.0, 3)));
+/// assert_eq!(lexical::parse_partial_radix::<f32, _>("1.", 10), Ok((1.0, 2)));
+/// assert_eq!(lexical::parse_partial_radix::<f32, _>("1a", 10), Ok((1.0, 1)));
+/// assert_eq!(lexical::parse_partial_radix::<f32, _>("1.0.", 10), Ok((1.0, 3)));
+///
+/// // Bytes overloads
+/// assert_eq!(lexical::parse_partial_radix::<i32, _>(b"5", 10), Ok((5, 1)));
+/// assert_eq!(lexical::parse_partial_radix::<i32, _>(b"1a", 10), Ok((1, 1)));
+/// assert_eq!(lexical::parse_partial_radix::<i32, _>(b"1.", 10), Ok((1, 1)));
+/// assert_eq!(lexical::parse_partial_radix::<f32, _>(b"0", 10), Ok((0.0, 1)));
+/// assert_eq!(lexical::parse_partial_radix::<f32, _>(b"1.0", 10), Ok((1.0, 3)));
+/// assert_eq!(lexical::parse_partial_radix::<f32, _>(b"1.", 10), Ok((1.0, 2)));
+/// assert_eq!(lexical::parse_partial_radix::<f32, _>(b"1a", 10), Ok((1.0, 1)));
+/// assert_eq!(lexical::parse_partial_radix::<f32, _>(b"1.0.", 10), Ok((1.0, 3)));
+/// # }
+/// ```
+///
+/// # Panics
+///
+/// Panics if radix is not in the range `[2, 36]`
+#[cfg(feature = "radix")]
+#[inline]
+pub fn parse_partial_radix<N: FromLexical, Bytes: AsRef<[u8]>>(bytes: Bytes, radix: u8)
+    -> Result<(N, usize)>
+{
+    N::from_lexical_partial_radix(bytes.as_ref(), radix)
+}
+
+/// High-level, lossy conversion of bytes to a float with a custom radix.
+///
+/// This function uses aggressive optimizations to avoid worst-case
+/// scenarios, and can return inaccurate results. For guaranteed accurate
+/// floats, use [`parse_radix`].
+///
+/// * `bytes`   - Byte slice to convert to number.
+/// * `radix`   - Number of unique digits for the number (base).
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # use lexical::ErrorCode;
+/// # pub fn main() {
+/// // Create our error wrapper.
+/// fn err_code<T>(r: lexical::Result<T>) -> ErrorCode {
+///     r.err().unwrap().code
+/// }
+///
+/// // String overloads
+/// assert_eq!(lexical::parse_lossy_radix::<f32, _>("0", 10), Ok(0.0));
+/// assert_eq!(lexical::parse_lossy_radix::<f32, _>("1.0", 10), Ok(1.0));
+/// assert_eq!(lexical::parse_lossy_radix::<f32, _>("1.", 10), Ok(1.0));
+/// assert_eq!(err_code(lexical::parse_lossy_radix::<f32, _>("1a", 10)), ErrorCode::InvalidDigit);
+/// assert_eq!(err_code(lexical::parse_lossy_radix::<f32, _>("1.0.", 10)), ErrorCode::InvalidDigit);
+///
+/// // Bytes overloads
+/// assert_eq!(lexical::parse_lossy_radix::<f32, _>(b"0", 10), Ok(0.0));
+/// assert_eq!(lexical::parse_lossy_radix::<f32, _>(b"1.0", 10), Ok(1.0));
+/// assert_eq!(lexical::parse_lossy_radix::<f32, _>(b"1.", 10), Ok(1.0));
+/// assert_eq!(err_code(lexical::parse_lossy_radix::<f32, _>(b"1a", 10)), ErrorCode::InvalidDigit);
+/// assert_eq!(err_code(lexical::parse_lossy_radix::<f32, _>(b"1.0.", 10)), ErrorCode::InvalidDigit);
+/// # }
+/// ```
+///
+/// # Panics
+///
+/// Panics if radix is not in the range `[2, 36]`
+///
+/// [`parse_radix`]: fn.parse_radix.html
+#[cfg(feature = "radix")]
+#[inline]
+pub fn parse_lossy_radix<N: FromLexicalLossy, Bytes: AsRef<[u8]>>(bytes: Bytes, radix: u8)
+    -> Result<N>
+{
+    N::from_lexical_lossy_radix(bytes.as_ref(), radix)
+}
+
+/// High-level, partial lossy conversion of bytes to a number with a custom radix.
+///
+/// This function uses aggressive optimizations to avoid worst-case
+/// scenarios, and can return inaccurate results. For guaranteed accurate
+/// floats, use [`parse_partial_radix`].
+///
+/// This functions parses as many digits as possible, returning the parsed
+/// value and the number of digits processed if at least one character
+/// is processed. If another error, such as numerical overflow or underflow
+/// occurs, this function returns the error code and the index at which
+/// the error occurred.
+///
+/// * `bytes`   - Byte slice to convert to number.
+/// * `radix`   - Number of unique digits for the number (base).
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # use lexical::ErrorCode;
+/// # pub fn main() {
+///
+/// // String overloads
+/// assert_eq!(lexical::parse_partial_lossy_radix::<f32, _>("0", 10), Ok((0.0, 1)));
+/// assert_eq!(lexical::parse_partial_lossy_radix::<f32, _>("1.0", 10), Ok((1.0, 3)));
+/// assert_eq!(lexical::parse_partial_lossy_radix::<f32, _>("1.", 10), Ok((1.0, 2)));
+/// assert_eq!(lexical::parse_partial_lossy_radix::<f32, _>("1a", 10), Ok((1.0, 1)));
+/// assert_eq!(lexical::parse_partial_lossy_radix::<f32, _>("1.0.", 10), Ok((1.0, 3)));
+///
+/// // Bytes overloads
+/// assert_eq!(lexical::parse_partial_lossy_radix::<f32, _>(b"0", 10), Ok((0.0, 1)));
+/// assert_eq!(lexical::parse_partial_lossy_radix::<f32, _>(b"1.0", 10), Ok((1.0, 3)));
+/// assert_eq!(lexical::parse_partial_lossy_radix::<f32, _>(b"1.", 10), Ok((1.0, 2)));
+/// assert_eq!(lexical::parse_partial_lossy_radix::<f32, _>(b"1a", 10), Ok((1.0, 1)));
+/// assert_eq!(lexical::parse_partial_lossy_radix::<f32, _>(b"1.0.", 10), Ok((1.0, 3)));
+/// # }
+/// ```
+///
+/// # Panics
+///
+/// Panics if radix is not in the range `[2, 36]`
+///
+/// [`parse_partial_radix`]: fn.parse_partial_radix.html
+#[cfg(feature = "radix")]
+#[inline]
+pub fn parse_partial_lossy_radix<N: FromLexicalLossy, Bytes: AsRef<[u8]>>(bytes: Bytes, radix: u8)
+    -> Result<(N, usize)>
+{
+    N::from_lexical_partial_lossy_radix(bytes.as_ref(), radix)
+}
+
+/// High-level conversion of bytes to a number with a custom radix and custom options.
+///
+/// This function only returns a value if the entire string is
+/// successfully parsed.
+///
+/// * `bytes`   - Byte slice to convert to number.
+/// * `radix`   - Number of unique digits for the number (base).
+/// * `options` - Options to customize the number parsing.
+///
+/// # Panics
+///
+/// Panics if radix is not in range `[2, 36]`.
+#[inline]
+#[cfg(feature = "radix")]
+pub fn parse_radix_with_options<N: FromLexicalWithOptions, Bytes: AsRef<[u8]>>(bytes: Bytes, radix: u8, options: &N::Options)
+    -> Result<N>
+{
+    N::from_lexical_radix_with_options(bytes.as_ref(), radix, options)
+}
+
+/// High-level conversion of bytes to a number with a custom radix and custom options.
+///
+/// This functions parses as many digits as possible, returning the parsed
+/// value and the number of digits processed if at least one character
+/// is processed.
+///
+/// * `bytes`   - Byte slice to convert to number.
+/// * `radix`   - Number of unique digits for the number (base).
+/// * `options` - Options to customize the number parsing.
+///
+/// # Panics
+///
+/// Panics if radix is not in range `[2, 36]`.
+#[inline]
+#[cfg(feature = "radix")]
+pub fn parse_partial_radix_with_options<N: FromLexicalWithOptions, Bytes: AsRef<[u8]>>(bytes: Bytes, radix: u8, options: &N::Options)
+    -> Result<(N, usize)>
+{
+    N::from_lexical_partial_radix_with_options(bytes.as_ref(), radix, options)
+}
+
+/// High-level conversion of bytes to a float with a custom radix, with parse metrics.
+///
+/// This function parses the entire string, returning an error if any
+/// invalid digits are found. Along with the value, returns metrics
+/// on the number of significant digits consumed, the parsed exponent,
+/// and if the value required the slower, arbitrary-precision paths.
+///
+/// * `bytes`   - Byte slice to convert to number.
+/// * `radix`   - Number of unique digits for the number (base).
+///
+/// # Panics
+///
+/// Panics if radix is not in range `[2, 36]`.
+#[inline]
+#[cfg(all(feature = "metrics", feature = "radix"))]
+pub fn parse_with_metrics_radix<N: FromLexicalMetrics, Bytes: AsRef<[u8]>>(bytes: Bytes, radix: u8)
+    -> Result<(N, ParseMetrics)>
+{
+    N::from_lexical_metrics_radix(bytes.as_ref(), radix)
+}
+
+/// High-level, format-dependent conversion of decimal-encoded bytes to a number.
+///
+/// This function only returns a value if the entire string is
+/// successfully parsed. The numerical format is specified by
+/// the format bitflags, which customize the required components,
+/// digit separators, and other parameters of the number.
+///
+/// * `bytes`   - Byte slice to convert to number.
+/// * `format`  - Numerical format.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # use lexical::ErrorCode;
+/// # pub fn main() {
+/// // Create our error.
+/// fn err_code<T>(r: lexical::Result<T>) -> ErrorCode {
+///     r.err().unwrap().code
+/// }
+///
+/// let format = lexical::NumberFormat::RUST_STRING;
+///
+/// // String overloads
+/// assert_eq!(lexical::parse_format::<i32, _>("5", format), Ok(5));
+/// assert_eq!(err_code(lexical::parse_format::<i32, _>("1a", format)), ErrorCode::InvalidDigit);
+/// assert_eq!(lexical::parse_format::<f32, _>("0", format), Ok(0.0));
+/// assert_eq!(lexical::parse_format::<f32, _>("1.0", format), Ok(1.0));
+/// assert_eq!(lexical::parse_format::<f32, _>("1.", format), Ok(1.0));
+///
+/// // Bytes overloads
+/// assert_eq!(lexical::parse_format::<i32, _>(b"5", format), Ok(5));
+/// assert_eq!(err_code(lexical::parse_format::<i32, _>(b"1a", format)), ErrorCode::InvalidDigit);
+/// assert_eq!(lexical::parse_format::<f32, _>(b"0", format), Ok(0.0));
+/// assert_eq!(lexical::parse_format::<f32, _>(b"1.0", format), Ok(1.0));
+/// assert_eq!(lexical::parse_format::<f32, _>(b"1.", format), Ok(1.0));
+/// # assert_eq!(lexical::parse_format::<f32, _>(b"5.002868148396374", format), Ok(5.002868148396374));
+/// # assert_eq!(lexical::parse_format::<f64, _>(b"5.002868148396374", format), Ok(5.002868148396374));
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "format")]
+pub fn parse_format<N: FromLexicalFormat, Bytes: AsRef<[u8]>>(bytes: Bytes, format: NumberFormat)
+    -> Result<N>
+{
+    N::from_lexical_format(bytes.as_ref(), format)
+}
+
+/// High-level, partial, format-dependent conversion of decimal-encoded bytes to a number.
+///
+/// This functions parses as many digits as possible, returning the parsed
+/// value and the number of digits processed if at least one character
+/// is processed. If another error, such as numerical overflow or underflow
+/// occurs, this function returns the error code and the index at which
+/// the error occurred. The numerical format is specified by the format
+/// bitflags, which customize the required components, digit separators,
+/// and other parameters of the number.
+///
+/// * `bytes`   - Byte slice to convert to number.
+/// * `format`  - Numerical format.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # use lexical::ErrorCode;
+/// # pub fn main() {
+///
+/// let format = lexical::NumberFormat::RUST_STRING;
+///
+/// // String overloads
+/// assert_eq!(lexical::parse_partial_format::<i32, _>("5", format), Ok((5, 1)));
+/// assert_eq!(lexical::parse_partial_format::<i32, _>("1a", format), Ok((1, 1)));
+/// assert_eq!(lexical::parse_partial_format::<f32, _>("0", format), Ok((0.0, 1)));
+/// assert_eq!(lexical::parse_partial_format::<f32, _>("1.0", format), Ok((1.0, 3)));
+/// assert_eq!(lexical::parse_partial_format::<f32, _>("1.", format), Ok((1.0, 2)));
+///
+/// // Bytes overloads
+/// assert_eq!(lexical::parse_partial_format::<i32, _>(b"5", format), Ok((5, 1)));
+/// assert_eq!(lexical::parse_partial_format::<i32, _>(b"1a", format), Ok((1, 1)));
+/// assert_eq!(lexical::parse_partial_format::<f32, _>(b"0", format), Ok((0.0, 1)));
+/// assert_eq!(lexical::parse_partial_format::<f32, _>(b"1.0", format), Ok((1.0, 3)));
+/// assert_eq!(lexical::parse_partial_format::<f32, _>(b"1.", format), Ok((1.0, 2)));
+/// # assert_eq!(lexical::parse_partial_format::<f32, _>(b"5.002868148396374", format), Ok((5.002868148396374, 17)));
+/// # assert_eq!(lexical::parse_partial_format::<f64, _>(b"5.002868148396374", format), Ok((5.002868148396374, 17)));
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "format")]
+pub fn parse_partial_format<N: FromLexicalFormat, Bytes: AsRef<[u8]>>(bytes: Bytes, format: NumberFormat)
+    -> Result<(N, usize)>
+{
+    N::from_lexical_partial_format(bytes.as_ref(), format)
+}
+
+/// High-level, lossy, format-dependent conversion of decimal-encoded bytes to a number.
+///
+/// This function uses aggressive optimizations to avoid worst-case
+/// scenarios, and can return inaccurate results. For guaranteed accurate
+/// floats, use [`parse_format`]. The numerical format is specified by
+/// the format bitflags, which customize the required components, digit
+/// separators, and other parameters of the number.
+///
+/// * `bytes`   - Byte slice to convert to number.
+/// * `format`  - Numerical format.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # use lexical::ErrorCode;
+/// # pub fn main() {
+/// // Get our error code.
+/// fn err_code<T>(r: lexical::Result<T>) -> ErrorCode {
+///     r.err().unwrap().code
+/// }
+///
+/// let format = lexical::NumberFormat::RUST_STRING;
+///
+/// // String overloads
+/// assert_eq!(lexical::parse_lossy_format::<f32, _>("0", format), Ok(0.0));
+/// assert_eq!(lexical::parse_lossy_format::<f32, _>("1.0", format), Ok(1.0));
+/// assert_eq!(lexical::parse_lossy_format::<f32, _>("1.", format), Ok(1.0));
+/// assert_eq!(err_code(lexical::parse_lossy_format::<f32, _>("1a", format)), ErrorCode::InvalidDigit);
+///
+/// // Bytes overloads
+/// assert_eq!(lexical::parse_lossy_format::<f32, _>(b"0", format), Ok(0.0));
+/// assert_eq!(lexical::parse_lossy_format::<f32, _>(b"1.0", format), Ok(1.0));
+/// assert_eq!(lexical::parse_lossy_format::<f32, _>(b"1.", format), Ok(1.0));
+/// assert_eq!(err_code(lexical::parse_lossy_format::<f32, _>(b"1a", format)), ErrorCode::InvalidDigit);
+/// # }
+/// ```
+///
+/// [`parse_format`]: fn.parse_format.html
+#[inline]
+#[cfg(feature = "format")]
+pub fn parse_lossy_format<N: FromLexicalLossyFormat, Bytes: AsRef<[u8]>>(bytes: Bytes, format: NumberFormat)
+    -> Result<N>
+{
+    N::from_lexical_lossy_format(bytes.as_ref(), format)
+}
+
+/// High-level, partial, lossy, format-dependent conversion of decimal-encoded bytes to a number.
+///
+/// This function uses aggressive optimizations to avoid worst-case
+/// scenarios, and can return inaccurate results. For guaranteed accurate
+/// floats, use [`parse_partial_format`].
+///
+/// This functions parses as many digits as possible, returning the parsed
+/// value and the number of digits processed if at least one character
+/// is processed. If another error, such as numerical overflow or underflow
+/// occurs, this function returns the error code and the index at which
+/// the error occurred. The numerical format is specified by the format
+/// bitflags, which customize the required components, digit separators,
+/// and other parameters of the number.
+///
+/// * `bytes`   - Byte slice to convert to number.
+/// * `format`  - Numerical format.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # use lexical::ErrorCode;
+/// # pub fn main() {
+///
+/// let format = lexical::NumberFormat::RUST_STRING;
+///
+/// // String overloads
+/// assert_eq!(lexical::parse_partial_lossy_format::<f32, _>("0", format), Ok((0.0, 1)));
+/// assert_eq!(lexical::parse_partial_lossy_format::<f32, _>("1.0", format), Ok((1.0, 3)));
+/// assert_eq!(lexical::parse_partial_lossy_format::<f32, _>("1.", format), Ok((1.0, 2)));
+///
+/// // Bytes overloads
+/// assert_eq!(lexical::parse_partial_lossy_format::<f32, _>(b"0", format), Ok((0.0, 1)));
+/// assert_eq!(lexical::parse_partial_lossy_format::<f32, _>(b"1.0", format), Ok((1.0, 3)));
+/// assert_eq!(lexical::parse_partial_lossy_format::<f32, _>(b"1.", format), Ok((1.0, 2)));
+/// # }
+/// ```
+///
+/// [`parse_partial_format`]: fn.parse_partial_format.html
+#[inline]
+#[cfg(feature = "format")]
+pub fn parse_partial_lossy_format<N: FromLexicalLossyFormat, Bytes: AsRef<[u8]>>(bytes: Bytes, format: NumberFormat)
+    -> Result<(N, usize)>
+{
+    N::from_lexical_partial_lossy_format(bytes.as_ref(), format)
+}
+
+/// High-level, format-dependent conversion of bytes to a number with a custom radix.
+///
+/// This function only returns a value if the entire string is
+/// successfully parsed. The numerical format is specified by the format
+/// bitflags, which customize the required components, digit separators,
+/// and other parameters of the number.
+///
+/// * `bytes`   - Byte slice to convert to number.
+/// * `radix`   - Number of unique digits for the number (base).
+/// * `format`  - Numerical format.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # use lexical::ErrorCode;
+/// # pub fn main() {
+/// // Get our error code wrapper.
+/// fn err_code<T>(r: lexical::Result<T>) -> ErrorCode {
+///     r.err().unwrap().code
+/// }
+///
+/// let format = lexical::NumberFormat::RUST_STRING;
+///
+/// // String overloads
+/// assert_eq!(lexical::parse_format_radix::<i32, _>("5", 10, format), Ok(5));
+/// assert_eq!(err_code(lexical::parse_format_radix::<i32, _>("1a", 10, format)), ErrorCode::InvalidDigit);
+/// assert_eq!(err_code(lexical::parse_format_radix::<i32, _>("1.", 10, format)), ErrorCode::InvalidDigit);
+/// assert_eq!(lexical::parse_format_radix::<f32, _>("0", 10, format), Ok(0.0));
+/// assert_eq!(lexical::parse_format_radix::<f32, _>("1.0", 10, format), Ok(1.0));
+/// assert_eq!(lexical::parse_format_radix::<f32, _>("1.", 10, format), Ok(1.0));
+/// assert_eq!(err_code(lexical::parse_format_radix::<f32, _>("1a", 10, format)), ErrorCode::InvalidDigit);
+/// assert_eq!(err_code(lexical::parse_format_radix::<f32, _>("1.0.", 10, format)), ErrorCode::InvalidDigit);
+///
+/// // Bytes overloads
+/// assert_eq!(lexical::parse_format_radix::<i32, _>(b"5", 10, format), Ok(5));
+/// assert_eq!(err_code(lexical::parse_format_radix::<i32, _>(b"1a", 10, format)), ErrorCode::InvalidDigit);
+/// assert_eq!(lexical::parse_format_radix::<f32, _>(b"0", 10, format), Ok(0.0));
+/// assert_eq!(lexical::parse_format_radix::<f32, _>(b"1.0", 10, format), Ok(1.0));
+/// assert_eq!(lexical::parse_format_radix::<f32, _>(b"1.", 10, format), Ok(1.0));
+/// assert_eq!(err_code(lexical::parse_format_radix::<f32, _>(b"1a", 10, format)), ErrorCode::InvalidDigit);
+/// assert_eq!(err_code(lexical::parse_format_radix::<f32, _>(b"1.0.", 10, format)), ErrorCode::InvalidDigit);
+/// # }
+/// ```
+///
+/// # Panics
+///
+/// Panics if radix is not in the range `[2, 36]`
+#[inline]
+#[cfg(all(feature = "radix", feature = "format"))]
+pub fn parse_format_radix<N: FromLexicalFormat, Bytes: AsRef<[u8]>>(bytes: Bytes, radix: u8, format: NumberFormat)
+    -> Result<N>
+{
+    N::from_lexical_format_radix(bytes.as_ref(), radix, format)
+}
+
+/// High-level, partial, format-dependent conversion of bytes to a number with a custom radix.
+///
+/// This functions parses as many digits as possible, returning the parsed
+/// value and the number of digits processed if at least one character
+/// is processed. If another error, such as numerical overflow or underflow
+/// occurs, this function returns the error code and the index at which
+/// the error occurred. The numerical format is specified by the format
+/// bitflags, which customize the required components, digit separators,
+/// and other parameters of the number.
+///
+/// * `bytes`   - Byte slice to convert to number.
+/// * `radix`   - Number of unique digits for the number (base).
+/// * `format`  - Numerical format.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # use lexical::ErrorCode;
+/// # pub fn main() {
+///
+/// let format = lexical::NumberFormat::RUST_STRING;
+///
+/// // String overloads
+/// assert_eq!(lexical::parse_partial_format_radix::<i32, _>("5", 10, format), Ok((5, 1)));
+/// assert_eq!(lexical::parse_partial_format_radix::<i32, _>("1a", 10, format), Ok((1, 1)));
+/// assert_eq!(lexical::parse_partial_format_radix::<i32, _>("1.", 10, format), Ok((1, 1)));
+/// assert_eq!(lexical::parse_partial_format_radix::<f32, _>("0", 10, format), Ok((0.0, 1)));
+/// assert_eq!(lexical::parse_partial_format_radix::<f32, _>("1.0", 10, format), Ok((1.0, 3)));
+/// assert_eq!(lexical::parse_partial_format_radix::<f32, _>("1.", 10, format), Ok((1.0, 2)));
+/// assert_eq!(lexical::parse_partial_format_radix::<f32, _>("1a", 10, format), Ok((1.0, 1)));
+/// assert_eq!(lexical::parse_partial_format_radix::<f32, _>("1.0.", 10, format), Ok((1.0, 3)));
+///
+/// // Bytes overloads
+/// assert_eq!(lexical::parse_partial_format_radix::<i32, _>(b"5", 10, format), Ok((5, 1)));
+/// assert_eq!(lexical::parse_partial_format_radix::<i32, _>(b"1a", 10, format), Ok((1, 1)));
+/// assert_eq!(lexical::parse_partial_format_radix::<i32, _>(b"1.", 10, format), Ok((1, 1)));
+/// assert_eq!(lexical::parse_partial_format_radix::<f32, _>(b"0", 10, format), Ok((0.0, 1)));
+/// assert_eq!(lexical::parse_partial_format_radix::<f32, _>(b"1.0", 10, format), Ok((1.0, 3)));
+/// assert_eq!(lexical::parse_partial_format_radix::<f32, _>(b"1.", 10, format), Ok((1.0, 2)));
+/// assert_eq!(lexical::parse_partial_format_radix::<f32, _>(b"1a", 10, format), Ok((1.0, 1)));
+/// assert_eq!(lexical::parse_partial_format_radix::<f32, _>(b"1.0.", 10, format), Ok((1.0, 3)));
+/// # }
+/// ```
+///
+/// # Panics
+///
+/// Panics if radix is not in the range `[2, 36]`
+#[inline]
+#[cfg(all(feature = "radix", feature = "format"))]
+pub fn parse_partial_format_radix<N: FromLexicalFormat, Bytes: AsRef<[u8]>>(bytes: Bytes, radix: u8, format: NumberFormat)
+    -> Result<(N, usize)>
+{
+    N::from_lexical_partial_format_radix(bytes.as_ref(), radix, format)
+}
+
+/// High-level, lossy, format-dependent conversion of bytes to a float with a custom radix.
+///
+/// This function uses aggressive optimizations to avoid worst-case
+/// scenarios, and can return inaccurate results. For guaranteed accurate
+/// floats, use [`parse_format_radix`]. The numerical format is specified
+/// by the format bitflags, which customize the required components,
+/// digit separators, and other parameters of the number.
+///
+/// * `bytes`   - Byte slice to convert to number.
+/// * `radix`   - Number of unique digits for the number (base).
+/// * `format`  - Numerical format.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # use lexical::ErrorCode;
+/// # pub fn main() {
+/// // Create our error wrapper.
+/// fn err_code<T>(r: lexical::Result<T>) -> ErrorCode {
+///     r.err().unwrap().code
+/// }
+///
+/// let format = lexical::NumberFormat::RUST_STRING;
+///
+/// // String overloads
+/// assert_eq!(lexical::parse_lossy_format_radix::<f32, _>("0", 10, format), Ok(0.0));
+/// assert_eq!(lexical::parse_lossy_format_radix::<f32, _>("1.0", 10, format), Ok(1.0));
+/// assert_eq!(lexical::parse_lossy_format_radix::<f32, _>("1.", 10, format), Ok(1.0));
+/// assert_eq!(err_code(lexical::parse_lossy_format_radix::<f32, _>("1a", 10, format)), ErrorCode::InvalidDigit);
+/// assert_eq!(err_code(lexical::parse_lossy_format_radix::<f32, _>("1.0.", 10, format)), ErrorCode::InvalidDigit);
+///
+/// // Bytes overloads
+/// assert_eq!(lexical::parse_lossy_format_radix::<f32, _>(b"0", 10, format), Ok(0.0));
+/// assert_eq!(lexical::parse_lossy_format_radix::<f32, _>(b"1.0", 10, format), Ok(1.0));
+/// assert_eq!(lexical::parse_lossy_format_radix::<f32, _>(b"1.", 10, format), Ok(1.0));
+/// assert_eq!(err_code(lexical::parse_lossy_format_radix::<f32, _>(b"1a", 10, format)), ErrorCode::InvalidDigit);
+/// assert_eq!(err_code(lexical::parse_lossy_format_radix::<f32, _>(b"1.0.", 10, format)), ErrorCode::InvalidDigit);
+/// # }
+/// ```
+///
+/// # Panics
+///
+/// Panics if radix is not in the range `[2, 36]`
+///
+/// [`parse_format_radix`]: fn.parse_format_radix.html
+#[inline]
+#[cfg(all(feature = "radix", feature = "format"))]
+pub fn parse_lossy_format_radix<N: FromLexicalLossyFormat, Bytes: AsRef<[u8]>>(bytes: Bytes, radix: u8, format: NumberFormat)
+    -> Result<N>
+{
+    N::from_lexical_lossy_format_radix(bytes.as_ref(), radix, format)
+}
+
+/// High-level, partial, lossy, format-dependent conversion of bytes to a number with a custom radix.
+///
+/// This function uses aggressive optimizations to avoid worst-case
+/// scenarios, and can return inaccurate results. For guaranteed accurate
+/// floats, use [`parse_partial_format_radix`]. The numerical format is
+/// specified by the format bitflags, which customize the required
+/// components, digit separators, and other parameters of the number.
+///
+/// This functions parses as many digits as possible, returning the parsed
+/// value and the number of digits processed if at least one character
+/// is processed. If another error, such as numerical overflow or underflow
+/// occurs, this function returns the error code and the index at which
+/// the error occurred.
+///
+/// * `bytes`   - Byte slice to convert to number.
+/// * `radix`   - Number of unique digits for the number (base).
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # use lexical::ErrorCode;
+/// # pub fn main() {
+///
+/// let format = lexical::NumberFormat::RUST_STRING;
+///
+/// // String overloads
+/// assert_eq!(lexical::parse_partial_lossy_format_radix::<f32, _>("0", 10, format), Ok((0.0, 1)));
+/// assert_eq!(lexical::parse_partial_lossy_format_radix::<f32, _>("1.0", 10, format), Ok((1.0, 3)));
+/// assert_eq!(lexical::parse_partial_lossy_format_radix::<f32, _>("1.", 10, format), Ok((1.0, 2)));
+/// assert_eq!(lexical::parse_partial_lossy_format_radix::<f32, _>("1a", 10, format), Ok((1.0, 1)));
+/// assert_eq!(lexical::parse_partial_lossy_format_radix::<f32, _>("1.0.", 10, format), Ok((1.0, 3)));
+///
+/// // Bytes overloads
+/// assert_eq!(lexical::parse_partial_lossy_format_radix::<f32, _>(b"0", 10, format), Ok((0.0, 1)));
+/// assert_eq!(lexical::parse_partial_lossy_format_radix::<f32, _>(b"1.0", 10, format), Ok((1.0, 3)));
+/// assert_eq!(lexical::parse_partial_lossy_format_radix::<f32, _>(b"1.", 10, format), Ok((1.0, 2)));
+/// assert_eq!(lexical::parse_partial_lossy_format_radix::<f32, _>(b"1a", 10, format), Ok((1.0, 1)));
+/// assert_eq!(lexical::parse_partial_lossy_format_radix::<f32, _>(b"1.0.", 10, format), Ok((1.0, 3)));
+/// # }
+/// ```
+///
+/// # Panics
+///
+/// Panics if radix is not in the range `[2, 36]`
+///
+/// [`parse_partial_format_radix`]: fn.parse_partial_format_radix.html
+#[inline]
+#[cfg(all(feature = "radix", feature = "format"))]
+pub fn parse_partial_lossy_format_radix<N: FromLexicalLossyFormat, Bytes: AsRef<[u8]>>(bytes: Bytes, radix: u8, format: NumberFormat)
+    -> Result<(N, usize)>
+{
+    N::from_lexical_partial_lossy_format_radix(bytes.as_ref(), radix, format)
+}
+
+/// High-level conversion of decimal-encoded bytes to an integer or float.
+///
+/// The bytes are parsed as an `i64`, unless the integer overflows or
+/// the number contains a decimal point or exponent, in which case the
+/// bytes are parsed as an `f64`.
+///
+/// * `bytes`   - Byte slice to convert to number.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # use lexical::IntOrFloat;
+/// # pub fn main() {
+/// assert_eq!(lexical::parse_int_or_float("42"), Ok(IntOrFloat::Int(42)));
+/// assert_eq!(lexical::parse_int_or_float("4.2"), Ok(IntOrFloat::Float(4.2)));
+/// assert_eq!(lexical::parse_int_or_float(b"1e100"), Ok(IntOrFloat::Float(1e100)));
+/// # }
+/// ```
+#[inline]
+pub fn parse_int_or_float<Bytes: AsRef<[u8]>>(bytes: Bytes)
+    -> Result<IntOrFloat>
+{
+    lexical_core::parse_int_or_float(bytes.as_ref())
+}
+
+/// High-level, partial conversion of decimal-encoded bytes to an integer or float.
+///
+/// The bytes are parsed as an `i64`, unless the integer overflows or
+/// is followed by a decimal point or exponent, in which case the bytes
+/// are parsed as an `f64`. Returns the value and the number of processed
+/// bytes.
+///
+/// * `bytes`   - Byte slice to convert to number.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # use lexical::IntOrFloat;
+/// # pub fn main() {
+/// assert_eq!(lexical::parse_partial_int_or_float("42,"), Ok((IntOrFloat::Int(42), 2)));
+/// assert_eq!(lexical::parse_partial_int_or_float("4.2,"), Ok((IntOrFloat::Float(4.2), 3)));
+/// # }
+/// ```
+#[inline]
+pub fn parse_partial_int_or_float<Bytes: AsRef<[u8]>>(bytes: Bytes)
+    -> Result<(IntOrFloat, usize)>
+{
+    lexical_core::parse_partial_int_or_float(bytes.as_ref())
+}
+
+/// High-level conversion of decimal-encoded bytes to an integer, or to a float if allowed.
+///
+/// If floats are not allowed, the bytes are parsed as an `i64`, and a
+/// decimal point or exponent is an invalid digit. Otherwise, the bytes
+/// are always parsed as an `f64`.
+///
+/// * `bytes`       - Byte slice to convert to number.
+/// * `allow_float` - If the bytes are parsed as a float, rather than an integer.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # use lexical::{ErrorCode, IntOrFloat};
+/// # pub fn main() {
+/// assert_eq!(lexical::parse_strict_number("15", false), Ok(IntOrFloat::Int(15)));
+/// assert_eq!(lexical::parse_strict_number("15", true), Ok(IntOrFloat::Float(15.0)));
+/// assert_eq!(lexical::parse_strict_number("1.5", false).err().map(|e| e.code), Some(ErrorCode::InvalidDigit));
+/// # }
+/// ```
+#[inline]
+pub fn parse_strict_number<Bytes: AsRef<[u8]>>(bytes: Bytes, allow_float: bool)
+    -> Result<IntOrFloat>
+{
+    lexical_core::parse_strict_number(bytes.as_ref(), allow_float)
+}
+
+/// High-level conversion of decimal-encoded bytes to a float, and if it was written as a float.
+///
+/// The flag is `false` if the bytes are only decimal digits, after an
+/// optional sign, and `true` if the bytes contain a decimal point or an
+/// exponent, or are a special value.
+///
+/// * `bytes`   - Byte slice to convert to number.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// assert_eq!(lexical::parse_float_typed("5"), Ok((5.0, false)));
+/// assert_eq!(lexical::parse_float_typed("5.0"), Ok((5.0, true)));
+/// # }
+/// ```
+#[inline]
+pub fn parse_float_typed<Bytes: AsRef<[u8]>>(bytes: Bytes)
+    -> Result<(f64, bool)>
+{
+    lexical_core::parse_float_typed(bytes.as_ref())
+}
+
+/// High-level conversion of decimal-encoded bytes to a rounded and clamped integer.
+///
+/// The bytes are parsed as an `f64`, which is rounded to an integral
+/// value with the rounding scheme, and then saturated to the range of
+/// the integer. `NaN` is an error.
+///
+/// * `bytes`       - Byte slice to convert to number.
+/// * `rounding`    - Rounding scheme for the fractional digits.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # use lexical::RoundingMode;
+/// # pub fn main() {
+/// let kind = RoundingMode::NearestTieEven;
+/// assert_eq!(lexical::parse_int_clamped::<u8, _>("300.7", kind), Ok(255));
+/// assert_eq!(lexical::parse_int_clamped::<u8, _>("-5", kind), Ok(0));
+/// assert_eq!(lexical::parse_int_clamped::<i32, _>("-2.7", RoundingMode::TowardZero), Ok(-2));
+/// # }
+/// ```
+#[inline]
+pub fn parse_int_clamped<N: Integer, Bytes: AsRef<[u8]>>(bytes: Bytes, rounding: RoundingMode)
+    -> Result<N>
+{
+    lexical_core::parse_int_clamped(bytes.as_ref(), rounding)
+}
+
+/// High-level, partial conversion of decimal-encoded bytes to a rounded and clamped integer.
+///
+/// The bytes are parsed as an `f64`, which is rounded to an integral
+/// value with the rounding scheme, and then saturated to the range of
+/// the integer. Returns the value and the number of processed bytes.
+///
+/// * `bytes`       - Byte slice to convert to number.
+/// * `rounding`    - Rounding scheme for the fractional digits.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # use lexical::RoundingMode;
+/// # pub fn main() {
+/// let kind = RoundingMode::NearestTieEven;
+/// assert_eq!(lexical::parse_partial_int_clamped::<u8, _>("300.7,", kind), Ok((255, 5)));
+/// # }
+/// ```
+#[inline]
+pub fn parse_partial_int_clamped<N: Integer, Bytes: AsRef<[u8]>>(bytes: Bytes, rounding: RoundingMode)
+    -> Result<(N, usize)>
+{
+    lexical_core::parse_partial_int_clamped(bytes.as_ref(), rounding)
+}
+
+/// High-level conversion of decimal-encoded bytes to a float saturated to the domain.
+///
+/// Values above `domain_max` are clamped to `domain_max`, and values
+/// below `-domain_max` are clamped to `-domain_max`, including
+/// infinities. `NaN` is returned unchanged.
+///
+/// * `bytes`       - Byte slice to convert to number.
+/// * `domain_max`  - Largest absolute value in the domain.
+///
+/// # Panics
+///
+/// Panics if `domain_max` is negative or `NaN`.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// assert_eq!(lexical::parse_saturating_float("9999", 5000.0), Ok(5000.0));
+/// assert_eq!(lexical::parse_saturating_float("-12.5", 5000.0), Ok(-12.5));
+/// # }
+/// ```
+#[inline]
+pub fn parse_saturating_float<Bytes: AsRef<[u8]>>(bytes: Bytes, domain_max: f64)
+    -> Result<f64>
+{
+    lexical_core::parse_saturating_float(bytes.as_ref(), domain_max)
+}
+
+/// High-level conversion of decimal-encoded bytes to a number within inclusive bounds.
+///
+/// Values that overflow the type are still reported as `Overflow`,
+/// while valid values below `min` or above `max` are reported as
+/// `OutOfRange` at the start of the bytes. A bound of `None` is
+/// unbounded.
+///
+/// * `bytes`   - Byte slice to convert to number.
+/// * `min`     - Inclusive lower bound, if any.
+/// * `max`     - Inclusive upper bound, if any.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # use lexical::{Error, ErrorCode};
+/// # pub fn main() {
+/// assert_eq!(lexical::parse_bounded::<i32, _>("42", Some(0), Some(100)), Ok(42));
+/// assert_eq!(lexical::parse_bounded::<i32, _>("101", Some(0), Some(100)), Err(Error::from((ErrorCode::OutOfRange, 0))));
+/// # }
+/// ```
+#[inline]
+pub fn parse_bounded<N: FromLexical + PartialOrd, Bytes: AsRef<[u8]>>(bytes: Bytes, min: Option<N>, max: Option<N>)
+    -> Result<N>
+{
+    lexical_core::parse_bounded(bytes.as_ref(), min, max)
+}
+
+/// High-level, partial conversion of decimal-encoded bytes to a number within inclusive bounds.
+///
+/// Returns the value and the number of processed bytes. Valid values
+/// below `min` or above `max` are reported as `OutOfRange` at the
+/// start of the bytes.
+///
+/// * `bytes`   - Byte slice to convert to number.
+/// * `min`     - Inclusive lower bound, if any.
+/// * `max`     - Inclusive upper bound, if any.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// assert_eq!(lexical::parse_partial_bounded::<u16, _>("1999,", Some(1900), Some(2100)), Ok((1999, 4)));
+/// # }
+/// ```
+#[inline]
+pub fn parse_partial_bounded<N: FromLexical + PartialOrd, Bytes: AsRef<[u8]>>(bytes: Bytes, min: Option<N>, max: Option<N>)
+    -> Result<(N, usize)>
+{
+    lexical_core::parse_partial_bounded(bytes.as_ref(), min, max)
+}
+
+/// High-level conversion of decimal-encoded bytes to a non-zero integer.
+///
+/// Zero is reported as [`Zero`](enum.ErrorCode.html#variant.Zero) at
+/// the start of the bytes, otherwise, the bytes are parsed the same as
+/// [`parse`](fn.parse.html) for the primitive integer.
+///
+/// * `bytes`   - Byte slice to convert to number.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # use lexical::{Error, ErrorCode};
+/// # use std::num::NonZeroU32;
+/// # pub fn main() {
+/// assert_eq!(lexical::parse_nonzero::<NonZeroU32, _>("1"), Ok(NonZeroU32::new(1).unwrap()));
+/// assert_eq!(lexical::parse_nonzero::<NonZeroU32, _>("0"), Err(Error::from((ErrorCode::Zero, 0))));
+/// # }
+/// ```
+#[inline]
+pub fn parse_nonzero<N: NonZeroInteger, Bytes: AsRef<[u8]>>(bytes: Bytes) -> Result<N> {
+    lexical_core::parse_nonzero(bytes.as_ref())
+}
+
+/// High-level conversion of hexadecimal-encoded bytes to an unsigned integer.
+///
+/// Digits are case-insensitive, and neither a sign nor a `0x` prefix is
+/// accepted.
+///
+/// * `bytes`   - Byte slice to convert to number.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// assert_eq!(lexical::parse_hex::<u32, _>("deadbeef"), Ok(0xdeadbeef));
+/// assert_eq!(lexical::parse_hex::<u8, _>("0F"), Ok(0x0F));
+/// # }
+/// ```
+#[inline]
+pub fn parse_hex<T: UnsignedInteger, Bytes: AsRef<[u8]>>(bytes: Bytes) -> Result<T> {
+    lexical_core::parse_hex(bytes.as_ref())
+}
+
+/// High-level conversion of hexadecimal-encoded bytes filling the full width of an unsigned integer.
+///
+/// The bytes must have exactly 2 digits per byte of the type, like 8
+/// digits for `u32`.
+///
+/// * `bytes`   - Byte slice to convert to number.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # use lexical::{Error, ErrorCode};
+/// # pub fn main() {
+/// assert_eq!(lexical::parse_hex_fixed::<u32, _>("0000beef"), Ok(0xbeef));
+/// assert_eq!(lexical::parse_hex_fixed::<u32, _>("beef"), Err(Error::from((ErrorCode::Empty, 4))));
+/// # }
+/// ```
+#[inline]
+pub fn parse_hex_fixed<T: UnsignedInteger, Bytes: AsRef<[u8]>>(bytes: Bytes) -> Result<T> {
+    lexical_core::parse_hex_fixed(bytes.as_ref())
+}
+
+/// High-level conversion of hexadecimal-encoded bytes in groups of 2 digits to an unsigned integer.
+///
+/// Every group must have exactly 2 digits, and the groups must be
+/// separated by exactly one separator, like a hex dump.
+///
+/// * `bytes`       - Byte slice to convert to number.
+/// * `separator`   - Character separating the groups.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # use lexical::{Error, ErrorCode};
+/// # pub fn main() {
+/// assert_eq!(lexical::parse_hex_grouped::<u32, _>("DE AD BE EF", b' '), Ok(0xDEADBEEF));
+/// assert_eq!(lexical::parse_hex_grouped::<u32, _>("DE A", b' '), Err(Error::from((ErrorCode::Empty, 4))));
+/// # }
+/// ```
+#[inline]
+pub fn parse_hex_grouped<T: UnsignedInteger, Bytes: AsRef<[u8]>>(bytes: Bytes, separator: u8) -> Result<T> {
+    lexical_core::parse_hex_grouped(bytes.as_ref(), separator)
+}
+
+/// High-level conversion of decimal-encoded bytes to the floats bracketing the exact value.
+///
+/// Returns the greatest float less than or equal to the exact value,
+/// and the least float greater than or equal to the exact value. If
+/// the exact value is representable, both floats are equal to it.
+///
+/// * `bytes`   - Byte slice to convert to number.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// assert_eq!(lexical::parse_bracket::<f64, _>("0.5"), Ok((0.5, 0.5)));
+/// assert_eq!(lexical::parse_bracket::<f64, _>("0.1"), Ok((0.09999999999999999, 0.1)));
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "correct")]
+pub fn parse_bracket<F: FromLexical + Float, Bytes: AsRef<[u8]>>(bytes: Bytes)
+    -> Result<(F, F)>
+{
+    lexical_core::parse_bracket(bytes.as_ref())
+}
+
+/// High-level conversion of decimal-encoded bytes to the bracketing floats and the halfway ordering.
+///
+/// Returns the floats bracketing the exact value, like [`parse_bracket`],
+/// and the ordering of the exact value relative to the halfway point
+/// between the floats, which can be rounded with [`reround`].
+///
+/// * `bytes`   - Byte slice to convert to number.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # use lexical::RoundingMode;
+/// # use std::cmp::Ordering;
+/// # pub fn main() {
+/// let (floor, ceil, halfway) = lexical::parse_bracket_halfway::<f64, _>("0.1").unwrap();
+/// assert_eq!(halfway, Ordering::Greater);
+/// assert_eq!(lexical::reround(floor, ceil, halfway, RoundingMode::TowardZero), 0.09999999999999999);
+/// # }
+/// ```
+///
+/// [`parse_bracket`]: fn.parse_bracket.html
+/// [`reround`]: fn.reround.html
+#[inline]
+#[cfg(feature = "correct")]
+pub fn parse_bracket_halfway<F: FromLexical + Float, Bytes: AsRef<[u8]>>(bytes: Bytes)
+    -> Result<(F, F, lib::cmp::Ordering)>
+{
+    lexical_core::parse_bracket_halfway(bytes.as_ref())
+}
+
+/// High-level conversion of decimal-encoded bytes to an optional number.
+///
+/// Returns `None` if the bytes match any of the missing tokens,
+/// ignoring ASCII case, otherwise, parses the bytes the same as
+/// [`parse`]. An empty token treats empty bytes as missing.
+///
+/// * `bytes`           - Byte slice to convert to number.
+/// * `missing_tokens`  - Tokens for missing values.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// let missing = ["NA", "-", ""];
+/// assert_eq!(lexical::parse_or_missing::<f64, _, _>("1.5", &missing), Ok(Some(1.5)));
+/// assert_eq!(lexical::parse_or_missing::<f64, _, _>("na", &missing), Ok(None));
+/// assert_eq!(lexical::parse_or_missing::<f64, _, _>("", &missing), Ok(None));
+/// # }
+/// ```
+///
+/// [`parse`]: fn.parse.html
+#[inline]
+#[cfg(feature = "optional")]
+pub fn parse_or_missing<N, Bytes, Token>(bytes: Bytes, missing_tokens: &[Token])
+    -> Result<Option<N>>
+    where N: FromLexical,
+          Bytes: AsRef<[u8]>,
+          Token: AsRef<[u8]>
+{
+    let bytes = bytes.as_ref();
+    if missing_tokens.iter().any(|token| token.as_ref().eq_ignore_ascii_case(bytes)) {
+        Ok(None)
+    } else {
+        N::from_lexical(bytes).map(Some)
+    }
+}
+
+/// High-level conversion of decimal-encoded bytes to the bit pattern of an `f64`.
+///
+/// The bytes are parsed the same as [`parse`], and the bits of the
+/// float are returned. `NaN` is parsed to the canonical quiet `NaN`,
+/// `0x7FF8000000000000`.
+///
+/// * `bytes`   - Byte slice to convert to number.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// assert_eq!(lexical::parse_to_bits("1.5"), Ok(0x3FF8000000000000));
+/// assert_eq!(lexical::parse_to_bits("-0.0"), Ok(0x8000000000000000));
+/// # }
+/// ```
+///
+/// [`parse`]: fn.parse.html
+#[inline]
+pub fn parse_to_bits<Bytes: AsRef<[u8]>>(bytes: Bytes) -> Result<u64> {
+    lexical_core::parse_to_bits(bytes.as_ref())
+}
+
+/// High-level conversion of decimal-encoded bytes to an `f64`, checking if the bytes are canonical.
+///
+/// The bytes are parsed the same as [`parse`], and returns the float,
+/// and if the bytes are exactly the string [`to_string`] writes for
+/// the float, so non-canonical numbers, like `"1.50"` or `"1E16"`,
+/// are flagged. The canonical form is written without allocating.
+///
+/// * `bytes`   - Byte slice to convert to number.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// assert_eq!(lexical::parse_canonical("2.5"), Ok((2.5, true)));
+/// assert_eq!(lexical::parse_canonical("02.50"), Ok((2.5, false)));
+/// assert_eq!(lexical::parse_canonical("2.5e0"), Ok((2.5, false)));
+/// # }
+/// ```
+///
+/// [`parse`]: fn.parse.html
+/// [`to_string`]: fn.to_string.html
+#[inline]
+pub fn parse_canonical<Bytes: AsRef<[u8]>>(bytes: Bytes) -> Result<(f64, bool)> {
+    lexical_core::parse_canonical(bytes.as_ref())
+}
+
+/// High-level conversion of fractional seconds to nanoseconds.
+///
+/// The bytes must start with a `.`, followed by at least 1 digit, like
+/// the fractional seconds of RFC 3339 timestamps. Up to 9 digits are
+/// scaled to nanoseconds, and any further digits are truncated.
+/// Returns the nanoseconds and the number of processed bytes.
+///
+/// * `bytes`   - Byte slice containing fractional seconds.
+///
+/// # Examples
 ///
-/// // Bytes overloads
-/// assert_eq!(lexical::parse_partial_radix::<i32, _>(b"5", 10), Ok((5, 1)));
-/// assert_eq!(lexical::parse_partial_radix::<i32, _>(b"1a", 10), Ok((1, 1)));
-/// assert_eq!(lexical::parse_partial_radix::<i32, _>(b"1.", 10), Ok((1, 1)));
-/// assert_eq!(lexical::parse_partial_radix::<f32, _>(b"0", 10), Ok((0.0, 1)));
-/// assert_eq!(lexical::parse_partial_radix::<f32, _>(b"1.0", 10), Ok((1.0, 3)));
-/// assert_eq!(lexical::parse_partial_radix::<f32, _>(b"1.", 10), Ok((1.0, 2)));
-/// assert_eq!(lexical::parse_partial_radix::<f32, _>(b"1a", 10), Ok((1.0, 1)));
-/// assert_eq!(lexical::parse_partial_radix::<f32, _>(b"1.0.", 10), Ok((1.0, 3)));
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// assert_eq!(lexical::parse_fractional_nanos(".1"), Ok((100000000, 2)));
+/// assert_eq!(lexical::parse_fractional_nanos(".1234567891Z"), Ok((123456789, 11)));
 /// # }
 /// ```
+#[inline]
+pub fn parse_fractional_nanos<Bytes: AsRef<[u8]>>(bytes: Bytes) -> Result<(u32, usize)> {
+    lexical_core::parse_fractional_nanos(bytes.as_ref())
+}
+
+/// High-level conversion of decimal seconds, like `"1.5"`, to a duration.
 ///
-/// # Panics
+/// The whole seconds are optionally followed by a `.` and up to 9
+/// fractional digits, which are kept exactly, with nanosecond
+/// precision, without rounding through a float. Further fractional
+/// digits are truncated. Seconds that do not fit in a `Duration` are
+/// reported as [`Overflow`](enum.ErrorCode.html#variant.Overflow).
 ///
-/// Panics if radix is not in the range `[2, 36]`
-#[cfg(feature = "radix")]
+/// * `bytes`   - Byte slice containing decimal seconds.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # use std::time::Duration;
+/// # use lexical::ErrorCode;
+/// # pub fn main() {
+/// assert_eq!(lexical::parse_duration_secs("1.5"), Ok(Duration::new(1, 500_000_000)));
+/// assert_eq!(lexical::parse_duration_secs("0.1"), Ok(Duration::from_millis(100)));
+/// assert_eq!(lexical::parse_duration_secs("99999999999999999999").err().unwrap().code, ErrorCode::Overflow);
+/// # }
+/// ```
 #[inline]
-pub fn parse_partial_radix<N: FromLexical, Bytes: AsRef<[u8]>>(bytes: Bytes, radix: u8)
-    -> Result<(N, usize)>
-{
-    N::from_lexical_partial_radix(bytes.as_ref(), radix)
+pub fn parse_duration_secs<Bytes: AsRef<[u8]>>(bytes: Bytes) -> Result<lib::time::Duration> {
+    lexical_core::parse_duration_secs(bytes.as_ref())
 }
 
-/// High-level, lossy conversion of bytes to a float with a custom radix.
+/// High-level conversion of decimal-encoded bytes to a float and its base-10 order of magnitude.
 ///
-/// This function uses aggressive optimizations to avoid worst-case
-/// scenarios, and can return inaccurate results. For guaranteed accurate
-/// floats, use [`parse_radix`].
+/// Returns the value and `floor(log10(|value|))`, computed exactly from
+/// the significant digits of the string, so the magnitude is correct
+/// near powers of ten. Zero and special values have a magnitude of
+/// `i32::MIN`.
 ///
 /// * `bytes`   - Byte slice to convert to number.
-/// * `radix`   - Number of unique digits for the number (base).
 ///
 /// # Examples
 ///
 /// ```rust
 /// # extern crate lexical;
-/// # use lexical::ErrorCode;
 /// # pub fn main() {
-/// // Create our error wrapper.
-/// fn err_code<T>(r: lexical::Result<T>) -> ErrorCode {
-///     r.err().unwrap().code
-/// }
+/// assert_eq!(lexical::parse_with_magnitude("1234.5"), Ok((1234.5, 3)));
+/// assert_eq!(lexical::parse_with_magnitude("1e-3"), Ok((0.001, -3)));
+/// # }
+/// ```
+#[inline]
+pub fn parse_with_magnitude<Bytes: AsRef<[u8]>>(bytes: Bytes) -> Result<(f64, i32)> {
+    lexical_core::parse_with_magnitude(bytes.as_ref())
+}
+
+/// Count the significant digits of a decimal-encoded float.
 ///
-/// // String overloads
-/// assert_eq!(lexical::parse_lossy_radix::<f32, _>("0", 10), Ok(0.0));
-/// assert_eq!(lexical::parse_lossy_radix::<f32, _>("1.0", 10), Ok(1.0));
-/// assert_eq!(lexical::parse_lossy_radix::<f32, _>("1.", 10), Ok(1.0));
-/// assert_eq!(err_code(lexical::parse_lossy_radix::<f32, _>("1a", 10)), ErrorCode::InvalidDigit);
-/// assert_eq!(err_code(lexical::parse_lossy_radix::<f32, _>("1.0.", 10)), ErrorCode::InvalidDigit);
+/// Leading zeros and the decimal point are not significant, while every
+/// digit after the first non-zero digit is, including trailing zeros,
+/// so `"0.00120"` and `"100"` both have 3 significant digits. The
+/// exponent is never counted, zero has a single significant digit, and
+/// special values have none.
 ///
-/// // Bytes overloads
-/// assert_eq!(lexical::parse_lossy_radix::<f32, _>(b"0", 10), Ok(0.0));
-/// assert_eq!(lexical::parse_lossy_radix::<f32, _>(b"1.0", 10), Ok(1.0));
-/// assert_eq!(lexical::parse_lossy_radix::<f32, _>(b"1.", 10), Ok(1.0));
-/// assert_eq!(err_code(lexical::parse_lossy_radix::<f32, _>(b"1a", 10)), ErrorCode::InvalidDigit);
-/// assert_eq!(err_code(lexical::parse_lossy_radix::<f32, _>(b"1.0.", 10)), ErrorCode::InvalidDigit);
+/// * `bytes`   - Byte slice containing a numeric string.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// assert_eq!(lexical::significant_digits("0.00120"), Ok(3));
+/// assert_eq!(lexical::significant_digits("1.5e10"), Ok(2));
 /// # }
 /// ```
+#[inline]
+pub fn significant_digits<Bytes: AsRef<[u8]>>(bytes: Bytes) -> Result<usize> {
+    lexical_core::significant_digits(bytes.as_ref())
+}
+
+/// Decompose a decimal-encoded float into its sign, significant digits and exponent.
 ///
-/// # Panics
+/// Parses until an invalid digit is found, and returns if the value is
+/// negative, the significant digits without a decimal point or leading
+/// zeros, the power-of-ten exponent, and the number of bytes consumed,
+/// so the value is exactly `digits * 10^exponent`. Zero has no digits
+/// and an exponent of `0`. The digits are copied into `buffer`, which
+/// should be at least as long as the bytes.
 ///
-/// Panics if radix is not in the range `[2, 36]`
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `buffer`  - Buffer to write the significant digits to.
 ///
-/// [`parse_radix`]: fn.parse_radix.html
-#[cfg(feature = "radix")]
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// let mut buffer = [0u8; 16];
+/// assert_eq!(lexical::decompose_decimal("-1.50e3", &mut buffer), Ok((true, &b"150"[..], 1, 7)));
+/// assert_eq!(lexical::decompose_decimal("0.0012", &mut buffer), Ok((false, &b"12"[..], -4, 6)));
+/// # }
+/// ```
 #[inline]
-pub fn parse_lossy_radix<N: FromLexicalLossy, Bytes: AsRef<[u8]>>(bytes: Bytes, radix: u8)
-    -> Result<N>
+pub fn decompose_decimal<'a, Bytes: AsRef<[u8]>>(bytes: Bytes, buffer: &'a mut [u8])
+    -> Result<(bool, &'a [u8], i32, usize)>
 {
-    N::from_lexical_lossy_radix(bytes.as_ref(), radix)
+    lexical_core::decompose_decimal(bytes.as_ref(), buffer)
 }
 
-/// High-level, partial lossy conversion of bytes to a number with a custom radix.
+/// High-level conversion of decimal-encoded bytes to the byte ranges of the components of a float.
 ///
-/// This function uses aggressive optimizations to avoid worst-case
-/// scenarios, and can return inaccurate results. For guaranteed accurate
-/// floats, use [`parse_partial_radix`].
+/// Parses until an invalid digit is found, and returns the ranges of
+/// the sign, the integer digits, the fraction digits, and the exponent,
+/// and the number of bytes consumed. Empty components have empty ranges,
+/// and the decimal point and exponent character are not in any range.
 ///
-/// This functions parses as many digits as possible, returning the parsed
-/// value and the number of digits processed if at least one character
-/// is processed. If another error, such as numerical overflow or underflow
-/// occurs, this function returns the error code and the index at which
-/// the error occurred.
+/// * `bytes`   - Byte slice containing a numeric string.
 ///
-/// * `bytes`   - Byte slice to convert to number.
-/// * `radix`   - Number of unique digits for the number (base).
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// let (spans, count) = lexical::parse_spans("-12.34e5").unwrap();
+/// assert_eq!((spans.sign, spans.integer, spans.fraction, spans.exponent), (0..1, 1..3, 4..6, 7..8));
+/// assert_eq!(count, 8);
+/// # }
+/// ```
+#[inline]
+pub fn parse_spans<Bytes: AsRef<[u8]>>(bytes: Bytes) -> Result<(NumberSpans, usize)> {
+    lexical_core::parse_spans(bytes.as_ref())
+}
+
+/// High-level conversion of decimal-encoded bytes to a float followed by a unit.
+///
+/// The unit is every byte directly after the float, up to the first
+/// ASCII whitespace. Returns the value, the unit, and the number of
+/// bytes consumed, including the unit.
+///
+/// * `bytes`   - Byte slice containing a numeric string and a unit.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// assert_eq!(lexical::parse_quantity("3.5kg "), Ok((3.5, &b"kg"[..], 5)));
+/// assert_eq!(lexical::parse_quantity("1.5eV"), Ok((1.5, &b"eV"[..], 5)));
+/// # }
+/// ```
+#[inline]
+pub fn parse_quantity<'a, Bytes: AsRef<[u8]> + ?Sized>(bytes: &'a Bytes) -> Result<(f64, &'a [u8], usize)> {
+    lexical_core::parse_quantity(bytes.as_ref())
+}
+
+/// High-level conversion of decimal-encoded bytes to a float followed by a unit, ending the unit at any of the delimiters.
+///
+/// The unit is every byte directly after the float, up to the first
+/// of the delimiters. Returns the value, the unit, and the number of
+/// bytes consumed, including the unit.
+///
+/// * `bytes`       - Byte slice containing a numeric string and a unit.
+/// * `delimiters`  - Bytes ending the unit.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// assert_eq!(lexical::parse_quantity_delimited("5 km;", b";"), Ok((5.0, &b" km"[..], 4)));
+/// # }
+/// ```
+#[inline]
+pub fn parse_quantity_delimited<'a, Bytes: AsRef<[u8]> + ?Sized>(bytes: &'a Bytes, delimiters: &[u8])
+    -> Result<(f64, &'a [u8], usize)>
+{
+    lexical_core::parse_quantity_delimited(bytes.as_ref(), delimiters)
+}
+
+/// High-level conversion of a ratio of decimal-encoded integers, like `16:9`, to a float.
+///
+/// Both integers are unsigned, and are separated by a `:`. Returns the
+/// float ratio and the two integers. A ratio with a zero second
+/// integer is an error.
+///
+/// * `bytes`   - Byte slice containing a ratio.
 ///
 /// # Examples
 ///
@@ -568,442 +2799,488 @@ pub fn parse_lossy_radix<N: FromLexicalLossy, Bytes: AsRef<[u8]>>(bytes: Bytes,
 /// # extern crate lexical;
 /// # use lexical::ErrorCode;
 /// # pub fn main() {
+/// assert_eq!(lexical::parse_colon_ratio::<f64, _>("3:2"), Ok((1.5, 3, 2)));
+/// assert_eq!(lexical::parse_colon_ratio::<f64, _>("16:0").err().unwrap().code, ErrorCode::Zero);
+/// # }
+/// ```
+#[inline]
+pub fn parse_colon_ratio<N: Float, Bytes: AsRef<[u8]>>(bytes: Bytes) -> Result<(N, u64, u64)> {
+    lexical_core::parse_colon_ratio::<N>(bytes.as_ref())
+}
+
+/// High-level conversion of a decimal-encoded number to the exact ratio of two integers.
 ///
-/// // String overloads
-/// assert_eq!(lexical::parse_partial_lossy_radix::<f32, _>("0", 10), Ok((0.0, 1)));
-/// assert_eq!(lexical::parse_partial_lossy_radix::<f32, _>("1.0", 10), Ok((1.0, 3)));
-/// assert_eq!(lexical::parse_partial_lossy_radix::<f32, _>("1.", 10), Ok((1.0, 2)));
-/// assert_eq!(lexical::parse_partial_lossy_radix::<f32, _>("1a", 10), Ok((1.0, 1)));
-/// assert_eq!(lexical::parse_partial_lossy_radix::<f32, _>("1.0.", 10), Ok((1.0, 3)));
+/// The ratio is the significant digits over the power of ten, reduced
+/// to lowest terms, with the sign on the numerator, so `"0.25"` is
+/// `(1, 4)`. A numerator or denominator that does not fit in 128 bits
+/// is an error.
 ///
-/// // Bytes overloads
-/// assert_eq!(lexical::parse_partial_lossy_radix::<f32, _>(b"0", 10), Ok((0.0, 1)));
-/// assert_eq!(lexical::parse_partial_lossy_radix::<f32, _>(b"1.0", 10), Ok((1.0, 3)));
-/// assert_eq!(lexical::parse_partial_lossy_radix::<f32, _>(b"1.", 10), Ok((1.0, 2)));
-/// assert_eq!(lexical::parse_partial_lossy_radix::<f32, _>(b"1a", 10), Ok((1.0, 1)));
-/// assert_eq!(lexical::parse_partial_lossy_radix::<f32, _>(b"1.0.", 10), Ok((1.0, 3)));
+/// * `bytes`   - Byte slice containing a decimal number.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// assert_eq!(lexical::parse_rational("0.25"), Ok((1, 4)));
+/// assert_eq!(lexical::parse_rational("-1.5"), Ok((-3, 2)));
 /// # }
 /// ```
+#[inline]
+pub fn parse_rational<Bytes: AsRef<[u8]>>(bytes: Bytes) -> Result<(i128, u128)> {
+    lexical_core::parse_rational(bytes.as_ref())
+}
+
+/// High-level conversion of many decimal-encoded fields to numbers.
 ///
-/// # Panics
+/// Each field is parsed independently, so every invalid field is
+/// reported, rather than stopping at the first error. The result for
+/// each field is at the same index as the field, and the index of any
+/// error is relative to the start of that field.
 ///
-/// Panics if radix is not in the range `[2, 36]`
+/// * `fields`  - Slice of byte slices to convert to numbers.
 ///
-/// [`parse_partial_radix`]: fn.parse_partial_radix.html
-#[cfg(feature = "radix")]
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # use lexical::{Error, ErrorCode};
+/// # pub fn main() {
+/// let results = lexical::parse_all::<u8, _>(&["1", "2a", "300"]);
+/// assert_eq!(results, vec![
+///     Ok(1),
+///     Err(Error::from((ErrorCode::InvalidDigit, 1, b'a'))),
+///     Err(Error::from((ErrorCode::Overflow, 2))),
+/// ]);
+/// # }
+/// ```
 #[inline]
-pub fn parse_partial_lossy_radix<N: FromLexicalLossy, Bytes: AsRef<[u8]>>(bytes: Bytes, radix: u8)
-    -> Result<(N, usize)>
+pub fn parse_all<N: FromLexical, Bytes: AsRef<[u8]>>(fields: &[Bytes])
+    -> lib::Vec<Result<N>>
 {
-    N::from_lexical_partial_lossy_radix(bytes.as_ref(), radix)
+    fields.iter().map(|field| N::from_lexical(field.as_ref())).collect()
 }
 
-/// High-level, format-dependent conversion of decimal-encoded bytes to a number.
+/// High-level conversion of many fields with a custom radix to numbers.
 ///
-/// This function only returns a value if the entire string is
-/// successfully parsed. The numerical format is specified by
-/// the format bitflags, which customize the required components,
-/// digit separators, and other parameters of the number.
+/// Each field is parsed independently, so every invalid field is
+/// reported, rather than stopping at the first error. The result for
+/// each field is at the same index as the field, and the index of any
+/// error is relative to the start of that field.
 ///
-/// * `bytes`   - Byte slice to convert to number.
-/// * `format`  - Numerical format.
+/// * `fields`  - Slice of byte slices to convert to numbers.
+/// * `radix`   - Number of unique digits for the number (base).
+///
+/// # Panics
+///
+/// Panics if radix is not in range `[2, 36]`.
 ///
 /// # Examples
 ///
 /// ```rust
 /// # extern crate lexical;
-/// # use lexical::ErrorCode;
+/// # use lexical::{Error, ErrorCode};
 /// # pub fn main() {
-/// // Create our error.
-/// fn err_code<T>(r: lexical::Result<T>) -> ErrorCode {
-///     r.err().unwrap().code
-/// }
+/// let results = lexical::parse_all_radix::<u8, _>(&["ff", "fg"], 16);
+/// assert_eq!(results, vec![Ok(255), Err(Error::from((ErrorCode::InvalidDigit, 1, b'g')))]);
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "radix")]
+pub fn parse_all_radix<N: FromLexical, Bytes: AsRef<[u8]>>(fields: &[Bytes], radix: u8)
+    -> lib::Vec<Result<N>>
+{
+    fields.iter().map(|field| N::from_lexical_radix(field.as_ref(), radix)).collect()
+}
+
+/// High-level conversion of many fields to numbers, in parallel.
 ///
-/// let format = lexical::NumberFormat::RUST_STRING;
+/// The fields are split across the threads of the global rayon thread
+/// pool. Each field is parsed independently, with no shared mutable
+/// state, so the results are identical to [`parse_all`], and the
+/// result for each field is at the same index as the field. The index
+/// of any error is relative to the start of that field.
 ///
-/// // String overloads
-/// assert_eq!(lexical::parse_format::<i32, _>("5", format), Ok(5));
-/// assert_eq!(err_code(lexical::parse_format::<i32, _>("1a", format)), ErrorCode::InvalidDigit);
-/// assert_eq!(lexical::parse_format::<f32, _>("0", format), Ok(0.0));
-/// assert_eq!(lexical::parse_format::<f32, _>("1.0", format), Ok(1.0));
-/// assert_eq!(lexical::parse_format::<f32, _>("1.", format), Ok(1.0));
+/// The NaN and infinity strings and the exponent characters are read
+/// while parsing, so they must not be changed while parsing.
 ///
-/// // Bytes overloads
-/// assert_eq!(lexical::parse_format::<i32, _>(b"5", format), Ok(5));
-/// assert_eq!(err_code(lexical::parse_format::<i32, _>(b"1a", format)), ErrorCode::InvalidDigit);
-/// assert_eq!(lexical::parse_format::<f32, _>(b"0", format), Ok(0.0));
-/// assert_eq!(lexical::parse_format::<f32, _>(b"1.0", format), Ok(1.0));
-/// assert_eq!(lexical::parse_format::<f32, _>(b"1.", format), Ok(1.0));
-/// # assert_eq!(lexical::parse_format::<f32, _>(b"5.002868148396374", format), Ok(5.002868148396374));
-/// # assert_eq!(lexical::parse_format::<f64, _>(b"5.002868148396374", format), Ok(5.002868148396374));
+/// * `fields`  - Slice of byte slices to convert to numbers.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # use lexical::{Error, ErrorCode};
+/// # pub fn main() {
+/// let results = lexical::par_parse_column::<f64, _>(&["1.5", "2a", "-3e2"]);
+/// assert_eq!(results, vec![
+///     Ok(1.5),
+///     Err(Error::from((ErrorCode::InvalidDigit, 1, b'a'))),
+///     Ok(-300.0),
+/// ]);
+///
+/// let fields: Vec<String> = (0..10000).map(|i| format!("{}.25", i)).collect();
+/// let results = lexical::par_parse_column::<f64, _>(&fields);
+/// assert_eq!(results, lexical::parse_all::<f64, _>(&fields));
 /// # }
 /// ```
+///
+/// [`parse_all`]: fn.parse_all.html
 #[inline]
-#[cfg(feature = "format")]
-pub fn parse_format<N: FromLexicalFormat, Bytes: AsRef<[u8]>>(bytes: Bytes, format: NumberFormat)
-    -> Result<N>
+#[cfg(feature = "rayon")]
+pub fn par_parse_column<N, Bytes>(fields: &[Bytes])
+    -> lib::Vec<Result<N>>
+    where N: FromLexical + Send,
+          Bytes: AsRef<[u8]> + Sync
 {
-    N::from_lexical_format(bytes.as_ref(), format)
+    use rayon::prelude::*;
+    fields.par_iter().map(|field| N::from_lexical(field.as_ref())).collect()
 }
 
-/// High-level, partial, format-dependent conversion of decimal-encoded bytes to a number.
+/// High-level conversion of delimited, decimal-encoded fields to counts in histogram bins.
 ///
-/// This functions parses as many digits as possible, returning the parsed
-/// value and the number of digits processed if at least one character
-/// is processed. If another error, such as numerical overflow or underflow
-/// occurs, this function returns the error code and the index at which
-/// the error occurred. The numerical format is specified by the format
-/// bitflags, which customize the required components, digit separators,
-/// and other parameters of the number.
+/// The bins are the edges between bins, in ascending order, and there
+/// must be one more count than bins. Values below the first edge are
+/// counted in the first count, values in `[bins[i-1], bins[i])` in
+/// count `i`, and values at or above the last edge in the last count.
+/// `NaN` values are not counted. Returns the number of counted values.
 ///
-/// * `bytes`   - Byte slice to convert to number.
-/// * `format`  - Numerical format.
+/// Parsing stops at the first invalid field, and the index of the
+/// error is relative to the start of the bytes. Values before the
+/// invalid field remain counted.
+///
+/// * `bytes`       - Byte slice containing delimited numeric strings.
+/// * `delimiter`   - Byte separating each field.
+/// * `bins`        - Ascending edges between the bins.
+/// * `counts`      - Counts for each bin, incremented for each value.
+///
+/// # Panics
+///
+/// Panics if `counts` does not have exactly one more element than `bins`.
 ///
 /// # Examples
 ///
 /// ```rust
 /// # extern crate lexical;
-/// # use lexical::ErrorCode;
 /// # pub fn main() {
+/// let mut counts = [0u64; 3];
+/// assert_eq!(lexical::parse_into_histogram("0.2 0.7 1.5 -3", b' ', &[0.0, 1.0], &mut counts), Ok(4));
+/// assert_eq!(counts, [1, 2, 1]);
+/// # }
+/// ```
+#[inline]
+pub fn parse_into_histogram<Bytes: AsRef<[u8]>>(bytes: Bytes, delimiter: u8, bins: &[f64], counts: &mut [u64])
+    -> Result<usize>
+{
+    lexical_core::parse_into_histogram(bytes.as_ref(), delimiter, bins, counts)
+}
+
+/// High-level conversion of a column of adjacent, fixed-width fields to integers.
 ///
-/// let format = lexical::NumberFormat::RUST_STRING;
+/// Parses `count` values, each from the next `width` bytes, without
+/// any delimiters. Any bytes after the last field are ignored. The
+/// index of any error is relative to the start of the bytes, and if
+/// the bytes end before the last field, the error is
+/// [`Empty`](enum.ErrorCode.html#variant.Empty) at the end of the bytes.
 ///
-/// // String overloads
-/// assert_eq!(lexical::parse_partial_format::<i32, _>("5", format), Ok((5, 1)));
-/// assert_eq!(lexical::parse_partial_format::<i32, _>("1a", format), Ok((1, 1)));
-/// assert_eq!(lexical::parse_partial_format::<f32, _>("0", format), Ok((0.0, 1)));
-/// assert_eq!(lexical::parse_partial_format::<f32, _>("1.0", format), Ok((1.0, 3)));
-/// assert_eq!(lexical::parse_partial_format::<f32, _>("1.", format), Ok((1.0, 2)));
+/// * `bytes`   - Byte slice containing adjacent numeric strings.
+/// * `width`   - Number of bytes in each field.
+/// * `count`   - Number of fields to parse.
 ///
-/// // Bytes overloads
-/// assert_eq!(lexical::parse_partial_format::<i32, _>(b"5", format), Ok((5, 1)));
-/// assert_eq!(lexical::parse_partial_format::<i32, _>(b"1a", format), Ok((1, 1)));
-/// assert_eq!(lexical::parse_partial_format::<f32, _>(b"0", format), Ok((0.0, 1)));
-/// assert_eq!(lexical::parse_partial_format::<f32, _>(b"1.0", format), Ok((1.0, 3)));
-/// assert_eq!(lexical::parse_partial_format::<f32, _>(b"1.", format), Ok((1.0, 2)));
-/// # assert_eq!(lexical::parse_partial_format::<f32, _>(b"5.002868148396374", format), Ok((5.002868148396374, 17)));
-/// # assert_eq!(lexical::parse_partial_format::<f64, _>(b"5.002868148396374", format), Ok((5.002868148396374, 17)));
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # use lexical::{Error, ErrorCode};
+/// # pub fn main() {
+/// let years = lexical::parse_fixed_width_column::<u16, _>("199920202021", 4, 3);
+/// assert_eq!(years, Ok(vec![1999, 2020, 2021]));
+///
+/// let years = lexical::parse_fixed_width_column::<u16, _>("19992O20", 4, 2);
+/// assert_eq!(years, Err(Error::from((ErrorCode::InvalidDigit, 5, b'O'))));
 /// # }
 /// ```
 #[inline]
-#[cfg(feature = "format")]
-pub fn parse_partial_format<N: FromLexicalFormat, Bytes: AsRef<[u8]>>(bytes: Bytes, format: NumberFormat)
-    -> Result<(N, usize)>
+pub fn parse_fixed_width_column<N: FromLexical + Integer, Bytes: AsRef<[u8]>>(bytes: Bytes, width: usize, count: usize)
+    -> Result<lib::Vec<N>>
 {
-    N::from_lexical_partial_format(bytes.as_ref(), format)
+    let mut values = lib::Vec::new();
+    values.resize(count, N::ZERO);
+    lexical_core::parse_fixed_width_column(bytes.as_ref(), width, &mut values)?;
+    Ok(values)
 }
 
-/// High-level, lossy, format-dependent conversion of decimal-encoded bytes to a number.
+/// High-level conversion of a column of numeric fields to `f64`.
 ///
-/// This function uses aggressive optimizations to avoid worst-case
-/// scenarios, and can return inaccurate results. For guaranteed accurate
-/// floats, use [`parse_format`]. The numerical format is specified by
-/// the format bitflags, which customize the required components, digit
-/// separators, and other parameters of the number.
+/// Each field is parsed as an `f64`, so a column may mix integers and
+/// floats, like `"5"` and `"5.5"`. Fields that are integers within the
+/// range of an `i64` are parsed as integers and converted, so they are
+/// always exact, like `"5"` to `5.0`, or rounded to the nearest float,
+/// even without the `correct` feature. Returns the index of the first
+/// field that fails to parse, and the error for that field.
 ///
-/// * `bytes`   - Byte slice to convert to number.
-/// * `format`  - Numerical format.
+/// * `fields`  - Slice of numeric strings.
 ///
 /// # Examples
 ///
 /// ```rust
 /// # extern crate lexical;
-/// # use lexical::ErrorCode;
+/// # use lexical::{Error, ErrorCode};
 /// # pub fn main() {
-/// // Get our error code.
-/// fn err_code<T>(r: lexical::Result<T>) -> ErrorCode {
-///     r.err().unwrap().code
-/// }
-///
-/// let format = lexical::NumberFormat::RUST_STRING;
-///
-/// // String overloads
-/// assert_eq!(lexical::parse_lossy_format::<f32, _>("0", format), Ok(0.0));
-/// assert_eq!(lexical::parse_lossy_format::<f32, _>("1.0", format), Ok(1.0));
-/// assert_eq!(lexical::parse_lossy_format::<f32, _>("1.", format), Ok(1.0));
-/// assert_eq!(err_code(lexical::parse_lossy_format::<f32, _>("1a", format)), ErrorCode::InvalidDigit);
+/// let fields: Vec<&[u8]> = "5,5.5,-2".split(',').map(str::as_bytes).collect();
+/// assert_eq!(lexical::parse_numeric_column(&fields), Ok(vec![5.0, 5.5, -2.0]));
 ///
-/// // Bytes overloads
-/// assert_eq!(lexical::parse_lossy_format::<f32, _>(b"0", format), Ok(0.0));
-/// assert_eq!(lexical::parse_lossy_format::<f32, _>(b"1.0", format), Ok(1.0));
-/// assert_eq!(lexical::parse_lossy_format::<f32, _>(b"1.", format), Ok(1.0));
-/// assert_eq!(err_code(lexical::parse_lossy_format::<f32, _>(b"1a", format)), ErrorCode::InvalidDigit);
+/// let fields: Vec<&[u8]> = "5,5.5x".split(',').map(str::as_bytes).collect();
+/// let error = Error::from((ErrorCode::InvalidDigit, 3, b'x'));
+/// assert_eq!(lexical::parse_numeric_column(&fields), Err((1, error)));
 /// # }
 /// ```
-///
-/// [`parse_format`]: fn.parse_format.html
 #[inline]
-#[cfg(feature = "format")]
-pub fn parse_lossy_format<N: FromLexicalLossyFormat, Bytes: AsRef<[u8]>>(bytes: Bytes, format: NumberFormat)
-    -> Result<N>
+pub fn parse_numeric_column(fields: &[&[u8]])
+    -> lib::result::Result<lib::Vec<f64>, (usize, Error)>
 {
-    N::from_lexical_lossy_format(bytes.as_ref(), format)
+    fields.iter().enumerate().map(|(index, field)| {
+        // Zero may be negative zero, so parse it as a float.
+        match i64::from_lexical(field) {
+            Ok(value) if value != 0 => Ok(value as f64),
+            _ => f64::from_lexical(field).map_err(|error| (index, error)),
+        }
+    }).collect()
 }
 
-/// High-level, partial, lossy, format-dependent conversion of decimal-encoded bytes to a number.
+/// High-level conversion of bytes to a number, using the radix prefix if present.
 ///
-/// This function uses aggressive optimizations to avoid worst-case
-/// scenarios, and can return inaccurate results. For guaranteed accurate
-/// floats, use [`parse_partial_format`].
+/// A leading `0x`, `0o`, or `0b` prefix, case-insensitive, parses the
+/// remaining bytes in radix 16, 8, or 2, respectively, otherwise, the
+/// bytes are parsed in the default radix. Returns the value and the
+/// radix used to parse it. Any sign must come before the prefix, like
+/// `"-0x5"`, and a sign after the prefix, like `"0x-5"`, is an error.
 ///
-/// This functions parses as many digits as possible, returning the parsed
-/// value and the number of digits processed if at least one character
-/// is processed. If another error, such as numerical overflow or underflow
-/// occurs, this function returns the error code and the index at which
-/// the error occurred. The numerical format is specified by the format
-/// bitflags, which customize the required components, digit separators,
-/// and other parameters of the number.
+/// * `bytes`           - Byte slice to convert to number.
+/// * `default_radix`   - Number of unique digits for the number (base) without a prefix.
 ///
-/// * `bytes`   - Byte slice to convert to number.
-/// * `format`  - Numerical format.
+/// # Panics
+///
+/// Panics if the default radix is not in range `[2, 36]`.
 ///
 /// # Examples
 ///
 /// ```rust
 /// # extern crate lexical;
-/// # use lexical::ErrorCode;
 /// # pub fn main() {
+/// assert_eq!(lexical::parse_flexible::<i64, _>("0b101", 10), Ok((5, 2)));
+/// assert_eq!(lexical::parse_flexible::<i64, _>("-0x5", 10), Ok((-5, 16)));
+/// assert_eq!(lexical::parse_flexible::<i64, _>("17", 10), Ok((17, 10)));
+/// assert_eq!(lexical::parse_flexible::<i64, _>("17", 8), Ok((15, 8)));
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "radix")]
+pub fn parse_flexible<N: FromLexical, Bytes: AsRef<[u8]>>(bytes: Bytes, default_radix: u8)
+    -> Result<(N, u8)>
+{
+    lexical_core::parse_flexible::<N>(bytes.as_ref(), default_radix)
+}
+
+/// High-level conversion of bytes to an integer, using the radix prefixes from the options.
 ///
-/// let format = lexical::NumberFormat::RUST_STRING;
+/// Identical to [`parse_flexible`], except the radix prefixes are
+/// taken from the [`ParseIntegerOptions`], ignoring ASCII case.
 ///
-/// // String overloads
-/// assert_eq!(lexical::parse_partial_lossy_format::<f32, _>("0", format), Ok((0.0, 1)));
-/// assert_eq!(lexical::parse_partial_lossy_format::<f32, _>("1.0", format), Ok((1.0, 3)));
-/// assert_eq!(lexical::parse_partial_lossy_format::<f32, _>("1.", format), Ok((1.0, 2)));
+/// * `bytes`           - Byte slice to convert to number.
+/// * `default_radix`   - Number of unique digits for the number (base) without a prefix.
+/// * `options`         - Options to specify the radix prefixes and parsing.
 ///
-/// // Bytes overloads
-/// assert_eq!(lexical::parse_partial_lossy_format::<f32, _>(b"0", format), Ok((0.0, 1)));
-/// assert_eq!(lexical::parse_partial_lossy_format::<f32, _>(b"1.0", format), Ok((1.0, 3)));
-/// assert_eq!(lexical::parse_partial_lossy_format::<f32, _>(b"1.", format), Ok((1.0, 2)));
+/// # Panics
+///
+/// Panics if the default radix is not in range `[2, 36]`.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # use lexical::ParseIntegerOptions;
+/// # pub fn main() {
+/// let options = ParseIntegerOptions::builder()
+///     .hex_prefix(b"$")
+///     .build()
+///     .unwrap();
+/// assert_eq!(lexical::parse_flexible_with_options::<i64, _>("$FF", 10, &options), Ok((255, 16)));
 /// # }
 /// ```
 ///
-/// [`parse_partial_format`]: fn.parse_partial_format.html
+/// [`parse_flexible`]: fn.parse_flexible.html
+/// [`ParseIntegerOptions`]: struct.ParseIntegerOptions.html
 #[inline]
-#[cfg(feature = "format")]
-pub fn parse_partial_lossy_format<N: FromLexicalLossyFormat, Bytes: AsRef<[u8]>>(bytes: Bytes, format: NumberFormat)
-    -> Result<(N, usize)>
+#[cfg(feature = "radix")]
+pub fn parse_flexible_with_options<N, Bytes: AsRef<[u8]>>(bytes: Bytes, default_radix: u8, options: &ParseIntegerOptions)
+    -> Result<(N, u8)>
+    where N: FromLexicalWithOptions<Options=ParseIntegerOptions>
 {
-    N::from_lexical_partial_lossy_format(bytes.as_ref(), format)
+    lexical_core::parse_flexible_with_options::<N>(bytes.as_ref(), default_radix, options)
 }
 
-/// High-level, format-dependent conversion of bytes to a number with a custom radix.
+/// High-level conversion of decimal-encoded bytes to the sign and magnitude of an integer.
 ///
-/// This function only returns a value if the entire string is
-/// successfully parsed. The numerical format is specified by the format
-/// bitflags, which customize the required components, digit separators,
-/// and other parameters of the number.
+/// At most one leading `+` or `-` sign is parsed, followed by the
+/// unsigned magnitude. Returns if the sign is negative, the magnitude,
+/// and the number of processed bytes.
 ///
 /// * `bytes`   - Byte slice to convert to number.
-/// * `radix`   - Number of unique digits for the number (base).
-/// * `format`  - Numerical format.
 ///
 /// # Examples
 ///
 /// ```rust
 /// # extern crate lexical;
-/// # use lexical::ErrorCode;
 /// # pub fn main() {
-/// // Get our error code wrapper.
-/// fn err_code<T>(r: lexical::Result<T>) -> ErrorCode {
-///     r.err().unwrap().code
-/// }
-///
-/// let format = lexical::NumberFormat::RUST_STRING;
-///
-/// // String overloads
-/// assert_eq!(lexical::parse_format_radix::<i32, _>("5", 10, format), Ok(5));
-/// assert_eq!(err_code(lexical::parse_format_radix::<i32, _>("1a", 10, format)), ErrorCode::InvalidDigit);
-/// assert_eq!(err_code(lexical::parse_format_radix::<i32, _>("1.", 10, format)), ErrorCode::InvalidDigit);
-/// assert_eq!(lexical::parse_format_radix::<f32, _>("0", 10, format), Ok(0.0));
-/// assert_eq!(lexical::parse_format_radix::<f32, _>("1.0", 10, format), Ok(1.0));
-/// assert_eq!(lexical::parse_format_radix::<f32, _>("1.", 10, format), Ok(1.0));
-/// assert_eq!(err_code(lexical::parse_format_radix::<f32, _>("1a", 10, format)), ErrorCode::InvalidDigit);
-/// assert_eq!(err_code(lexical::parse_format_radix::<f32, _>("1.0.", 10, format)), ErrorCode::InvalidDigit);
-///
-/// // Bytes overloads
-/// assert_eq!(lexical::parse_format_radix::<i32, _>(b"5", 10, format), Ok(5));
-/// assert_eq!(err_code(lexical::parse_format_radix::<i32, _>(b"1a", 10, format)), ErrorCode::InvalidDigit);
-/// assert_eq!(lexical::parse_format_radix::<f32, _>(b"0", 10, format), Ok(0.0));
-/// assert_eq!(lexical::parse_format_radix::<f32, _>(b"1.0", 10, format), Ok(1.0));
-/// assert_eq!(lexical::parse_format_radix::<f32, _>(b"1.", 10, format), Ok(1.0));
-/// assert_eq!(err_code(lexical::parse_format_radix::<f32, _>(b"1a", 10, format)), ErrorCode::InvalidDigit);
-/// assert_eq!(err_code(lexical::parse_format_radix::<f32, _>(b"1.0.", 10, format)), ErrorCode::InvalidDigit);
+/// assert_eq!(lexical::parse_signed_parts::<u64, _>("-5"), Ok((true, 5, 2)));
+/// assert_eq!(lexical::parse_signed_parts::<u8, _>("255 "), Ok((false, 255, 3)));
 /// # }
 /// ```
-///
-/// # Panics
-///
-/// Panics if radix is not in the range `[2, 36]`
 #[inline]
-#[cfg(all(feature = "radix", feature = "format"))]
-pub fn parse_format_radix<N: FromLexicalFormat, Bytes: AsRef<[u8]>>(bytes: Bytes, radix: u8, format: NumberFormat)
-    -> Result<N>
+pub fn parse_signed_parts<N: FromLexical + UnsignedInteger, Bytes: AsRef<[u8]>>(bytes: Bytes)
+    -> Result<(bool, N, usize)>
 {
-    N::from_lexical_format_radix(bytes.as_ref(), radix, format)
+    lexical_core::parse_signed_parts::<N>(bytes.as_ref())
 }
 
-/// High-level, partial, format-dependent conversion of bytes to a number with a custom radix.
+/// High-level conversion of bytes with a leading or trailing currency symbol to a float.
 ///
-/// This functions parses as many digits as possible, returning the parsed
-/// value and the number of digits processed if at least one character
-/// is processed. If another error, such as numerical overflow or underflow
-/// occurs, this function returns the error code and the index at which
-/// the error occurred. The numerical format is specified by the format
-/// bitflags, which customize the required components, digit separators,
-/// and other parameters of the number.
+/// The first symbol that starts or ends the bytes is removed, and
+/// the remaining bytes are parsed. A sign may come before or after a
+/// leading symbol. Returns the value and the index of the symbol in
+/// `symbols`, or `None` if the bytes did not have a symbol.
 ///
 /// * `bytes`   - Byte slice to convert to number.
-/// * `radix`   - Number of unique digits for the number (base).
-/// * `format`  - Numerical format.
+/// * `symbols` - Currency symbols to remove.
 ///
 /// # Examples
 ///
 /// ```rust
 /// # extern crate lexical;
-/// # use lexical::ErrorCode;
 /// # pub fn main() {
-///
-/// let format = lexical::NumberFormat::RUST_STRING;
-///
-/// // String overloads
-/// assert_eq!(lexical::parse_partial_format_radix::<i32, _>("5", 10, format), Ok((5, 1)));
-/// assert_eq!(lexical::parse_partial_format_radix::<i32, _>("1a", 10, format), Ok((1, 1)));
-/// assert_eq!(lexical::parse_partial_format_radix::<i32, _>("1.", 10, format), Ok((1, 1)));
-/// assert_eq!(lexical::parse_partial_format_radix::<f32, _>("0", 10, format), Ok((0.0, 1)));
-/// assert_eq!(lexical::parse_partial_format_radix::<f32, _>("1.0", 10, format), Ok((1.0, 3)));
-/// assert_eq!(lexical::parse_partial_format_radix::<f32, _>("1.", 10, format), Ok((1.0, 2)));
-/// assert_eq!(lexical::parse_partial_format_radix::<f32, _>("1a", 10, format), Ok((1.0, 1)));
-/// assert_eq!(lexical::parse_partial_format_radix::<f32, _>("1.0.", 10, format), Ok((1.0, 3)));
-///
-/// // Bytes overloads
-/// assert_eq!(lexical::parse_partial_format_radix::<i32, _>(b"5", 10, format), Ok((5, 1)));
-/// assert_eq!(lexical::parse_partial_format_radix::<i32, _>(b"1a", 10, format), Ok((1, 1)));
-/// assert_eq!(lexical::parse_partial_format_radix::<i32, _>(b"1.", 10, format), Ok((1, 1)));
-/// assert_eq!(lexical::parse_partial_format_radix::<f32, _>(b"0", 10, format), Ok((0.0, 1)));
-/// assert_eq!(lexical::parse_partial_format_radix::<f32, _>(b"1.0", 10, format), Ok((1.0, 3)));
-/// assert_eq!(lexical::parse_partial_format_radix::<f32, _>(b"1.", 10, format), Ok((1.0, 2)));
-/// assert_eq!(lexical::parse_partial_format_radix::<f32, _>(b"1a", 10, format), Ok((1.0, 1)));
-/// assert_eq!(lexical::parse_partial_format_radix::<f32, _>(b"1.0.", 10, format), Ok((1.0, 3)));
+/// let symbols: &[&[u8]] = &[b"$", "€".as_bytes()];
+/// assert_eq!(lexical::parse_currency::<f64, _>("$1234.56", symbols), Ok((1234.56, Some(0))));
+/// assert_eq!(lexical::parse_currency::<f64, _>("1234.56€", symbols), Ok((1234.56, Some(1))));
+/// assert_eq!(lexical::parse_currency::<f64, _>("$-5", symbols), Ok((-5.0, Some(0))));
 /// # }
 /// ```
-///
-/// # Panics
-///
-/// Panics if radix is not in the range `[2, 36]`
 #[inline]
-#[cfg(all(feature = "radix", feature = "format"))]
-pub fn parse_partial_format_radix<N: FromLexicalFormat, Bytes: AsRef<[u8]>>(bytes: Bytes, radix: u8, format: NumberFormat)
-    -> Result<(N, usize)>
+#[cfg(feature = "currency")]
+pub fn parse_currency<N: FromLexical + Float, Bytes: AsRef<[u8]>>(bytes: Bytes, symbols: &[&[u8]])
+    -> Result<(N, Option<usize>)>
 {
-    N::from_lexical_partial_format_radix(bytes.as_ref(), radix, format)
+    lexical_core::parse_currency::<N>(bytes.as_ref(), symbols)
 }
 
-/// High-level, lossy, format-dependent conversion of bytes to a float with a custom radix.
+/// High-level conversion of bytes with a currency symbol to a float, using a custom format.
 ///
-/// This function uses aggressive optimizations to avoid worst-case
-/// scenarios, and can return inaccurate results. For guaranteed accurate
-/// floats, use [`parse_format_radix`]. The numerical format is specified
-/// by the format bitflags, which customize the required components,
-/// digit separators, and other parameters of the number.
+/// Like [`parse_currency`], but the remaining bytes are parsed with the
+/// number format, such as [`NumberFormat::grouping`] for digit groups.
 ///
 /// * `bytes`   - Byte slice to convert to number.
-/// * `radix`   - Number of unique digits for the number (base).
+/// * `symbols` - Currency symbols to remove.
 /// * `format`  - Numerical format.
 ///
 /// # Examples
 ///
 /// ```rust
 /// # extern crate lexical;
-/// # use lexical::ErrorCode;
+/// # use lexical::NumberFormat;
 /// # pub fn main() {
-/// // Create our error wrapper.
-/// fn err_code<T>(r: lexical::Result<T>) -> ErrorCode {
-///     r.err().unwrap().code
-/// }
+/// let format = NumberFormat::grouping(b',', true).unwrap();
+/// assert_eq!(lexical::parse_currency_format::<f64, _>("$1,234.56", &[b"$"], format), Ok((1234.56, Some(0))));
+/// # }
+/// ```
 ///
-/// let format = lexical::NumberFormat::RUST_STRING;
+/// [`parse_currency`]: fn.parse_currency.html
+/// [`NumberFormat::grouping`]: struct.NumberFormat.html#method.grouping
+#[inline]
+#[cfg(all(feature = "currency", feature = "format"))]
+pub fn parse_currency_format<N: FromLexicalFormat + Float, Bytes: AsRef<[u8]>>(bytes: Bytes, symbols: &[&[u8]], format: NumberFormat)
+    -> Result<(N, Option<usize>)>
+{
+    lexical_core::parse_currency_format::<N>(bytes.as_ref(), symbols, format)
+}
+
+/// High-level search for the first decimal-encoded number in bytes.
 ///
-/// // String overloads
-/// assert_eq!(lexical::parse_lossy_format_radix::<f32, _>("0", 10, format), Ok(0.0));
-/// assert_eq!(lexical::parse_lossy_format_radix::<f32, _>("1.0", 10, format), Ok(1.0));
-/// assert_eq!(lexical::parse_lossy_format_radix::<f32, _>("1.", 10, format), Ok(1.0));
-/// assert_eq!(err_code(lexical::parse_lossy_format_radix::<f32, _>("1a", 10, format)), ErrorCode::InvalidDigit);
-/// assert_eq!(err_code(lexical::parse_lossy_format_radix::<f32, _>("1.0.", 10, format)), ErrorCode::InvalidDigit);
+/// Scans forward to the first position where a valid number begins,
+/// a digit, sign, or `.`, and parses as much of the number as possible.
+/// Returns the parsed value and the range of bytes it was parsed from,
+/// or `None` if no number is found. Unlike [`parse_partial`], leading
+/// non-numeric data is skipped.
 ///
-/// // Bytes overloads
-/// assert_eq!(lexical::parse_lossy_format_radix::<f32, _>(b"0", 10, format), Ok(0.0));
-/// assert_eq!(lexical::parse_lossy_format_radix::<f32, _>(b"1.0", 10, format), Ok(1.0));
-/// assert_eq!(lexical::parse_lossy_format_radix::<f32, _>(b"1.", 10, format), Ok(1.0));
-/// assert_eq!(err_code(lexical::parse_lossy_format_radix::<f32, _>(b"1a", 10, format)), ErrorCode::InvalidDigit);
-/// assert_eq!(err_code(lexical::parse_lossy_format_radix::<f32, _>(b"1.0.", 10, format)), ErrorCode::InvalidDigit);
-/// # }
-/// ```
+/// * `bytes`   - Byte slice to search for a number.
 ///
-/// # Panics
+/// # Examples
 ///
-/// Panics if radix is not in the range `[2, 36]`
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// assert_eq!(lexical::find_number::<f64, _>("abc-12.5xyz"), Some((-12.5, 3..8)));
+/// assert_eq!(lexical::find_number::<u32, _>(b"latency=35ms"), Some((35, 8..10)));
+/// assert_eq!(lexical::find_number::<f64, _>("n/a"), None);
+/// # }
+/// ```
 ///
-/// [`parse_format_radix`]: fn.parse_format_radix.html
+/// [`parse_partial`]: fn.parse_partial.html
 #[inline]
-#[cfg(all(feature = "radix", feature = "format"))]
-pub fn parse_lossy_format_radix<N: FromLexicalLossyFormat, Bytes: AsRef<[u8]>>(bytes: Bytes, radix: u8, format: NumberFormat)
-    -> Result<N>
+#[cfg(feature = "find")]
+pub fn find_number<N: FromLexical, Bytes: AsRef<[u8]>>(bytes: Bytes)
+    -> Option<(N, lib::ops::Range<usize>)>
 {
-    N::from_lexical_lossy_format_radix(bytes.as_ref(), radix, format)
+    lexical_core::find_number(bytes.as_ref())
 }
 
-/// High-level, partial, lossy, format-dependent conversion of bytes to a number with a custom radix.
+/// Find the sample floats that do not round-trip with custom options.
 ///
-/// This function uses aggressive optimizations to avoid worst-case
-/// scenarios, and can return inaccurate results. For guaranteed accurate
-/// floats, use [`parse_partial_format_radix`]. The numerical format is
-/// specified by the format bitflags, which customize the required
-/// components, digit separators, and other parameters of the number.
+/// Each sample is written with the write options, like
+/// [`to_string_with_options`], and parsed back with the parse options,
+/// like [`parse_with_options`]. Returns the samples that fail to parse,
+/// or parse to a different float, in the order of the samples, so an
+/// empty result means every sample round-trips. This catches options
+/// that do not agree, like a write exponent string the parse options
+/// do not parse, in a single call.
 ///
-/// This functions parses as many digits as possible, returning the parsed
-/// value and the number of digits processed if at least one character
-/// is processed. If another error, such as numerical overflow or underflow
-/// occurs, this function returns the error code and the index at which
-/// the error occurred.
+/// Floats are compared by their bits, since `NaN` is never equal to
+/// itself, so `NaN` round-trips, but `-0.0` parsed as `0.0` does not.
+/// NaN payloads are not written, so a NaN with a payload only
+/// round-trips through a sentinel string.
 ///
-/// * `bytes`   - Byte slice to convert to number.
-/// * `radix`   - Number of unique digits for the number (base).
+/// * `parse_options`   - Options to parse the written samples.
+/// * `write_options`   - Options to write the samples.
+/// * `samples`         - Floats to round-trip.
 ///
 /// # Examples
 ///
 /// ```rust
 /// # extern crate lexical;
-/// # use lexical::ErrorCode;
+/// # use lexical::{ParseFloatOptions, WriteFloatOptions};
 /// # pub fn main() {
+/// let samples = [1.5, -0.25, 1e300, std::f64::NAN];
+/// let parse_options = ParseFloatOptions::new();
+/// let write_options = WriteFloatOptions::builder().exponent_string(b"x10^").build().unwrap();
+/// assert_eq!(lexical::audit_options(&parse_options, &write_options, &samples), vec![1e300]);
 ///
-/// let format = lexical::NumberFormat::RUST_STRING;
-///
-/// // String overloads
-/// assert_eq!(lexical::parse_partial_lossy_format_radix::<f32, _>("0", 10, format), Ok((0.0, 1)));
-/// assert_eq!(lexical::parse_partial_lossy_format_radix::<f32, _>("1.0", 10, format), Ok((1.0, 3)));
-/// assert_eq!(lexical::parse_partial_lossy_format_radix::<f32, _>("1.", 10, format), Ok((1.0, 2)));
-/// assert_eq!(lexical::parse_partial_lossy_format_radix::<f32, _>("1a", 10, format), Ok((1.0, 1)));
-/// assert_eq!(lexical::parse_partial_lossy_format_radix::<f32, _>("1.0.", 10, format), Ok((1.0, 3)));
-///
-/// // Bytes overloads
-/// assert_eq!(lexical::parse_partial_lossy_format_radix::<f32, _>(b"0", 10, format), Ok((0.0, 1)));
-/// assert_eq!(lexical::parse_partial_lossy_format_radix::<f32, _>(b"1.0", 10, format), Ok((1.0, 3)));
-/// assert_eq!(lexical::parse_partial_lossy_format_radix::<f32, _>(b"1.", 10, format), Ok((1.0, 2)));
-/// assert_eq!(lexical::parse_partial_lossy_format_radix::<f32, _>(b"1a", 10, format), Ok((1.0, 1)));
-/// assert_eq!(lexical::parse_partial_lossy_format_radix::<f32, _>(b"1.0.", 10, format), Ok((1.0, 3)));
+/// let parse_options = ParseFloatOptions::builder().exponent_string(b"x10^").build().unwrap();
+/// assert!(lexical::audit_options(&parse_options, &write_options, &samples).is_empty());
 /// # }
 /// ```
 ///
-/// # Panics
-///
-/// Panics if radix is not in the range `[2, 36]`
-///
-/// [`parse_partial_format_radix`]: fn.parse_partial_format_radix.html
-#[inline]
-#[cfg(all(feature = "radix", feature = "format"))]
-pub fn parse_partial_lossy_format_radix<N: FromLexicalLossyFormat, Bytes: AsRef<[u8]>>(bytes: Bytes, radix: u8, format: NumberFormat)
-    -> Result<(N, usize)>
+/// [`to_string_with_options`]: fn.to_string_with_options.html
+/// [`parse_with_options`]: fn.parse_with_options.html
+#[cfg(feature = "testing")]
+pub fn audit_options(parse_options: &ParseFloatOptions, write_options: &WriteFloatOptions, samples: &[f64])
+    -> lib::Vec<f64>
 {
-    N::from_lexical_partial_lossy_format_radix(bytes.as_ref(), radix, format)
+    let mut buffer = lib::Vec::new();
+    buffer.resize(f64::formatted_size_with_options(10, write_options), b'\0');
+    samples.iter().cloned().filter(|&sample| {
+        let written = lexical_core::write_with_options(sample, &mut buffer, write_options);
+        match f64::from_lexical_with_options(written, parse_options) {
+            Ok(parsed) => parsed.to_bits() != sample.to_bits(),
+            Err(_)     => true,
+        }
+    }).collect()
 }
@@ -0,0 +1,93 @@
+//! Force fixed or scientific notation for float strings.
+
+use crate::lib::{String, Vec};
+use crate::lib::iter;
+use crate::budget::compose;
+use lexical_core::get_exponent_default_char;
+
+// HELPERS
+
+// Split a decimal float string into the sign, significant digits, and scientific exponent.
+//
+// The scientific exponent is the power of 10 of the first significant
+// digit, and zero is represented by a single `0` digit.
+pub(crate) fn decompose(string: &str) -> Option<(&str, Vec<u8>, i32)> {
+    let (sign, rest) = match string.starts_with('-') {
+        true  => ("-", &string[1..]),
+        false => ("", string),
+    };
+    let exponent_char = get_exponent_default_char() as char;
+    let (mantissa, exponent) = match rest.find(exponent_char) {
+        Some(index) => (&rest[..index], rest[index+1..].parse::<i32>().ok()?),
+        None        => (rest, 0),
+    };
+    let (integer, fraction) = match mantissa.find('.') {
+        Some(index) => (&mantissa[..index], &mantissa[index+1..]),
+        None        => (mantissa, ""),
+    };
+    if integer.is_empty() || !mantissa.bytes().all(|c| c.is_ascii_digit() || c == b'.') {
+        // Special values do not have any digits.
+        return None;
+    }
+
+    let digits: Vec<u8> = integer.bytes().chain(fraction.bytes()).collect();
+    let leading = digits.iter().take_while(|&&c| c == b'0').count();
+    if leading == digits.len() {
+        return Some((sign, b"0".to_vec(), 0));
+    }
+    let trailing = digits.iter().rev().take_while(|&&c| c == b'0').count();
+    let exponent = exponent + integer.len() as i32 - 1 - leading as i32;
+    Some((sign, digits[leading..digits.len()-trailing].to_vec(), exponent))
+}
+
+// Write the digits in fixed notation.
+fn fixed(sign: &str, digits: &[u8], exponent: i32) -> String {
+    let mut string = match exponent {
+        exponent if exponent < 0 => {
+            let fraction: Vec<u8> = iter::repeat(b'0').take((-exponent - 1) as usize)
+                .chain(digits.iter().cloned())
+                .collect();
+            compose(sign, b"0", &fraction, None)
+        },
+        exponent if (exponent as usize) < digits.len() => {
+            let (integer, fraction) = digits.split_at(exponent as usize + 1);
+            compose(sign, integer, fraction, None)
+        },
+        exponent => {
+            let integer: Vec<u8> = digits.iter().cloned()
+                .chain(iter::repeat(b'0').take(exponent as usize + 1 - digits.len()))
+                .collect();
+            compose(sign, &integer, b"", None)
+        },
+    };
+    if !cfg!(feature = "trim_floats") && !string.contains('.') {
+        string.push_str(".0");
+    }
+    string
+}
+
+// Write the digits in scientific notation.
+pub(crate) fn scientific(sign: &str, digits: &[u8], exponent: i32) -> String {
+    let (integer, fraction) = digits.split_at(1);
+    compose(sign, integer, fraction, Some(exponent))
+}
+
+// API
+
+// Write the shortest float string in fixed notation.
+pub(crate) fn to_fixed(string: String) -> String {
+    match decompose(&string) {
+        Some((sign, digits, exponent)) => fixed(sign, &digits, exponent),
+        None                           => string,
+    }
+}
+
+// Write the shortest float string in scientific notation, if the
+// absolute value of the exponent is at least the threshold.
+pub(crate) fn to_scientific(string: String, threshold: u32) -> String {
+    match decompose(&string) {
+        Some((sign, digits, exponent)) if exponent.abs() as u32 >= threshold => scientific(sign, &digits, exponent),
+        Some((sign, digits, exponent)) => fixed(sign, &digits, exponent),
+        None                           => string,
+    }
+}
@@ -0,0 +1,39 @@
+//! Pad number strings to a fixed field width.
+
+use crate::lib::String;
+use crate::lib::iter;
+
+/// Alignment of a number string within a padded field.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Align {
+    /// Write the number at the start of the field, followed by the fill.
+    Left,
+    /// Write the fill, followed by the number at the end of the field.
+    Right,
+    /// Write the number in the middle of the field.
+    ///
+    /// If the fill cannot be split evenly, the extra fill character
+    /// is written after the number.
+    Center,
+}
+
+// HELPERS
+
+// Pad the ASCII string with the fill character to at least `width` characters.
+pub(crate) fn pad(string: String, width: usize, fill: char, align: Align) -> String {
+    // Number strings are ASCII, so the length is the number of characters.
+    let count = width.saturating_sub(string.len());
+    if count == 0 {
+        return string;
+    }
+    let (before, after) = match align {
+        Align::Left     => (0, count),
+        Align::Right    => (count, 0),
+        Align::Center   => (count / 2, count - count / 2),
+    };
+    let mut padded = String::with_capacity(string.len() + count * fill.len_utf8());
+    padded.extend(iter::repeat(fill).take(before));
+    padded.push_str(&string);
+    padded.extend(iter::repeat(fill).take(after));
+    padded
+}
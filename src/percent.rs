@@ -0,0 +1,36 @@
+//! Write floats as percentages with a fixed number of decimal places.
+
+use crate::lib::String;
+use crate::budget::compose;
+use crate::digits::round_fixed;
+use crate::notation::decompose;
+
+// API
+
+// Write the float as a percentage, with exactly `decimal_places`
+// fraction digits, followed by a percent sign.
+//
+// The float is scaled by 100 exactly, by shifting the exponent of the
+// shortest digits, and then rounded half away from zero.
+pub(crate) fn to_percent(n: f64, decimal_places: usize) -> String {
+    let mut string = crate::to_string(n);
+    let (sign, digits, exponent) = match decompose(&string) {
+        Some(parts) => parts,
+        None        => {
+            string.push('%');
+            return string;
+        },
+    };
+
+    let (fixed, integer_len) = round_fixed(&digits, exponent + 2, decimal_places);
+
+    // Do not write the sign of values that round to zero.
+    let sign = match fixed.iter().all(|&c| c == b'0') {
+        true  => "",
+        false => sign,
+    };
+    let (integer, fraction) = fixed.split_at(integer_len);
+    let mut string = compose(sign, integer, fraction, None);
+    string.push('%');
+    string
+}
@@ -0,0 +1,65 @@
+//! Shorten float strings to the fewest digits within a tolerance.
+
+use crate::lib::String;
+use crate::budget::round_digits;
+use crate::notation::{decompose, scientific};
+
+// HELPERS
+
+// Write the fewest significant digits that reparse to a float within
+// the relative tolerance of the float.
+//
+// The shortest digits are rounded to each number of significant digits,
+// half away from zero, and the rounded float is written as its shortest
+// string, until the string reparses within the tolerance.
+fn to_precision_with<Parse>(n: f64, rel_tol: f64, parse: Parse) -> String
+    where Parse: Fn(&str) -> Option<f64>
+{
+    let string = crate::to_string(n);
+    if !(rel_tol > 0.0) {
+        // Zero, negative, and NaN tolerances write the shortest digits.
+        return string;
+    }
+    let (sign, digits, exponent) = match decompose(&string) {
+        Some(parts) => parts,
+        None        => return string,
+    };
+
+    let tolerance = rel_tol * n.abs();
+    for count in 1..digits.len() {
+        let mut rounded = digits.clone();
+        let mut exponent = exponent;
+        if round_digits(&mut rounded, count) {
+            // Carried into a new digit, like `9.9` to `10`.
+            rounded.insert(0, b'1');
+            exponent += 1;
+        }
+        let value: f64 = match crate::parse(scientific(sign, &rounded[..count], exponent)) {
+            Ok(value) => value,
+            Err(_)    => continue,
+        };
+        if !value.is_finite() {
+            continue;
+        }
+        let candidate = crate::to_string(value);
+        match parse(&candidate) {
+            Some(value) if (value - n).abs() <= tolerance => return candidate,
+            _                                             => continue,
+        }
+    }
+    string
+}
+
+// API
+
+// Write the fewest significant digits that parse to a float within
+// the relative tolerance of the float.
+pub(crate) fn to_precision(n: f64, rel_tol: f64) -> String {
+    to_precision_with(n, rel_tol, |string| crate::parse(string).ok())
+}
+
+// Write the fewest significant digits that parse, with the lossy
+// parser, to a float within the relative tolerance of the float.
+pub(crate) fn to_lossy(n: f64, max_rel_error: f64) -> String {
+    to_precision_with(n, max_rel_error, |string| crate::parse_lossy(string).ok())
+}